@@ -0,0 +1,65 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Converts a directory of Databento DBN files into a Nautilus Parquet data catalog.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use nautilus_databento::{
+    converter::convert_dbn_directory_to_catalog, loader::DatabentoDataLoader,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Convert Databento DBN files to a Parquet data catalog", long_about = None)]
+struct Args {
+    /// Directory containing the DBN files (`*.dbn.zst`) to convert.
+    input_dir: PathBuf,
+    /// Root path of the Parquet data catalog to write to.
+    catalog_path: PathBuf,
+    /// Path to the Databento publishers JSON file (defaults to `publishers.json` next to the binary).
+    #[arg(long)]
+    publishers: Option<PathBuf>,
+    /// Use the instrument exchange as the venue for GLBX definitions.
+    #[arg(long)]
+    use_exchange_as_venue: bool,
+    /// Number of worker threads to convert files with (defaults to the available parallelism).
+    #[arg(long)]
+    workers: Option<usize>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let mut loader = DatabentoDataLoader::new(args.publishers)?;
+    let written = convert_dbn_directory_to_catalog(
+        &mut loader,
+        &args.input_dir,
+        &args.catalog_path,
+        args.use_exchange_as_venue,
+        args.workers,
+    )?;
+
+    for path in &written {
+        println!("{}", path.display());
+    }
+    println!(
+        "Wrote {} files to {}",
+        written.len(),
+        args.catalog_path.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,658 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bulk conversion of Databento DBN files into a Nautilus Parquet data catalog.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use arrow::record_batch::RecordBatch;
+use databento::dbn;
+use indexmap::{map::Entry, IndexMap};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{
+        AuctionImbalance, Bar, Data, GetTsInit, MarketStatistic, OrderBookDelta, OrderBookDepth10,
+        QuoteTick, TradeTick,
+    },
+    identifiers::InstrumentId,
+};
+use nautilus_persistence::backend::catalog::CatalogPathPrefix;
+use nautilus_serialization::{arrow::EncodeToRecordBatch, parquet::create_parquet_writer};
+use parquet::arrow::ArrowWriter;
+
+use crate::loader::DatabentoDataLoader;
+
+const DBN_FILE_EXTENSION: &str = ".dbn.zst";
+const DECODE_CHUNK_SIZE: usize = 100_000;
+
+/// Converts all DBN files (`*.dbn.zst`) in `input_dir` into partitioned Parquet files in the
+/// catalog at `catalog_path`, returning the paths of the written files.
+///
+/// The schema of each file is detected from its metadata. Instrument definition files are
+/// decoded first so that market data for those instruments is decoded with the correct price
+/// precision. Market data files are then decoded and written in parallel across `num_workers`
+/// threads (defaults to the available parallelism), with each file streamed in chunks so that
+/// memory use does not grow with the file size.
+///
+/// Each file is written to `data/<type>/<instrument_id>/<file_stem>.parquet`, so that multiple
+/// files for the same instrument and data type are partitioned rather than overwritten. Any
+/// characters in the file stem which are not valid in a catalog table name (such as `.` and `-`)
+/// are replaced with `_`, so the written files can be queried directly by the catalog.
+/// Instrument definitions themselves are not written, and files with schemas which have no
+/// catalog representation (such as `status`) are skipped.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The `input_dir` cannot be read.
+/// - The schema of a file cannot be detected.
+/// - A file fails to decode.
+/// - Decoded data is not in ascending `ts_init` order within a partition.
+/// - A Parquet file fails to write.
+pub fn convert_dbn_directory_to_catalog(
+    loader: &mut DatabentoDataLoader,
+    input_dir: &Path,
+    catalog_path: &Path,
+    use_exchange_as_venue: bool,
+    num_workers: Option<usize>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut definition_files = Vec::new();
+    let mut data_files = Vec::new();
+
+    for filepath in find_dbn_files(input_dir)? {
+        let schema = loader
+            .schema_from_file(&filepath)?
+            .ok_or_else(|| anyhow::anyhow!("No schema in metadata for {filepath:?}"))?;
+        let schema = dbn::Schema::from_str(&schema)?;
+        match schema {
+            dbn::Schema::Definition => definition_files.push(filepath),
+            _ => data_files.push((filepath, schema)),
+        }
+    }
+
+    // Definitions update the loaders symbol venue map, so must be decoded before market data
+    let mut price_precisions = HashMap::new();
+    for filepath in &definition_files {
+        for instrument in loader.load_instruments(filepath, use_exchange_as_venue)? {
+            price_precisions.insert(instrument.id(), instrument.price_precision());
+        }
+    }
+    log::info!(
+        "Decoded {} instruments from {} definition files",
+        price_precisions.len(),
+        definition_files.len(),
+    );
+
+    let num_workers = num_workers
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from))
+        .clamp(1, data_files.len().max(1));
+    let next_file = AtomicUsize::new(0);
+    let loader: &DatabentoDataLoader = loader;
+
+    let results: Vec<anyhow::Result<Vec<PathBuf>>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..num_workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut written = Vec::new();
+                    loop {
+                        let i = next_file.fetch_add(1, Ordering::Relaxed);
+                        let Some((filepath, schema)) = data_files.get(i) else {
+                            break;
+                        };
+                        written.extend(convert_dbn_file(
+                            loader,
+                            catalog_path,
+                            filepath,
+                            *schema,
+                            &price_precisions,
+                        )?);
+                    }
+                    Ok(written)
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("DBN conversion worker panicked")))
+            })
+            .collect()
+    });
+
+    let mut written = Vec::new();
+    for result in results {
+        written.extend(result?);
+    }
+    written.sort();
+
+    Ok(written)
+}
+
+fn find_dbn_files(input_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut filepaths = Vec::new();
+    for entry in fs::read_dir(input_dir)? {
+        let path = entry?.path();
+        if path.is_file() && file_stem(&path).is_some() {
+            filepaths.push(path);
+        }
+    }
+    filepaths.sort();
+    Ok(filepaths)
+}
+
+fn file_stem(filepath: &Path) -> Option<&str> {
+    filepath
+        .file_name()?
+        .to_str()?
+        .strip_suffix(DBN_FILE_EXTENSION)
+}
+
+fn convert_dbn_file(
+    loader: &DatabentoDataLoader,
+    catalog_path: &Path,
+    filepath: &Path,
+    schema: dbn::Schema,
+    price_precisions: &HashMap<InstrumentId, u8>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let Some(mut records) = decode_dbn_file(loader, filepath, schema, price_precisions)? else {
+        log::warn!("Skipping {filepath:?}, schema {schema} not supported for the catalog");
+        return Ok(Vec::new());
+    };
+
+    // SAFETY: Only files with the DBN extension are converted
+    let file_stem = sanitize_file_stem(file_stem(filepath).unwrap());
+    let mut writer = CatalogFileWriter::new(catalog_path, &file_stem);
+    let mut count = 0;
+    loop {
+        let chunk = records
+            .by_ref()
+            .take(DECODE_CHUNK_SIZE)
+            .collect::<anyhow::Result<Vec<Data>>>()?;
+        if chunk.is_empty() {
+            break;
+        }
+        count += chunk.len();
+        writer.write(chunk)?;
+    }
+    log::info!("Decoded {count} records from {filepath:?}");
+
+    writer.finish()
+}
+
+/// The catalog uses the file stem as the table name when querying, so only
+/// alphanumeric characters and underscores are retained.
+fn sanitize_file_stem(file_stem: &str) -> String {
+    file_stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+type DataIterator<'a> = Box<dyn Iterator<Item = anyhow::Result<Data>> + 'a>;
+
+fn decode_dbn_file<'a>(
+    loader: &'a DatabentoDataLoader,
+    filepath: &Path,
+    schema: dbn::Schema,
+    price_precisions: &'a HashMap<InstrumentId, u8>,
+) -> anyhow::Result<Option<DataIterator<'a>>> {
+    let precisions = Some(price_precisions);
+    let records = match schema {
+        dbn::Schema::Mbo => flatten_records(loader.read_records_with_precisions::<dbn::MboMsg>(
+            filepath, None, precisions, None, false,
+        )?),
+        dbn::Schema::Mbp1 => flatten_records(loader.read_records_with_precisions::<dbn::Mbp1Msg>(
+            filepath, None, precisions, None, false,
+        )?),
+        dbn::Schema::Mbp10 => {
+            flatten_records(loader.read_records_with_precisions::<dbn::Mbp10Msg>(
+                filepath, None, precisions, None, false,
+            )?)
+        }
+        dbn::Schema::Tbbo => flatten_records(loader.read_records_with_precisions::<dbn::TbboMsg>(
+            filepath, None, precisions, None, true,
+        )?),
+        dbn::Schema::Bbo1S | dbn::Schema::Bbo1M => {
+            flatten_records(loader.read_records_with_precisions::<dbn::BboMsg>(
+                filepath, None, precisions, None, false,
+            )?)
+        }
+        dbn::Schema::Trades => {
+            flatten_records(loader.read_records_with_precisions::<dbn::TradeMsg>(
+                filepath, None, precisions, None, false,
+            )?)
+        }
+        dbn::Schema::Ohlcv1S
+        | dbn::Schema::Ohlcv1M
+        | dbn::Schema::Ohlcv1H
+        | dbn::Schema::Ohlcv1D => {
+            flatten_records(loader.read_records_with_precisions::<dbn::OhlcvMsg>(
+                filepath, None, precisions, None, false,
+            )?)
+        }
+        dbn::Schema::Imbalance => Box::new(
+            loader
+                .read_imbalance_records_with_precisions::<dbn::ImbalanceMsg>(
+                    filepath, None, precisions, None,
+                )?
+                .map(|result| {
+                    result.map(|imbalance| Data::from(AuctionImbalance::from(&imbalance)))
+                }),
+        ),
+        dbn::Schema::Statistics => Box::new(
+            loader
+                .read_statistics_records_with_precisions::<dbn::StatMsg>(
+                    filepath, None, precisions, None,
+                )?
+                .filter_map(|result| {
+                    result
+                        .map(|stats| stats.to_market_statistic().map(Data::from))
+                        .transpose()
+                }),
+        ),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(records))
+}
+
+fn flatten_records<'a>(
+    records: impl Iterator<Item = anyhow::Result<(Option<Data>, Option<Data>)>> + 'a,
+) -> DataIterator<'a> {
+    Box::new(records.flat_map(|record| {
+        let (item1, item2) = match record {
+            Ok((item1, item2)) => (item1.map(Ok), item2.map(Ok)),
+            Err(e) => (Some(Err(e)), None),
+        };
+        item1.into_iter().chain(item2)
+    }))
+}
+
+/// Writes the data decoded from a single DBN file to the catalog, one Parquet file per data
+/// type and partition key.
+struct CatalogFileWriter {
+    deltas: PartitionedParquetWriter<OrderBookDelta>,
+    depths: PartitionedParquetWriter<OrderBookDepth10>,
+    quotes: PartitionedParquetWriter<QuoteTick>,
+    trades: PartitionedParquetWriter<TradeTick>,
+    bars: PartitionedParquetWriter<Bar>,
+    imbalances: PartitionedParquetWriter<AuctionImbalance>,
+    statistics: PartitionedParquetWriter<MarketStatistic>,
+}
+
+impl CatalogFileWriter {
+    fn new(catalog_path: &Path, file_stem: &str) -> Self {
+        Self {
+            deltas: PartitionedParquetWriter::new(catalog_path, file_stem, |d| {
+                d.instrument_id.to_string()
+            }),
+            depths: PartitionedParquetWriter::new(catalog_path, file_stem, |d| {
+                d.instrument_id.to_string()
+            }),
+            quotes: PartitionedParquetWriter::new(catalog_path, file_stem, |q| {
+                q.instrument_id.to_string()
+            }),
+            trades: PartitionedParquetWriter::new(catalog_path, file_stem, |t| {
+                t.instrument_id.to_string()
+            }),
+            bars: PartitionedParquetWriter::new(catalog_path, file_stem, |b| {
+                b.bar_type.to_string()
+            }),
+            imbalances: PartitionedParquetWriter::new(catalog_path, file_stem, |i| {
+                i.instrument_id.to_string()
+            }),
+            statistics: PartitionedParquetWriter::new(catalog_path, file_stem, |s| {
+                s.instrument_id.to_string()
+            }),
+        }
+    }
+
+    fn write(&mut self, data: Vec<Data>) -> anyhow::Result<()> {
+        let mut deltas: Vec<OrderBookDelta> = Vec::new();
+        let mut depths: Vec<OrderBookDepth10> = Vec::new();
+        let mut quotes: Vec<QuoteTick> = Vec::new();
+        let mut trades: Vec<TradeTick> = Vec::new();
+        let mut bars: Vec<Bar> = Vec::new();
+        let mut imbalances: Vec<AuctionImbalance> = Vec::new();
+        let mut statistics: Vec<MarketStatistic> = Vec::new();
+
+        for d in data {
+            match d {
+                Data::Delta(delta) => deltas.push(delta),
+                Data::Depth10(depth) => depths.push(depth),
+                Data::Quote(quote) => quotes.push(quote),
+                Data::Trade(trade) => trades.push(trade),
+                Data::Bar(bar) => bars.push(bar),
+                Data::AuctionImbalance(imbalance) => imbalances.push(imbalance),
+                Data::MarketStatistic(statistic) => statistics.push(statistic),
+                _ => anyhow::bail!("Unexpected data decoded from DBN file: {d:?}"),
+            }
+        }
+
+        self.deltas.write(deltas)?;
+        self.depths.write(depths)?;
+        self.quotes.write(quotes)?;
+        self.trades.write(trades)?;
+        self.bars.write(bars)?;
+        self.imbalances.write(imbalances)?;
+        self.statistics.write(statistics)
+    }
+
+    fn finish(self) -> anyhow::Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        written.extend(self.deltas.finish()?);
+        written.extend(self.depths.finish()?);
+        written.extend(self.quotes.finish()?);
+        written.extend(self.trades.finish()?);
+        written.extend(self.bars.finish()?);
+        written.extend(self.imbalances.finish()?);
+        written.extend(self.statistics.finish()?);
+        Ok(written)
+    }
+}
+
+/// Incrementally writes data to one Parquet file per partition key, at
+/// `data/<type>/<partition_key>/<file_stem>.parquet` under the catalog path.
+///
+/// Data must be in ascending `ts_init` order within each partition, which holds for DBN files
+/// as these are ordered by their index timestamp.
+struct PartitionedParquetWriter<T> {
+    directory: PathBuf,
+    file_name: String,
+    partition_key: fn(&T) -> String,
+    partitions: IndexMap<String, PartitionFile>,
+}
+
+impl<T> PartitionedParquetWriter<T>
+where
+    T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+{
+    fn new(catalog_path: &Path, file_stem: &str, partition_key: fn(&T) -> String) -> Self {
+        Self {
+            directory: catalog_path.join("data").join(T::path_prefix()),
+            file_name: format!("{file_stem}.parquet"),
+            partition_key,
+            partitions: IndexMap::new(),
+        }
+    }
+
+    fn write(&mut self, data: Vec<T>) -> anyhow::Result<()> {
+        let mut chunks: IndexMap<String, Vec<T>> = IndexMap::new();
+        for item in data {
+            chunks
+                .entry((self.partition_key)(&item))
+                .or_default()
+                .push(item);
+        }
+
+        for (key, items) in chunks {
+            let metadata = T::chunk_metadata(&items);
+            let batch = T::encode_batch(&metadata, &items)?;
+
+            let partition = match self.partitions.entry(key) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let path = self.directory.join(entry.key()).join(&self.file_name);
+                    let partition = PartitionFile::create(path, &batch)?;
+                    entry.insert(partition)
+                }
+            };
+            partition.write(&items, &batch)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> anyhow::Result<Vec<PathBuf>> {
+        self.partitions
+            .into_values()
+            .map(PartitionFile::commit)
+            .collect()
+    }
+}
+
+/// A Parquet file being written to a temporary path, which is only renamed to its final path
+/// once committed. The temporary file is removed if dropped without being committed, so a
+/// failed conversion never leaves a partial file in the catalog.
+struct PartitionFile {
+    writer: Option<ArrowWriter<File>>,
+    path: PathBuf,
+    temp_path: PathBuf,
+    last_ts_init: UnixNanos,
+}
+
+impl PartitionFile {
+    fn create(path: PathBuf, batch: &RecordBatch) -> anyhow::Result<Self> {
+        let temp_path = path.with_extension("parquet.tmp");
+        let writer = create_parquet_writer(batch.schema(), &temp_path, None, None)
+            .map_err(|e| anyhow::anyhow!("{e}"))?;
+        Ok(Self {
+            writer: Some(writer),
+            path,
+            temp_path,
+            last_ts_init: UnixNanos::default(),
+        })
+    }
+
+    fn write<T: GetTsInit>(&mut self, items: &[T], batch: &RecordBatch) -> anyhow::Result<()> {
+        for item in items {
+            anyhow::ensure!(
+                self.last_ts_init <= item.ts_init(),
+                "Timestamps must be in ascending order for {:?}",
+                self.path
+            );
+            self.last_ts_init = item.ts_init();
+        }
+
+        // SAFETY: The writer is only taken when committing, which consumes the file
+        self.writer.as_mut().unwrap().write(batch)?;
+        Ok(())
+    }
+
+    fn commit(mut self) -> anyhow::Result<PathBuf> {
+        if let Some(writer) = self.writer.take() {
+            writer.close()?;
+        }
+        fs::rename(&self.temp_path, &self.path)?;
+        Ok(self.path.clone())
+    }
+}
+
+impl Drop for PartitionFile {
+    fn drop(&mut self) {
+        // Committed files have been renamed, so this only removes incomplete files
+        self.writer.take();
+        let _ = fs::remove_file(&self.temp_path);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::to_variant,
+        enums::AggressorSide,
+        identifiers::TradeId,
+        types::{Price, Quantity},
+    };
+    use nautilus_persistence::backend::catalog::ParquetDataCatalog;
+    use rstest::*;
+
+    use super::*;
+
+    fn test_data_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data")
+    }
+
+    fn data_loader() -> DatabentoDataLoader {
+        let publishers_filepath = Path::new(env!("CARGO_MANIFEST_DIR")).join("publishers.json");
+        DatabentoDataLoader::new(Some(publishers_filepath)).unwrap()
+    }
+
+    fn copy_test_files(dir: &Path, filenames: &[&str]) {
+        for filename in filenames {
+            fs::copy(test_data_path().join(filename), dir.join(filename)).unwrap();
+        }
+    }
+
+    #[rstest]
+    #[case("test_data.mbo", "test_data_mbo")]
+    #[case("glbx-mdp3-20240101.ohlcv-1m", "glbx_mdp3_20240101_ohlcv_1m")]
+    fn test_sanitize_file_stem(#[case] file_stem: &str, #[case] expected: &str) {
+        assert_eq!(sanitize_file_stem(file_stem), expected);
+    }
+
+    #[rstest]
+    fn test_find_dbn_files_filters_by_extension() {
+        let input_dir = tempfile::tempdir().unwrap();
+        copy_test_files(
+            input_dir.path(),
+            &["test_data.mbo.dbn", "test_data.trades.dbn.zst"],
+        );
+
+        let filepaths = find_dbn_files(input_dir.path()).unwrap();
+
+        assert_eq!(
+            filepaths,
+            vec![input_dir.path().join("test_data.trades.dbn.zst")]
+        );
+    }
+
+    #[rstest]
+    fn test_partitioned_writer_out_of_order_leaves_no_partial_file() {
+        let catalog_dir = tempfile::tempdir().unwrap();
+        let trade = |ts: u64| {
+            TradeTick::new(
+                InstrumentId::from("ESH1.GLBX"),
+                Price::from("4000.00"),
+                Quantity::from(1),
+                AggressorSide::Buyer,
+                TradeId::new("1"),
+                ts.into(),
+                ts.into(),
+            )
+        };
+        let mut writer =
+            PartitionedParquetWriter::<TradeTick>::new(catalog_dir.path(), "test_data", |t| {
+                t.instrument_id.to_string()
+            });
+
+        writer.write(vec![trade(1), trade(2)]).unwrap();
+        let result = writer.write(vec![trade(1)]);
+        drop(writer);
+
+        let partition_dir = catalog_dir.path().join("data/trades/ESH1.GLBX");
+        assert!(result.is_err());
+        assert_eq!(fs::read_dir(partition_dir).unwrap().count(), 0);
+    }
+
+    #[rstest]
+    fn test_decode_dbn_file_imbalance_uses_instrument_price_precision() {
+        let loader = data_loader();
+        let filepath = test_data_path().join("test_data.imbalance.dbn.zst");
+        let price_precisions = HashMap::from([(InstrumentId::from("SPOT.XNAS"), 4)]);
+
+        let records: Vec<Data> = decode_dbn_file(
+            &loader,
+            &filepath,
+            dbn::Schema::Imbalance,
+            &price_precisions,
+        )
+        .unwrap()
+        .unwrap()
+        .collect::<anyhow::Result<_>>()
+        .unwrap();
+
+        assert!(!records.is_empty());
+        for data in records {
+            let Data::AuctionImbalance(imbalance) = data else {
+                panic!("Expected `AuctionImbalance`, was {data:?}");
+            };
+            assert_eq!(imbalance.ref_price.precision, 4);
+            assert_eq!(imbalance.near_price.precision, 4);
+            assert_eq!(imbalance.far_price.precision, 4);
+        }
+    }
+
+    #[rstest]
+    fn test_convert_dbn_directory_to_catalog() {
+        let input_dir = tempfile::tempdir().unwrap();
+        let catalog_dir = tempfile::tempdir().unwrap();
+        copy_test_files(
+            input_dir.path(),
+            &[
+                "test_data.definition.v1.dbn.zst",
+                "test_data.mbo.dbn.zst",
+                "test_data.tbbo.dbn.zst",
+                "test_data.ohlcv-1m.dbn.zst",
+                "test_data.imbalance.dbn.zst",
+                "test_data.status.dbn.zst",
+            ],
+        );
+        let mut loader = data_loader();
+
+        let written = convert_dbn_directory_to_catalog(
+            &mut loader,
+            input_dir.path(),
+            catalog_dir.path(),
+            false,
+            Some(2),
+        )
+        .unwrap();
+
+        let data_dir = catalog_dir.path().join("data");
+        let relative: Vec<PathBuf> = written
+            .iter()
+            .map(|path| path.strip_prefix(&data_dir).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            relative,
+            vec![
+                PathBuf::from("auction_imbalance/SPOT.XNAS/test_data_imbalance.parquet"),
+                PathBuf::from("bars/ESH1.GLBX-1-MINUTE-LAST-EXTERNAL/test_data_ohlcv_1m.parquet"),
+                PathBuf::from("order_book_deltas/ESH1.GLBX/test_data_mbo.parquet"),
+                PathBuf::from("quotes/ESH1.GLBX/test_data_tbbo.parquet"),
+                PathBuf::from("trades/ESH1.GLBX/test_data_tbbo.parquet"),
+            ]
+        );
+
+        let mut catalog = ParquetDataCatalog::new(catalog_dir.path().to_path_buf(), None);
+        let result = catalog
+            .query_file::<OrderBookDelta>(
+                data_dir.join("order_book_deltas/ESH1.GLBX/test_data_mbo.parquet"),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let deltas: Vec<OrderBookDelta> = to_variant(result.collect());
+        let expected = loader
+            .load_order_book_deltas(&input_dir.path().join("test_data.mbo.dbn.zst"), None, None)
+            .unwrap();
+        assert_eq!(deltas, expected);
+    }
+}
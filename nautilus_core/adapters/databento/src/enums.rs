@@ -17,7 +17,10 @@
 
 use std::str::FromStr;
 
-use nautilus_model::{enum_strum_serde, enums::FromU8};
+use nautilus_model::{
+    enum_strum_serde,
+    enums::{FromU8, MarketStatisticType},
+};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
 
@@ -121,3 +124,23 @@ impl FromU8 for DatabentoStatisticUpdateAction {
 
 enum_strum_serde!(DatabentoStatisticType);
 enum_strum_serde!(DatabentoStatisticUpdateAction);
+
+impl From<DatabentoStatisticType> for MarketStatisticType {
+    fn from(value: DatabentoStatisticType) -> Self {
+        match value {
+            DatabentoStatisticType::OpeningPrice => Self::OpeningPrice,
+            DatabentoStatisticType::IndicativeOpeningPrice => Self::IndicativeOpeningPrice,
+            DatabentoStatisticType::SettlementPrice => Self::SettlementPrice,
+            DatabentoStatisticType::TradingSessionLowPrice => Self::SessionLowPrice,
+            DatabentoStatisticType::TradingSessionHighPrice => Self::SessionHighPrice,
+            DatabentoStatisticType::ClearedVolume => Self::ClearedVolume,
+            DatabentoStatisticType::LowestOffer => Self::LowestOffer,
+            DatabentoStatisticType::HighestBid => Self::HighestBid,
+            DatabentoStatisticType::OpenInterest => Self::OpenInterest,
+            DatabentoStatisticType::FixingPrice => Self::FixingPrice,
+            DatabentoStatisticType::ClosePrice => Self::ClosePrice,
+            DatabentoStatisticType::NetChange => Self::NetChange,
+            DatabentoStatisticType::Vwap => Self::Vwap,
+        }
+    }
+}
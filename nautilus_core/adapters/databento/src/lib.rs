@@ -16,6 +16,7 @@
 //! The [Databento](https://databento.com) integration adapter.
 
 pub mod common;
+pub mod converter;
 pub mod decode;
 pub mod enums;
 pub mod live;
@@ -230,6 +230,31 @@ impl DatabentoDataLoader {
         price_precision: Option<u8>,
         include_trades: bool,
     ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<(Option<Data>, Option<Data>)>> + '_>
+    where
+        T: dbn::Record + dbn::HasRType + 'static,
+    {
+        self.read_records_with_precisions::<T>(
+            filepath,
+            instrument_id,
+            None,
+            price_precision,
+            include_trades,
+        )
+    }
+
+    /// Reads the records from the DBN file at `filepath`, decoding prices with the precision
+    /// found in `price_precisions` for each record's instrument.
+    ///
+    /// Instruments not found in `price_precisions` are decoded with `price_precision`, which
+    /// defaults to the USD precision if not specified.
+    pub fn read_records_with_precisions<'a, T>(
+        &'a self,
+        filepath: &Path,
+        instrument_id: Option<InstrumentId>,
+        price_precisions: Option<&'a HashMap<InstrumentId, u8>>,
+        price_precision: Option<u8>,
+        include_trades: bool,
+    ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<(Option<Data>, Option<Data>)>> + 'a>
     where
         T: dbn::Record + dbn::HasRType + 'static,
     {
@@ -256,6 +281,9 @@ impl DatabentoDataLoader {
                         )
                         .expect("Failed to decode record"),
                     };
+                    let price_precision = price_precisions
+                        .and_then(|map| map.get(&instrument_id).copied())
+                        .unwrap_or(price_precision);
 
                     match decode_record(
                         &record,
@@ -476,6 +504,29 @@ impl DatabentoDataLoader {
         instrument_id: Option<InstrumentId>,
         price_precision: Option<u8>,
     ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<DatabentoImbalance>> + '_>
+    where
+        T: dbn::Record + dbn::HasRType + 'static,
+    {
+        self.read_imbalance_records_with_precisions::<T>(
+            filepath,
+            instrument_id,
+            None,
+            price_precision,
+        )
+    }
+
+    /// Reads the imbalance records from the DBN file at `filepath`, decoding prices with the
+    /// precision found in `price_precisions` for each record's instrument.
+    ///
+    /// Instruments not found in `price_precisions` are decoded with `price_precision`, which
+    /// defaults to the USD precision if not specified.
+    pub fn read_imbalance_records_with_precisions<'a, T>(
+        &'a self,
+        filepath: &Path,
+        instrument_id: Option<InstrumentId>,
+        price_precisions: Option<&'a HashMap<InstrumentId, u8>>,
+        price_precision: Option<u8>,
+    ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<DatabentoImbalance>> + 'a>
     where
         T: dbn::Record + dbn::HasRType + 'static,
     {
@@ -502,6 +553,9 @@ impl DatabentoDataLoader {
                         )
                         .expect("Failed to decode record"),
                     };
+                    let price_precision = price_precisions
+                        .and_then(|map| map.get(&instrument_id).copied())
+                        .unwrap_or(price_precision);
 
                     let msg = record
                         .get::<dbn::ImbalanceMsg>()
@@ -527,6 +581,29 @@ impl DatabentoDataLoader {
         instrument_id: Option<InstrumentId>,
         price_precision: Option<u8>,
     ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<DatabentoStatistics>> + '_>
+    where
+        T: dbn::Record + dbn::HasRType + 'static,
+    {
+        self.read_statistics_records_with_precisions::<T>(
+            filepath,
+            instrument_id,
+            None,
+            price_precision,
+        )
+    }
+
+    /// Reads the statistics records from the DBN file at `filepath`, decoding prices with the
+    /// precision found in `price_precisions` for each record's instrument.
+    ///
+    /// Instruments not found in `price_precisions` are decoded with `price_precision`, which
+    /// defaults to the USD precision if not specified.
+    pub fn read_statistics_records_with_precisions<'a, T>(
+        &'a self,
+        filepath: &Path,
+        instrument_id: Option<InstrumentId>,
+        price_precisions: Option<&'a HashMap<InstrumentId, u8>>,
+        price_precision: Option<u8>,
+    ) -> anyhow::Result<impl Iterator<Item = anyhow::Result<DatabentoStatistics>> + 'a>
     where
         T: dbn::Record + dbn::HasRType + 'static,
     {
@@ -553,6 +630,9 @@ impl DatabentoDataLoader {
                         )
                         .expect("Failed to decode record"),
                     };
+                    let price_precision = price_precisions
+                        .and_then(|map| map.get(&instrument_id).copied())
+                        .unwrap_or(price_precision);
 
                     let msg = record.get::<dbn::StatMsg>().expect("Invalid `StatMsg`");
                     match decode_statistics_msg(
@@ -18,7 +18,8 @@ use std::ffi::c_char;
 use databento::dbn;
 use nautilus_core::UnixNanos;
 use nautilus_model::{
-    enums::OrderSide,
+    data::{AuctionImbalance, MarketStatistic},
+    enums::{MarketStatisticType, OrderSide},
     identifiers::InstrumentId,
     types::{Price, Quantity},
 };
@@ -189,3 +190,119 @@ impl DatabentoStatistics {
         })
     }
 }
+
+impl From<&DatabentoImbalance> for AuctionImbalance {
+    /// Converts to the vendor-neutral auction imbalance, dropping the Databento specific
+    /// `significant_imbalance` and `ts_recv` fields.
+    fn from(value: &DatabentoImbalance) -> Self {
+        Self::new(
+            value.instrument_id,
+            value.ref_price,
+            value.cont_book_clr_price,
+            value.auct_interest_clr_price,
+            value.paired_qty,
+            value.total_imbalance_qty,
+            value.side,
+            value.ts_event,
+            value.ts_init,
+        )
+    }
+}
+
+impl DatabentoStatistics {
+    /// Converts to the vendor-neutral [`MarketStatistic`].
+    ///
+    /// Returns `None` for a `Deleted` update action, since the deletion carries no
+    /// statistic value to record.
+    #[must_use]
+    pub fn to_market_statistic(&self) -> Option<MarketStatistic> {
+        if self.update_action == DatabentoStatisticUpdateAction::Deleted {
+            return None;
+        }
+
+        Some(MarketStatistic::new(
+            self.instrument_id,
+            MarketStatisticType::from(self.stat_type),
+            self.price,
+            self.quantity,
+            self.ts_event,
+            self.ts_init,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn statistics(update_action: DatabentoStatisticUpdateAction) -> DatabentoStatistics {
+        DatabentoStatistics::new(
+            InstrumentId::from("ESM4.GLBX"),
+            DatabentoStatisticType::SettlementPrice,
+            update_action,
+            Some(Price::from("5250.25")),
+            None,
+            1,
+            0,
+            100,
+            UnixNanos::from(1),
+            0,
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+            UnixNanos::from(4),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_imbalance_to_auction_imbalance() {
+        let imbalance = DatabentoImbalance::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from("150.25"),
+            Price::from("150.30"),
+            Price::from("150.20"),
+            Quantity::from(1_000),
+            Quantity::from(250),
+            OrderSide::Buy,
+            b'L' as c_char,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+        )
+        .unwrap();
+
+        let result = AuctionImbalance::from(&imbalance);
+
+        assert_eq!(result.instrument_id, imbalance.instrument_id);
+        assert_eq!(result.near_price, imbalance.cont_book_clr_price);
+        assert_eq!(result.far_price, imbalance.auct_interest_clr_price);
+        assert_eq!(result.imbalance_qty, imbalance.total_imbalance_qty);
+        assert_eq!(result.ts_event, UnixNanos::from(1));
+        assert_eq!(result.ts_init, UnixNanos::from(3));
+    }
+
+    #[rstest]
+    fn test_statistics_to_market_statistic() {
+        let result = statistics(DatabentoStatisticUpdateAction::Added)
+            .to_market_statistic()
+            .unwrap();
+
+        assert_eq!(result.stat_type, MarketStatisticType::SettlementPrice);
+        assert_eq!(result.price(), Some(Price::from("5250.25")));
+        assert_eq!(result.quantity(), None);
+        assert_eq!(result.ts_event, UnixNanos::from(2));
+        assert_eq!(result.ts_init, UnixNanos::from(4));
+    }
+
+    #[rstest]
+    fn test_deleted_statistics_to_market_statistic_returns_none() {
+        let result = statistics(DatabentoStatisticUpdateAction::Deleted).to_market_statistic();
+
+        assert!(result.is_none());
+    }
+}
@@ -14,29 +14,36 @@
 // -------------------------------------------------------------------------------------------------
 
 mod record;
+pub mod stream;
 
-use std::{error::Error, fs::File, io::BufReader, path::Path};
+use std::{collections::HashSet, error::Error, fs::File, io::BufReader, path::Path};
 
 use csv::{Reader, ReaderBuilder, StringRecord};
 use flate2::read::GzDecoder;
-use nautilus_core::UnixNanos;
+use nautilus_core::{parsing::precision_from_str, UnixNanos};
 use nautilus_model::{
     data::{
-        BookOrder, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN, NULL_ORDER,
+        BookOrder, Data, IndexPriceUpdate, Liquidation, MarkPriceUpdate, OpenInterestUpdate,
+        OptionGreeks, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN,
+        NULL_ORDER,
     },
-    enums::{OrderSide, RecordFlag},
-    identifiers::{InstrumentId, TradeId},
+    enums::{AssetClass, OrderSide, RecordFlag},
+    identifiers::{InstrumentId, Symbol, TradeId},
+    instruments::OptionsContract,
     types::{Price, Quantity},
 };
+use ustr::Ustr;
 
 use super::{
     csv::record::{
-        TardisBookUpdateRecord, TardisOrderBookSnapshot25Record, TardisOrderBookSnapshot5Record,
+        TardisBookUpdateRecord, TardisDerivativeTickerRecord, TardisLiquidationRecord,
+        TardisOptionsChainRecord, TardisOrderBookSnapshot25Record, TardisOrderBookSnapshot5Record,
         TardisQuoteRecord, TardisTradeRecord,
     },
+    http::parse::get_currency,
     parse::{
-        parse_aggressor_side, parse_book_action, parse_instrument_id, parse_order_side,
-        parse_timestamp,
+        parse_aggressor_side, parse_book_action, parse_instrument_id, parse_liquidation_side,
+        parse_option_kind, parse_order_side, parse_timestamp,
     },
 };
 
@@ -475,14 +482,323 @@ pub fn load_trade_ticks<P: AsRef<Path>>(
     Ok(trades)
 }
 
+/// Returns a deterministic trade ID for a liquidation `record` without an exchange provided ID,
+/// derived from its timestamp, side, price, amount and `row` index in the file.
+fn liquidation_trade_id(record: &TardisLiquidationRecord, row: usize) -> TradeId {
+    // FNV-1a hash, which (unlike the std hasher) is stable across Rust versions
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in record
+        .side
+        .bytes()
+        .chain(record.price.to_bits().to_le_bytes())
+        .chain(record.amount.to_bits().to_le_bytes())
+        .chain((row as u64).to_le_bytes())
+    {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    TradeId::new(format!("{}-{hash:016x}", record.timestamp))
+}
+
+/// Load [`Liquidation`]s from a Tardis format CSV at the given `filepath`.
+///
+/// Records without an exchange provided ID are assigned a trade ID derived from the record, so
+/// that loading the same file always produces the same IDs.
+pub fn load_liquidations<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> Result<Vec<Liquidation>, Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    let mut liquidations = Vec::new();
+
+    let mut raw_record = StringRecord::new();
+    let mut row = 0;
+    while csv_reader.read_record(&mut raw_record)? {
+        let record: TardisLiquidationRecord = raw_record.deserialize(None)?;
+
+        let instrument_id = match &instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let side = parse_liquidation_side(&record.side);
+        let price = Price::new(record.price, price_precision);
+        let quantity = Quantity::new(record.amount, size_precision);
+        let trade_id = match &record.id {
+            Some(id) => TradeId::new(id),
+            None => liquidation_trade_id(&record, row),
+        };
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+        row += 1;
+
+        let liquidation = Liquidation::new(
+            instrument_id,
+            side,
+            price,
+            quantity,
+            trade_id,
+            ts_event,
+            ts_init,
+        );
+
+        liquidations.push(liquidation);
+
+        if let Some(limit) = limit {
+            if liquidations.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(liquidations)
+}
+
+/// Load [`MarkPriceUpdate`]s, [`IndexPriceUpdate`]s and [`OpenInterestUpdate`]s from a Tardis
+/// format derivative ticker CSV at the given `filepath`.
+///
+/// Fields which the exchange did not provide are skipped, the `limit` applies to the number of
+/// records read.
+pub fn load_derivative_tickers<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> Result<Vec<Data>, Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    let mut data = Vec::new();
+    let mut count = 0;
+
+    let mut raw_record = StringRecord::new();
+    while csv_reader.read_record(&mut raw_record)? {
+        let record: TardisDerivativeTickerRecord = raw_record.deserialize(None)?;
+
+        let instrument_id = match &instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+
+        if let Some(mark_price) = record.mark_price {
+            data.push(Data::MarkPriceUpdate(MarkPriceUpdate::new(
+                instrument_id,
+                Price::new(mark_price, price_precision),
+                ts_event,
+                ts_init,
+            )));
+        }
+
+        if let Some(index_price) = record.index_price {
+            data.push(Data::IndexPriceUpdate(IndexPriceUpdate::new(
+                instrument_id,
+                Price::new(index_price, price_precision),
+                ts_event,
+                ts_init,
+            )));
+        }
+
+        if let Some(open_interest) = record.open_interest {
+            data.push(Data::OpenInterestUpdate(OpenInterestUpdate::new(
+                instrument_id,
+                Quantity::new(open_interest, size_precision),
+                ts_event,
+                ts_init,
+            )));
+        }
+
+        count += 1;
+        if let Some(limit) = limit {
+            if count >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+/// The instruments, quotes and greeks loaded from a Tardis format options chain CSV.
+#[derive(Clone, Debug, Default)]
+pub struct OptionsChainData {
+    /// The option instruments, synthesized from the first record of each symbol.
+    pub instruments: Vec<OptionsContract>,
+    /// The top of book quotes, for records with a bid or ask.
+    pub quotes: Vec<QuoteTick>,
+    /// The greeks and implied volatilities, for records where these were provided.
+    pub greeks: Vec<OptionGreeks>,
+}
+
+/// Parses the underlying and settlement currency codes from a Tardis option `symbol`.
+///
+/// Inverse options such as `BTC-9JUN20-9875-P` settle in the underlying, whereas linear options
+/// such as `SOL_USDC-28MAR25-200-C` settle in the quote currency following the underscore.
+fn parse_option_currencies(symbol: &str) -> (Ustr, Ustr) {
+    let prefix = symbol.split('-').next().unwrap_or(symbol);
+    match prefix.split_once('_') {
+        Some((underlying, currency)) => (Ustr::from(underlying), Ustr::from(currency)),
+        None => (Ustr::from(prefix), Ustr::from(prefix)),
+    }
+}
+
+fn create_options_contract(
+    record: &TardisOptionsChainRecord,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+) -> anyhow::Result<OptionsContract> {
+    let (underlying, currency) = parse_option_currencies(record.symbol.as_str());
+    let price_increment = Price::new(10f64.powi(-i32::from(price_precision)), price_precision);
+    let strike_price = Price::new(
+        record.strike_price,
+        precision_from_str(&record.strike_price.to_string()),
+    );
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    let mut instrument = OptionsContract::new_checked(
+        instrument_id,
+        Symbol::new(record.symbol),
+        AssetClass::Cryptocurrency,
+        Some(Ustr::from(instrument_id.venue.as_str())),
+        underlying,
+        parse_option_kind(record.option_type.clone()),
+        strike_price,
+        get_currency(currency.as_str()),
+        UnixNanos::default(), // Activation not available in options chain data
+        parse_timestamp(record.expiration),
+        price_precision,
+        price_increment,
+        Quantity::from(1),
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        ts_event,
+        ts_init,
+    )?;
+
+    // Options contracts default to integer sizes, override for fractional exchange amounts
+    let size_increment = Quantity::new(10f64.powi(-i32::from(size_precision)), size_precision);
+    instrument.size_precision = size_precision;
+    instrument.size_increment = size_increment;
+    instrument.min_quantity = Some(size_increment);
+
+    Ok(instrument)
+}
+
+/// Load [`OptionsContract`]s, [`QuoteTick`]s and [`OptionGreeks`] from a Tardis format options
+/// chain CSV at the given `filepath`.
+///
+/// An instrument is synthesized from the first record of each option symbol, the activation
+/// time is not available and so defaults to the UNIX epoch. Quotes are only loaded for records
+/// with both a bid and an ask, and greeks only for records with all greeks populated.
+pub fn load_options_chain<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    size_precision: u8,
+    limit: Option<usize>,
+) -> Result<OptionsChainData, Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    let mut chain = OptionsChainData::default();
+    let mut seen_instrument_ids = HashSet::new();
+    let mut count = 0;
+
+    let mut raw_record = StringRecord::new();
+    while csv_reader.read_record(&mut raw_record)? {
+        let record: TardisOptionsChainRecord = raw_record.deserialize(None)?;
+
+        let instrument_id = parse_instrument_id(&record.exchange, record.symbol);
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+
+        if seen_instrument_ids.insert(instrument_id) {
+            let instrument =
+                create_options_contract(&record, instrument_id, price_precision, size_precision)?;
+            chain.instruments.push(instrument);
+        }
+
+        // One-sided quotes are skipped, rather than emitting a zero price for the missing side
+        if let (Some(bid_price), Some(ask_price), Some(bid_amount), Some(ask_amount)) = (
+            record.bid_price,
+            record.ask_price,
+            record.bid_amount,
+            record.ask_amount,
+        ) {
+            let quote = QuoteTick::new(
+                instrument_id,
+                Price::new(bid_price, price_precision),
+                Price::new(ask_price, price_precision),
+                Quantity::new(bid_amount, size_precision),
+                Quantity::new(ask_amount, size_precision),
+                ts_event,
+                ts_init,
+            );
+            chain.quotes.push(quote);
+        }
+
+        if let (
+            Some(delta),
+            Some(gamma),
+            Some(vega),
+            Some(theta),
+            Some(rho),
+            Some(mark_iv),
+            Some(underlying_price),
+        ) = (
+            record.delta,
+            record.gamma,
+            record.vega,
+            record.theta,
+            record.rho,
+            record.mark_iv,
+            record.underlying_price,
+        ) {
+            let greeks = OptionGreeks::new(
+                instrument_id,
+                delta,
+                gamma,
+                vega,
+                theta,
+                rho,
+                mark_iv,
+                record.bid_iv,
+                record.ask_iv,
+                underlying_price,
+                ts_event,
+                ts_init,
+            );
+            chain.greeks.push(greeks);
+        }
+
+        count += 1;
+        if let Some(limit) = limit {
+            if count >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(chain)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use nautilus_model::{
-        enums::{AggressorSide, BookAction},
+        enums::{AggressorSide, BookAction, OptionKind},
         identifiers::InstrumentId,
+        types::Currency,
     };
     use nautilus_test_kit::common::{
         ensure_data_exists_tardis_binance_snapshot25, ensure_data_exists_tardis_binance_snapshot5,
@@ -492,6 +808,7 @@ mod tests {
     use rstest::*;
 
     use super::*;
+    use crate::{enums::Exchange, tests::load_test_file_path};
 
     #[rstest]
     pub fn test_read_deltas() {
@@ -601,4 +918,184 @@ mod tests {
         assert_eq!(trades[0].ts_event, 1583020803145000000);
         assert_eq!(trades[0].ts_init, 1583020803307160000);
     }
+
+    #[rstest]
+    pub fn test_read_liquidations() {
+        let filepath = load_test_file_path("liquidations.csv");
+        let liquidations = load_liquidations(filepath, 1, 0, None, None).unwrap();
+
+        assert_eq!(liquidations.len(), 3);
+        assert_eq!(
+            liquidations[0].instrument_id,
+            InstrumentId::from("XBTUSD.BITMEX")
+        );
+        assert_eq!(liquidations[0].side, OrderSide::Sell);
+        assert_eq!(liquidations[0].price, Price::from("8531.5"));
+        assert_eq!(liquidations[0].quantity, Quantity::from("2152"));
+        assert_eq!(
+            liquidations[0].trade_id,
+            TradeId::new("ccc3c1fa-212c-e8b0-1706-9b9c4f3d5ecf")
+        );
+        assert_eq!(liquidations[0].ts_event, 1583020803145000000);
+        assert_eq!(liquidations[0].ts_init, 1583020803307160000);
+        assert_eq!(liquidations[1].side, OrderSide::Buy);
+        // Derived from the record when not provided, so reloading yields the same ID
+        let reloaded =
+            load_liquidations(load_test_file_path("liquidations.csv"), 1, 0, None, None).unwrap();
+        assert_eq!(liquidations[2].trade_id, reloaded[2].trade_id);
+    }
+
+    #[rstest]
+    fn test_liquidation_trade_id_is_deterministic() {
+        let record = TardisLiquidationRecord {
+            exchange: Exchange::Bitmex,
+            symbol: Ustr::from("XBTUSD"),
+            timestamp: 1583020803145000,
+            local_timestamp: 1583020803307160,
+            id: None,
+            side: "buy".to_string(),
+            price: 8531.5,
+            amount: 2152.0,
+        };
+
+        let trade_id = liquidation_trade_id(&record, 2);
+
+        assert_eq!(trade_id, liquidation_trade_id(&record, 2));
+        assert_ne!(trade_id, liquidation_trade_id(&record, 3));
+        assert_ne!(
+            trade_id,
+            liquidation_trade_id(
+                &TardisLiquidationRecord {
+                    side: "sell".to_string(),
+                    ..record.clone()
+                },
+                2
+            )
+        );
+        assert!(trade_id.to_string().starts_with("1583020803145000-"));
+    }
+
+    #[rstest]
+    pub fn test_read_liquidations_with_limit() {
+        let filepath = load_test_file_path("liquidations.csv");
+        let instrument_id = InstrumentId::from("XBTUSD-PERP.BITMEX");
+        let liquidations = load_liquidations(filepath, 1, 0, Some(instrument_id), Some(2)).unwrap();
+
+        assert_eq!(liquidations.len(), 2);
+        assert_eq!(liquidations[0].instrument_id, instrument_id);
+    }
+
+    #[rstest]
+    pub fn test_read_derivative_tickers() {
+        let filepath = load_test_file_path("derivative_ticker.csv");
+        let data = load_derivative_tickers(filepath, 2, 0, None, None).unwrap();
+
+        assert_eq!(data.len(), 6);
+        let instrument_id = InstrumentId::from("XBTUSD.BITMEX");
+        match &data[0] {
+            Data::MarkPriceUpdate(mark_price) => {
+                assert_eq!(mark_price.instrument_id, instrument_id);
+                assert_eq!(mark_price.value, Price::from("8532.18"));
+                assert_eq!(mark_price.ts_event, 1583020803145000000);
+                assert_eq!(mark_price.ts_init, 1583020803307160000);
+            }
+            other => panic!("Expected mark price, was {other:?}"),
+        }
+        match &data[1] {
+            Data::IndexPriceUpdate(index_price) => {
+                assert_eq!(index_price.value, Price::from("8532.43"));
+            }
+            other => panic!("Expected index price, was {other:?}"),
+        }
+        match &data[2] {
+            Data::OpenInterestUpdate(open_interest) => {
+                assert_eq!(open_interest.value, Quantity::from("1095637568"));
+            }
+            other => panic!("Expected open interest, was {other:?}"),
+        }
+        assert!(matches!(data[3], Data::IndexPriceUpdate(_)));
+        assert!(matches!(data[5], Data::MarkPriceUpdate(_)));
+    }
+
+    #[rstest]
+    pub fn test_read_derivative_tickers_with_limit() {
+        let filepath = load_test_file_path("derivative_ticker.csv");
+        let instrument_id = InstrumentId::from("XBTUSD-PERP.BITMEX");
+        let data = load_derivative_tickers(filepath, 2, 0, Some(instrument_id), Some(1)).unwrap();
+
+        assert_eq!(data.len(), 3);
+        assert!(data.iter().all(|d| d.instrument_id() == instrument_id));
+    }
+
+    #[rstest]
+    #[case("BTC-9JUN20-9875-P", "BTC", "BTC")]
+    #[case("SOL_USDC-28MAR25-200-C", "SOL", "USDC")]
+    fn test_parse_option_currencies(
+        #[case] symbol: &str,
+        #[case] expected_underlying: &str,
+        #[case] expected_currency: &str,
+    ) {
+        let (underlying, currency) = parse_option_currencies(symbol);
+
+        assert_eq!(underlying, expected_underlying);
+        assert_eq!(currency, expected_currency);
+    }
+
+    #[rstest]
+    pub fn test_read_options_chain() {
+        let filepath = load_test_file_path("options_chain.csv");
+        let chain = load_options_chain(filepath, 4, 1, None).unwrap();
+
+        assert_eq!(chain.instruments.len(), 3);
+        let instrument = &chain.instruments[0];
+        assert_eq!(
+            instrument.id,
+            InstrumentId::from("BTC-9JUN20-9875-P.DERIBIT")
+        );
+        assert_eq!(instrument.option_kind, OptionKind::Put);
+        assert_eq!(instrument.strike_price, Price::from("9875"));
+        assert_eq!(instrument.underlying, "BTC");
+        assert_eq!(instrument.currency, Currency::BTC());
+        assert_eq!(instrument.expiration_ns, 1591689600000000000);
+        assert_eq!(instrument.price_increment, Price::from("0.0001"));
+        assert_eq!(instrument.size_increment, Quantity::from("0.1"));
+        assert_eq!(chain.instruments[1].option_kind, OptionKind::Call);
+
+        // The one-sided quote of the second record is skipped
+        assert_eq!(chain.quotes.len(), 2);
+        let quote = chain.quotes[0];
+        assert_eq!(quote.instrument_id, instrument.id);
+        assert_eq!(quote.bid_price, Price::from("0.0205"));
+        assert_eq!(quote.ask_price, Price::from("0.0230"));
+        assert_eq!(quote.bid_size, Quantity::from("5.0"));
+        assert_eq!(quote.ask_size, Quantity::from("1.2"));
+        assert_eq!(quote.ts_event, 1591574400012000000);
+        assert_eq!(quote.ts_init, 1591574400201543000);
+        assert_eq!(chain.quotes[1].instrument_id, instrument.id);
+        assert_eq!(chain.quotes[1].bid_price, Price::from("0.0210"));
+
+        assert_eq!(chain.greeks.len(), 3);
+        let greeks = chain.greeks[0];
+        assert_eq!(greeks.instrument_id, instrument.id);
+        assert_eq!(greeks.delta, -0.66961);
+        assert_eq!(greeks.gamma, 0.00117);
+        assert_eq!(greeks.vega, 3.27047);
+        assert_eq!(greeks.theta, -46.99823);
+        assert_eq!(greeks.rho, -0.66548);
+        assert_eq!(greeks.mark_iv, 63.9);
+        assert_eq!(greeks.bid_iv, Some(62.23));
+        assert_eq!(greeks.ask_iv, Some(65.57));
+        assert_eq!(greeks.underlying_price, 9733.72);
+        assert_eq!(chain.greeks[1].bid_iv, None);
+    }
+
+    #[rstest]
+    pub fn test_read_options_chain_with_limit() {
+        let filepath = load_test_file_path("options_chain.csv");
+        let chain = load_options_chain(filepath, 4, 1, Some(2)).unwrap();
+
+        assert_eq!(chain.instruments.len(), 2);
+        assert_eq!(chain.quotes.len(), 1);
+        assert_eq!(chain.greeks.len(), 2);
+    }
 }
@@ -16,7 +16,10 @@
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use crate::{enums::Exchange, parse::deserialize_uppercase};
+use crate::{
+    enums::{Exchange, OptionType},
+    parse::deserialize_uppercase,
+};
 
 /// Represents a Tardis format order book update record.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,3 +279,109 @@ pub struct TardisTradeRecord {
     /// The trade amount as provided by the exchange.
     pub amount: f64,
 }
+
+/// Represents a Tardis format liquidation record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisLiquidationRecord {
+    /// The exchange ID.
+    pub exchange: Exchange,
+    /// The instrument symbol as provided by the exchange.
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub symbol: Ustr,
+    // UNIX microseconds timestamp provided by the exchange.
+    pub timestamp: u64,
+    // UNIX microseconds timestamp of message received.
+    pub local_timestamp: u64,
+    /// The liquidation ID provided by the exchange, empty if not provided.
+    pub id: Option<String>,
+    /// The liquidation side, `buy` when a short position was liquidated and `sell` when a long
+    /// position was liquidated.
+    pub side: String,
+    /// The liquidation price as provided by the exchange.
+    pub price: f64,
+    /// The liquidation amount as provided by the exchange.
+    pub amount: f64,
+}
+
+/// Represents a Tardis format options chain record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisOptionsChainRecord {
+    /// The exchange ID.
+    pub exchange: Exchange,
+    /// The option symbol as provided by the exchange.
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub symbol: Ustr,
+    // UNIX microseconds timestamp provided by the exchange.
+    pub timestamp: u64,
+    // UNIX microseconds timestamp of message received.
+    pub local_timestamp: u64,
+    /// The option type.
+    #[serde(rename = "type")]
+    pub option_type: OptionType,
+    /// The option strike price.
+    pub strike_price: f64,
+    /// UNIX microseconds timestamp of the option expiration.
+    pub expiration: u64,
+    /// The current open interest.
+    pub open_interest: Option<f64>,
+    /// The price of the last trade.
+    pub last_price: Option<f64>,
+    /// The best bid price.
+    pub bid_price: Option<f64>,
+    /// The best bid amount.
+    pub bid_amount: Option<f64>,
+    /// The implied volatility of the best bid.
+    pub bid_iv: Option<f64>,
+    /// The best ask price.
+    pub ask_price: Option<f64>,
+    /// The best ask amount.
+    pub ask_amount: Option<f64>,
+    /// The implied volatility of the best ask.
+    pub ask_iv: Option<f64>,
+    /// The mark price.
+    pub mark_price: Option<f64>,
+    /// The implied volatility of the mark price.
+    pub mark_iv: Option<f64>,
+    /// The underlying index or future name.
+    pub underlying_index: String,
+    /// The underlying price.
+    pub underlying_price: Option<f64>,
+    /// The option delta.
+    pub delta: Option<f64>,
+    /// The option gamma.
+    pub gamma: Option<f64>,
+    /// The option vega.
+    pub vega: Option<f64>,
+    /// The option theta.
+    pub theta: Option<f64>,
+    /// The option rho.
+    pub rho: Option<f64>,
+}
+
+/// Represents a Tardis format derivative ticker record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisDerivativeTickerRecord {
+    /// The exchange ID.
+    pub exchange: Exchange,
+    /// The instrument symbol as provided by the exchange.
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub symbol: Ustr,
+    // UNIX microseconds timestamp provided by the exchange.
+    pub timestamp: u64,
+    // UNIX microseconds timestamp of message received.
+    pub local_timestamp: u64,
+    /// UNIX microseconds timestamp of the next funding event.
+    pub funding_timestamp: Option<u64>,
+    /// The current funding rate.
+    pub funding_rate: Option<f64>,
+    /// The predicted funding rate for the next funding event.
+    pub predicted_funding_rate: Option<f64>,
+    /// The current open interest.
+    pub open_interest: Option<f64>,
+    /// The price of the last trade.
+    pub last_price: Option<f64>,
+    /// The index price.
+    pub index_price: Option<f64>,
+    /// The mark price.
+    pub mark_price: Option<f64>,
+}
@@ -0,0 +1,688 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming (bounded-memory) loaders for Tardis format CSV files.
+//!
+//! Each loader returns an iterator which yields chunks of at most `chunk_size` records, so that
+//! arbitrarily large (gzip) CSV files can be processed, or written to a catalog, without holding
+//! the entire file in memory.
+
+use std::{io::Read, path::Path};
+
+use csv::{Reader, StringRecord};
+use nautilus_core::parsing::precision_from_str;
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta, QuoteTick, TradeTick},
+    enums::RecordFlag,
+    identifiers::{InstrumentId, TradeId},
+    types::{fixed::FIXED_PRECISION, Price, Quantity},
+};
+
+use super::{
+    create_csv_reader,
+    record::{TardisBookUpdateRecord, TardisQuoteRecord, TardisTradeRecord},
+};
+use crate::parse::{
+    parse_aggressor_side, parse_book_action, parse_instrument_id, parse_order_side, parse_timestamp,
+};
+
+/// The number of records sampled from the head of a file when inferring precisions.
+pub const PRECISION_INFERENCE_SAMPLE_SIZE: usize = 10_000;
+
+/// Returns the decimal precision required to represent the given `value`.
+fn infer_precision(value: f64) -> u8 {
+    precision_from_str(&value.to_string()).min(FIXED_PRECISION)
+}
+
+/// Infers the price and size precisions by sampling the head of the CSV at the given `filepath`.
+///
+/// The `extract` function returns the price and size values for a single record, the maximum
+/// precision seen for each across the sample is returned.
+fn infer_precisions<P, F>(
+    filepath: P,
+    sample_size: usize,
+    mut extract: F,
+) -> anyhow::Result<(u8, u8)>
+where
+    P: AsRef<Path>,
+    F: FnMut(&StringRecord) -> anyhow::Result<(Vec<f64>, Vec<f64>)>,
+{
+    let mut csv_reader = create_csv_reader(filepath)?;
+    let mut raw_record = StringRecord::new();
+    let mut price_precision = 0;
+    let mut size_precision = 0;
+    let mut count = 0;
+
+    while count < sample_size && csv_reader.read_record(&mut raw_record)? {
+        let (prices, sizes) = extract(&raw_record)?;
+        for price in prices {
+            price_precision = price_precision.max(infer_precision(price));
+        }
+        for size in sizes {
+            size_precision = size_precision.max(infer_precision(size));
+        }
+        count += 1;
+    }
+
+    Ok((price_precision, size_precision))
+}
+
+/// The price and size precisions for streamed records, tracking which were inferred.
+#[derive(Clone, Copy, Debug)]
+struct Precisions {
+    price: u8,
+    size: u8,
+    price_inferred: bool,
+    size_inferred: bool,
+}
+
+impl Precisions {
+    /// Resolves the price and size precisions, inferring any which were not specified.
+    fn resolve<P, F>(
+        filepath: P,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+        limit: Option<usize>,
+        extract: F,
+    ) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&StringRecord) -> anyhow::Result<(Vec<f64>, Vec<f64>)>,
+    {
+        let (inferred_price, inferred_size) = match (price_precision, size_precision) {
+            (Some(price_precision), Some(size_precision)) => (price_precision, size_precision),
+            _ => {
+                let sample_size = limit.map_or(PRECISION_INFERENCE_SAMPLE_SIZE, |limit| {
+                    limit.min(PRECISION_INFERENCE_SAMPLE_SIZE)
+                });
+                infer_precisions(filepath, sample_size, extract)?
+            }
+        };
+
+        Ok(Self {
+            price: price_precision.unwrap_or(inferred_price),
+            size: size_precision.unwrap_or(inferred_size),
+            price_inferred: price_precision.is_none(),
+            size_inferred: size_precision.is_none(),
+        })
+    }
+
+    /// Returns a price for `value`, checking it is representable at an inferred precision.
+    fn price(&self, value: f64) -> anyhow::Result<Price> {
+        Self::check(value, self.price, self.price_inferred, "price")?;
+        Price::new_checked(value, self.price)
+    }
+
+    /// Returns a quantity for `value`, checking it is representable at an inferred precision.
+    fn quantity(&self, value: f64) -> anyhow::Result<Quantity> {
+        Self::check(value, self.size, self.size_inferred, "size")?;
+        Quantity::new_checked(value, self.size)
+    }
+
+    // Precisions are inferred from a sample at the head of the file, so a later value with a
+    // higher precision would otherwise be silently rounded
+    fn check(value: f64, precision: u8, inferred: bool, name: &str) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !inferred || infer_precision(value) <= precision,
+            "Invalid {name} {value}: exceeds the precision {precision} inferred from the head of the file, specify the {name} precision explicitly",
+        );
+        Ok(())
+    }
+}
+
+/// Common state for streaming records from a Tardis format CSV.
+struct RecordStream {
+    csv_reader: Reader<Box<dyn Read>>,
+    raw_record: StringRecord,
+    chunk_size: usize,
+    limit: Option<usize>,
+    records_read: usize,
+    done: bool,
+}
+
+impl RecordStream {
+    fn new<P: AsRef<Path>>(
+        filepath: P,
+        chunk_size: usize,
+        limit: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(chunk_size > 0, "`chunk_size` must be positive");
+        Ok(Self {
+            csv_reader: create_csv_reader(filepath)?,
+            raw_record: StringRecord::new(),
+            chunk_size,
+            limit,
+            records_read: 0,
+            done: false,
+        })
+    }
+
+    /// Reads the next raw record, returning `false` once the file or limit is exhausted.
+    fn read_next(&mut self) -> anyhow::Result<bool> {
+        if self.done || self.limit.is_some_and(|limit| self.records_read >= limit) {
+            self.done = true;
+            return Ok(false);
+        }
+
+        match self.csv_reader.read_record(&mut self.raw_record) {
+            Ok(true) => {
+                self.records_read += 1;
+                Ok(true)
+            }
+            Ok(false) => {
+                self.done = true;
+                Ok(false)
+            }
+            Err(e) => {
+                self.done = true;
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Ends the stream after an error, so that no further chunks are yielded.
+    fn fail<T>(&mut self, e: anyhow::Error) -> Option<anyhow::Result<T>> {
+        self.done = true;
+        Some(Err(e))
+    }
+}
+
+/// An iterator yielding chunks of [`OrderBookDelta`]s from a Tardis format CSV.
+///
+/// The last delta of each event (the final delta before the timestamp changes) is flagged with
+/// `F_LAST`, including across chunk boundaries.
+pub struct DeltaStreamIterator {
+    stream: RecordStream,
+    precisions: Precisions,
+    instrument_id: Option<InstrumentId>,
+    pending: Option<OrderBookDelta>,
+}
+
+impl DeltaStreamIterator {
+    fn parse_record(&self) -> anyhow::Result<OrderBookDelta> {
+        let record: TardisBookUpdateRecord = self.stream.raw_record.deserialize(None)?;
+
+        let instrument_id = match &self.instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let side = parse_order_side(&record.side);
+        let price = self.precisions.price(record.price)?;
+        let size = self.precisions.quantity(record.amount)?;
+        let order_id = 0; // Not applicable for L2 data
+        let order = BookOrder::new(side, price, size, order_id);
+
+        let action = parse_book_action(record.is_snapshot, record.amount);
+        let flags = 0; // Flags always zero until timestamp changes
+        let sequence = 0; // Sequence not available
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+
+        Ok(OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        ))
+    }
+}
+
+impl Iterator for DeltaStreamIterator {
+    type Item = anyhow::Result<Vec<OrderBookDelta>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut deltas = Vec::with_capacity(self.stream.chunk_size);
+
+        while deltas.len() < self.stream.chunk_size {
+            match self.stream.read_next() {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Set F_LAST flag for final delta
+                    if let Some(mut last_delta) = self.pending.take() {
+                        last_delta.flags = RecordFlag::F_LAST.value();
+                        deltas.push(last_delta);
+                    }
+                    break;
+                }
+                Err(e) => return self.stream.fail(e),
+            }
+
+            let delta = match self.parse_record() {
+                Ok(delta) => delta,
+                Err(e) => return self.stream.fail(e),
+            };
+
+            // Hold back each delta until the next is known, so that F_LAST can be set
+            if let Some(mut pending) = self.pending.replace(delta) {
+                if pending.ts_event != delta.ts_event {
+                    pending.flags = RecordFlag::F_LAST.value();
+                }
+                deltas.push(pending);
+            }
+        }
+
+        if deltas.is_empty() {
+            None
+        } else {
+            Some(Ok(deltas))
+        }
+    }
+}
+
+/// An iterator yielding chunks of [`QuoteTick`]s from a Tardis format CSV.
+pub struct QuoteStreamIterator {
+    stream: RecordStream,
+    precisions: Precisions,
+    instrument_id: Option<InstrumentId>,
+}
+
+impl QuoteStreamIterator {
+    fn parse_record(&self) -> anyhow::Result<QuoteTick> {
+        let record: TardisQuoteRecord = self.stream.raw_record.deserialize(None)?;
+
+        let instrument_id = match &self.instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let bid_price = self.precisions.price(record.bid_price.unwrap_or(0.0))?;
+        let bid_size = self.precisions.quantity(record.bid_amount.unwrap_or(0.0))?;
+        let ask_price = self.precisions.price(record.ask_price.unwrap_or(0.0))?;
+        let ask_size = self.precisions.quantity(record.ask_amount.unwrap_or(0.0))?;
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+
+        Ok(QuoteTick::new(
+            instrument_id,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            ts_event,
+            ts_init,
+        ))
+    }
+}
+
+impl Iterator for QuoteStreamIterator {
+    type Item = anyhow::Result<Vec<QuoteTick>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut quotes = Vec::with_capacity(self.stream.chunk_size);
+
+        while quotes.len() < self.stream.chunk_size {
+            match self.stream.read_next() {
+                Ok(true) => match self.parse_record() {
+                    Ok(quote) => quotes.push(quote),
+                    Err(e) => return self.stream.fail(e),
+                },
+                Ok(false) => break,
+                Err(e) => return self.stream.fail(e),
+            }
+        }
+
+        if quotes.is_empty() {
+            None
+        } else {
+            Some(Ok(quotes))
+        }
+    }
+}
+
+/// An iterator yielding chunks of [`TradeTick`]s from a Tardis format CSV.
+pub struct TradeStreamIterator {
+    stream: RecordStream,
+    precisions: Precisions,
+    instrument_id: Option<InstrumentId>,
+}
+
+impl TradeStreamIterator {
+    fn parse_record(&self) -> anyhow::Result<TradeTick> {
+        let record: TardisTradeRecord = self.stream.raw_record.deserialize(None)?;
+
+        let instrument_id = match &self.instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let price = self.precisions.price(record.price)?;
+        let size = self.precisions.quantity(record.amount)?;
+        let aggressor_side = parse_aggressor_side(&record.side);
+        let trade_id = TradeId::new(&record.id);
+        let ts_event = parse_timestamp(record.timestamp);
+        let ts_init = parse_timestamp(record.local_timestamp);
+
+        Ok(TradeTick::new(
+            instrument_id,
+            price,
+            size,
+            aggressor_side,
+            trade_id,
+            ts_event,
+            ts_init,
+        ))
+    }
+}
+
+impl Iterator for TradeStreamIterator {
+    type Item = anyhow::Result<Vec<TradeTick>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut trades = Vec::with_capacity(self.stream.chunk_size);
+
+        while trades.len() < self.stream.chunk_size {
+            match self.stream.read_next() {
+                Ok(true) => match self.parse_record() {
+                    Ok(trade) => trades.push(trade),
+                    Err(e) => return self.stream.fail(e),
+                },
+                Ok(false) => break,
+                Err(e) => return self.stream.fail(e),
+            }
+        }
+
+        if trades.is_empty() {
+            None
+        } else {
+            Some(Ok(trades))
+        }
+    }
+}
+
+/// Streams [`OrderBookDelta`]s from a Tardis format CSV at the given `filepath`, yielding chunks
+/// of at most `chunk_size` deltas.
+///
+/// Any precision which is not specified is inferred from the first
+/// [`PRECISION_INFERENCE_SAMPLE_SIZE`] records of the file, a later record requiring a higher
+/// precision is yielded as an error (rather than being rounded), after which the stream ends.
+///
+/// # Errors
+///
+/// This function returns an error if `chunk_size` is zero, or if the file cannot be opened or
+/// sampled for precision inference.
+pub fn stream_deltas<P: AsRef<Path>>(
+    filepath: P,
+    chunk_size: usize,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> anyhow::Result<DeltaStreamIterator> {
+    let precisions = Precisions::resolve(
+        &filepath,
+        price_precision,
+        size_precision,
+        limit,
+        |raw_record| {
+            let record: TardisBookUpdateRecord = raw_record.deserialize(None)?;
+            Ok((vec![record.price], vec![record.amount]))
+        },
+    )?;
+
+    Ok(DeltaStreamIterator {
+        stream: RecordStream::new(filepath, chunk_size, limit)?,
+        precisions,
+        instrument_id,
+        pending: None,
+    })
+}
+
+/// Streams [`QuoteTick`]s from a Tardis format CSV at the given `filepath`, yielding chunks of
+/// at most `chunk_size` quotes.
+///
+/// Any precision which is not specified is inferred from the first
+/// [`PRECISION_INFERENCE_SAMPLE_SIZE`] records of the file, a later record requiring a higher
+/// precision is yielded as an error (rather than being rounded), after which the stream ends.
+///
+/// # Errors
+///
+/// This function returns an error if `chunk_size` is zero, or if the file cannot be opened or
+/// sampled for precision inference.
+pub fn stream_quote_ticks<P: AsRef<Path>>(
+    filepath: P,
+    chunk_size: usize,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> anyhow::Result<QuoteStreamIterator> {
+    let precisions = Precisions::resolve(
+        &filepath,
+        price_precision,
+        size_precision,
+        limit,
+        |raw_record| {
+            let record: TardisQuoteRecord = raw_record.deserialize(None)?;
+            let prices = [record.bid_price, record.ask_price];
+            let sizes = [record.bid_amount, record.ask_amount];
+            Ok((
+                prices.into_iter().flatten().collect(),
+                sizes.into_iter().flatten().collect(),
+            ))
+        },
+    )?;
+
+    Ok(QuoteStreamIterator {
+        stream: RecordStream::new(filepath, chunk_size, limit)?,
+        precisions,
+        instrument_id,
+    })
+}
+
+/// Streams [`TradeTick`]s from a Tardis format CSV at the given `filepath`, yielding chunks of
+/// at most `chunk_size` trades.
+///
+/// Any precision which is not specified is inferred from the first
+/// [`PRECISION_INFERENCE_SAMPLE_SIZE`] records of the file, a later record requiring a higher
+/// precision is yielded as an error (rather than being rounded), after which the stream ends.
+///
+/// # Errors
+///
+/// This function returns an error if `chunk_size` is zero, or if the file cannot be opened or
+/// sampled for precision inference.
+pub fn stream_trade_ticks<P: AsRef<Path>>(
+    filepath: P,
+    chunk_size: usize,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> anyhow::Result<TradeStreamIterator> {
+    let precisions = Precisions::resolve(
+        &filepath,
+        price_precision,
+        size_precision,
+        limit,
+        |raw_record| {
+            let record: TardisTradeRecord = raw_record.deserialize(None)?;
+            Ok((vec![record.price], vec![record.amount]))
+        },
+    )?;
+
+    Ok(TradeStreamIterator {
+        stream: RecordStream::new(filepath, chunk_size, limit)?,
+        precisions,
+        instrument_id,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::{AggressorSide, BookAction, OrderSide};
+    use rstest::*;
+
+    use super::*;
+    use crate::{csv::load_trade_ticks, tests::load_test_file_path};
+
+    #[rstest]
+    #[case(8531.5, 1)]
+    #[case(6421.0, 0)]
+    #[case(0.000_001, 6)]
+    #[case(2152.0, 0)]
+    fn test_infer_precision(#[case] value: f64, #[case] expected: u8) {
+        assert_eq!(infer_precision(value), expected);
+    }
+
+    #[rstest]
+    fn test_stream_deltas_chunks_and_flags() {
+        let filepath = load_test_file_path("book_updates.csv");
+        let chunks: Vec<Vec<OrderBookDelta>> = stream_deltas(filepath, 4, None, None, None, None)
+            .unwrap()
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 4);
+        assert_eq!(chunks[1].len(), 2);
+
+        let deltas: Vec<OrderBookDelta> = chunks.into_iter().flatten().collect();
+        let flags: Vec<u8> = deltas.iter().map(|d| d.flags).collect();
+        assert_eq!(flags, vec![0, 128, 128, 0, 0, 128]);
+        assert_eq!(
+            deltas[0].instrument_id,
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT")
+        );
+        assert_eq!(deltas[0].action, BookAction::Add);
+        assert_eq!(deltas[0].order.side, OrderSide::Sell);
+        assert_eq!(deltas[0].order.price, Price::from("6421.50"));
+        assert_eq!(deltas[0].order.size, Quantity::from("18640.0"));
+        assert_eq!(deltas[3].action, BookAction::Delete);
+        assert_eq!(deltas[0].ts_event, 1585699200245000000);
+        assert_eq!(deltas[0].ts_init, 1585699200355684000);
+    }
+
+    #[rstest]
+    fn test_stream_deltas_with_limit_sets_final_flag() {
+        let filepath = load_test_file_path("book_updates.csv");
+        let deltas: Vec<OrderBookDelta> =
+            stream_deltas(filepath, 10, Some(1), Some(0), None, Some(4))
+                .unwrap()
+                .flat_map(Result::unwrap)
+                .collect();
+
+        assert_eq!(deltas.len(), 4);
+        assert_eq!(deltas[3].order.price.precision, 1);
+        assert_eq!(deltas[3].order.size.precision, 0);
+        assert_eq!(deltas[3].flags, RecordFlag::F_LAST.value());
+    }
+
+    #[rstest]
+    fn test_stream_deltas_with_zero_chunk_size_errors() {
+        let filepath = load_test_file_path("book_updates.csv");
+        assert!(stream_deltas(filepath, 0, Some(1), Some(0), None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_stream_quote_ticks_infers_precisions() {
+        let filepath = load_test_file_path("quotes.csv");
+        let chunks: Vec<Vec<QuoteTick>> = stream_quote_ticks(filepath, 2, None, None, None, None)
+            .unwrap()
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        let quote = chunks[0][0];
+        assert_eq!(quote.instrument_id, InstrumentId::from("BTC-USD.HUOBI"));
+        assert_eq!(quote.bid_price, Price::from("8629.20"));
+        assert_eq!(quote.ask_price, Price::from("8629.30"));
+        assert_eq!(quote.bid_size, Quantity::from("806.00"));
+        assert_eq!(quote.ask_size, Quantity::from("5494.00"));
+        assert_eq!(quote.ts_event, 1588291201099000000);
+        assert_eq!(quote.ts_init, 1588291201234268000);
+    }
+
+    #[rstest]
+    fn test_stream_quote_ticks_with_explicit_price_precision() {
+        let filepath = load_test_file_path("quotes.csv");
+        let quotes: Vec<QuoteTick> = stream_quote_ticks(filepath, 2, Some(3), None, None, None)
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        assert_eq!(quotes.len(), 5);
+        assert_eq!(quotes[0].bid_price.precision, 3);
+        assert_eq!(quotes[0].bid_size.precision, 2);
+    }
+
+    #[rstest]
+    fn test_stream_trade_ticks_from_gzip_matches_load() {
+        let filepath = load_test_file_path("trades.csv.gz");
+        let instrument_id = InstrumentId::from("XBTUSD-PERP.BITMEX");
+        let chunks: Vec<Vec<TradeTick>> =
+            stream_trade_ticks(&filepath, 3, None, None, Some(instrument_id), None)
+                .unwrap()
+                .collect::<anyhow::Result<_>>()
+                .unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        let trades: Vec<TradeTick> = chunks.into_iter().flatten().collect();
+        let expected = load_trade_ticks(&filepath, 1, 0, Some(instrument_id), None).unwrap();
+        assert_eq!(trades, expected);
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Seller);
+        assert_eq!(
+            trades[0].trade_id,
+            TradeId::new("ccc3c1fa-212c-e8b0-1706-9b9c4f3d5ecf")
+        );
+    }
+
+    #[rstest]
+    fn test_stream_trade_ticks_when_price_exceeds_inferred_precision_errors_and_ends() {
+        let filepath = load_test_file_path("trades.csv");
+        // Simulates a precision inferred from a sample not containing the 8531.5 price
+        let mut stream = TradeStreamIterator {
+            stream: RecordStream::new(filepath, 2, None).unwrap(),
+            precisions: Precisions {
+                price: 0,
+                size: 0,
+                price_inferred: true,
+                size_inferred: true,
+            },
+            instrument_id: None,
+        };
+
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+
+    #[rstest]
+    fn test_stream_trade_ticks_with_explicit_precision_rounds() {
+        let filepath = load_test_file_path("trades.csv");
+        let trades: Vec<TradeTick> = stream_trade_ticks(filepath, 2, Some(0), Some(0), None, None)
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        assert_eq!(trades[0].price, Price::from("8532"));
+    }
+
+    #[rstest]
+    fn test_stream_trade_ticks_with_limit() {
+        let filepath = load_test_file_path("trades.csv");
+        let chunks: Vec<Vec<TradeTick>> =
+            stream_trade_ticks(filepath, 2, None, None, None, Some(3))
+                .unwrap()
+                .collect::<anyhow::Result<_>>()
+                .unwrap();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+    }
+}
@@ -287,7 +287,7 @@ fn get_size_increment(value: f64) -> Quantity {
 }
 
 /// Returns the currency either from the internal currency map or creates a default crypto.
-fn get_currency(code: &str) -> Currency {
+pub(crate) fn get_currency(code: &str) -> Currency {
     CURRENCY_MAP
         .lock()
         .unwrap()
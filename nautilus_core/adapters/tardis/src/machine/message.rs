@@ -98,6 +98,30 @@ pub struct TradeMsg {
     pub local_timestamp: DateTime<Utc>,
 }
 
+/// Represents a Tardis WebSocket message for liquidations.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationMsg {
+    /// The symbol as provided by the exchange.
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub symbol: Ustr,
+    /// The exchange ID.
+    pub exchange: Exchange,
+    /// The liquidation ID provided by the exchange (optional).
+    pub id: Option<String>,
+    /// The liquidation price as provided by the exchange.
+    pub price: f64,
+    /// The liquidation amount as provided by the exchange.
+    pub amount: f64,
+    /// The liquidation side, `buy` when a short position was liquidated and `sell` when a long
+    /// position was liquidated.
+    pub side: String,
+    /// The liquidation timestamp provided by the exchange.
+    pub timestamp: DateTime<Utc>,
+    /// The local timestamp when the message was received.
+    pub local_timestamp: DateTime<Utc>,
+}
+
 /// Derivative instrument ticker info sourced from real-time ticker & instrument channels.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -186,6 +210,7 @@ pub enum WsMessage {
     BookSnapshot(BookSnapshotMsg),
     Trade(TradeMsg),
     TradeBar(BarMsg),
+    Liquidation(LiquidationMsg),
     DerivativeTicker(DerivativeTickerMsg),
     Disconnect(DisconnectMsg),
 }
@@ -272,6 +297,38 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_parse_liquidation_message() {
+        let json_data = load_test_json("liquidation.json");
+        let message: LiquidationMsg = serde_json::from_str(&json_data).unwrap();
+
+        assert_eq!(message.symbol, "XBTUSD");
+        assert_eq!(message.exchange, Exchange::Bitmex);
+        assert_eq!(
+            message.id,
+            Some("6b6f3d7c-90fa-4e3b-a7d8-e1c5a2b3f4d5".to_string())
+        );
+        assert_eq!(message.price, 7_992.5);
+        assert_eq!(message.amount, 1_200.0);
+        assert_eq!(message.side, "buy");
+        assert_eq!(
+            message.timestamp,
+            DateTime::parse_from_rfc3339("2019-10-23T10:32:49.669Z").unwrap()
+        );
+        assert_eq!(
+            message.local_timestamp,
+            DateTime::parse_from_rfc3339("2019-10-23T10:32:49.740Z").unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_parse_liquidation_ws_message() {
+        let json_data = load_test_json("liquidation.json");
+        let message: WsMessage = serde_json::from_str(&json_data).unwrap();
+
+        assert!(matches!(message, WsMessage::Liquidation(_)));
+    }
+
     #[rstest]
     fn test_parse_derivative_ticker_message() {
         let json_data = load_test_json("derivative_ticker.json");
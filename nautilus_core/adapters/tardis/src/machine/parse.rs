@@ -19,8 +19,9 @@ use chrono::{DateTime, Utc};
 use nautilus_core::UnixNanos;
 use nautilus_model::{
     data::{
-        Bar, BarType, BookOrder, Data, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, BarType, BookOrder, Data, IndexPriceUpdate, Liquidation, MarkPriceUpdate,
+        OpenInterestUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API, QuoteTick,
+        TradeTick,
     },
     enums::{AggregationSource, OrderSide, RecordFlag},
     identifiers::{InstrumentId, TradeId},
@@ -29,48 +30,68 @@ use nautilus_model::{
 use uuid::Uuid;
 
 use super::{
-    message::{BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, TradeMsg, WsMessage},
+    message::{
+        BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, DerivativeTickerMsg, LiquidationMsg,
+        TradeMsg, WsMessage,
+    },
     types::InstrumentMiniInfo,
 };
-use crate::parse::{parse_aggressor_side, parse_bar_spec, parse_book_action};
+use crate::parse::{
+    parse_aggressor_side, parse_bar_spec, parse_book_action, parse_liquidation_side,
+};
 
+/// Parses the given Tardis Machine `msg` into Nautilus data.
+///
+/// Most messages produce a single data item, a derivative ticker produces one item for each of
+/// its mark price, index price and open interest fields which are present.
 #[must_use]
-pub fn parse_tardis_ws_message(msg: WsMessage, info: Arc<InstrumentMiniInfo>) -> Option<Data> {
+pub fn parse_tardis_ws_message(msg: WsMessage, info: Arc<InstrumentMiniInfo>) -> Vec<Data> {
     match msg {
-        WsMessage::BookChange(msg) => Some(Data::Deltas(parse_book_change_msg_as_deltas(
+        WsMessage::BookChange(msg) => vec![Data::Deltas(parse_book_change_msg_as_deltas(
             msg,
             info.price_precision,
             info.size_precision,
             info.instrument_id,
-        ))),
+        ))],
         WsMessage::BookSnapshot(msg) => match msg.bids.len() {
-            1 => Some(Data::Quote(parse_book_snapshot_msg_as_quote(
+            1 => vec![Data::Quote(parse_book_snapshot_msg_as_quote(
                 msg,
                 info.price_precision,
                 info.size_precision,
                 info.instrument_id,
-            ))),
-            _ => Some(Data::Deltas(parse_book_snapshot_msg_as_deltas(
+            ))],
+            _ => vec![Data::Deltas(parse_book_snapshot_msg_as_deltas(
                 msg,
                 info.price_precision,
                 info.size_precision,
                 info.instrument_id,
-            ))),
+            ))],
         },
-        WsMessage::Trade(msg) => Some(Data::Trade(parse_trade_msg(
+        WsMessage::Trade(msg) => vec![Data::Trade(parse_trade_msg(
+            msg,
+            info.price_precision,
+            info.size_precision,
+            info.instrument_id,
+        ))],
+        WsMessage::TradeBar(msg) => vec![Data::Bar(parse_bar_msg(
+            msg,
+            info.price_precision,
+            info.size_precision,
+            info.instrument_id,
+        ))],
+        WsMessage::Liquidation(msg) => vec![Data::Liquidation(parse_liquidation_msg(
             msg,
             info.price_precision,
             info.size_precision,
             info.instrument_id,
-        ))),
-        WsMessage::TradeBar(msg) => Some(Data::Bar(parse_bar_msg(
+        ))],
+        WsMessage::DerivativeTicker(msg) => parse_derivative_ticker_msg(
             msg,
             info.price_precision,
             info.size_precision,
             info.instrument_id,
-        ))),
-        WsMessage::DerivativeTicker(_) => None,
-        WsMessage::Disconnect(_) => None,
+        ),
+        WsMessage::Disconnect(_) => Vec::new(),
     }
 }
 
@@ -252,6 +273,33 @@ pub fn parse_trade_msg(
     )
 }
 
+/// Parses a liquidation `msg`, assigning a random UUID v4 trade ID when the exchange did not
+/// provide one.
+#[must_use]
+pub fn parse_liquidation_msg(
+    msg: LiquidationMsg,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: InstrumentId,
+) -> Liquidation {
+    let side = parse_liquidation_side(&msg.side);
+    let price = Price::new(msg.price, price_precision);
+    let quantity = Quantity::new(msg.amount, size_precision);
+    let trade_id = TradeId::new(msg.id.unwrap_or_else(|| Uuid::new_v4().to_string()));
+    let ts_event = UnixNanos::from(msg.timestamp);
+    let ts_init = UnixNanos::from(msg.local_timestamp);
+
+    Liquidation::new(
+        instrument_id,
+        side,
+        price,
+        quantity,
+        trade_id,
+        ts_event,
+        ts_init,
+    )
+}
+
 #[must_use]
 pub fn parse_bar_msg(
     msg: BarMsg,
@@ -273,6 +321,51 @@ pub fn parse_bar_msg(
     Bar::new(bar_type, open, high, low, close, volume, ts_event, ts_init)
 }
 
+/// Parses a derivative ticker `msg` into mark price, index price and open interest updates.
+///
+/// Fields which the exchange did not provide are skipped.
+#[must_use]
+pub fn parse_derivative_ticker_msg(
+    msg: DerivativeTickerMsg,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: InstrumentId,
+) -> Vec<Data> {
+    let ts_event = UnixNanos::from(msg.timestamp);
+    let ts_init = UnixNanos::from(msg.local_timestamp);
+
+    let mut data = Vec::with_capacity(3);
+
+    if let Some(mark_price) = msg.mark_price {
+        data.push(Data::MarkPriceUpdate(MarkPriceUpdate::new(
+            instrument_id,
+            Price::new(mark_price, price_precision),
+            ts_event,
+            ts_init,
+        )));
+    }
+
+    if let Some(index_price) = msg.index_price {
+        data.push(Data::IndexPriceUpdate(IndexPriceUpdate::new(
+            instrument_id,
+            Price::new(index_price, price_precision),
+            ts_event,
+            ts_init,
+        )));
+    }
+
+    if let Some(open_interest) = msg.open_interest {
+        data.push(Data::OpenInterestUpdate(OpenInterestUpdate::new(
+            instrument_id,
+            Quantity::new(open_interest, size_precision),
+            ts_event,
+            ts_init,
+        )));
+    }
+
+    data
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -403,6 +496,29 @@ mod tests {
         assert_eq!(trade.ts_init, UnixNanos::from(1571826769740000000));
     }
 
+    #[rstest]
+    fn test_parse_liquidation_message() {
+        let json_data = load_test_json("liquidation.json");
+        let msg: LiquidationMsg = serde_json::from_str(&json_data).unwrap();
+
+        let price_precision = 1;
+        let size_precision = 0;
+        let instrument_id = InstrumentId::from("XBTUSD.BITMEX");
+        let liquidation =
+            parse_liquidation_msg(msg, price_precision, size_precision, instrument_id);
+
+        assert_eq!(liquidation.instrument_id, instrument_id);
+        assert_eq!(liquidation.side, OrderSide::Buy);
+        assert_eq!(liquidation.price, Price::from("7992.5"));
+        assert_eq!(liquidation.quantity, Quantity::from(1_200));
+        assert_eq!(
+            liquidation.trade_id,
+            TradeId::new("6b6f3d7c-90fa-4e3b-a7d8-e1c5a2b3f4d5")
+        );
+        assert_eq!(liquidation.ts_event, UnixNanos::from(1571826769669000000));
+        assert_eq!(liquidation.ts_init, UnixNanos::from(1571826769740000000));
+    }
+
     #[rstest]
     fn test_parse_bar_message() {
         let json_data = load_test_json("bar.json");
@@ -425,4 +541,46 @@ mod tests {
         assert_eq!(bar.ts_event, UnixNanos::from(1572009100000000000));
         assert_eq!(bar.ts_init, UnixNanos::from(1572009100369000000));
     }
+
+    #[rstest]
+    fn test_parse_derivative_ticker_message() {
+        let json_data = load_test_json("derivative_ticker.json");
+        let msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+
+        let price_precision = 2;
+        let size_precision = 0;
+        let instrument_id = InstrumentId::from("BTC-PERPETUAL.DERIBIT");
+        let data = parse_derivative_ticker_msg(msg, price_precision, size_precision, instrument_id);
+
+        assert_eq!(data.len(), 3);
+        let Data::MarkPriceUpdate(mark_price) = data[0] else {
+            panic!("Expected `MarkPriceUpdate`, was {:?}", data[0]);
+        };
+        let Data::IndexPriceUpdate(index_price) = data[1] else {
+            panic!("Expected `IndexPriceUpdate`, was {:?}", data[1]);
+        };
+        let Data::OpenInterestUpdate(open_interest) = data[2] else {
+            panic!("Expected `OpenInterestUpdate`, was {:?}", data[2]);
+        };
+        assert_eq!(mark_price.instrument_id, instrument_id);
+        assert_eq!(mark_price.value, Price::from("7987.56"));
+        assert_eq!(mark_price.ts_event, UnixNanos::from(1571830469302000000));
+        assert_eq!(mark_price.ts_init, UnixNanos::from(1571830469416000000));
+        assert_eq!(index_price.value, Price::from("7989.28"));
+        assert_eq!(open_interest.value, Quantity::from(84129491));
+    }
+
+    #[rstest]
+    fn test_parse_derivative_ticker_message_skips_missing_fields() {
+        let json_data = load_test_json("derivative_ticker.json");
+        let mut msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+        msg.index_price = None;
+        msg.open_interest = None;
+
+        let instrument_id = InstrumentId::from("BTC-PERPETUAL.DERIBIT");
+        let data = parse_derivative_ticker_msg(msg, 2, 0, instrument_id);
+
+        assert_eq!(data.len(), 1);
+        assert!(matches!(data[0], Data::MarkPriceUpdate(_)));
+    }
 }
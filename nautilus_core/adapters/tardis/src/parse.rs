@@ -122,6 +122,16 @@ pub fn parse_aggressor_side(value: &str) -> AggressorSide {
     }
 }
 
+/// Parses a Nautilus liquidation order side from the given Tardis string `value`.
+#[must_use]
+pub fn parse_liquidation_side(value: &str) -> OrderSide {
+    match value {
+        "buy" => OrderSide::Buy,
+        "sell" => OrderSide::Sell,
+        _ => OrderSide::NoOrderSide,
+    }
+}
+
 /// Parses a Nautilus option kind from the given Tardis enum `value`.
 #[must_use]
 pub const fn parse_option_kind(value: OptionType) -> OptionKind {
@@ -293,6 +303,15 @@ mod tests {
         assert_eq!(parse_aggressor_side(input), expected);
     }
 
+    #[rstest]
+    #[case("buy", OrderSide::Buy)]
+    #[case("sell", OrderSide::Sell)]
+    #[case("unknown", OrderSide::NoOrderSide)]
+    #[case("", OrderSide::NoOrderSide)]
+    fn test_parse_liquidation_side(#[case] input: &str, #[case] expected: OrderSide) {
+        assert_eq!(parse_liquidation_side(input), expected);
+    }
+
     #[rstest]
     fn test_parse_timestamp() {
         let input_timestamp: u64 = 1583020803145000;
@@ -17,13 +17,17 @@ use std::path::PathBuf;
 
 use nautilus_core::{ffi::cvec::CVec, python::to_pyvalue_err};
 use nautilus_model::{
-    data::{Data, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    data::{
+        Data, Liquidation, OptionGreeks, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+    },
     identifiers::InstrumentId,
+    instruments::OptionsContract,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
 use crate::csv::{
-    load_deltas, load_depth10_from_snapshot25, load_depth10_from_snapshot5, load_quote_ticks,
+    load_deltas, load_depth10_from_snapshot25, load_depth10_from_snapshot5,
+    load_derivative_tickers, load_liquidations, load_options_chain, load_quote_ticks,
     load_trade_ticks,
 };
 
@@ -246,3 +250,65 @@ pub fn py_load_tardis_trades_as_pycapsule(
     let capsule = PyCapsule::new_bound::<CVec>(py, cvec, None)?;
     Ok(capsule.into_py(py))
 }
+
+#[pyfunction(name = "load_tardis_liquidations")]
+#[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, limit=None))]
+pub fn py_load_tardis_liquidations(
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> PyResult<Vec<Liquidation>> {
+    load_liquidations(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        limit,
+    )
+    .map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "load_tardis_derivative_tickers")]
+#[pyo3(signature = (filepath, price_precision, size_precision, instrument_id=None, limit=None))]
+pub fn py_load_tardis_derivative_tickers(
+    py: Python,
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> PyResult<Vec<PyObject>> {
+    let data = load_derivative_tickers(
+        filepath,
+        price_precision,
+        size_precision,
+        instrument_id,
+        limit,
+    )
+    .map_err(to_pyvalue_err)?;
+
+    Ok(data
+        .into_iter()
+        .filter_map(|data| match data {
+            Data::MarkPriceUpdate(mark_price) => Some(mark_price.into_py(py)),
+            Data::IndexPriceUpdate(index_price) => Some(index_price.into_py(py)),
+            Data::OpenInterestUpdate(open_interest) => Some(open_interest.into_py(py)),
+            _ => None, // Only derivative ticker updates are loaded
+        })
+        .collect())
+}
+
+#[pyfunction(name = "load_tardis_options_chain")]
+#[pyo3(signature = (filepath, price_precision, size_precision, limit=None))]
+pub fn py_load_tardis_options_chain(
+    filepath: PathBuf,
+    price_precision: u8,
+    size_precision: u8,
+    limit: Option<usize>,
+) -> PyResult<(Vec<OptionsContract>, Vec<QuoteTick>, Vec<OptionGreeks>)> {
+    let chain = load_options_chain(filepath, price_precision, size_precision, limit)
+        .map_err(to_pyvalue_err)?;
+    Ok((chain.instruments, chain.quotes, chain.greeks))
+}
@@ -155,10 +155,13 @@ impl TardisMachineClient {
             while let Some(result) = stream.next().await {
                 match result {
                     Ok(msg) => {
-                        if let Some(Data::Bar(bar)) = determine_instrument_info(&msg, &map)
-                            .and_then(|info| parse_tardis_ws_message(msg, info))
-                        {
-                            bars.push(bar);
+                        if let Some(info) = determine_instrument_info(&msg, &map) {
+                            bars.extend(parse_tardis_ws_message(msg, info).into_iter().filter_map(
+                                |data| match data {
+                                    Data::Bar(bar) => Some(bar),
+                                    _ => None,
+                                },
+                            ));
                         }
                     }
                     Err(e) => {
@@ -246,7 +249,7 @@ async fn handle_python_stream<S>(
                 });
 
                 if let Some(info) = info {
-                    if let Some(data) = parse_tardis_ws_message(msg, info) {
+                    for data in parse_tardis_ws_message(msg, info) {
                         Python::with_gil(|py| {
                             let py_obj = data_to_pycapsule(py, data);
                             call_python(py, &callback, py_obj);
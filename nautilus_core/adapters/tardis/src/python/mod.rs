@@ -71,5 +71,8 @@ pub fn tardis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         csv::py_load_tardis_trades_as_pycapsule,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(csv::py_load_tardis_liquidations, m)?)?;
+    m.add_function(wrap_pyfunction!(csv::py_load_tardis_derivative_tickers, m)?)?;
+    m.add_function(wrap_pyfunction!(csv::py_load_tardis_options_chain, m)?)?;
     Ok(())
 }
@@ -26,14 +26,17 @@ use heck::ToSnakeCase;
 use nautilus_core::{parsing::precision_from_str, UnixNanos};
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, OrderBookDelta, OrderBookDeltas_API, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        Bar, BarType, Data, IndexPriceUpdate, Liquidation, MarkPriceUpdate, OpenInterestUpdate,
+        OrderBookDelta, OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
     },
     identifiers::InstrumentId,
 };
 use nautilus_serialization::{
     arrow::{
-        bars_to_arrow_record_batch_bytes, order_book_deltas_to_arrow_record_batch_bytes,
+        bars_to_arrow_record_batch_bytes, index_price_updates_to_arrow_record_batch_bytes,
+        liquidations_to_arrow_record_batch_bytes, mark_price_updates_to_arrow_record_batch_bytes,
+        open_interest_updates_to_arrow_record_batch_bytes,
+        order_book_deltas_to_arrow_record_batch_bytes,
         order_book_depth10_to_arrow_record_batch_bytes, quote_ticks_to_arrow_record_batch_bytes,
         trade_ticks_to_arrow_record_batch_bytes,
     },
@@ -175,6 +178,10 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
     let mut quotes_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
     let mut trades_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
     let mut bars_cursors: HashMap<BarType, DateCursor> = HashMap::new();
+    let mut mark_prices_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
+    let mut index_prices_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
+    let mut open_interest_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
+    let mut liquidations_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
 
     // Initialize date collection maps
     let mut deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>> = HashMap::new();
@@ -182,6 +189,10 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
     let mut quotes_map: HashMap<InstrumentId, Vec<QuoteTick>> = HashMap::new();
     let mut trades_map: HashMap<InstrumentId, Vec<TradeTick>> = HashMap::new();
     let mut bars_map: HashMap<BarType, Vec<Bar>> = HashMap::new();
+    let mut mark_prices_map: HashMap<InstrumentId, Vec<MarkPriceUpdate>> = HashMap::new();
+    let mut index_prices_map: HashMap<InstrumentId, Vec<IndexPriceUpdate>> = HashMap::new();
+    let mut open_interest_map: HashMap<InstrumentId, Vec<OpenInterestUpdate>> = HashMap::new();
+    let mut liquidations_map: HashMap<InstrumentId, Vec<Liquidation>> = HashMap::new();
 
     let mut msg_count = 0;
 
@@ -196,7 +207,30 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
             Data::Quote(msg) => handle_quote_msg(msg, &mut quotes_map, &mut quotes_cursors, &path),
             Data::Trade(msg) => handle_trade_msg(msg, &mut trades_map, &mut trades_cursors, &path),
             Data::Bar(msg) => handle_bar_msg(msg, &mut bars_map, &mut bars_cursors, &path),
+            Data::MarkPriceUpdate(msg) => {
+                handle_mark_price_msg(msg, &mut mark_prices_map, &mut mark_prices_cursors, &path);
+            }
+            Data::IndexPriceUpdate(msg) => {
+                handle_index_price_msg(msg, &mut index_prices_map, &mut index_prices_cursors, &path)
+            }
+            Data::OpenInterestUpdate(msg) => handle_open_interest_msg(
+                msg,
+                &mut open_interest_map,
+                &mut open_interest_cursors,
+                &path,
+            ),
+            Data::Liquidation(msg) => {
+                handle_liquidation_msg(
+                    msg,
+                    &mut liquidations_map,
+                    &mut liquidations_cursors,
+                    &path,
+                );
+            }
             Data::Delta(_) => panic!("Individual delta message not implemented (or required)"),
+            Data::AuctionImbalance(_) | Data::MarketStatistic(_) => {
+                panic!("Imbalance and statistics messages not supported by Tardis Machine")
+            }
         }
 
         msg_count += 1;
@@ -232,6 +266,34 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
         batch_and_write_bars(bars, &bar_type, cursor.date_utc, &path);
     }
 
+    for (instrument_id, mark_prices) in mark_prices_map {
+        let cursor = mark_prices_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_mark_prices(mark_prices, &instrument_id, cursor.date_utc, &path);
+    }
+
+    for (instrument_id, index_prices) in index_prices_map {
+        let cursor = index_prices_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_index_prices(index_prices, &instrument_id, cursor.date_utc, &path);
+    }
+
+    for (instrument_id, open_interest) in open_interest_map {
+        let cursor = open_interest_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_open_interest(open_interest, &instrument_id, cursor.date_utc, &path);
+    }
+
+    for (instrument_id, liquidations) in liquidations_map {
+        let cursor = liquidations_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_liquidations(liquidations, &instrument_id, cursor.date_utc, &path);
+    }
+
     tracing::info!(
         "Replay completed after {} messages",
         msg_count.separate_with_commas()
@@ -354,6 +416,118 @@ fn handle_bar_msg(
         .push(bar);
 }
 
+fn handle_mark_price_msg(
+    mark_price: MarkPriceUpdate,
+    map: &mut HashMap<InstrumentId, Vec<MarkPriceUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(mark_price.instrument_id)
+        .or_insert_with(|| DateCursor::new(mark_price.ts_init));
+
+    if mark_price.ts_init > cursor.end_ns {
+        if let Some(mark_prices_vec) = map.remove(&mark_price.instrument_id) {
+            batch_and_write_mark_prices(
+                mark_prices_vec,
+                &mark_price.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        };
+        // Update cursor
+        *cursor = DateCursor::new(mark_price.ts_init);
+    }
+
+    map.entry(mark_price.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(100_000))
+        .push(mark_price);
+}
+
+fn handle_index_price_msg(
+    index_price: IndexPriceUpdate,
+    map: &mut HashMap<InstrumentId, Vec<IndexPriceUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(index_price.instrument_id)
+        .or_insert_with(|| DateCursor::new(index_price.ts_init));
+
+    if index_price.ts_init > cursor.end_ns {
+        if let Some(index_prices_vec) = map.remove(&index_price.instrument_id) {
+            batch_and_write_index_prices(
+                index_prices_vec,
+                &index_price.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        };
+        // Update cursor
+        *cursor = DateCursor::new(index_price.ts_init);
+    }
+
+    map.entry(index_price.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(100_000))
+        .push(index_price);
+}
+
+fn handle_open_interest_msg(
+    open_interest: OpenInterestUpdate,
+    map: &mut HashMap<InstrumentId, Vec<OpenInterestUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(open_interest.instrument_id)
+        .or_insert_with(|| DateCursor::new(open_interest.ts_init));
+
+    if open_interest.ts_init > cursor.end_ns {
+        if let Some(open_interest_vec) = map.remove(&open_interest.instrument_id) {
+            batch_and_write_open_interest(
+                open_interest_vec,
+                &open_interest.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        };
+        // Update cursor
+        *cursor = DateCursor::new(open_interest.ts_init);
+    }
+
+    map.entry(open_interest.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(100_000))
+        .push(open_interest);
+}
+
+fn handle_liquidation_msg(
+    liquidation: Liquidation,
+    map: &mut HashMap<InstrumentId, Vec<Liquidation>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(liquidation.instrument_id)
+        .or_insert_with(|| DateCursor::new(liquidation.ts_init));
+
+    if liquidation.ts_init > cursor.end_ns {
+        if let Some(liquidations_vec) = map.remove(&liquidation.instrument_id) {
+            batch_and_write_liquidations(
+                liquidations_vec,
+                &liquidation.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        };
+        // Update cursor
+        *cursor = DateCursor::new(liquidation.ts_init);
+    }
+
+    map.entry(liquidation.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(100_000))
+        .push(liquidation);
+}
+
 fn batch_and_write_deltas(
     deltas: Vec<OrderBookDelta>,
     instrument_id: &InstrumentId,
@@ -414,6 +588,66 @@ fn batch_and_write_trades(
     };
 }
 
+fn batch_and_write_mark_prices(
+    mark_prices: Vec<MarkPriceUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(MarkPriceUpdate);
+    match mark_price_updates_to_arrow_record_batch_bytes(mark_prices) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    };
+}
+
+fn batch_and_write_index_prices(
+    index_prices: Vec<IndexPriceUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(IndexPriceUpdate);
+    match index_price_updates_to_arrow_record_batch_bytes(index_prices) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    };
+}
+
+fn batch_and_write_open_interest(
+    open_interest: Vec<OpenInterestUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(OpenInterestUpdate);
+    match open_interest_updates_to_arrow_record_batch_bytes(open_interest) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    };
+}
+
+fn batch_and_write_liquidations(
+    liquidations: Vec<Liquidation>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(Liquidation);
+    match liquidations_to_arrow_record_batch_bytes(liquidations) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    };
+}
+
 fn batch_and_write_bars(bars: Vec<Bar>, bar_type: &BarType, date: NaiveDate, path: &Path) {
     let typename = stringify!(Bar);
     let batch = match bars_to_arrow_record_batch_bytes(bars) {
@@ -15,14 +15,16 @@
 
 #[cfg(test)]
 #[must_use]
-pub fn load_test_json(file_name: &str) -> String {
-    use std::{fs, path::PathBuf};
-
-    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+pub fn load_test_file_path(file_name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("src")
         .join("tests")
         .join("data")
-        .join(file_name);
+        .join(file_name)
+}
 
-    fs::read_to_string(path).expect("Failed to read test JSON file")
+#[cfg(test)]
+#[must_use]
+pub fn load_test_json(file_name: &str) -> String {
+    std::fs::read_to_string(load_test_file_path(file_name)).expect("Failed to read test JSON file")
 }
@@ -22,13 +22,20 @@ use std::{
 use nautilus_core::UnixNanos;
 use nautilus_model::{
     accounts::Account,
-    identifiers::PositionId,
+    data::Bar,
+    events::AccountState,
+    identifiers::{AccountId, PositionId},
     position::Position,
     types::{Currency, Money},
 };
 use rust_decimal::Decimal;
 
-use crate::{statistic::PortfolioStatistic, Returns};
+use crate::{
+    attribution::{PerformanceAttribution, PerformanceAttributionReport},
+    statistic::PortfolioStatistic,
+    statistics::max_drawdown::calculate_drawdowns,
+    EquityCurve, Returns,
+};
 
 pub type Statistic = Arc<dyn PortfolioStatistic<Item = f64> + Send + Sync>;
 
@@ -37,6 +44,12 @@ pub type Statistic = Arc<dyn PortfolioStatistic<Item = f64> + Send + Sync>;
 /// The `PortfolioAnalyzer` tracks account balances, positions, and realized PnLs
 /// to provide comprehensive portfolio analysis including returns, PnL calculations,
 /// and customizable statistics.
+///
+/// An equity curve per currency is also built from the balances of each account and their
+/// mark-to-market unrealized PnL (sampled at most once per equity sample interval, when
+/// configured). When a single currency equity curve is available, return-based statistics
+/// are calculated from its time-weighted returns rather than from the realized returns of
+/// closed positions.
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
@@ -50,6 +63,12 @@ pub struct PortfolioAnalyzer {
     positions: Vec<Position>,
     realized_pnls: HashMap<Currency, Vec<(PositionId, f64)>>,
     returns: Returns,
+    benchmark_returns: Returns,
+    equity_sample_interval_ns: Option<u64>,
+    equity_balances: HashMap<AccountId, HashMap<Currency, f64>>,
+    equity_unrealized_pnls: HashMap<AccountId, HashMap<Currency, f64>>,
+    equity_curves: HashMap<Currency, EquityCurve>,
+    last_equity_sample: Option<UnixNanos>,
 }
 
 impl Default for PortfolioAnalyzer {
@@ -72,9 +91,22 @@ impl PortfolioAnalyzer {
             positions: Vec::new(),
             realized_pnls: HashMap::new(),
             returns: BTreeMap::new(),
+            benchmark_returns: BTreeMap::new(),
+            equity_sample_interval_ns: None,
+            equity_balances: HashMap::new(),
+            equity_unrealized_pnls: HashMap::new(),
+            equity_curves: HashMap::new(),
+            last_equity_sample: None,
         }
     }
 
+    /// Sets the minimum interval (nanoseconds) between mark-to-market equity samples.
+    ///
+    /// If `None` (the default), the equity curve is only sampled on account state events.
+    pub fn set_equity_sample_interval(&mut self, interval_ns: Option<u64>) {
+        self.equity_sample_interval_ns = interval_ns;
+    }
+
     /// Registers a new portfolio statistic for calculation.
     pub fn register_statistic(&mut self, statistic: Statistic) {
         self.statistics.insert(statistic.name(), statistic);
@@ -96,6 +128,10 @@ impl PortfolioAnalyzer {
         self.account_balances.clear();
         self.realized_pnls.clear();
         self.returns.clear();
+        self.equity_balances.clear();
+        self.equity_unrealized_pnls.clear();
+        self.equity_curves.clear();
+        self.last_equity_sample = None;
     }
 
     /// Returns all tracked currencies.
@@ -116,6 +152,220 @@ impl PortfolioAnalyzer {
         &self.returns
     }
 
+    /// Returns the registered benchmark returns.
+    #[must_use]
+    pub const fn benchmark_returns(&self) -> &Returns {
+        &self.benchmark_returns
+    }
+
+    /// Registers the benchmark `returns` for benchmark-relative statistics.
+    pub fn set_benchmark_returns(&mut self, returns: Returns) {
+        self.benchmark_returns = returns;
+    }
+
+    /// Registers benchmark returns calculated from the close-to-close returns of the
+    /// given `bars` (e.g. bars of an index instrument), in timestamp order.
+    pub fn set_benchmark_from_bars(&mut self, bars: &[Bar]) {
+        self.benchmark_returns = bars
+            .windows(2)
+            .filter(|pair| pair[0].close.as_f64() > 0.0)
+            .map(|pair| {
+                let (previous, bar) = (&pair[0], &pair[1]);
+                (
+                    bar.ts_event,
+                    bar.close.as_f64() / previous.close.as_f64() - 1.0,
+                )
+            })
+            .collect();
+    }
+
+    /// Returns the drawdown series calculated from the compounded returns.
+    #[must_use]
+    pub fn drawdowns(&self) -> Returns {
+        calculate_drawdowns(&self.returns)
+    }
+
+    /// Returns the equity curve for the given `currency` (if tracked).
+    #[must_use]
+    pub fn equity_curve(&self, currency: &Currency) -> Option<&EquityCurve> {
+        self.equity_curves.get(currency)
+    }
+
+    /// Returns the time-weighted returns between consecutive samples of the equity curve
+    /// for the given `currency`.
+    #[must_use]
+    pub fn equity_returns(&self, currency: &Currency) -> Returns {
+        self.equity_curve(currency)
+            .map(calculate_equity_returns)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether a mark-to-market equity sample is due at `timestamp`.
+    #[must_use]
+    pub fn is_equity_sample_due(&self, timestamp: UnixNanos) -> bool {
+        match (self.equity_sample_interval_ns, self.last_equity_sample) {
+            (None, _) => false,
+            (Some(interval_ns), Some(last)) => timestamp >= last + interval_ns,
+            (Some(_), None) => true,
+        }
+    }
+
+    /// Records the account balances from the given `event` and samples the equity curve.
+    pub fn add_account_state(&mut self, event: &AccountState) {
+        let balances = self.equity_balances.entry(event.account_id).or_default();
+        for balance in &event.balances {
+            balances.insert(balance.currency, balance.total.as_f64());
+        }
+        self.sample_equity(event.ts_event);
+    }
+
+    /// Records the mark-to-market `unrealized_pnls` of the account with the given
+    /// `account_id` (replacing any previous values) and samples the equity curve if due.
+    pub fn update_unrealized_pnls(
+        &mut self,
+        account_id: AccountId,
+        timestamp: UnixNanos,
+        unrealized_pnls: &[Money],
+    ) {
+        self.equity_unrealized_pnls.insert(
+            account_id,
+            unrealized_pnls
+                .iter()
+                .map(|pnl| (pnl.currency, pnl.as_f64()))
+                .collect(),
+        );
+
+        if self.is_equity_sample_due(timestamp) {
+            self.sample_equity(timestamp);
+        }
+    }
+
+    fn sample_equity(&mut self, timestamp: UnixNanos) {
+        let mut equities: HashMap<Currency, f64> = HashMap::new();
+        for (account_id, balances) in &self.equity_balances {
+            let unrealized_pnls = self.equity_unrealized_pnls.get(account_id);
+            for (currency, balance) in balances {
+                let unrealized_pnl = unrealized_pnls
+                    .and_then(|pnls| pnls.get(currency))
+                    .copied()
+                    .unwrap_or(0.0);
+                *equities.entry(*currency).or_default() += balance + unrealized_pnl;
+            }
+        }
+
+        for (currency, equity) in equities {
+            self.equity_curves
+                .entry(currency)
+                .or_default()
+                .insert(timestamp, equity);
+        }
+        self.last_equity_sample = Some(timestamp);
+    }
+
+    /// Calculates the performance attribution of the given `positions`, grouped by
+    /// strategy and by instrument, applying all registered statistics to each group.
+    #[must_use]
+    pub fn calculate_attribution(&self, positions: &[Position]) -> PerformanceAttributionReport {
+        let mut by_strategy: BTreeMap<_, Vec<&Position>> = BTreeMap::new();
+        let mut by_instrument: BTreeMap<_, Vec<&Position>> = BTreeMap::new();
+
+        for position in positions {
+            by_strategy
+                .entry(position.strategy_id)
+                .or_default()
+                .push(position);
+            by_instrument
+                .entry(position.instrument_id)
+                .or_default()
+                .push(position);
+        }
+
+        PerformanceAttributionReport {
+            strategies: by_strategy
+                .into_iter()
+                .map(|(strategy_id, group)| (strategy_id, self.attribute(&group)))
+                .collect(),
+            instruments: by_instrument
+                .into_iter()
+                .map(|(instrument_id, group)| (instrument_id, self.attribute(&group)))
+                .collect(),
+        }
+    }
+
+    fn attribute(&self, positions: &[&Position]) -> PerformanceAttribution {
+        let mut attribution = PerformanceAttribution {
+            position_count: positions.len(),
+            ..Default::default()
+        };
+        let mut realized_pnls: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        let mut returns = Returns::new();
+
+        for position in positions {
+            attribution.fill_count += position.events.len();
+
+            if let Some(pnl) = position.realized_pnl {
+                let code = pnl.currency.code.to_string();
+                *attribution.realized_pnls.entry(code.clone()).or_default() += pnl.as_f64();
+                realized_pnls.entry(code).or_default().push(pnl.as_f64());
+            }
+
+            for commission in position.commissions.values() {
+                *attribution
+                    .commissions
+                    .entry(commission.currency.code.to_string())
+                    .or_default() += commission.as_f64();
+            }
+
+            for fill in &position.events {
+                let quantity = fill.last_qty.as_f64() * position.multiplier.as_f64();
+                let (notional, currency) = if position.is_inverse {
+                    let currency = position.base_currency.unwrap_or(position.quote_currency);
+                    (quantity / fill.last_px.as_f64(), currency)
+                } else {
+                    (quantity * fill.last_px.as_f64(), position.quote_currency)
+                };
+                *attribution
+                    .turnover
+                    .entry(currency.code.to_string())
+                    .or_default() += notional;
+            }
+
+            // Only closed positions have a realized return at a known close time
+            if let Some(ts_closed) = position.ts_closed {
+                *returns.entry(ts_closed).or_default() += position.realized_return;
+            }
+        }
+
+        let owned_positions: Vec<Position> = positions.iter().map(|&p| p.clone()).collect();
+
+        for (name, stat) in &self.statistics {
+            for (code, pnls) in &realized_pnls {
+                if let Some(value) = stat.calculate_from_realized_pnls(pnls) {
+                    attribution
+                        .stats_pnls
+                        .entry(code.clone())
+                        .or_default()
+                        .insert(name.clone(), value);
+                }
+            }
+            if let Some(value) = stat.calculate_from_returns(&returns) {
+                attribution.stats_returns.insert(name.clone(), value);
+            }
+            if !self.benchmark_returns.is_empty() {
+                if let Some(value) =
+                    stat.calculate_from_benchmark(&returns, &self.benchmark_returns)
+                {
+                    attribution.stats_returns.insert(name.clone(), value);
+                }
+            }
+            if let Some(value) = stat.calculate_from_positions(&owned_positions) {
+                attribution.stats_general.insert(name.clone(), value);
+            }
+        }
+
+        attribution
+    }
+
     /// Calculates statistics based on account and position data.
     pub fn calculate_statistics(&mut self, account: &dyn Account, positions: &[Position]) {
         self.account_balances_starting = account.starting_balances();
@@ -277,12 +527,33 @@ impl PortfolioAnalyzer {
     }
 
     /// Gets all return-based performance statistics.
+    ///
+    /// Uses the equity curve returns when exactly one currency equity curve is tracked, since
+    /// equity in different currencies cannot be combined without conversion, otherwise the
+    /// realized position returns. Benchmark-relative statistics are included when benchmark
+    /// returns are registered.
     #[must_use]
     pub fn get_performance_stats_returns(&self) -> HashMap<String, f64> {
         let mut output = HashMap::new();
 
+        let equity_returns = match self.equity_curves.values().collect::<Vec<_>>().as_slice() {
+            [equity_curve] => calculate_equity_returns(equity_curve),
+            _ => Returns::new(),
+        };
+        let returns = if equity_returns.is_empty() {
+            &self.returns
+        } else {
+            &equity_returns
+        };
+
         for (name, stat) in &self.statistics {
-            if let Some(value) = stat.calculate_from_returns(&self.returns) {
+            if let Some(value) = stat.calculate_from_returns(returns) {
+                output.insert(name.clone(), value);
+            }
+            if self.benchmark_returns.is_empty() {
+                continue;
+            }
+            if let Some(value) = stat.calculate_from_benchmark(returns, &self.benchmark_returns) {
                 output.insert(name.clone(), value);
             }
         }
@@ -362,22 +633,36 @@ impl PortfolioAnalyzer {
     }
 }
 
+/// Calculates the time-weighted returns between consecutive samples of the `equity_curve`.
+fn calculate_equity_returns(equity_curve: &EquityCurve) -> Returns {
+    equity_curve
+        .iter()
+        .zip(equity_curve.iter().skip(1))
+        .filter(|((_, &previous), _)| previous > 0.0)
+        .map(|((_, &previous), (&timestamp, &equity))| (timestamp, equity / previous - 1.0))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
+    use nautilus_core::UUID4;
     use nautilus_model::{
+        accounts::AccountError,
+        data::BarType,
         enums::{AccountType, LiquiditySide, OrderSide},
         events::{AccountState, OrderFilled},
         identifiers::{
             stubs::{instrument_id_aud_usd_sim, strategy_id_ema_cross, trader_id},
-            AccountId, ClientOrderId,
+            AccountId, ClientOrderId, InstrumentId, StrategyId,
         },
         instruments::InstrumentAny,
         types::{AccountBalance, Money, Price, Quantity},
     };
 
     use super::*;
+    use crate::statistics::{beta::Beta, max_drawdown::MaxDrawdown};
 
     /// Mock implementation of `PortfolioStatistic` for testing.
     #[derive(Debug)]
@@ -516,7 +801,7 @@ mod tests {
         fn balances(&self) -> HashMap<Currency, AccountBalance> {
             todo!()
         }
-        fn apply(&mut self, _: AccountState) {
+        fn apply(&mut self, _: AccountState) -> Result<(), AccountError> {
             todo!()
         }
         fn calculate_balance_locked(
@@ -743,6 +1028,306 @@ mod tests {
         assert!(general_formatted.iter().all(|s| s.contains(':')));
     }
 
+    #[test]
+    fn test_drawdowns() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.add_return(UnixNanos::from(1), 0.1);
+        analyzer.add_return(UnixNanos::from(2), -0.5);
+        analyzer.add_return(UnixNanos::from(3), 1.0);
+
+        let drawdowns = analyzer.drawdowns();
+
+        assert_eq!(drawdowns.len(), 3);
+        assert_eq!(drawdowns[&UnixNanos::from(1)], 0.0);
+        assert!((drawdowns[&UnixNanos::from(2)] + 0.5).abs() < 1e-12);
+        assert!(drawdowns[&UnixNanos::from(3)].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_statistics_without_implementation_are_skipped() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(Arc::new(MaxDrawdown {}));
+        analyzer.add_return(UnixNanos::from(1), -0.1);
+        analyzer.add_positions(&[create_mock_position(
+            "AUD/USD".to_owned(),
+            100.0,
+            0.1,
+            Currency::USD(),
+        )]);
+
+        let return_stats = analyzer.get_performance_stats_returns();
+        let general_stats = analyzer.get_performance_stats_general();
+
+        assert!(return_stats.contains_key("MaxDrawdown"));
+        assert!(general_stats.is_empty());
+    }
+
+    fn account_state(total: f64, ts_event: u64) -> AccountState {
+        let currency = Currency::USD();
+        AccountState::new(
+            AccountId::new("SIM-001"),
+            AccountType::Cash,
+            vec![AccountBalance::new(
+                Money::new(total, currency),
+                Money::new(0.0, currency),
+                Money::new(total, currency),
+            )],
+            vec![],
+            true,
+            UUID4::new(),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+            Some(currency),
+        )
+    }
+
+    #[test]
+    fn test_equity_curve_from_account_states() {
+        let mut analyzer = PortfolioAnalyzer::new();
+
+        analyzer.add_account_state(&account_state(1000.0, 1));
+        analyzer.add_account_state(&account_state(1100.0, 2));
+        analyzer.add_account_state(&account_state(990.0, 3));
+
+        let equity_curve = analyzer.equity_curve(&Currency::USD()).unwrap();
+        assert_eq!(
+            equity_curve.values().copied().collect::<Vec<f64>>(),
+            vec![1000.0, 1100.0, 990.0]
+        );
+
+        let returns = analyzer.equity_returns(&Currency::USD());
+        assert_eq!(returns.len(), 2);
+        assert!((returns[&UnixNanos::from(2)] - 0.1).abs() < 1e-12);
+        assert!((returns[&UnixNanos::from(3)] + 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_equity_curve_samples_unrealized_pnl_at_interval() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.set_equity_sample_interval(Some(10));
+        analyzer.add_account_state(&account_state(1000.0, 0));
+
+        let usd = Currency::USD();
+        let account_id = AccountId::new("SIM-001");
+        analyzer.update_unrealized_pnls(account_id, UnixNanos::from(5), &[Money::new(50.0, usd)]);
+        analyzer.update_unrealized_pnls(account_id, UnixNanos::from(10), &[Money::new(100.0, usd)]);
+        analyzer.update_unrealized_pnls(account_id, UnixNanos::from(15), &[Money::new(-20.0, usd)]);
+        analyzer.update_unrealized_pnls(account_id, UnixNanos::from(20), &[Money::new(-50.0, usd)]);
+
+        let equity_curve = analyzer.equity_curve(&usd).unwrap();
+        assert_eq!(
+            equity_curve
+                .iter()
+                .map(|(k, v)| (k.as_u64(), *v))
+                .collect::<Vec<_>>(),
+            vec![(0, 1000.0), (10, 1100.0), (20, 950.0)]
+        );
+    }
+
+    #[test]
+    fn test_equity_curve_without_sample_interval_ignores_unrealized_pnl_updates() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.add_account_state(&account_state(1000.0, 0));
+        analyzer.update_unrealized_pnls(
+            AccountId::new("SIM-001"),
+            UnixNanos::from(10),
+            &[Money::new(50.0, Currency::USD())],
+        );
+
+        assert!(!analyzer.is_equity_sample_due(UnixNanos::from(10)));
+        assert_eq!(analyzer.equity_curve(&Currency::USD()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_equity_curve_combines_balances_of_multiple_accounts() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let usd = Currency::USD();
+
+        analyzer.add_account_state(&account_state(1000.0, 1));
+        let mut other_account_state = account_state(500.0, 2);
+        other_account_state.account_id = AccountId::new("OTHER-001");
+        analyzer.add_account_state(&other_account_state);
+        analyzer.add_account_state(&account_state(1100.0, 3));
+
+        let equity_curve = analyzer.equity_curve(&usd).unwrap();
+        assert_eq!(
+            equity_curve.values().copied().collect::<Vec<f64>>(),
+            vec![1000.0, 1500.0, 1600.0]
+        );
+        assert!(analyzer.equity_curve(&Currency::EUR()).is_none());
+    }
+
+    #[test]
+    fn test_returns_stats_use_realized_returns_when_multiple_equity_currencies() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(MockStatistic::new("test_stat"));
+        analyzer.add_return(UnixNanos::from(1), 0.5);
+
+        let eur = Currency::EUR();
+        let mut eur_account_state = account_state(1000.0, 1);
+        eur_account_state.account_id = AccountId::new("EUR-001");
+        eur_account_state.balances = vec![AccountBalance::new(
+            Money::new(1000.0, eur),
+            Money::new(0.0, eur),
+            Money::new(1000.0, eur),
+        )];
+        analyzer.add_account_state(&eur_account_state);
+        analyzer.add_account_state(&account_state(1000.0, 1));
+        analyzer.add_account_state(&account_state(1100.0, 2));
+
+        assert_eq!(analyzer.get_performance_stats_returns()["test_stat"], 0.5);
+    }
+
+    #[test]
+    fn test_returns_stats_use_equity_returns_when_available() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(MockStatistic::new("test_stat"));
+        analyzer.add_return(UnixNanos::from(1), 0.5);
+
+        assert_eq!(analyzer.get_performance_stats_returns()["test_stat"], 0.5);
+
+        analyzer.add_account_state(&account_state(1000.0, 1));
+        analyzer.add_account_state(&account_state(1100.0, 2));
+
+        assert!((analyzer.get_performance_stats_returns()["test_stat"] - 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calculate_attribution() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(MockStatistic::new("test_stat"));
+        let currency = Currency::USD();
+
+        let mut position1 = create_mock_position("P-1".to_owned(), 100.0, 0.1, currency);
+        position1
+            .commissions
+            .insert(currency, Money::new(2.0, currency));
+        position1.multiplier = Quantity::from(1);
+        position1.events = vec![
+            OrderFilled::default(),
+            OrderFilled {
+                order_side: OrderSide::Sell,
+                last_px: Price::from("1.10000"),
+                ..Default::default()
+            },
+        ];
+        position1.ts_closed = Some(UnixNanos::from(1));
+        let mut position2 = create_mock_position("P-2".to_owned(), -40.0, -0.05, currency);
+        position2.strategy_id = StrategyId::new("S-002");
+        position2.ts_closed = Some(UnixNanos::from(1));
+        let mut position3 = create_mock_position("P-3".to_owned(), 10.0, 0.01, currency);
+        position3.strategy_id = StrategyId::new("S-002");
+        position3.instrument_id = InstrumentId::from("GBP/USD.SIM");
+        position3.ts_closed = Some(UnixNanos::from(2));
+
+        let report = analyzer.calculate_attribution(&[position1, position2, position3]);
+
+        assert_eq!(report.strategies.len(), 2);
+        assert_eq!(report.instruments.len(), 2);
+
+        let ema_cross = &report.strategies[&strategy_id_ema_cross()];
+        assert_eq!(ema_cross.position_count, 1);
+        assert_eq!(ema_cross.realized_pnls["USD"], 100.0);
+        assert_eq!(ema_cross.commissions["USD"], 2.0);
+        assert_eq!(ema_cross.stats_pnls["USD"]["test_stat"], 100.0);
+        assert_eq!(ema_cross.stats_general["test_stat"], 1.0);
+
+        let other = &report.strategies[&StrategyId::new("S-002")];
+        assert_eq!(other.position_count, 2);
+        assert_eq!(other.realized_pnls["USD"], -30.0);
+        assert_eq!(other.stats_returns["test_stat"], -0.04);
+
+        let audusd = &report.instruments[&instrument_id_aud_usd_sim()];
+        assert_eq!(audusd.position_count, 2);
+        assert_eq!(audusd.realized_pnls["USD"], 60.0);
+        assert_eq!(audusd.fill_count, 2);
+        assert!((audusd.turnover["USD"] - 210_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_calculate_attribution_excludes_open_positions_from_returns() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(MockStatistic::new("test_stat"));
+        let currency = Currency::USD();
+
+        let mut closed = create_mock_position("P-1".to_owned(), 100.0, 0.1, currency);
+        closed.ts_closed = Some(UnixNanos::from(1));
+        let open = create_mock_position("P-2".to_owned(), 0.0, 0.5, currency);
+
+        let report = analyzer.calculate_attribution(&[closed, open]);
+
+        let ema_cross = &report.strategies[&strategy_id_ema_cross()];
+        assert_eq!(ema_cross.position_count, 2);
+        assert_eq!(ema_cross.stats_returns["test_stat"], 0.1);
+    }
+
+    #[test]
+    fn test_attribution_report_serialization_round_trip() {
+        let analyzer = PortfolioAnalyzer::new();
+        let currency = Currency::USD();
+        let position = create_mock_position("P-1".to_owned(), 100.0, 0.1, currency);
+
+        let report = analyzer.calculate_attribution(&[position]);
+        let json = serde_json::to_string(&report).unwrap();
+        let deserialized: PerformanceAttributionReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, report);
+    }
+
+    #[test]
+    fn test_set_benchmark_from_bars() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let bar_type = BarType::from("AUD/USD.SIM-1-DAY-LAST-EXTERNAL");
+        let bars: Vec<Bar> = [100.0, 110.0, 99.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| {
+                let price = Price::new(close, 1);
+                let ts = UnixNanos::from(i as u64 + 1);
+                Bar::new(
+                    bar_type,
+                    price,
+                    price,
+                    price,
+                    price,
+                    Quantity::from(1),
+                    ts,
+                    ts,
+                )
+            })
+            .collect();
+
+        analyzer.set_benchmark_from_bars(&bars);
+
+        let benchmark = analyzer.benchmark_returns();
+        assert_eq!(benchmark.len(), 2);
+        assert!((benchmark[&UnixNanos::from(2)] - 0.1).abs() < 1e-12);
+        assert!((benchmark[&UnixNanos::from(3)] + 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_returns_stats_include_benchmark_statistics() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        analyzer.register_statistic(Arc::new(Beta {}));
+
+        let one_day = 86_400_000_000_000;
+        let benchmark: Returns = [0.01, -0.02, 0.03]
+            .iter()
+            .enumerate()
+            .map(|(i, &r)| (UnixNanos::from(i as u64 * one_day), r))
+            .collect();
+        for (&ts, &r) in &benchmark {
+            analyzer.add_return(ts, r * 2.0);
+        }
+
+        assert!(analyzer.get_performance_stats_returns().is_empty());
+
+        analyzer.set_benchmark_returns(benchmark);
+
+        let stats = analyzer.get_performance_stats_returns();
+        assert!((stats["Beta"] - 2.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_reset() {
         let mut analyzer = PortfolioAnalyzer::new();
@@ -772,5 +1357,7 @@ mod tests {
         assert!(analyzer.account_balances.is_empty());
         assert!(analyzer.realized_pnls.is_empty());
         assert!(analyzer.returns.is_empty());
+        assert!(analyzer.equity_curves.is_empty());
+        assert!(analyzer.last_equity_sample.is_none());
     }
 }
@@ -0,0 +1,53 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Performance attribution of positions by strategy and instrument.
+
+use std::collections::BTreeMap;
+
+use nautilus_model::identifiers::{InstrumentId, StrategyId};
+use serde::{Deserialize, Serialize};
+
+/// The performance attributed to a group of positions.
+///
+/// Monetary values and PnL statistics are keyed by currency code.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceAttribution {
+    /// The number of positions in the group.
+    pub position_count: usize,
+    /// The number of fills applied to the positions in the group.
+    pub fill_count: usize,
+    /// The realized PnL per currency.
+    pub realized_pnls: BTreeMap<String, f64>,
+    /// The commissions paid per currency.
+    pub commissions: BTreeMap<String, f64>,
+    /// The traded notional value per currency.
+    pub turnover: BTreeMap<String, f64>,
+    /// The statistics calculated from realized PnLs, per currency.
+    pub stats_pnls: BTreeMap<String, BTreeMap<String, f64>>,
+    /// The statistics calculated from returns.
+    pub stats_returns: BTreeMap<String, f64>,
+    /// The statistics calculated from positions.
+    pub stats_general: BTreeMap<String, f64>,
+}
+
+/// A performance attribution report grouping positions by strategy and by instrument.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceAttributionReport {
+    /// The performance attributed to each strategy.
+    pub strategies: BTreeMap<StrategyId, PerformanceAttribution>,
+    /// The performance attributed to each instrument.
+    pub instruments: BTreeMap<InstrumentId, PerformanceAttribution>,
+}
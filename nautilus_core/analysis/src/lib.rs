@@ -32,8 +32,10 @@
 //! - `python`: Enables Python bindings from `pyo3` (requires the `msgspec` Python package).
 
 pub mod analyzer;
+pub mod attribution;
 pub mod statistic;
 pub mod statistics;
+pub mod tca;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -43,3 +45,5 @@ use std::collections::BTreeMap;
 use nautilus_core::UnixNanos;
 
 pub type Returns = BTreeMap<UnixNanos, f64>;
+
+pub type EquityCurve = BTreeMap<UnixNanos, f64>;
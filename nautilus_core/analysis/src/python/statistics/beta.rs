@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use pyo3::prelude::*;
+
+use super::transform_returns;
+use crate::{statistic::PortfolioStatistic, statistics::beta::Beta};
+
+#[pymethods]
+impl Beta {
+    fn __repr__(&self) -> String {
+        format!("Beta({})", self.name(),)
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "calculate_from_benchmark")]
+    fn py_calculate_from_benchmark(
+        &mut self,
+        raw_returns: BTreeMap<u64, f64>,
+        raw_benchmark: BTreeMap<u64, f64>,
+    ) -> Option<f64> {
+        self.calculate_from_benchmark(
+            &transform_returns(raw_returns),
+            &transform_returns(raw_benchmark),
+        )
+    }
+}
@@ -13,11 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod alpha;
+pub mod benchmark_correlation;
+pub mod beta;
+pub mod cagr;
+pub mod calmar_ratio;
 pub mod expectancy;
+pub mod information_ratio;
 pub mod long_ratio;
 pub mod loser_avg;
 pub mod loser_max;
 pub mod loser_min;
+pub mod max_drawdown;
+pub mod max_drawdown_duration;
+pub mod omega_ratio;
 pub mod profit_factor;
 pub mod returns_avg;
 pub mod returns_avg_loss;
@@ -26,6 +35,10 @@ pub mod returns_volatlity;
 pub mod risk_return_ratio;
 pub mod sharpe_ratio;
 pub mod sortino_ratio;
+pub mod tail_ratio;
+pub mod tracking_error;
+pub mod ulcer_index;
+pub mod value_at_risk;
 pub mod win_rate;
 pub mod winner_avg;
 pub mod winner_max;
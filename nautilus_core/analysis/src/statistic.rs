@@ -19,8 +19,6 @@ use nautilus_model::{orders::Order, position::Position};
 
 use crate::Returns;
 
-const IMPL_ERR: &str = "is not implemented for";
-
 #[allow(unused_variables)]
 pub trait PortfolioStatistic: Debug {
     type Item;
@@ -28,23 +26,28 @@ pub trait PortfolioStatistic: Debug {
     fn name(&self) -> String;
 
     fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
-        panic!("`calculate_from_returns` {IMPL_ERR} `{}`", self.name());
+        None
     }
 
     fn calculate_from_realized_pnls(&self, realized_pnls: &[f64]) -> Option<Self::Item> {
-        panic!(
-            "`calculate_from_realized_pnls` {IMPL_ERR} `{}`",
-            self.name()
-        );
+        None
     }
 
     #[allow(dead_code)]
     fn calculate_from_orders(&self, orders: Vec<Box<dyn Order>>) -> Option<Self::Item> {
-        panic!("`calculate_from_orders` {IMPL_ERR} `{}`", self.name());
+        None
     }
 
     fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
-        panic!("`calculate_from_positions` {IMPL_ERR} `{}`", self.name());
+        None
+    }
+
+    fn calculate_from_benchmark(
+        &self,
+        returns: &Returns,
+        benchmark: &Returns,
+    ) -> Option<Self::Item> {
+        None
     }
 
     fn check_valid_returns(&self, returns: &Returns) -> bool {
@@ -78,4 +81,50 @@ pub trait PortfolioStatistic: Debug {
 
         variance.sqrt()
     }
+
+    /// Aligns the daily bins of the `returns` and `benchmark` returns on their common days.
+    fn align_to_benchmark(&self, returns: &Returns, benchmark: &Returns) -> (Vec<f64>, Vec<f64>) {
+        let returns = self.downsample_to_daily_bins(returns);
+        let benchmark = self.downsample_to_daily_bins(benchmark);
+
+        returns
+            .iter()
+            .filter_map(|(day, &r)| benchmark.get(day).map(|&b| (r, b)))
+            .unzip()
+    }
+
+    /// Calculates the sample covariance of the paired values `x` and `y`.
+    fn calculate_covariance(&self, x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len().min(y.len()) as f64;
+        if n < 2.0 {
+            return f64::NAN;
+        }
+
+        let mean_x = x.iter().sum::<f64>() / n;
+        let mean_y = y.iter().sum::<f64>() / n;
+
+        x.iter()
+            .zip(y)
+            .map(|(a, b)| (a - mean_x) * (b - mean_y))
+            .sum::<f64>()
+            / (n - 1.0)
+    }
+
+    /// Calculates the `percentile` (0 to 100) of `values` using linear interpolation
+    /// between the closest ranks.
+    fn calculate_percentile(&self, values: &[f64], percentile: f64) -> f64 {
+        if values.is_empty() {
+            return f64::NAN;
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let rank = (percentile / 100.0).clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let weight = rank - lower as f64;
+
+        sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+    }
 }
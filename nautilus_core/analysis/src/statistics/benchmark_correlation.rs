@@ -0,0 +1,111 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the Pearson correlation of the daily returns with the benchmark returns.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct BenchmarkCorrelation {}
+
+impl PortfolioStatistic for BenchmarkCorrelation {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(BenchmarkCorrelation).to_string()
+    }
+
+    fn calculate_from_benchmark(
+        &self,
+        returns: &Returns,
+        benchmark: &Returns,
+    ) -> Option<Self::Item> {
+        let (returns, benchmark) = self.align_to_benchmark(returns, benchmark);
+        let std_returns = self.calculate_covariance(&returns, &returns).sqrt();
+        let std_benchmark = self.calculate_covariance(&benchmark, &benchmark).sqrt();
+
+        if std_returns.is_nan() || std_returns < f64::EPSILON || std_benchmark < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(self.calculate_covariance(&returns, &benchmark) / (std_returns * std_benchmark))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let stat = BenchmarkCorrelation {};
+        let result =
+            stat.calculate_from_benchmark(&create_returns(vec![]), &create_returns(vec![]));
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_not_calculated_from_returns_only() {
+        let stat = BenchmarkCorrelation {};
+        assert!(stat
+            .calculate_from_returns(&create_returns(vec![0.01, 0.02]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_perfect_correlation() {
+        let stat = BenchmarkCorrelation {};
+        let benchmark = create_returns(vec![0.01, -0.02, 0.03, 0.0]);
+        let returns = create_returns(vec![0.02, -0.04, 0.06, 0.0]);
+        let result = stat.calculate_from_benchmark(&returns, &benchmark).unwrap();
+        assert!((result - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_correlation() {
+        let stat = BenchmarkCorrelation {};
+        let benchmark = create_returns(vec![0.01, -0.02, 0.03, 0.0]);
+        let returns = create_returns(vec![-0.01, 0.02, -0.03, 0.0]);
+        let result = stat.calculate_from_benchmark(&returns, &benchmark).unwrap();
+        assert!((result + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(BenchmarkCorrelation {}.name(), "BenchmarkCorrelation");
+    }
+}
@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the compound annual growth rate (CAGR) of the returns, annualized by the
+/// number of daily bins in `period` (252 trading days by default).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct Cagr {
+    period: usize,
+}
+
+impl Cagr {
+    /// Creates a new [`Cagr`] instance.
+    #[must_use]
+    pub fn new(period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(252),
+        }
+    }
+}
+
+impl PortfolioStatistic for Cagr {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        "CAGR".to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        let total_growth = returns.values().fold(1.0, |acc, r| acc * (1.0 + r));
+
+        if total_growth <= 0.0 {
+            return Some(-1.0); // Total loss
+        }
+
+        let years = returns.len() as f64 / self.period as f64;
+
+        Some(total_growth.powf(1.0 / years) - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let cagr = Cagr::new(None);
+        let returns = create_returns(vec![]);
+        let result = cagr.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_one_year_of_returns() {
+        let cagr = Cagr::new(None);
+        let mut values = vec![0.0; 252];
+        values[0] = 0.1;
+        let returns = create_returns(values);
+        let result = cagr.calculate_from_returns(&returns).unwrap();
+        assert!((result - 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_half_year_of_returns() {
+        let cagr = Cagr::new(Some(10));
+        let returns = create_returns(vec![0.1, 0.0, 0.0, 0.0, 0.0]);
+        let result = cagr.calculate_from_returns(&returns).unwrap();
+        assert!((result - (1.1_f64.powi(2) - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_total_loss() {
+        let cagr = Cagr::new(None);
+        let returns = create_returns(vec![0.1, -1.0]);
+        let result = cagr.calculate_from_returns(&returns);
+        assert_eq!(result, Some(-1.0));
+    }
+
+    #[test]
+    fn test_name() {
+        let cagr = Cagr::new(None);
+        assert_eq!(cagr.name(), "CAGR");
+    }
+}
@@ -0,0 +1,121 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    statistic::PortfolioStatistic,
+    statistics::{cagr::Cagr, max_drawdown::MaxDrawdown},
+    Returns,
+};
+
+/// Calculates the Calmar ratio, the CAGR divided by the absolute maximum drawdown.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct CalmarRatio {
+    period: usize,
+}
+
+impl CalmarRatio {
+    /// Creates a new [`CalmarRatio`] instance.
+    #[must_use]
+    pub fn new(period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(252),
+        }
+    }
+}
+
+impl PortfolioStatistic for CalmarRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(CalmarRatio).to_string()
+    }
+
+    fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(returns) {
+            return Some(f64::NAN);
+        }
+
+        let cagr = Cagr::new(Some(self.period)).calculate_from_returns(returns)?;
+        let max_drawdown = MaxDrawdown {}.calculate_from_returns(returns)?.abs();
+
+        if max_drawdown < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(cagr / max_drawdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let ratio = CalmarRatio::new(None);
+        let returns = create_returns(vec![]);
+        let result = ratio.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_no_drawdown() {
+        let ratio = CalmarRatio::new(None);
+        let returns = create_returns(vec![0.01, 0.02]);
+        let result = ratio.calculate_from_returns(&returns);
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_valid_calmar_ratio() {
+        let ratio = CalmarRatio::new(None);
+        let mut values = vec![0.0; 252];
+        values[0] = 0.1;
+        values[1] = -0.05;
+        let returns = create_returns(values);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        // CAGR 1.1 * 0.95 - 1 = 0.045, max drawdown 0.05
+        assert!((result - 0.9).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        let ratio = CalmarRatio::new(None);
+        assert_eq!(ratio.name(), "CalmarRatio");
+    }
+}
@@ -0,0 +1,119 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the annualized information ratio, the mean daily active return (returns less
+/// benchmark returns) divided by its standard deviation.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct InformationRatio {
+    period: usize,
+}
+
+impl InformationRatio {
+    /// Creates a new [`InformationRatio`] instance.
+    #[must_use]
+    pub fn new(period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(252),
+        }
+    }
+}
+
+impl PortfolioStatistic for InformationRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(InformationRatio).to_string()
+    }
+
+    fn calculate_from_benchmark(
+        &self,
+        returns: &Returns,
+        benchmark: &Returns,
+    ) -> Option<Self::Item> {
+        let (returns, benchmark) = self.align_to_benchmark(returns, benchmark);
+        let active: Vec<f64> = returns.iter().zip(&benchmark).map(|(r, b)| r - b).collect();
+        let std = self.calculate_covariance(&active, &active).sqrt();
+
+        if std.is_nan() || std < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        let mean = active.iter().sum::<f64>() / active.len() as f64;
+
+        Some((mean / std) * (self.period as f64).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let stat = InformationRatio::new(None);
+        let result =
+            stat.calculate_from_benchmark(&create_returns(vec![]), &create_returns(vec![]));
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_not_calculated_from_returns_only() {
+        let stat = InformationRatio::new(None);
+        assert!(stat
+            .calculate_from_returns(&create_returns(vec![0.01, 0.02]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_information_ratio() {
+        let stat = InformationRatio::new(Some(4));
+        let benchmark = create_returns(vec![0.01, -0.02, 0.03, 0.0]);
+        let returns = create_returns(vec![0.03, -0.01, 0.03, 0.0]);
+        let result = stat.calculate_from_benchmark(&returns, &benchmark).unwrap();
+        // Active returns [0.02, 0.01, 0.0, 0.0], mean 0.0075, sample std sqrt(0.000275 / 3)
+        let expected = 0.0075 / (0.000275_f64 / 3.0).sqrt() * 2.0;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(InformationRatio::new(None).name(), "InformationRatio");
+    }
+}
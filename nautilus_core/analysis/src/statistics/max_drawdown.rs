@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the drawdown series for the given `returns`.
+///
+/// Returns are compounded into an equity curve starting at 1.0, with each drawdown
+/// expressed as the fractional decline from the running peak (zero or negative).
+#[must_use]
+pub fn calculate_drawdowns(returns: &Returns) -> Returns {
+    let mut equity = 1.0;
+    let mut peak = 1.0;
+
+    returns
+        .iter()
+        .map(|(&timestamp, &value)| {
+            equity *= 1.0 + value;
+            peak = f64::max(peak, equity);
+            (timestamp, equity / peak - 1.0)
+        })
+        .collect()
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct MaxDrawdown {}
+
+impl PortfolioStatistic for MaxDrawdown {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(MaxDrawdown).to_string()
+    }
+
+    fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(returns) {
+            return Some(f64::NAN);
+        }
+
+        let max_drawdown = calculate_drawdowns(returns)
+            .values()
+            .copied()
+            .fold(0.0, f64::min);
+
+        Some(max_drawdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let max_drawdown = MaxDrawdown {};
+        let returns = create_returns(vec![]);
+        let result = max_drawdown.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_no_drawdown() {
+        let max_drawdown = MaxDrawdown {};
+        let returns = create_returns(vec![0.01, 0.02, 0.03]);
+        let result = max_drawdown.calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        let max_drawdown = MaxDrawdown {};
+        let returns = create_returns(vec![0.1, -0.2, 0.05, -0.1, 0.5]);
+        let result = max_drawdown.calculate_from_returns(&returns).unwrap();
+        // Peak 1.1, trough 1.1 * 0.8 * 1.05 * 0.9 = 0.8316
+        assert!((result - (0.8316 / 1.1 - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calculate_drawdowns() {
+        let returns = create_returns(vec![0.1, -0.5, 1.0]);
+        let drawdowns: Vec<f64> = calculate_drawdowns(&returns).into_values().collect();
+        assert_eq!(drawdowns.len(), 3);
+        assert_eq!(drawdowns[0], 0.0);
+        assert!((drawdowns[1] + 0.5).abs() < 1e-12);
+        assert!(drawdowns[2].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        let max_drawdown = MaxDrawdown {};
+        assert_eq!(max_drawdown.name(), "MaxDrawdown");
+    }
+}
@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the longest drawdown duration (in days), measured from a peak in the
+/// compounded returns until the peak is recovered, or until the last return if it never is.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct MaxDrawdownDuration {}
+
+impl PortfolioStatistic for MaxDrawdownDuration {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(MaxDrawdownDuration).to_string()
+    }
+
+    fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(returns) {
+            return Some(f64::NAN);
+        }
+
+        let nanos_per_day = 86_400_000_000_000.0;
+        let mut equity = 1.0;
+        let mut peak = 1.0;
+        let mut peak_ts = *returns.keys().next()?;
+        let mut last_ts = peak_ts;
+        let mut in_drawdown = false;
+        let mut max_duration_ns = 0;
+
+        for (&timestamp, &value) in returns {
+            equity *= 1.0 + value;
+            last_ts = timestamp;
+
+            if equity < peak {
+                in_drawdown = true;
+                continue;
+            }
+
+            if in_drawdown {
+                max_duration_ns = max_duration_ns.max(timestamp.as_u64() - peak_ts.as_u64());
+                in_drawdown = false;
+            }
+            peak = equity;
+            peak_ts = timestamp;
+        }
+
+        // Still in drawdown at the last return
+        if in_drawdown {
+            max_duration_ns = max_duration_ns.max(last_ts.as_u64() - peak_ts.as_u64());
+        }
+
+        Some(max_duration_ns as f64 / nanos_per_day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let duration = MaxDrawdownDuration {};
+        let returns = create_returns(vec![]);
+        let result = duration.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_no_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        let returns = create_returns(vec![0.01, 0.02, 0.03]);
+        let result = duration.calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[test]
+    fn test_recovered_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        // Peak on day 0, recovered on day 3
+        let returns = create_returns(vec![0.1, -0.1, 0.05, 0.1, -0.01]);
+        let result = duration.calculate_from_returns(&returns);
+        assert_eq!(result, Some(3.0));
+    }
+
+    #[test]
+    fn test_unrecovered_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        // Peak on day 1, never recovered through day 5
+        let returns = create_returns(vec![0.1, 0.1, -0.1, 0.01, 0.01, -0.02]);
+        let result = duration.calculate_from_returns(&returns);
+        assert_eq!(result, Some(4.0));
+    }
+
+    #[test]
+    fn test_name() {
+        let duration = MaxDrawdownDuration {};
+        assert_eq!(duration.name(), "MaxDrawdownDuration");
+    }
+}
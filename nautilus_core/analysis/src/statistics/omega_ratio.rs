@@ -0,0 +1,129 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the Omega ratio, the sum of daily returns above the `threshold` divided by
+/// the sum of daily shortfalls below it.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct OmegaRatio {
+    threshold: f64,
+}
+
+impl OmegaRatio {
+    /// Creates a new [`OmegaRatio`] instance.
+    #[must_use]
+    pub fn new(threshold: Option<f64>) -> Self {
+        Self {
+            threshold: threshold.unwrap_or(0.0),
+        }
+    }
+}
+
+impl PortfolioStatistic for OmegaRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(OmegaRatio).to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        let gains: f64 = returns
+            .values()
+            .map(|r| (r - self.threshold).max(0.0))
+            .sum();
+        let losses: f64 = returns
+            .values()
+            .map(|r| (self.threshold - r).max(0.0))
+            .sum();
+
+        if losses < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(gains / losses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let ratio = OmegaRatio::new(None);
+        let returns = create_returns(vec![]);
+        let result = ratio.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_no_losses() {
+        let ratio = OmegaRatio::new(None);
+        let returns = create_returns(vec![0.01, 0.02]);
+        let result = ratio.calculate_from_returns(&returns);
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_valid_omega_ratio() {
+        let ratio = OmegaRatio::new(None);
+        let returns = create_returns(vec![0.02, -0.01, 0.03, -0.02]);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!((result - 0.05 / 0.03).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_omega_ratio_with_threshold() {
+        let ratio = OmegaRatio::new(Some(0.01));
+        let returns = create_returns(vec![0.02, -0.01, 0.03, -0.02]);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!((result - 0.03 / 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        let ratio = OmegaRatio::new(None);
+        assert_eq!(ratio.name(), "OmegaRatio");
+    }
+}
@@ -0,0 +1,112 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the tail ratio, the absolute 95th percentile of daily returns divided by
+/// the absolute 5th percentile.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct TailRatio {}
+
+impl PortfolioStatistic for TailRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(TailRatio).to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns: Vec<f64> = self
+            .downsample_to_daily_bins(raw_returns)
+            .into_values()
+            .collect();
+        let upper = self.calculate_percentile(&returns, 95.0).abs();
+        let lower = self.calculate_percentile(&returns, 5.0).abs();
+
+        if lower < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(upper / lower)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let ratio = TailRatio {};
+        let returns = create_returns(vec![]);
+        let result = ratio.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_symmetric_returns() {
+        let ratio = TailRatio {};
+        let values: Vec<f64> = (-10..=10).map(|i| f64::from(i) / 100.0).collect();
+        let returns = create_returns(values);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!((result - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_right_skewed_returns() {
+        let ratio = TailRatio {};
+        let values: Vec<f64> = (-10..=10).map(|i| f64::from(i) / 100.0).collect();
+        let values = values
+            .into_iter()
+            .map(|x| if x > 0.0 { x * 2.0 } else { x })
+            .collect();
+        let returns = create_returns(values);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!((result - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        let ratio = TailRatio {};
+        assert_eq!(ratio.name(), "TailRatio");
+    }
+}
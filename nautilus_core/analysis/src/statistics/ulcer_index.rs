@@ -0,0 +1,101 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    statistic::PortfolioStatistic, statistics::max_drawdown::calculate_drawdowns, Returns,
+};
+
+/// Calculates the ulcer index, the root mean square of the drawdowns (as fractions)
+/// of the compounded returns.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct UlcerIndex {}
+
+impl PortfolioStatistic for UlcerIndex {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(UlcerIndex).to_string()
+    }
+
+    fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(returns) {
+            return Some(f64::NAN);
+        }
+
+        let drawdowns = calculate_drawdowns(returns);
+        let mean_square =
+            drawdowns.values().map(|dd| dd.powi(2)).sum::<f64>() / drawdowns.len() as f64;
+
+        Some(mean_square.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let ulcer_index = UlcerIndex {};
+        let returns = create_returns(vec![]);
+        let result = ulcer_index.calculate_from_returns(&returns);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_no_drawdown() {
+        let ulcer_index = UlcerIndex {};
+        let returns = create_returns(vec![0.01, 0.02]);
+        let result = ulcer_index.calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[test]
+    fn test_valid_ulcer_index() {
+        let ulcer_index = UlcerIndex {};
+        let returns = create_returns(vec![0.1, -0.5, 1.0]);
+        let result = ulcer_index.calculate_from_returns(&returns).unwrap();
+        assert!((result - (0.25_f64 / 3.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_name() {
+        let ulcer_index = UlcerIndex {};
+        assert_eq!(ulcer_index.name(), "UlcerIndex");
+    }
+}
@@ -0,0 +1,177 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{statistic::PortfolioStatistic, Returns};
+
+/// Calculates the historical value at risk (VaR), the daily return at the `1 - confidence`
+/// percentile (a loss is expressed as a negative return).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct ValueAtRisk {
+    confidence: f64,
+}
+
+impl ValueAtRisk {
+    /// Creates a new [`ValueAtRisk`] instance.
+    #[must_use]
+    pub fn new(confidence: Option<f64>) -> Self {
+        Self {
+            confidence: confidence.unwrap_or(0.95),
+        }
+    }
+}
+
+impl PortfolioStatistic for ValueAtRisk {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(ValueAtRisk).to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns: Vec<f64> = self
+            .downsample_to_daily_bins(raw_returns)
+            .into_values()
+            .collect();
+
+        Some(self.calculate_percentile(&returns, (1.0 - self.confidence) * 100.0))
+    }
+}
+
+/// Calculates the historical conditional value at risk (CVaR), the mean of the daily returns
+/// at or below the value at risk for the given `confidence`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct ConditionalValueAtRisk {
+    confidence: f64,
+}
+
+impl ConditionalValueAtRisk {
+    /// Creates a new [`ConditionalValueAtRisk`] instance.
+    #[must_use]
+    pub fn new(confidence: Option<f64>) -> Self {
+        Self {
+            confidence: confidence.unwrap_or(0.95),
+        }
+    }
+}
+
+impl PortfolioStatistic for ConditionalValueAtRisk {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(ConditionalValueAtRisk).to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns: Vec<f64> = self
+            .downsample_to_daily_bins(raw_returns)
+            .into_values()
+            .collect();
+        let var = self.calculate_percentile(&returns, (1.0 - self.confidence) * 100.0);
+        let tail: Vec<f64> = returns.into_iter().filter(|&r| r <= var).collect();
+
+        if tail.is_empty() {
+            return Some(var);
+        }
+
+        Some(tail.iter().sum::<f64>() / tail.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::UnixNanos;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let mut new_return = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            new_return.insert(UnixNanos::from(timestamp), value);
+        }
+
+        new_return
+    }
+
+    #[test]
+    fn test_empty_returns() {
+        let returns = create_returns(vec![]);
+        let var = ValueAtRisk::new(None).calculate_from_returns(&returns);
+        let cvar = ConditionalValueAtRisk::new(None).calculate_from_returns(&returns);
+        assert!(var.unwrap().is_nan());
+        assert!(cvar.unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_value_at_risk() {
+        let values: Vec<f64> = (-10..=10).map(|i| f64::from(i) / 100.0).collect();
+        let returns = create_returns(values);
+        let result = ValueAtRisk::new(None)
+            .calculate_from_returns(&returns)
+            .unwrap();
+        assert!((result + 0.09).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_conditional_value_at_risk() {
+        let values: Vec<f64> = (-10..=10).map(|i| f64::from(i) / 100.0).collect();
+        let returns = create_returns(values);
+        let result = ConditionalValueAtRisk::new(None)
+            .calculate_from_returns(&returns)
+            .unwrap();
+        assert!((result + 0.095).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_single_return() {
+        let returns = create_returns(vec![-0.02]);
+        let var = ValueAtRisk::new(Some(0.99)).calculate_from_returns(&returns);
+        let cvar = ConditionalValueAtRisk::new(Some(0.99)).calculate_from_returns(&returns);
+        assert_eq!(var, Some(-0.02));
+        assert_eq!(cvar, Some(-0.02));
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(ValueAtRisk::new(None).name(), "ValueAtRisk");
+        assert_eq!(
+            ConditionalValueAtRisk::new(None).name(),
+            "ConditionalValueAtRisk"
+        );
+    }
+}
@@ -0,0 +1,584 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Transaction cost analysis (TCA) of order executions.
+//!
+//! Execution costs are expressed in basis points relative to a reference price, with positive
+//! values representing a cost (paying up to buy or selling lower).
+
+use std::collections::{BTreeMap, HashMap};
+
+use nautilus_common::cache::Cache;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    enums::OrderSide,
+    events::OrderEventAny,
+    identifiers::{ClientOrderId, ExecAlgorithmId, InstrumentId, StrategyId},
+    orders::OrderAny,
+};
+use serde::{Deserialize, Serialize};
+
+const BPS: f64 = 10_000.0;
+
+/// The execution costs of a single order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderExecutionCosts {
+    /// The client order ID for the order.
+    pub client_order_id: ClientOrderId,
+    /// The strategy ID for the order.
+    pub strategy_id: StrategyId,
+    /// The instrument ID for the order.
+    pub instrument_id: InstrumentId,
+    /// The execution algorithm ID for the order (if any).
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    /// The order side.
+    pub side: OrderSide,
+    /// The order quantity.
+    pub quantity: f64,
+    /// The filled quantity.
+    pub filled_qty: f64,
+    /// The number of fills.
+    pub fill_count: usize,
+    /// UNIX timestamp (nanoseconds) when the order was initialized (the arrival time).
+    pub ts_arrival: UnixNanos,
+    /// The mid price of the last quote at or before arrival.
+    pub arrival_mid: Option<f64>,
+    /// The average fill price.
+    pub avg_px: Option<f64>,
+    /// The market VWAP of trades over the order's life.
+    pub vwap: Option<f64>,
+    /// The fill quantity weighted effective spread (bps), twice the signed distance of each
+    /// fill price from the prevailing mid.
+    pub effective_spread_bps: Option<f64>,
+    /// The slippage of the average fill price versus the arrival mid (bps).
+    pub slippage_arrival_bps: Option<f64>,
+    /// The slippage of the average fill price versus the market VWAP (bps).
+    pub slippage_vwap_bps: Option<f64>,
+    /// The implementation shortfall versus the arrival mid (bps), combining the slippage on
+    /// the filled quantity with the opportunity cost of the unfilled quantity.
+    pub implementation_shortfall_bps: Option<f64>,
+}
+
+impl OrderExecutionCosts {
+    /// Returns the fraction of the order quantity which was filled.
+    #[must_use]
+    pub fn fill_rate(&self) -> f64 {
+        if self.quantity > 0.0 {
+            self.filled_qty / self.quantity
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Aggregated execution costs for a group of orders.
+///
+/// Averages are simple means over the orders for which the cost could be calculated.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionCostsSummary {
+    /// The number of orders.
+    pub order_count: usize,
+    /// The number of orders with at least one fill.
+    pub filled_order_count: usize,
+    /// The number of fills.
+    pub fill_count: usize,
+    /// The mean fill rate of the orders.
+    pub fill_rate: f64,
+    /// The mean effective spread (bps).
+    pub avg_effective_spread_bps: Option<f64>,
+    /// The mean slippage versus the arrival mid (bps).
+    pub avg_slippage_arrival_bps: Option<f64>,
+    /// The mean slippage versus the market VWAP (bps).
+    pub avg_slippage_vwap_bps: Option<f64>,
+    /// The mean implementation shortfall (bps).
+    pub avg_implementation_shortfall_bps: Option<f64>,
+}
+
+impl ExecutionCostsSummary {
+    /// Creates a new [`ExecutionCostsSummary`] aggregating the given `orders`.
+    #[must_use]
+    pub fn new(orders: &[&OrderExecutionCosts]) -> Self {
+        if orders.is_empty() {
+            return Self::default();
+        }
+
+        Self {
+            order_count: orders.len(),
+            filled_order_count: orders.iter().filter(|o| o.fill_count > 0).count(),
+            fill_count: orders.iter().map(|o| o.fill_count).sum(),
+            fill_rate: orders.iter().map(|o| o.fill_rate()).sum::<f64>() / orders.len() as f64,
+            avg_effective_spread_bps: mean(orders.iter().map(|o| o.effective_spread_bps)),
+            avg_slippage_arrival_bps: mean(orders.iter().map(|o| o.slippage_arrival_bps)),
+            avg_slippage_vwap_bps: mean(orders.iter().map(|o| o.slippage_vwap_bps)),
+            avg_implementation_shortfall_bps: mean(
+                orders.iter().map(|o| o.implementation_shortfall_bps),
+            ),
+        }
+    }
+}
+
+/// A transaction cost analysis report, with the execution costs per order aggregated by
+/// strategy and by execution algorithm.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionCostReport {
+    /// The execution costs per order.
+    pub orders: Vec<OrderExecutionCosts>,
+    /// The aggregated execution costs per strategy.
+    pub strategies: BTreeMap<StrategyId, ExecutionCostsSummary>,
+    /// The aggregated execution costs per execution algorithm (orders without an
+    /// execution algorithm are only included in the strategy breakdown).
+    pub exec_algorithms: BTreeMap<ExecAlgorithmId, ExecutionCostsSummary>,
+}
+
+impl TransactionCostReport {
+    /// Creates a new [`TransactionCostReport`] aggregating the given `orders`.
+    #[must_use]
+    pub fn new(orders: Vec<OrderExecutionCosts>) -> Self {
+        let mut by_strategy: BTreeMap<StrategyId, Vec<&OrderExecutionCosts>> = BTreeMap::new();
+        let mut by_exec_algorithm: BTreeMap<ExecAlgorithmId, Vec<&OrderExecutionCosts>> =
+            BTreeMap::new();
+
+        for order in &orders {
+            by_strategy
+                .entry(order.strategy_id)
+                .or_default()
+                .push(order);
+            if let Some(exec_algorithm_id) = order.exec_algorithm_id {
+                by_exec_algorithm
+                    .entry(exec_algorithm_id)
+                    .or_default()
+                    .push(order);
+            }
+        }
+
+        let strategies = by_strategy
+            .into_iter()
+            .map(|(id, group)| (id, ExecutionCostsSummary::new(&group)))
+            .collect();
+        let exec_algorithms = by_exec_algorithm
+            .into_iter()
+            .map(|(id, group)| (id, ExecutionCostsSummary::new(&group)))
+            .collect();
+
+        Self {
+            orders,
+            strategies,
+            exec_algorithms,
+        }
+    }
+
+    /// Creates a new [`TransactionCostReport`] for all orders in the `cache`, using the
+    /// quotes and trades currently held in the cache as market reference prices.
+    #[must_use]
+    pub fn from_cache(cache: &Cache) -> Self {
+        let mut market_data: HashMap<InstrumentId, (Vec<QuoteTick>, Vec<TradeTick>)> =
+            HashMap::new();
+        let mut orders = Vec::new();
+
+        for order in cache.orders(None, None, None, None) {
+            let (quotes, trades) = market_data.entry(order.instrument_id()).or_insert_with(|| {
+                let mut quotes = cache.quotes(&order.instrument_id()).unwrap_or_default();
+                let mut trades = cache.trades(&order.instrument_id()).unwrap_or_default();
+                quotes.sort_by_key(|quote| quote.ts_event);
+                trades.sort_by_key(|trade| trade.ts_event);
+                (quotes, trades)
+            });
+
+            orders.push(calculate_order_execution_costs(order, quotes, trades));
+        }
+
+        Self::new(orders)
+    }
+}
+
+/// Calculates the execution costs of the given `order` against the market `quotes` and
+/// `trades` for its instrument (both sorted by `ts_event`).
+#[must_use]
+pub fn calculate_order_execution_costs(
+    order: &OrderAny,
+    quotes: &[QuoteTick],
+    trades: &[TradeTick],
+) -> OrderExecutionCosts {
+    let side = order.order_side();
+    let sign = match side {
+        OrderSide::Sell => -1.0,
+        _ => 1.0,
+    };
+    let ts_arrival = order.ts_init();
+    let ts_end = order.ts_last();
+
+    let fills: Vec<_> = order
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => Some(fill),
+            _ => None,
+        })
+        .collect();
+
+    let quantity = order.quantity().as_f64();
+    let filled_qty: f64 = fills.iter().map(|fill| fill.last_qty.as_f64()).sum();
+    let fill_rate = if quantity > 0.0 {
+        filled_qty / quantity
+    } else {
+        0.0
+    };
+    let avg_px = (filled_qty > 0.0).then(|| {
+        fills
+            .iter()
+            .map(|fill| fill.last_px.as_f64() * fill.last_qty.as_f64())
+            .sum::<f64>()
+            / filled_qty
+    });
+    let arrival_mid = mid_at(quotes, ts_arrival);
+    let vwap = calculate_vwap(trades, ts_arrival, ts_end);
+
+    // Effective spread weighted by fill quantity
+    let mut spread_qty = 0.0;
+    let mut spread_sum = 0.0;
+    for fill in &fills {
+        if let Some(mid) = mid_at(quotes, fill.ts_event) {
+            let qty = fill.last_qty.as_f64();
+            spread_sum += 2.0 * signed_bps(sign, fill.last_px.as_f64(), mid) * qty;
+            spread_qty += qty;
+        }
+    }
+    let effective_spread_bps = (spread_qty > 0.0).then(|| spread_sum / spread_qty);
+
+    let slippage_arrival_bps = avg_px
+        .zip(arrival_mid)
+        .map(|(px, mid)| signed_bps(sign, px, mid));
+    let slippage_vwap_bps = avg_px
+        .zip(vwap)
+        .map(|(px, vwap)| signed_bps(sign, px, vwap));
+
+    let implementation_shortfall_bps = arrival_mid.and_then(|arrival| {
+        let filled_cost = slippage_arrival_bps.unwrap_or(0.0) * fill_rate;
+        if fill_rate >= 1.0 {
+            return Some(filled_cost);
+        }
+        let opportunity_cost = signed_bps(sign, mid_at(quotes, ts_end)?, arrival);
+        Some(filled_cost + opportunity_cost * (1.0 - fill_rate))
+    });
+
+    OrderExecutionCosts {
+        client_order_id: order.client_order_id(),
+        strategy_id: order.strategy_id(),
+        instrument_id: order.instrument_id(),
+        exec_algorithm_id: order.exec_algorithm_id(),
+        side,
+        quantity,
+        filled_qty,
+        fill_count: fills.len(),
+        ts_arrival,
+        arrival_mid,
+        avg_px,
+        vwap,
+        effective_spread_bps,
+        slippage_arrival_bps,
+        slippage_vwap_bps,
+        implementation_shortfall_bps,
+    }
+}
+
+/// Returns the mid price of the last quote at or before `ts`.
+fn mid_at(quotes: &[QuoteTick], ts: UnixNanos) -> Option<f64> {
+    let index = quotes.partition_point(|quote| quote.ts_event <= ts);
+    let quote = quotes.get(index.checked_sub(1)?)?;
+    Some((quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0)
+}
+
+/// Returns the volume weighted average price of the trades between `start` and `end` (inclusive).
+fn calculate_vwap(trades: &[TradeTick], start: UnixNanos, end: UnixNanos) -> Option<f64> {
+    let (notional, volume) = trades
+        .iter()
+        .filter(|trade| trade.ts_event >= start && trade.ts_event <= end)
+        .fold((0.0, 0.0), |(notional, volume), trade| {
+            let size = trade.size.as_f64();
+            (notional + trade.price.as_f64() * size, volume + size)
+        });
+
+    (volume > 0.0).then(|| notional / volume)
+}
+
+fn signed_bps(sign: f64, price: f64, reference: f64) -> f64 {
+    sign * (price - reference) / reference * BPS
+}
+
+fn mean(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    let values: Vec<f64> = values.flatten().collect();
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{AggressorSide, OrderType},
+        identifiers::{AccountId, TradeId, VenueOrderId},
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument: &InstrumentAny, bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn trade(instrument: &InstrumentAny, price: &str, size: u64, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument.id(),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::new(format!("T-{ts}").as_str()),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn filled_order(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        fills: &[(&str, u64, u64)],
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(OrderType::Market);
+        builder
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(100_000));
+        if let Some(exec_algorithm_id) = exec_algorithm_id {
+            builder.exec_algorithm_id(exec_algorithm_id);
+        }
+        let mut order = builder.build();
+
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("SIM-001"),
+            ))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                AccountId::from("SIM-001"),
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+
+        for (i, &(px, qty, ts)) in fills.iter().enumerate() {
+            let fill = TestOrderEventStubs::order_filled(
+                &order,
+                instrument,
+                Some(TradeId::new(format!("E-{i}").as_str())),
+                None,
+                Some(Price::from(px)),
+                Some(Quantity::from(qty)),
+                None,
+                None,
+                Some(UnixNanos::from(ts)),
+                None,
+            );
+            let fill = match fill {
+                OrderEventAny::Filled(fill) if fill.last_qty < order.leaves_qty() => {
+                    OrderEventAny::PartiallyFilled(fill)
+                }
+                fill => fill,
+            };
+            order.apply(fill).unwrap();
+        }
+
+        order
+    }
+
+    #[rstest]
+    fn test_buy_order_execution_costs(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let quotes = vec![
+            quote(&instrument, "0.99990", "1.00010", 0),
+            quote(&instrument, "1.00090", "1.00110", 5),
+        ];
+        let trades = vec![
+            trade(&instrument, "1.00000", 100_000, 1),
+            trade(&instrument, "1.00100", 100_000, 6),
+        ];
+        let order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            &[("1.00010", 50_000, 1), ("1.00110", 50_000, 6)],
+            None,
+        );
+
+        let costs = calculate_order_execution_costs(&order, &quotes, &trades);
+
+        assert_eq!(costs.fill_count, 2);
+        assert_eq!(costs.fill_rate(), 1.0);
+        assert_eq!(costs.arrival_mid, Some(1.0));
+        assert!((costs.avg_px.unwrap() - 1.0006).abs() < 1e-9);
+        assert!((costs.vwap.unwrap() - 1.0005).abs() < 1e-9);
+        assert!((costs.slippage_arrival_bps.unwrap() - 6.0).abs() < 1e-6);
+        assert!((costs.slippage_vwap_bps.unwrap() - 1.0 / 1.0005).abs() < 1e-6);
+        // Each fill crossed one bp from the prevailing mid
+        assert!((costs.effective_spread_bps.unwrap() - 1.999_500_124_96).abs() < 1e-3);
+        assert_eq!(
+            costs.implementation_shortfall_bps,
+            costs.slippage_arrival_bps
+        );
+    }
+
+    #[rstest]
+    fn test_partially_filled_sell_order_execution_costs(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let quotes = vec![
+            quote(&instrument, "0.99990", "1.00010", 0),
+            quote(&instrument, "0.99890", "0.99910", 2),
+        ];
+        let order = filled_order(
+            &instrument,
+            OrderSide::Sell,
+            &[("0.99990", 50_000, 2)],
+            None,
+        );
+
+        let costs = calculate_order_execution_costs(&order, &quotes, &[]);
+
+        assert_eq!(costs.fill_rate(), 0.5);
+        assert!(costs.vwap.is_none());
+        assert!(costs.slippage_vwap_bps.is_none());
+        assert!((costs.slippage_arrival_bps.unwrap() - 1.0).abs() < 1e-6);
+        // Half filled at 1 bp cost, unfilled half lost 10 bps of adverse move
+        assert!((costs.implementation_shortfall_bps.unwrap() - 5.5).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_order_without_quotes_has_no_arrival_costs(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = filled_order(&instrument, OrderSide::Buy, &[], None);
+
+        let costs = calculate_order_execution_costs(&order, &[], &[]);
+
+        assert_eq!(costs.fill_count, 0);
+        assert_eq!(costs.fill_rate(), 0.0);
+        assert!(costs.arrival_mid.is_none());
+        assert!(costs.slippage_arrival_bps.is_none());
+        assert!(costs.effective_spread_bps.is_none());
+        assert!(costs.implementation_shortfall_bps.is_none());
+    }
+
+    #[rstest]
+    fn test_report_aggregates_by_strategy_and_exec_algorithm(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let quotes = vec![quote(&instrument, "0.99990", "1.00010", 0)];
+        let twap = ExecAlgorithmId::new("TWAP");
+        let orders = [
+            filled_order(
+                &instrument,
+                OrderSide::Buy,
+                &[("1.00020", 100_000, 1)],
+                Some(twap),
+            ),
+            filled_order(&instrument, OrderSide::Buy, &[("1.00040", 50_000, 1)], None),
+        ];
+
+        let report = TransactionCostReport::new(
+            orders
+                .iter()
+                .map(|order| calculate_order_execution_costs(order, &quotes, &[]))
+                .collect(),
+        );
+
+        assert_eq!(report.orders.len(), 2);
+        let strategy = &report.strategies[&orders[0].strategy_id()];
+        assert_eq!(strategy.order_count, 2);
+        assert_eq!(strategy.filled_order_count, 2);
+        assert_eq!(strategy.fill_rate, 0.75);
+        assert!((strategy.avg_slippage_arrival_bps.unwrap() - 3.0).abs() < 1e-6);
+
+        let algorithm = &report.exec_algorithms[&twap];
+        assert_eq!(algorithm.order_count, 1);
+        assert!((algorithm.avg_slippage_arrival_bps.unwrap() - 2.0).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_report_from_cache(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            &[("1.00020", 100_000, 1)],
+            None,
+        );
+        let mut cache = Cache::default();
+        cache
+            .add_quote(quote(&instrument, "0.99990", "1.00010", 0))
+            .unwrap();
+        cache.add_order(order, None, None, false).unwrap();
+
+        let report = TransactionCostReport::from_cache(&cache);
+
+        assert_eq!(report.orders.len(), 1);
+        assert_eq!(report.orders[0].arrival_mid, Some(1.0));
+        assert!((report.orders[0].slippage_arrival_bps.unwrap() - 2.0).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_report_serde_round_trip(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let quotes = vec![quote(&instrument, "0.99990", "1.00010", 0)];
+        let order = filled_order(
+            &instrument,
+            OrderSide::Buy,
+            &[("1.00020", 100_000, 1)],
+            Some(ExecAlgorithmId::new("TWAP")),
+        );
+        let report =
+            TransactionCostReport::new(vec![calculate_order_execution_costs(&order, &quotes, &[])]);
+
+        let json = serde_json::to_string(&report).unwrap();
+        let deserialized: TransactionCostReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.orders.len(), 1);
+        assert_eq!(
+            deserialized.orders[0].client_order_id,
+            order.client_order_id()
+        );
+        assert_eq!(deserialized.strategies.len(), 1);
+        assert_eq!(
+            deserialized.exec_algorithms.keys().collect::<Vec<_>>(),
+            vec![&ExecAlgorithmId::new("TWAP")]
+        );
+        assert!(
+            (deserialized.orders[0].slippage_arrival_bps.unwrap()
+                - report.orders[0].slippage_arrival_bps.unwrap())
+            .abs()
+                < 1e-9
+        );
+    }
+}
@@ -15,8 +15,16 @@
 
 //! The core `BacktestEngine` for backtesting on historical data.
 
+use std::collections::HashMap;
+
 use nautilus_common::{clock::TestClock, timer::TimeEventHandlerV2};
-use nautilus_core::UnixNanos;
+use nautilus_core::{AtomicTime, UnixNanos};
+use nautilus_model::{
+    data::{Data, GetTsInit},
+    identifiers::Venue,
+};
+
+use crate::exchange::SimulatedExchange;
 
 /// Provides a means of accumulating and draining time event handlers.
 pub struct TimeEventAccumulator {
@@ -56,18 +64,123 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Provides a backtest engine which processes historical data, in `ts_init` order, through the
+/// simulated exchanges of the data venues.
+pub struct BacktestEngine {
+    clock: &'static AtomicTime,
+    venues: HashMap<Venue, SimulatedExchange>,
+    data: Vec<Data>,
+}
+
+impl BacktestEngine {
+    /// Creates a new [`BacktestEngine`] instance, advancing the given `clock` as data is processed.
+    #[must_use]
+    pub fn new(clock: &'static AtomicTime) -> Self {
+        Self {
+            clock,
+            venues: HashMap::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Adds the simulated `exchange` for its venue, replacing any existing exchange.
+    pub fn add_venue(&mut self, exchange: SimulatedExchange) {
+        log::info!("Added venue {}", exchange.id());
+        self.venues.insert(exchange.id(), exchange);
+    }
+
+    #[must_use]
+    pub fn get_venue(&self, venue: &Venue) -> Option<&SimulatedExchange> {
+        self.venues.get(venue)
+    }
+
+    /// Adds the `data` to the engine, keeping all data sorted by `ts_init`.
+    pub fn add_data(&mut self, data: Vec<Data>) {
+        self.data.extend(data);
+        self.data.sort_by_key(GetTsInit::ts_init);
+    }
+
+    /// Runs the backtest, processing all added data through the exchanges of its venues.
+    pub fn run(&mut self) {
+        for data in std::mem::take(&mut self.data) {
+            let ts_init = data.ts_init();
+            if ts_init > self.clock.get_time_ns() {
+                self.clock.set_time(ts_init);
+            }
+            self.process_data(data);
+
+            for exchange in self.venues.values_mut() {
+                exchange.process(ts_init);
+            }
+        }
+    }
+
+    fn process_data(&mut self, data: Data) {
+        let venue = data.instrument_id().venue;
+        let Some(exchange) = self.venues.get_mut(&venue) else {
+            log::warn!("No exchange for venue {venue}, skipping {data:?}");
+            return;
+        };
+
+        match data {
+            Data::Delta(delta) => exchange.process_order_book_delta(delta),
+            Data::Deltas(deltas) => exchange.process_order_book_deltas(deltas.into_inner()),
+            Data::Quote(quote) => exchange.process_quote_tick(&quote),
+            Data::Trade(trade) => exchange.process_trade_tick(&trade),
+            Data::Bar(bar) => exchange.process_bar(bar),
+            Data::MarkPriceUpdate(mark_price) => exchange.process_mark_price(mark_price),
+            _ => {} // Not simulated by the exchange
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_common::timer::{TimeEvent, TimeEventCallback};
+    use std::{cell::RefCell, rc::Rc, sync::LazyLock};
+
+    use nautilus_common::{
+        cache::Cache,
+        msgbus::{
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+        timer::{TimeEvent, TimeEventCallback},
+    };
     use nautilus_core::UUID4;
+    use nautilus_model::{
+        accounts::{Account, AccountAny, CashAccount, MarginAccount},
+        data::{MarkPriceUpdate, QuoteTick},
+        enums::{AccountType, BookType, LiquiditySide, OmsType, OrderSide, OrderType},
+        events::{AccountState, OrderEventAny, OrderFilled},
+        identifiers::{
+            AccountId, ClientOrderId, PositionId, StrategyId, TradeId, TraderId, VenueOrderId,
+        },
+        instruments::{
+            stubs::{crypto_perpetual_ethusdt, currency_pair_btcusdt},
+            CryptoPerpetual, CurrencyPair, InstrumentAny,
+        },
+        position::Position,
+        types::{AccountBalance, Currency, Money, Price, Quantity},
+    };
     use pyo3::{prelude::*, types::PyList, Py, Python};
     use rstest::*;
     use ustr::Ustr;
 
     use super::*;
+    use crate::models::{
+        fee::{FeeModelAny, MakerTakerFeeModel},
+        fill::FillModel,
+        latency::LatencyModel,
+        liquidation::LiquidationModel,
+    };
+
+    static ATOMIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+    static INTEREST_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
@@ -119,4 +232,228 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    #[rstest]
+    fn test_run_accrues_borrow_interest_with_account_state(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let account_id = AccountId::from("BINANCE-001");
+        let balance = |total: &str| {
+            AccountBalance::new(
+                Money::from(total),
+                Money::from("0 USDT"),
+                Money::from(total),
+            )
+        };
+
+        // Cash account borrowing 1,000 USDT at 10% per year
+        let mut account = CashAccount::new(
+            AccountState::new(
+                account_id,
+                AccountType::Cash,
+                vec![balance("0 USDT")],
+                vec![],
+                true,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        account.set_allow_borrowing(true);
+        account.set_borrow_rate(Currency::USDT(), 0.1);
+        account
+            .update_balances(vec![balance("-1000 USDT")])
+            .unwrap();
+        let mut cache = Cache::default();
+        cache.add_account(AccountAny::Cash(account)).unwrap();
+        let cache = Rc::new(RefCell::new(cache));
+
+        let account_handler = get_message_saving_handler::<AccountState>(None);
+        let mut msgbus = MessageBus::default();
+        msgbus.subscribe(
+            format!("events.account.{account_id}"),
+            account_handler.clone(),
+            None,
+        );
+
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Cash,
+            vec![Money::from("0 USDT")],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            &INTEREST_TIME,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel,
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        exchange.add_instrument(instrument.clone()).unwrap();
+
+        let quote = |ts: u64| {
+            Data::Quote(QuoteTick::new(
+                instrument.id(),
+                Price::from("10000.00"),
+                Price::from("10001.00"),
+                Quantity::from("1.000000"),
+                Quantity::from("1.000000"),
+                ts.into(),
+                ts.into(),
+            ))
+        };
+        let one_year_ns = 365 * 86_400 * 1_000_000_000;
+        let mut engine = BacktestEngine::new(&INTEREST_TIME);
+        engine.add_venue(exchange);
+        engine.add_data(vec![quote(1), quote(1 + one_year_ns)]);
+
+        engine.run();
+
+        let account_states = get_saved_messages::<AccountState>(account_handler);
+        assert_eq!(account_states.len(), 1);
+        assert_eq!(account_states[0].balances, vec![balance("-1100 USDT")]);
+        assert_eq!(account_states[0].ts_event, UnixNanos::from(1 + one_year_ns));
+
+        let cache = cache.borrow();
+        let Some(AccountAny::Cash(account)) = cache.account(&account_id) else {
+            panic!("Expected a cash account");
+        };
+        assert_eq!(account.interest(Currency::USDT()), Money::from("100 USDT"));
+        assert_eq!(account.last_event(), Some(account_states[0].clone()));
+    }
+
+    #[rstest]
+    fn test_run_liquidates_position_on_mark_price_margin_breach(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let account_id = AccountId::from("BINANCE-001");
+
+        // 1,000 USDT margin account holding a 10 ETH long opened at 1,000.00 (500 USDT margin)
+        let mut account = MarginAccount::new(
+            AccountState::new(
+                account_id,
+                AccountType::Margin,
+                vec![AccountBalance::new(
+                    Money::from("1000 USDT"),
+                    Money::from("0 USDT"),
+                    Money::from("1000 USDT"),
+                )],
+                vec![],
+                true,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        account
+            .update_maintenance_margin(instrument.id(), Money::from("500 USDT"))
+            .unwrap();
+        let fill = OrderFilled::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("S-001"),
+            instrument.id(),
+            ClientOrderId::from("O-1"),
+            VenueOrderId::from("V-1"),
+            account_id,
+            TradeId::from("T-1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            Quantity::from("10.000"),
+            Price::from("1000.00"),
+            Currency::USDT(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            Some(PositionId::from("P-1")),
+            None,
+        );
+        let mut cache = Cache::default();
+        cache.add_account(AccountAny::Margin(account)).unwrap();
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+
+        let order_handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        let mut msgbus = MessageBus::default();
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            order_handler.clone(),
+        );
+
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::from("1000 USDT")],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(cache)),
+            &ATOMIC_TIME,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel,
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        exchange.add_instrument(instrument.clone()).unwrap();
+        exchange.set_liquidation_model(LiquidationModel::new(0.01, false).unwrap());
+
+        let mark_price = |value: &str, ts: u64| {
+            Data::MarkPriceUpdate(MarkPriceUpdate::new(
+                instrument.id(),
+                Price::from(value),
+                ts.into(),
+                ts.into(),
+            ))
+        };
+        let mut engine = BacktestEngine::new(&ATOMIC_TIME);
+        engine.add_venue(exchange);
+        engine.add_data(vec![mark_price("940.00", 2), mark_price("950.00", 1)]);
+
+        engine.run();
+
+        // Equity is at the maintenance margin at 950.00, and breaches it at 940.00
+        let order_events = get_saved_messages::<OrderEventAny>(order_handler);
+        assert_eq!(order_events.len(), 2);
+        let OrderEventAny::Filled(fill) = order_events[1] else {
+            panic!("Expected a fill, was {}", order_events[1]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from("10.000"));
+        assert_eq!(fill.last_px, Price::from("940.00"));
+        assert_eq!(fill.commission, Some(Money::from("94 USDT")));
+        assert_eq!(fill.ts_event, UnixNanos::from(2));
+    }
 }
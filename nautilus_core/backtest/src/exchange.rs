@@ -24,27 +24,35 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use nautilus_common::{cache::Cache, msgbus::MessageBus};
 use nautilus_core::{
     correctness::{check_equal, FAILED},
-    AtomicTime, UnixNanos,
+    AtomicTime, UnixNanos, UUID4,
 };
 use nautilus_execution::{client::ExecutionClient, messages::TradingCommand};
 use nautilus_model::{
-    accounts::AccountAny,
+    accounts::{Account, AccountAny, MarginAccount},
     data::{
-        Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, Data, InstrumentStatus, MarkPriceUpdate, OrderBookDelta, OrderBookDeltas,
+        OrderBookDeltas_API, QuoteTick, TradeTick,
     },
     enums::{AccountType, BookType, OmsType},
+    events::AccountState,
     identifiers::{InstrumentId, Venue},
     instruments::InstrumentAny,
     orderbook::OrderBook,
     orders::PassiveOrderAny,
+    position::Position,
     types::{Currency, Money, Price},
 };
-use rust_decimal::Decimal;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use ustr::Ustr;
 
 use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
+    models::{
+        fee::FeeModelAny, fill::FillModel, latency::LatencyModel, liquidation::LiquidationModel,
+    },
     modules::SimulationModule,
 };
 
@@ -58,9 +66,11 @@ pub struct SimulatedExchange {
     fee_model: FeeModelAny,
     fill_model: FillModel,
     latency_model: LatencyModel,
+    liquidation_model: Option<LiquidationModel>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
+    mark_prices: HashMap<InstrumentId, Price>,
     modules: Vec<Box<dyn SimulationModule>>,
     clock: &'static AtomicTime,
     msgbus: Rc<RefCell<MessageBus>>,
@@ -122,9 +132,11 @@ impl SimulatedExchange {
             fee_model,
             fill_model,
             latency_model,
+            liquidation_model: None,
             instruments: HashMap::new(),
             matching_engines: HashMap::new(),
             leverages,
+            mark_prices: HashMap::new(),
             modules,
             clock,
             msgbus,
@@ -141,6 +153,11 @@ impl SimulatedExchange {
         })
     }
 
+    #[must_use]
+    pub const fn id(&self) -> Venue {
+        self.id
+    }
+
     pub fn register_client(&mut self, client: ExecutionClient) {
         let client_id = client.client_id;
         self.exec_client = Some(client);
@@ -164,6 +181,13 @@ impl SimulatedExchange {
         log::info!("Setting latency model to {}", self.latency_model);
     }
 
+    /// Sets the liquidation model, enabling liquidation of margin account positions on
+    /// maintenance margin breaches (liquidations are not simulated until a model is set).
+    pub fn set_liquidation_model(&mut self, liquidation_model: LiquidationModel) {
+        self.liquidation_model = Some(liquidation_model);
+        log::info!("Setting liquidation model to {liquidation_model}");
+    }
+
     pub fn initialize_account(&mut self, _account_id: u64) {
         todo!("initialize account")
     }
@@ -487,8 +511,240 @@ impl SimulatedExchange {
         }
     }
 
-    pub fn process(&mut self, _ts_now: UnixNanos) {
-        todo!("process")
+    pub fn process_mark_price(&mut self, mark_price: MarkPriceUpdate) {
+        for module in &self.modules {
+            module.pre_process(Data::MarkPriceUpdate(mark_price));
+        }
+
+        self.mark_prices
+            .insert(mark_price.instrument_id, mark_price.value);
+        self.check_liquidations();
+    }
+
+    /// Checks the venue margin account for maintenance margin breaches, valuing open positions
+    /// at their latest mark prices, and liquidates positions for any breached currency.
+    ///
+    /// Positions without a mark price are not valued or liquidated.
+    pub fn check_liquidations(&mut self) {
+        let Some(liquidation_model) = self.liquidation_model else {
+            return;
+        };
+        if self.frozen_account {
+            return;
+        }
+
+        let (account, positions) = {
+            let cache = self.cache.as_ref().borrow();
+            let account = match cache.account_for_venue(&self.id) {
+                Some(AccountAny::Margin(account)) => account.clone(),
+                _ => return,
+            };
+            let positions: Vec<Position> = cache
+                .positions_open(Some(&self.id), None, None, None)
+                .into_iter()
+                .filter(|position| position.account_id == account.id)
+                .cloned()
+                .collect();
+            (account, positions)
+        };
+
+        for currency in account.currencies() {
+            self.liquidate_positions(&account, &positions, currency, liquidation_model);
+        }
+    }
+
+    /// Liquidates positions settled in `currency` until the maintenance margin is restored
+    /// (or entirely, unless the liquidation model is partial).
+    ///
+    /// Liquidations are only emitted as fills (with the liquidation fee as commission), so the
+    /// account balances are updated once, when the fills are applied by the execution engine.
+    fn liquidate_positions(
+        &mut self,
+        account: &MarginAccount,
+        positions: &[Position],
+        currency: Currency,
+        liquidation_model: LiquidationModel,
+    ) {
+        let Some(balance) = account.balances.get(&currency).copied() else {
+            return;
+        };
+
+        let mut equity = balance.total.as_decimal();
+        let mut open_qty: HashMap<InstrumentId, Decimal> = HashMap::new();
+        let mut candidates: Vec<(&Position, Price)> = Vec::new();
+        for position in positions
+            .iter()
+            .filter(|position| position.settlement_currency == currency)
+        {
+            if let Some(mark_px) = self.mark_prices.get(&position.instrument_id) {
+                equity += position.unrealized_pnl(*mark_px).as_decimal();
+                *open_qty.entry(position.instrument_id).or_default() +=
+                    position.quantity.as_decimal();
+                candidates.push((position, *mark_px));
+            }
+        }
+
+        let margins_maint: HashMap<InstrumentId, Decimal> = account
+            .maintenance_margins()
+            .into_iter()
+            .filter(|(_, margin)| margin.currency == currency)
+            .map(|(instrument_id, margin)| (instrument_id, margin.as_decimal()))
+            .collect();
+        let total_margin_maint: Decimal = margins_maint.values().sum();
+        if total_margin_maint.is_zero() || equity >= total_margin_maint {
+            return;
+        }
+
+        log::warn!(
+            "Maintenance margin breached for {}: equity={equity}, margin_maint={total_margin_maint}, currency={currency}",
+            account.id,
+        );
+
+        // Maintenance margin per unit of open quantity for each instrument
+        let margin_per_unit = |instrument_id: &InstrumentId| -> Decimal {
+            let qty = open_qty.get(instrument_id).copied().unwrap_or_default();
+            if qty.is_zero() {
+                return Decimal::ZERO;
+            }
+            margins_maint
+                .get(instrument_id)
+                .copied()
+                .unwrap_or_default()
+                / qty
+        };
+
+        // Liquidate the positions carrying the most maintenance margin first
+        candidates.sort_by(|(a, _), (b, _)| {
+            let margin_a = margin_per_unit(&a.instrument_id) * a.quantity.as_decimal();
+            let margin_b = margin_per_unit(&b.instrument_id) * b.quantity.as_decimal();
+            margin_b.cmp(&margin_a)
+        });
+
+        let fee_rate = Decimal::from_f64(liquidation_model.fee_rate).unwrap_or_default();
+        let mut shortfall = total_margin_maint - equity;
+        for (position, mark_px) in candidates {
+            if liquidation_model.partial && shortfall <= Decimal::ZERO {
+                break;
+            }
+
+            let Some(instrument) = self.instruments.get(&position.instrument_id).cloned() else {
+                log::error!(
+                    "Cannot liquidate {}: no instrument {}",
+                    position.id,
+                    position.instrument_id
+                );
+                continue;
+            };
+
+            let margin_unit = margin_per_unit(&position.instrument_id);
+            let notional = instrument.calculate_notional_value(position.quantity, mark_px, None);
+            let fee_unit = notional.as_decimal() / position.quantity.as_decimal() * fee_rate;
+
+            let mut close_qty = position.quantity;
+            if liquidation_model.partial && margin_unit > fee_unit {
+                // Close the minimum quantity (in size increments) restoring the margin
+                let increment = instrument.size_increment().as_decimal();
+                let required =
+                    (shortfall / (margin_unit - fee_unit) / increment).ceil() * increment;
+                if required < position.quantity.as_decimal() {
+                    close_qty = instrument.make_qty(required.to_f64().unwrap_or_default());
+                }
+            }
+
+            let close_notional = instrument.calculate_notional_value(close_qty, mark_px, None);
+            let fee = Money::new(
+                (close_notional.as_decimal() * fee_rate)
+                    .to_f64()
+                    .unwrap_or_default(),
+                close_notional.currency,
+            );
+            let pnl = position.calculate_pnl(position.avg_px_open, mark_px.as_f64(), close_qty);
+
+            let Some(matching_engine) = self.matching_engines.get_mut(&position.instrument_id)
+            else {
+                log::error!("Cannot liquidate {}: no matching engine", position.id);
+                continue;
+            };
+            if let Err(e) = matching_engine.liquidate_position(position, close_qty, mark_px, fee) {
+                log::error!("Cannot liquidate {}: {e}", position.id);
+                continue;
+            }
+
+            log::warn!(
+                "Liquidated {close_qty} of {} at mark price {mark_px} (fee={fee}, pnl={pnl})",
+                position.id
+            );
+
+            shortfall -= close_qty.as_decimal() * (margin_unit - fee_unit);
+        }
+    }
+
+    /// Processes the exchange up to `ts_now`, accruing borrow interest on the venue account and
+    /// running the simulation modules.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        // TODO: Process inflight and queued trading commands
+        self.accrue_interest(ts_now);
+
+        for module in &self.modules {
+            module.process(ts_now);
+        }
+    }
+
+    /// Accrues borrow interest on the negative balances of the venue cash account up to
+    /// `ts_now`, publishing an account state for any interest charged.
+    fn accrue_interest(&mut self, ts_now: UnixNanos) {
+        if self.frozen_account {
+            return;
+        }
+
+        let mut account = match self.cache.as_ref().borrow().account_for_venue(&self.id) {
+            Some(AccountAny::Cash(account)) if account.allow_borrowing => account.clone(),
+            _ => return,
+        };
+
+        let charged = account.accrue_interest(ts_now);
+        let account_state = if charged.is_empty() {
+            None
+        } else {
+            for interest in &charged {
+                log::info!("Charged {interest} borrow interest to {}", account.id);
+            }
+            let account_state = AccountState::new(
+                account.id,
+                account.account_type,
+                account.balances.values().copied().collect(),
+                vec![],
+                true,
+                UUID4::new(),
+                ts_now,
+                self.clock.get_time_ns(),
+                account.base_currency,
+            );
+            if let Err(e) = account.apply(account_state.clone()) {
+                log::error!("Cannot apply interest to {}: {e}", account.id);
+                return;
+            }
+            Some(account_state)
+        };
+
+        let account_id = account.id;
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .update_account(AccountAny::Cash(account))
+        {
+            log::error!("Cannot update account {account_id}: {e}");
+            return;
+        }
+
+        if let Some(account_state) = account_state {
+            let topic = Ustr::from(&format!("events.account.{account_id}"));
+            self.msgbus
+                .as_ref()
+                .borrow()
+                .publish(&topic, &account_state);
+        }
     }
 
     pub fn reset(&mut self) {
@@ -511,22 +767,36 @@ impl SimulatedExchange {
 mod tests {
     use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::LazyLock};
 
-    use nautilus_common::{cache::Cache, msgbus::MessageBus};
-    use nautilus_core::{AtomicTime, UnixNanos};
+    use nautilus_common::{
+        cache::Cache,
+        msgbus::{
+            handler::ShareableMessageHandler,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+    };
+    use nautilus_core::{AtomicTime, UnixNanos, UUID4};
     use nautilus_model::{
+        accounts::{AccountAny, MarginAccount},
         data::{
-            Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
-            TradeTick,
+            Bar, BarType, BookOrder, InstrumentStatus, MarkPriceUpdate, OrderBookDelta,
+            OrderBookDeltas, QuoteTick, TradeTick,
         },
         enums::{
-            AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
-            OmsType, OrderSide,
+            AccountType, AggressorSide, BookAction, BookType, LiquiditySide, MarketStatus,
+            MarketStatusAction, OmsType, OrderSide, OrderType,
+        },
+        events::{AccountState, OrderEventAny, OrderFilled},
+        identifiers::{
+            AccountId, ClientOrderId, PositionId, StrategyId, TradeId, TraderId, Venue,
+            VenueOrderId,
         },
-        identifiers::{TradeId, Venue},
         instruments::{stubs::crypto_perpetual_ethusdt, CryptoPerpetual, InstrumentAny},
-        types::{Currency, Money, Price, Quantity},
+        position::Position,
+        types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
+    use ustr::Ustr;
 
     use crate::{
         exchange::SimulatedExchange,
@@ -534,6 +804,7 @@ mod tests {
             fee::{FeeModelAny, MakerTakerFeeModel},
             fill::FillModel,
             latency::LatencyModel,
+            liquidation::LiquidationModel,
         },
     };
 
@@ -544,6 +815,22 @@ mod tests {
         venue: Venue,
         account_type: AccountType,
         book_type: BookType,
+    ) -> SimulatedExchange {
+        get_exchange_with(
+            venue,
+            account_type,
+            book_type,
+            Rc::new(RefCell::new(MessageBus::default())),
+            Rc::new(RefCell::new(Cache::default())),
+        )
+    }
+
+    fn get_exchange_with(
+        venue: Venue,
+        account_type: AccountType,
+        book_type: BookType,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
     ) -> SimulatedExchange {
         SimulatedExchange::new(
             venue,
@@ -554,8 +841,8 @@ mod tests {
             1.into(),
             HashMap::new(),
             vec![],
-            Rc::new(RefCell::new(MessageBus::default())),
-            Rc::new(RefCell::new(Cache::default())),
+            msgbus,
+            cache,
             &ATOMIC_TIME,
             FillModel::default(),
             FeeModelAny::MakerTaker(MakerTakerFeeModel),
@@ -574,6 +861,102 @@ mod tests {
         .unwrap()
     }
 
+    /// Sets up a margin exchange with a 1,000 USDT account holding a 10 ETH long position opened
+    /// at 1,000.00 with a 500 USDT maintenance margin.
+    fn get_exchange_with_long_position(
+        instrument: &InstrumentAny,
+        liquidation_model: LiquidationModel,
+    ) -> (
+        SimulatedExchange,
+        ShareableMessageHandler,
+        ShareableMessageHandler,
+    ) {
+        let account_id = AccountId::from("BINANCE-001");
+        let mut account = MarginAccount::new(
+            AccountState::new(
+                account_id,
+                AccountType::Margin,
+                vec![AccountBalance::new(
+                    Money::from("1000 USDT"),
+                    Money::from("0 USDT"),
+                    Money::from("1000 USDT"),
+                )],
+                vec![],
+                true,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        account
+            .update_maintenance_margin(instrument.id(), Money::from("500 USDT"))
+            .unwrap();
+
+        let fill = OrderFilled::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("S-001"),
+            instrument.id(),
+            ClientOrderId::from("O-1"),
+            VenueOrderId::from("V-1"),
+            account_id,
+            TradeId::from("T-1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            Quantity::from("10.000"),
+            Price::from("1000.00"),
+            Currency::USDT(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            Some(PositionId::from("P-1")),
+            None,
+        );
+        let position = Position::new(instrument, fill);
+
+        let mut cache = Cache::default();
+        cache.add_account(AccountAny::Margin(account)).unwrap();
+        cache.add_position(position, OmsType::Netting).unwrap();
+
+        let order_handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        let account_handler = get_message_saving_handler::<AccountState>(None);
+        let mut msgbus = MessageBus::default();
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            order_handler.clone(),
+        );
+        msgbus.subscribe(
+            format!("events.account.{account_id}"),
+            account_handler.clone(),
+            None,
+        );
+
+        let mut exchange = get_exchange_with(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(cache)),
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+        exchange.set_liquidation_model(liquidation_model);
+
+        (exchange, order_handler, account_handler)
+    }
+
+    fn mark_price(instrument: &InstrumentAny, value: &str) -> MarkPriceUpdate {
+        MarkPriceUpdate::new(
+            instrument.id(),
+            Price::from(value),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
     #[rstest]
     #[should_panic(
         expected = r#"Condition failed: 'Venue of instrument id' value of BINANCE was not equal to 'Venue of simulated exchange' value of SIM"#
@@ -828,6 +1211,85 @@ mod tests {
         assert_eq!(best_ask_price, Some(Price::from("1000.00")));
     }
 
+    #[rstest]
+    fn test_process_mark_price_when_above_maintenance_margin_does_not_liquidate(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let (mut exchange, order_handler, account_handler) =
+            get_exchange_with_long_position(&instrument, LiquidationModel::default());
+
+        // Equity of 500 USDT is still at the maintenance margin
+        exchange.process_mark_price(mark_price(&instrument, "950.00"));
+
+        assert!(get_saved_messages::<OrderEventAny>(order_handler).is_empty());
+        assert!(get_saved_messages::<AccountState>(account_handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_process_mark_price_when_margin_breached_liquidates_position(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let (mut exchange, order_handler, account_handler) = get_exchange_with_long_position(
+            &instrument,
+            LiquidationModel::new(0.01, false).unwrap(),
+        );
+
+        exchange.process_mark_price(mark_price(&instrument, "940.00"));
+
+        let order_events = get_saved_messages::<OrderEventAny>(order_handler);
+        assert_eq!(order_events.len(), 2);
+        assert!(matches!(order_events[0], OrderEventAny::Accepted(_)));
+        let OrderEventAny::Filled(fill) = order_events[1] else {
+            panic!("Expected a fill, was {}", order_events[1]);
+        };
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("10.000"));
+        assert_eq!(fill.last_px, Price::from("940.00"));
+        assert_eq!(fill.commission, Some(Money::from("94 USDT")));
+        assert_eq!(fill.position_id, Some(PositionId::from("P-1")));
+
+        // Balances are only updated when the fill is applied, not by the exchange
+        assert!(get_saved_messages::<AccountState>(account_handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_process_mark_price_when_margin_breached_partially_liquidates_position(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let (mut exchange, order_handler, account_handler) =
+            get_exchange_with_long_position(&instrument, LiquidationModel::new(0.0, true).unwrap());
+
+        // Equity of 400 USDT is 100 USDT short of the margin (50 USDT margin per unit)
+        exchange.process_mark_price(mark_price(&instrument, "940.00"));
+
+        let order_events = get_saved_messages::<OrderEventAny>(order_handler);
+        assert_eq!(order_events.len(), 2);
+        let OrderEventAny::Filled(fill) = order_events[1] else {
+            panic!("Expected a fill, was {}", order_events[1]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from("2.000"));
+        assert_eq!(fill.commission, Some(Money::from("0 USDT")));
+        assert!(get_saved_messages::<AccountState>(account_handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_process_mark_price_when_no_liquidation_model_does_not_liquidate(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let (mut exchange, order_handler, account_handler) =
+            get_exchange_with_long_position(&instrument, LiquidationModel::default());
+        exchange.liquidation_model = None;
+
+        exchange.process_mark_price(mark_price(&instrument, "940.00"));
+
+        assert!(get_saved_messages::<OrderEventAny>(order_handler).is_empty());
+        assert!(get_saved_messages::<AccountState>(account_handler).is_empty());
+    }
+
     fn test_exchange_process_instrument_status(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let mut exchange: SimulatedExchange =
             get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L2_MBP);
@@ -54,8 +54,8 @@ use nautilus_model::{
     instruments::{InstrumentAny, EXPIRING_INSTRUMENT_TYPES},
     orderbook::OrderBook,
     orders::{
-        LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny, TrailingStopLimitOrder,
-        TrailingStopMarketOrder,
+        LimitOrderAny, MarketOrder, OrderAny, PassiveOrderAny, StopOrderAny,
+        TrailingStopLimitOrder, TrailingStopMarketOrder,
     },
     position::Position,
     types::{
@@ -1310,6 +1310,70 @@ impl OrderMatchingEngine {
         todo!("Check for contingent orders")
     }
 
+    /// Closes `quantity` of the given `position` at `last_px` as a forced liquidation.
+    ///
+    /// A reduce-only market order is created for the liquidation and added to the cache, then
+    /// accepted and filled events are generated, with the liquidation `fee` as the commission.
+    pub fn liquidate_position(
+        &mut self,
+        position: &Position,
+        quantity: Quantity,
+        last_px: Price,
+        fee: Money,
+    ) -> anyhow::Result<ClientOrderId> {
+        let venue_order_id = self.ids_generator.generate_venue_order_id();
+        let client_order_id = ClientOrderId::new_checked(format!("LIQ-{venue_order_id}"))?;
+        let order_side = if position.is_long() {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+
+        let mut order = OrderAny::Market(MarketOrder::new_checked(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            TimeInForce::Ioc,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+            true,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Ustr::from("LIQUIDATION")]),
+        )?);
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            cache.add_order(order.clone(), Some(position.id), None, false)?;
+            cache.add_venue_order_id(&client_order_id, &venue_order_id, false)?;
+        }
+
+        self.account_ids
+            .insert(position.trader_id, position.account_id);
+        self.generate_order_accepted(&mut order, venue_order_id);
+        self.generate_order_filled(
+            &order,
+            venue_order_id,
+            Some(position.id),
+            quantity,
+            last_px,
+            self.instrument.quote_currency(),
+            fee,
+            LiquiditySide::Taker,
+        );
+
+        Ok(client_order_id)
+    }
+
     fn update_trailing_stop_market(&mut self, order: &TrailingStopMarketOrder) {
         todo!()
     }
@@ -0,0 +1,81 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
+
+/// Models the forced liquidation of margin account positions when the account equity (at mark
+/// price) falls below the total maintenance margin.
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidationModel {
+    /// The fee rate charged on the notional value of liquidated positions.
+    pub fee_rate: f64,
+    /// If positions are only partially liquidated, closing the minimum quantity required to
+    /// restore the maintenance margin (otherwise positions are fully closed).
+    pub partial: bool,
+}
+
+impl LiquidationModel {
+    /// Creates a new [`LiquidationModel`] instance.
+    pub fn new(fee_rate: f64, partial: bool) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(fee_rate, 0.0, 1.0, "fee_rate").expect(FAILED);
+        Ok(Self { fee_rate, partial })
+    }
+}
+
+impl Display for LiquidationModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LiquidationModel(fee_rate: {}, partial: {})",
+            self.fee_rate, self.partial
+        )
+    }
+}
+
+impl Default for LiquidationModel {
+    /// Creates a new default [`LiquidationModel`] instance.
+    fn default() -> Self {
+        Self::new(0.0, false).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[should_panic(
+        expected = "Condition failed: invalid f64 for 'fee_rate' not in range [0, 1], was 1.1"
+    )]
+    fn test_liquidation_model_param_fee_rate_error() {
+        let _ = LiquidationModel::new(1.1, false).unwrap();
+    }
+
+    #[rstest]
+    fn test_liquidation_model_display() {
+        let model = LiquidationModel::new(0.005, true).unwrap();
+        assert_eq!(
+            model.to_string(),
+            "LiquidationModel(fee_rate: 0.005, partial: true)"
+        );
+    }
+}
@@ -16,3 +16,4 @@
 pub mod fee;
 pub mod fill;
 pub mod latency;
+pub mod liquidation;
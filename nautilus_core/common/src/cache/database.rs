@@ -133,6 +133,10 @@ pub trait CacheDatabaseAdapter {
 
     fn add_bar(&self, bar: &Bar) -> anyhow::Result<()>;
 
+    fn delete_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()>;
+
+    fn delete_position(&self, position_id: &PositionId) -> anyhow::Result<()>;
+
     fn delete_actor(&self, component_id: &ComponentId) -> anyhow::Result<()>;
 
     fn delete_strategy(&self, component_id: &StrategyId) -> anyhow::Result<()>;
@@ -149,9 +153,17 @@ pub trait CacheDatabaseAdapter {
         position_id: PositionId,
     ) -> anyhow::Result<()>;
 
-    fn update_actor(&self) -> anyhow::Result<()>;
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()>;
 
-    fn update_strategy(&self) -> anyhow::Result<()>;
+    fn update_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()>;
 
     fn update_account(&self, account: &AccountAny) -> anyhow::Result<()>;
 
@@ -36,12 +36,15 @@ use nautilus_core::{
         check_key_not_in_map, check_predicate_false, check_slice_not_empty, check_valid_string,
         FAILED,
     },
-    UUID4,
+    UnixNanos, UUID4,
 };
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, BarType, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, IndexPriceUpdate, MarkPriceUpdate, OpenInterestUpdate, QuoteTick, TradeTick,
+    },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
+    events::{position::snapshot::PositionSnapshot, OrderSnapshot},
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Symbol, Venue, VenueOrderId,
@@ -60,6 +63,9 @@ use crate::{
     enums::SerializationEncoding, msgbus::database::DatabaseConfig, xrate::get_exchange_rate,
 };
 
+/// The length (bytes) of the header prefixing each serialized position snapshot frame.
+const FRAME_HEADER_LEN: usize = std::mem::size_of::<u64>();
+
 /// Configuration for `Cache` instances.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
@@ -86,6 +92,9 @@ pub struct CacheConfig {
     pub bar_capacity: usize,
     /// If market data should be persisted to disk.
     pub save_market_data: bool,
+    /// The maximum number of in-memory state snapshots retained per order and per position
+    /// (0 disables in-memory retention, snapshots are still persisted to the database).
+    pub snapshot_capacity: usize,
 }
 
 impl Default for CacheConfig {
@@ -103,6 +112,7 @@ impl Default for CacheConfig {
             tick_capacity: 10_000,
             bar_capacity: 10_000,
             save_market_data: false,
+            snapshot_capacity: 1_000,
         }
     }
 }
@@ -123,6 +133,7 @@ impl CacheConfig {
         tick_capacity: usize,
         bar_capacity: usize,
         save_market_data: bool,
+        snapshot_capacity: usize,
     ) -> Self {
         Self {
             database,
@@ -136,6 +147,7 @@ impl CacheConfig {
             tick_capacity,
             bar_capacity,
             save_market_data,
+            snapshot_capacity,
         }
     }
 }
@@ -214,6 +226,9 @@ pub struct Cache {
     general: HashMap<String, Bytes>,
     quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
+    mark_prices: HashMap<InstrumentId, VecDeque<MarkPriceUpdate>>,
+    index_prices: HashMap<InstrumentId, VecDeque<IndexPriceUpdate>>,
+    open_interest: HashMap<InstrumentId, VecDeque<OpenInterestUpdate>>,
     books: HashMap<InstrumentId, OrderBook>,
     bars: HashMap<BarType, VecDeque<Bar>>,
     currencies: HashMap<Ustr, Currency>,
@@ -224,6 +239,8 @@ pub struct Cache {
     order_lists: HashMap<OrderListId, OrderList>,
     positions: HashMap<PositionId, Position>,
     position_snapshots: HashMap<PositionId, Bytes>,
+    order_state_snapshots: HashMap<ClientOrderId, VecDeque<OrderSnapshot>>,
+    position_state_snapshots: HashMap<PositionId, VecDeque<PositionSnapshot>>,
 }
 
 // SAFETY: Cache is not meant to be passed between threads
@@ -282,6 +299,9 @@ impl Cache {
             general: HashMap::new(),
             quotes: HashMap::new(),
             trades: HashMap::new(),
+            mark_prices: HashMap::new(),
+            index_prices: HashMap::new(),
+            open_interest: HashMap::new(),
             books: HashMap::new(),
             bars: HashMap::new(),
             currencies: HashMap::new(),
@@ -292,6 +312,8 @@ impl Cache {
             order_lists: HashMap::new(),
             positions: HashMap::new(),
             position_snapshots: HashMap::new(),
+            order_state_snapshots: HashMap::new(),
+            position_state_snapshots: HashMap::new(),
         }
     }
 
@@ -963,6 +985,9 @@ impl Cache {
         self.general.clear();
         self.quotes.clear();
         self.trades.clear();
+        self.mark_prices.clear();
+        self.index_prices.clear();
+        self.open_interest.clear();
         self.books.clear();
         self.bars.clear();
         self.currencies.clear();
@@ -973,6 +998,8 @@ impl Cache {
         self.order_lists.clear();
         self.positions.clear();
         self.position_snapshots.clear();
+        self.order_state_snapshots.clear();
+        self.position_state_snapshots.clear();
 
         self.clear_index();
 
@@ -1112,6 +1139,45 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the given `mark_price` update to the cache.
+    pub fn add_mark_price(&mut self, mark_price: MarkPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `MarkPriceUpdate` {}", mark_price.instrument_id);
+
+        let mark_prices_deque = self
+            .mark_prices
+            .entry(mark_price.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
+        mark_prices_deque.push_front(mark_price);
+        Ok(())
+    }
+
+    /// Adds the given `index_price` update to the cache.
+    pub fn add_index_price(&mut self, index_price: IndexPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `IndexPriceUpdate` {}", index_price.instrument_id);
+
+        let index_prices_deque = self
+            .index_prices
+            .entry(index_price.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
+        index_prices_deque.push_front(index_price);
+        Ok(())
+    }
+
+    /// Adds the given `open_interest` update to the cache.
+    pub fn add_open_interest(&mut self, open_interest: OpenInterestUpdate) -> anyhow::Result<()> {
+        log::debug!(
+            "Adding `OpenInterestUpdate` {}",
+            open_interest.instrument_id
+        );
+
+        let open_interest_deque = self
+            .open_interest
+            .entry(open_interest.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
+        open_interest_deque.push_front(open_interest);
+        Ok(())
+    }
+
     /// Adds the given `bar` to the cache.
     pub fn add_bar(&mut self, bar: Bar) -> anyhow::Result<()> {
         log::debug!("Adding `Bar` {}", bar.bar_type);
@@ -1369,10 +1435,6 @@ impl Cache {
 
         if let Some(database) = &mut self.database {
             database.add_order(&order, client_id)?;
-            // TODO: Implement
-            // if self.config.snapshot_orders {
-            //     database.snapshot_order_state(order)?;
-            // }
         }
 
         self.orders.insert(client_order_id, order);
@@ -1449,14 +1511,6 @@ impl Cache {
 
         if let Some(database) = &mut self.database {
             database.add_position(&position)?;
-            // TODO: Implement position snapshots
-            // if self.snapshot_positions {
-            //     database.snapshot_position_state(
-            //         position,
-            //         position.ts_last,
-            //         self.calculate_unrealized_pnl(&position),
-            //     )?;
-            // }
         }
 
         Ok(())
@@ -1467,6 +1521,7 @@ impl Cache {
         if let Some(database) = &mut self.database {
             database.update_account(&account)?;
         }
+        self.accounts.insert(account.id(), account);
         Ok(())
     }
 
@@ -1511,10 +1566,6 @@ impl Cache {
 
         if let Some(database) = &mut self.database {
             database.update_order(order.last_event())?;
-            // TODO: Implement order snapshots
-            // if self.snapshot_orders {
-            //     database.snapshot_order_state(order)?;
-            // }
         }
 
         Ok(())
@@ -1540,10 +1591,6 @@ impl Cache {
 
         if let Some(database) = &mut self.database {
             database.update_position(position)?;
-            // TODO: Implement order snapshots
-            // if self.snapshot_orders {
-            //     database.snapshot_order_state(order)?;
-            // }
         }
         Ok(())
     }
@@ -1557,29 +1604,35 @@ impl Cache {
         let new_id = format!("{}-{}", position_id.as_str(), UUID4::new());
         copied_position.id = PositionId::new(new_id);
 
-        // Serialize the position
-        let position_serialized = bincode::serialize(&copied_position)?;
-
-        let snapshots: Option<&Bytes> = self.position_snapshots.get(&position_id);
-        let new_snapshots = match snapshots {
-            Some(existing_snapshots) => {
-                let mut combined = existing_snapshots.to_vec();
-                combined.extend(position_serialized);
-                Bytes::from(combined)
-            }
-            None => Bytes::from(position_serialized),
-        };
+        // Serialize the position, framing each record with its length so that multiple
+        // snapshots can be decoded back out of the combined buffer
+        let position_serialized = serde_json::to_vec(&copied_position)?;
+        let frame_len = position_serialized.len() as u64;
+
+        let mut combined = self
+            .position_snapshots
+            .get(&position_id)
+            .map(|existing_snapshots| existing_snapshots.to_vec())
+            .unwrap_or_default();
+        combined.extend_from_slice(&frame_len.to_le_bytes());
+        combined.extend(position_serialized);
+        let new_snapshots = Bytes::from(combined);
         self.position_snapshots.insert(position_id, new_snapshots);
 
         log::debug!("Snapshot {}", copied_position);
         Ok(())
     }
 
+    /// Creates a state snapshot of the given `position` at `ts_snapshot`, storing it in the
+    /// cache and persisting it to the backing database (if configured).
+    ///
+    /// If `open_only` is true (the default) then closed positions will not be snapshot, which
+    /// avoids race conditions where a position is snapshot when no longer open.
     pub fn snapshot_position_state(
         &mut self,
         position: &Position,
-        // ts_snapshot: u64,
-        // unrealized_pnl: Option<Money>,
+        ts_snapshot: UnixNanos,
+        unrealized_pnl: Option<Money>,
         open_only: Option<bool>,
     ) -> anyhow::Result<()> {
         let open_only = open_only.unwrap_or(true);
@@ -1588,38 +1641,88 @@ impl Cache {
             return Ok(());
         }
 
+        let mut snapshot = PositionSnapshot::from(position, unrealized_pnl);
+        snapshot.ts_init = ts_snapshot;
+
         if let Some(database) = &mut self.database {
-            database.snapshot_position_state(position).map_err(|e| {
+            database.add_position_snapshot(&snapshot).map_err(|e| {
                 log::error!(
-                    "Failed to snapshot position state for {}: {:?}",
+                    "Failed to snapshot position state for {}: {e:?}",
                     position.id,
-                    e
                 );
                 e
             })?;
-        } else {
-            log::warn!(
-                "Cannot snapshot position state for {} (no database configured)",
-                position.id
-            );
         }
 
-        // Ok(())
-        todo!()
+        if self.config.snapshot_capacity > 0 {
+            let snapshots = self
+                .position_state_snapshots
+                .entry(position.id)
+                .or_default();
+            if snapshots.len() >= self.config.snapshot_capacity {
+                snapshots.pop_front();
+            }
+            snapshots.push_back(snapshot);
+        }
+
+        log::debug!("Snapshot position state for {}", position.id);
+        Ok(())
     }
 
-    pub fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()> {
-        let database = if let Some(database) = &self.database {
-            database
-        } else {
-            log::warn!(
-                "Cannot snapshot order state for {} (no database configured)",
-                order.client_order_id()
-            );
-            return Ok(());
-        };
+    /// Creates a state snapshot of the given `order`, storing it in the cache and persisting
+    /// it to the backing database (if configured).
+    pub fn snapshot_order_state(&mut self, order: &OrderAny) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let snapshot = OrderSnapshot::from(order.clone());
+
+        if let Some(database) = &mut self.database {
+            database.add_order_snapshot(&snapshot).map_err(|e| {
+                log::error!("Failed to snapshot order state for {client_order_id}: {e:?}");
+                e
+            })?;
+        }
+
+        if self.config.snapshot_capacity > 0 {
+            let snapshots = self
+                .order_state_snapshots
+                .entry(client_order_id)
+                .or_default();
+            if snapshots.len() >= self.config.snapshot_capacity {
+                snapshots.pop_front();
+            }
+            snapshots.push_back(snapshot);
+        }
 
-        database.snapshot_order_state(order)
+        log::debug!("Snapshot order state for {client_order_id}");
+        Ok(())
+    }
+
+    /// Loads the latest persisted order state snapshot for the given `client_order_id` from the
+    /// backing database.
+    pub fn load_order_snapshot(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> anyhow::Result<Option<OrderSnapshot>> {
+        match &self.database {
+            Some(database) => database.load_order_snapshot(client_order_id),
+            None => anyhow::bail!(
+                "Cannot load order snapshot for {client_order_id} (no database configured)"
+            ),
+        }
+    }
+
+    /// Loads the latest persisted position state snapshot for the given `position_id` from the
+    /// backing database.
+    pub fn load_position_snapshot(
+        &self,
+        position_id: &PositionId,
+    ) -> anyhow::Result<Option<PositionSnapshot>> {
+        match &self.database {
+            Some(database) => database.load_position_snapshot(position_id),
+            None => anyhow::bail!(
+                "Cannot load position snapshot for {position_id} (no database configured)"
+            ),
+        }
     }
 
     // -- IDENTIFIER QUERIES ----------------------------------------------------------------------
@@ -2155,6 +2258,23 @@ impl Cache {
         self.orders(venue, instrument_id, strategy_id, side).len()
     }
 
+    /// Returns all order state snapshots with the given optional `client_order_id` filter,
+    /// in the order they were taken for each order.
+    #[must_use]
+    pub fn order_state_snapshots(
+        &self,
+        client_order_id: Option<&ClientOrderId>,
+    ) -> Vec<&OrderSnapshot> {
+        match client_order_id {
+            Some(client_order_id) => self
+                .order_state_snapshots
+                .get(client_order_id)
+                .map(|snapshots| snapshots.iter().collect())
+                .unwrap_or_default(),
+            None => self.order_state_snapshots.values().flatten().collect(),
+        }
+    }
+
     /// Returns the order list for the given `order_list_id`.
     #[must_use]
     pub fn order_list(&self, order_list_id: &OrderListId) -> Option<&OrderList> {
@@ -2322,6 +2442,59 @@ impl Cache {
             .and_then(|position_id| self.positions.get(position_id))
     }
 
+    /// Returns all position snapshots with the given optional `position_id` filter.
+    ///
+    /// These are the copies of positions taken prior to being reopened (for NETTING OMS).
+    #[must_use]
+    pub fn position_snapshots(&self, position_id: Option<&PositionId>) -> Vec<Position> {
+        let mut snapshots = Vec::new();
+        let buffers: Vec<&Bytes> = match position_id {
+            Some(position_id) => self
+                .position_snapshots
+                .get(position_id)
+                .into_iter()
+                .collect(),
+            None => self.position_snapshots.values().collect(),
+        };
+
+        for buffer in buffers {
+            let mut remaining: &[u8] = buffer;
+            while remaining.len() >= FRAME_HEADER_LEN {
+                let (header, rest) = remaining.split_at(FRAME_HEADER_LEN);
+                let frame_len = u64::from_le_bytes(header.try_into().expect(FAILED)) as usize;
+                if rest.len() < frame_len {
+                    log::error!("Truncated position snapshot frame");
+                    break;
+                }
+                let (frame, rest) = rest.split_at(frame_len);
+                match serde_json::from_slice::<Position>(frame) {
+                    Ok(position) => snapshots.push(position),
+                    Err(e) => log::error!("Failed to deserialize position snapshot: {e}"),
+                }
+                remaining = rest;
+            }
+        }
+
+        snapshots
+    }
+
+    /// Returns all position state snapshots with the given optional `position_id` filter,
+    /// in the order they were taken for each position.
+    #[must_use]
+    pub fn position_state_snapshots(
+        &self,
+        position_id: Option<&PositionId>,
+    ) -> Vec<&PositionSnapshot> {
+        match position_id {
+            Some(position_id) => self
+                .position_state_snapshots
+                .get(position_id)
+                .map(|snapshots| snapshots.iter().collect())
+                .unwrap_or_default(),
+            None => self.position_state_snapshots.values().flatten().collect(),
+        }
+    }
+
     /// Returns a reference to the position ID for the given `client_order_id` (if found).
     #[must_use]
     pub fn position_id(&self, client_order_id: &ClientOrderId) -> Option<&PositionId> {
@@ -2492,6 +2665,33 @@ impl Cache {
             .map(|trades| trades.iter().copied().collect())
     }
 
+    /// Gets all mark price updates for the given `instrument_id`.
+    #[must_use]
+    pub fn mark_prices(&self, instrument_id: &InstrumentId) -> Option<Vec<MarkPriceUpdate>> {
+        self.mark_prices
+            .get(instrument_id)
+            .map(|mark_prices| mark_prices.iter().copied().collect())
+    }
+
+    /// Gets all index price updates for the given `instrument_id`.
+    #[must_use]
+    pub fn index_prices(&self, instrument_id: &InstrumentId) -> Option<Vec<IndexPriceUpdate>> {
+        self.index_prices
+            .get(instrument_id)
+            .map(|index_prices| index_prices.iter().copied().collect())
+    }
+
+    /// Gets all open interest updates for the given `instrument_id`.
+    #[must_use]
+    pub fn open_interest_updates(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Option<Vec<OpenInterestUpdate>> {
+        self.open_interest
+            .get(instrument_id)
+            .map(|open_interest| open_interest.iter().copied().collect())
+    }
+
     /// Gets all bars for the given `bar_type`.
     #[must_use]
     pub fn bars(&self, bar_type: &BarType) -> Option<Vec<Bar>> {
@@ -2528,6 +2728,30 @@ impl Cache {
             .and_then(|trades| trades.front())
     }
 
+    /// Gets a reference to the latest mark price update for the given `instrument_id`.
+    #[must_use]
+    pub fn mark_price(&self, instrument_id: &InstrumentId) -> Option<&MarkPriceUpdate> {
+        self.mark_prices
+            .get(instrument_id)
+            .and_then(|mark_prices| mark_prices.front())
+    }
+
+    /// Gets a reference to the latest index price update for the given `instrument_id`.
+    #[must_use]
+    pub fn index_price(&self, instrument_id: &InstrumentId) -> Option<&IndexPriceUpdate> {
+        self.index_prices
+            .get(instrument_id)
+            .and_then(|index_prices| index_prices.front())
+    }
+
+    /// Gets a reference to the latest open interest update for the given `instrument_id`.
+    #[must_use]
+    pub fn open_interest(&self, instrument_id: &InstrumentId) -> Option<&OpenInterestUpdate> {
+        self.open_interest
+            .get(instrument_id)
+            .and_then(|open_interest| open_interest.front())
+    }
+
     /// Gets a reference to the latest bar for the given `bar_type`.
     #[must_use]
     pub fn bar(&self, bar_type: &BarType) -> Option<&Bar> {
@@ -18,10 +18,13 @@
 use bytes::Bytes;
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, QuoteTick, TradeTick},
+    data::{
+        stubs::{stub_index_price_update, stub_mark_price_update, stub_open_interest_update},
+        Bar, IndexPriceUpdate, MarkPriceUpdate, OpenInterestUpdate, QuoteTick, TradeTick,
+    },
     enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType},
     events::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
-    identifiers::{AccountId, ClientOrderId, PositionId, Venue},
+    identifiers::{AccountId, ClientOrderId, PositionId, TradeId, Venue},
     instruments::{stubs::*, CurrencyPair, InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
     orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
@@ -30,7 +33,7 @@ use nautilus_model::{
 };
 use rstest::{fixture, rstest};
 
-use super::Cache;
+use super::{Cache, CacheConfig};
 
 #[fixture]
 fn cache() -> Cache {
@@ -405,6 +408,253 @@ fn test_position_when_some(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(cache.positions_closed_count(None, None, None, None), 0);
 }
 
+#[rstest]
+fn test_snapshot_order_state(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let mut order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .build();
+    cache.add_order(order.clone(), None, None, false).unwrap();
+
+    cache.snapshot_order_state(&order).unwrap();
+    let submitted = OrderSubmitted::default();
+    order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+    cache.snapshot_order_state(&order).unwrap();
+
+    let snapshots = cache.order_state_snapshots(Some(&order.client_order_id()));
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[0].status, OrderStatus::Initialized);
+    assert_eq!(snapshots[1].status, OrderStatus::Submitted);
+    assert_eq!(cache.order_state_snapshots(None).len(), 2);
+    assert!(cache
+        .order_state_snapshots(Some(&ClientOrderId::from("O-UNKNOWN")))
+        .is_empty());
+}
+
+#[rstest]
+fn test_snapshot_order_state_when_at_capacity(audusd_sim: CurrencyPair) {
+    let config = CacheConfig {
+        snapshot_capacity: 2,
+        ..Default::default()
+    };
+    let mut cache = Cache::new(Some(config), None);
+    let mut order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .build();
+
+    cache.snapshot_order_state(&order).unwrap();
+    order
+        .apply(OrderEventAny::Submitted(OrderSubmitted::default()))
+        .unwrap();
+    cache.snapshot_order_state(&order).unwrap();
+    order
+        .apply(OrderEventAny::Accepted(OrderAccepted::default()))
+        .unwrap();
+    cache.snapshot_order_state(&order).unwrap();
+
+    let snapshots = cache.order_state_snapshots(Some(&order.client_order_id()));
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[0].status, OrderStatus::Submitted);
+    assert_eq!(snapshots[1].status, OrderStatus::Accepted);
+}
+
+#[rstest]
+fn test_snapshot_order_state_when_capacity_zero(audusd_sim: CurrencyPair) {
+    let config = CacheConfig {
+        snapshot_capacity: 0,
+        ..Default::default()
+    };
+    let mut cache = Cache::new(Some(config), None);
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .build();
+
+    cache.snapshot_order_state(&order).unwrap();
+
+    assert!(cache
+        .order_state_snapshots(Some(&order.client_order_id()))
+        .is_empty());
+    assert!(cache.order_state_snapshots(None).is_empty());
+}
+
+#[rstest]
+fn test_snapshot_position_state(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, fill.into());
+
+    cache
+        .snapshot_position_state(&position, 1.into(), None, None)
+        .unwrap();
+
+    let snapshots = cache.position_state_snapshots(Some(&position.id));
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].position_id, position.id);
+    assert_eq!(snapshots[0].quantity, position.quantity);
+    assert_eq!(snapshots[0].ts_init, 1);
+    assert_eq!(cache.position_state_snapshots(None).len(), 1);
+}
+
+#[rstest]
+fn test_snapshot_position_state_when_capacity_zero(audusd_sim: CurrencyPair) {
+    let config = CacheConfig {
+        snapshot_capacity: 0,
+        ..Default::default()
+    };
+    let mut cache = Cache::new(Some(config), None);
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, fill.into());
+
+    cache
+        .snapshot_position_state(&position, 1.into(), None, None)
+        .unwrap();
+
+    assert!(cache
+        .position_state_snapshots(Some(&position.id))
+        .is_empty());
+    assert!(cache.position_state_snapshots(None).is_empty());
+}
+
+#[rstest]
+fn test_snapshot_position_state_when_closed_and_open_only(
+    mut cache: Cache,
+    audusd_sim: CurrencyPair,
+) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order1 = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let order2 = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let position_id = PositionId::new("P-123456");
+    let fill1 = TestOrderEventStubs::order_filled(
+        &order1,
+        &audusd_sim,
+        None,
+        Some(position_id),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let fill2 = TestOrderEventStubs::order_filled(
+        &order2,
+        &audusd_sim,
+        Some(TradeId::from("E-2")),
+        Some(position_id),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let mut position = Position::new(&audusd_sim, fill1.into());
+    position.apply(&fill2.into());
+
+    cache
+        .snapshot_position_state(&position, 1.into(), None, Some(true))
+        .unwrap();
+    assert!(cache
+        .position_state_snapshots(Some(&position_id))
+        .is_empty());
+
+    cache
+        .snapshot_position_state(&position, 2.into(), None, Some(false))
+        .unwrap();
+    assert_eq!(cache.position_state_snapshots(Some(&position_id)).len(), 1);
+}
+
+#[rstest]
+fn test_position_snapshots(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, fill.into());
+
+    cache.snapshot_position(&position).unwrap();
+    cache.snapshot_position(&position).unwrap();
+
+    let snapshots = cache.position_snapshots(Some(&position.id));
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[0].quantity, position.quantity);
+    assert_ne!(snapshots[0].id, position.id);
+    assert_eq!(cache.position_snapshots(None).len(), 2);
+}
+
+#[rstest]
+fn test_load_snapshots_when_no_database(cache: Cache) {
+    assert!(cache
+        .load_order_snapshot(&ClientOrderId::from("O-123456"))
+        .is_err());
+    assert!(cache
+        .load_position_snapshot(&PositionId::from("P-123456"))
+        .is_err());
+}
+
 // -- DATA ------------------------------------------------------------------------------------
 
 #[rstest]
@@ -566,6 +816,59 @@ fn test_trade_ticks_when_some(mut cache: Cache) {
     assert_eq!(result, Some(trades));
 }
 
+#[rstest]
+fn test_mark_price_when_empty(cache: Cache, stub_mark_price_update: MarkPriceUpdate) {
+    let result = cache.mark_price(&stub_mark_price_update.instrument_id);
+    assert!(result.is_none());
+}
+
+#[rstest]
+fn test_mark_price_when_some(mut cache: Cache, stub_mark_price_update: MarkPriceUpdate) {
+    cache.add_mark_price(stub_mark_price_update).unwrap();
+    let result = cache.mark_price(&stub_mark_price_update.instrument_id);
+    assert_eq!(result, Some(&stub_mark_price_update));
+}
+
+#[rstest]
+fn test_mark_prices_returns_latest_first(
+    mut cache: Cache,
+    stub_mark_price_update: MarkPriceUpdate,
+) {
+    let mut update2 = stub_mark_price_update;
+    update2.value = Price::from("100001.00");
+    update2.ts_init = 3.into();
+    cache.add_mark_price(stub_mark_price_update).unwrap();
+    cache.add_mark_price(update2).unwrap();
+    let result = cache.mark_prices(&stub_mark_price_update.instrument_id);
+    assert_eq!(result, Some(vec![update2, stub_mark_price_update]));
+    assert_eq!(
+        cache.mark_price(&stub_mark_price_update.instrument_id),
+        Some(&update2)
+    );
+}
+
+#[rstest]
+fn test_index_price_when_some(mut cache: Cache, stub_index_price_update: IndexPriceUpdate) {
+    cache.add_index_price(stub_index_price_update).unwrap();
+    let result = cache.index_price(&stub_index_price_update.instrument_id);
+    assert_eq!(result, Some(&stub_index_price_update));
+    assert_eq!(
+        cache.index_prices(&stub_index_price_update.instrument_id),
+        Some(vec![stub_index_price_update])
+    );
+}
+
+#[rstest]
+fn test_open_interest_when_some(mut cache: Cache, stub_open_interest_update: OpenInterestUpdate) {
+    cache.add_open_interest(stub_open_interest_update).unwrap();
+    let result = cache.open_interest(&stub_open_interest_update.instrument_id);
+    assert_eq!(result, Some(&stub_open_interest_update));
+    assert_eq!(
+        cache.open_interest_updates(&stub_open_interest_update.instrument_id),
+        Some(vec![stub_open_interest_update])
+    );
+}
+
 #[rstest]
 fn test_bar_when_empty(cache: Cache) {
     let bar = Bar::default();
@@ -51,6 +51,7 @@ impl Display for BusMessage {
 /// # Notes
 ///
 /// If `database_type` is `"redis"`, it requires Redis version 6.2 or higher for correct operation.
+/// If `database_type` is `"file"`, messages are written to segment files under `path`.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct DatabaseConfig {
@@ -69,6 +70,8 @@ pub struct DatabaseConfig {
     pub ssl: bool,
     /// The timeout (in seconds) to wait for a new connection.
     pub timeout: u16,
+    /// The base directory for file backed databases. If `None`, the current working directory is used.
+    pub path: Option<String>,
 }
 
 impl Default for DatabaseConfig {
@@ -82,6 +85,7 @@ impl Default for DatabaseConfig {
             password: None,
             ssl: false,
             timeout: 20,
+            path: None,
         }
     }
 }
@@ -184,6 +188,7 @@ mod tests {
         assert_eq!(config.password, None);
         assert!(!config.ssl);
         assert_eq!(config.timeout, 20);
+        assert_eq!(config.path, None);
     }
 
     #[rstest]
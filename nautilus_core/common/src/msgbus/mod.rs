@@ -341,9 +341,10 @@ impl MessageBus {
     pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
         let mut matching_subs: Vec<&'a Subscription> = Vec::new();
 
-        // Collect matching subscriptions from direct subscriptions
+        // Collect matching subscriptions from direct subscriptions, where a
+        // subscription topic may itself be a wildcard pattern (e.g. `data.quotes.*`)
         matching_subs.extend(self.subscriptions.iter().filter_map(|(sub, _)| {
-            if is_matching(&sub.topic, pattern) {
+            if is_matching(&sub.topic, pattern) || is_matching(pattern, &sub.topic) {
                 Some(sub)
             } else {
                 None
@@ -628,6 +629,24 @@ mod tests {
         assert_eq!(subs[3].handler_id, handler_id2);
     }
 
+    #[rstest]
+    fn test_matching_subscriptions_for_wildcard_subscription() {
+        let mut msgbus = stub_msgbus();
+
+        let handler_id = Ustr::from("1");
+        let handler = get_stub_shareable_handler(Some(handler_id));
+        msgbus.subscribe("data.quotes.*", handler, None);
+
+        let topic = Ustr::from("data.quotes.SIM.AUD/USD");
+        let subs = msgbus.matching_subscriptions(&topic);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].handler_id, handler_id);
+
+        let topic = Ustr::from("data.trades.SIM.AUD/USD");
+        let subs = msgbus.matching_subscriptions(&topic);
+        assert!(subs.is_empty());
+    }
+
     #[rstest]
     #[case("*", "*", true)]
     #[case("a", "*", true)]
@@ -37,6 +37,12 @@ pub struct MessagingSwitchboard {
     depth_topics: HashMap<InstrumentId, Ustr>,
     quote_topics: HashMap<InstrumentId, Ustr>,
     trade_topics: HashMap<InstrumentId, Ustr>,
+    mark_price_topics: HashMap<InstrumentId, Ustr>,
+    index_price_topics: HashMap<InstrumentId, Ustr>,
+    open_interest_topics: HashMap<InstrumentId, Ustr>,
+    liquidation_topics: HashMap<InstrumentId, Ustr>,
+    imbalance_topics: HashMap<InstrumentId, Ustr>,
+    statistics_topics: HashMap<InstrumentId, Ustr>,
     bar_topics: HashMap<BarType, Ustr>,
     order_snapshots_topics: HashMap<ClientOrderId, Ustr>,
     positions_snapshots_topics: HashMap<PositionId, Ustr>,
@@ -57,6 +63,12 @@ impl Default for MessagingSwitchboard {
             depth_topics: HashMap::new(),
             quote_topics: HashMap::new(),
             trade_topics: HashMap::new(),
+            mark_price_topics: HashMap::new(),
+            index_price_topics: HashMap::new(),
+            open_interest_topics: HashMap::new(),
+            liquidation_topics: HashMap::new(),
+            imbalance_topics: HashMap::new(),
+            statistics_topics: HashMap::new(),
             bar_topics: HashMap::new(),
             order_snapshots_topics: HashMap::new(),
             event_orders_topics: HashMap::new(),
@@ -141,6 +153,84 @@ impl MessagingSwitchboard {
         })
     }
 
+    #[must_use]
+    pub fn get_mark_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .mark_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.mark_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_index_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .index_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.index_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_open_interest_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .open_interest_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.open_interest.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_liquidations_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .liquidation_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.liquidations.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_imbalance_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .imbalance_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.imbalance.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_statistics_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .statistics_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.statistics.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_bars_topic(&mut self, bar_type: BarType) -> Ustr {
         *self
@@ -270,6 +360,74 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_mark_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.mark_prices.XCME.ESZ24");
+        let result = switchboard.get_mark_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.mark_price_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_index_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.index_prices.XCME.ESZ24");
+        let result = switchboard.get_index_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.index_price_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_open_interest_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.open_interest.XCME.ESZ24");
+        let result = switchboard.get_open_interest_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .open_interest_topics
+            .contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_liquidations_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.liquidations.XCME.ESZ24");
+        let result = switchboard.get_liquidations_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.liquidation_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_imbalance_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.imbalance.XCME.ESZ24");
+        let result = switchboard.get_imbalance_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.imbalance_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_statistics_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.statistics.XCME.ESZ24");
+        let result = switchboard.get_statistics_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.statistics_topics.contains_key(&instrument_id));
+    }
+
     #[rstest]
     fn test_get_bars_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
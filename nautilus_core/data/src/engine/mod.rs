@@ -69,8 +69,9 @@ use nautilus_core::{
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        AuctionImbalance, Bar, BarType, Data, DataType, IndexPriceUpdate, Liquidation,
+        MarkPriceUpdate, MarketStatistic, OpenInterestUpdate, OrderBookDelta, OrderBookDeltas,
+        OrderBookDepth10, QuoteTick, TradeTick,
     },
     enums::{AggregationSource, BarAggregation, BookType, PriceType, RecordFlag},
     identifiers::{ClientId, InstrumentId, Venue},
@@ -400,6 +401,12 @@ impl DataEngine {
             Data::Quote(quote) => self.handle_quote(quote),
             Data::Trade(trade) => self.handle_trade(trade),
             Data::Bar(bar) => self.handle_bar(bar),
+            Data::MarkPriceUpdate(mark_price) => self.handle_mark_price(mark_price),
+            Data::IndexPriceUpdate(index_price) => self.handle_index_price(index_price),
+            Data::OpenInterestUpdate(open_interest) => self.handle_open_interest(open_interest),
+            Data::Liquidation(liquidation) => self.handle_liquidation(liquidation),
+            Data::AuctionImbalance(imbalance) => self.handle_imbalance(imbalance),
+            Data::MarketStatistic(statistic) => self.handle_market_statistic(statistic),
         }
     }
 
@@ -541,6 +548,76 @@ impl DataEngine {
         msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
     }
 
+    fn handle_mark_price(&mut self, mark_price: MarkPriceUpdate) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_mark_price(mark_price) {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_mark_prices_topic(mark_price.instrument_id);
+        msgbus.publish(&topic, &mark_price as &dyn Any);
+    }
+
+    fn handle_index_price(&mut self, index_price: IndexPriceUpdate) {
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .add_index_price(index_price)
+        {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_index_prices_topic(index_price.instrument_id);
+        msgbus.publish(&topic, &index_price as &dyn Any);
+    }
+
+    fn handle_open_interest(&mut self, open_interest: OpenInterestUpdate) {
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .add_open_interest(open_interest)
+        {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_open_interest_topic(open_interest.instrument_id);
+        msgbus.publish(&topic, &open_interest as &dyn Any);
+    }
+
+    fn handle_liquidation(&mut self, liquidation: Liquidation) {
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_liquidations_topic(liquidation.instrument_id);
+        msgbus.publish(&topic, &liquidation as &dyn Any);
+    }
+
+    fn handle_imbalance(&mut self, imbalance: AuctionImbalance) {
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_imbalance_topic(imbalance.instrument_id);
+        msgbus.publish(&topic, &imbalance as &dyn Any);
+    }
+
+    fn handle_market_statistic(&mut self, statistic: MarketStatistic) {
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_statistics_topic(statistic.instrument_id);
+        msgbus.publish(&topic, &statistic as &dyn Any);
+    }
+
     fn handle_bar(&mut self, bar: Bar) {
         // TODO: Handle additional bar logic
         if self.config.validate_data_sequence {
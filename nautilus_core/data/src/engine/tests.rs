@@ -37,9 +37,14 @@ use nautilus_common::{
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
     data::{
-        stubs::{stub_delta, stub_deltas, stub_depth10},
-        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
-        QuoteTick, TradeTick,
+        stubs::{
+            stub_auction_imbalance, stub_delta, stub_deltas, stub_depth10, stub_index_price_update,
+            stub_liquidation, stub_mark_price_update, stub_market_statistic,
+            stub_open_interest_update,
+        },
+        AuctionImbalance, Bar, BarType, Data, DataType, IndexPriceUpdate, Liquidation,
+        MarkPriceUpdate, MarketStatistic, OpenInterestUpdate, OrderBookDeltas, OrderBookDeltas_API,
+        OrderBookDepth10, QuoteTick, TradeTick,
     },
     enums::BookType,
     identifiers::{ClientId, TraderId, Venue},
@@ -904,3 +909,162 @@ fn test_process_bar(
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&bar));
 }
+
+#[rstest]
+fn test_process_mark_price(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_mark_price_update: MarkPriceUpdate,
+) {
+    let mark_price = stub_mark_price_update;
+    let handler = get_message_saving_handler::<MarkPriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_mark_prices_topic(mark_price.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::MarkPriceUpdate(mark_price));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<MarkPriceUpdate>(handler);
+
+    assert_eq!(
+        cache.mark_price(&mark_price.instrument_id),
+        Some(mark_price).as_ref()
+    );
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&mark_price));
+}
+
+#[rstest]
+fn test_process_index_price(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_index_price_update: IndexPriceUpdate,
+) {
+    let index_price = stub_index_price_update;
+    let handler = get_message_saving_handler::<IndexPriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_index_prices_topic(index_price.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::IndexPriceUpdate(index_price));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<IndexPriceUpdate>(handler);
+
+    assert_eq!(
+        cache.index_price(&index_price.instrument_id),
+        Some(index_price).as_ref()
+    );
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&index_price));
+}
+
+#[rstest]
+fn test_process_open_interest(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_open_interest_update: OpenInterestUpdate,
+) {
+    let open_interest = stub_open_interest_update;
+    let handler = get_message_saving_handler::<OpenInterestUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_open_interest_topic(open_interest.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::OpenInterestUpdate(open_interest));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<OpenInterestUpdate>(handler);
+
+    assert_eq!(
+        cache.open_interest(&open_interest.instrument_id),
+        Some(open_interest).as_ref()
+    );
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&open_interest));
+}
+
+#[rstest]
+fn test_process_liquidation(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_liquidation: Liquidation,
+) {
+    let liquidation = stub_liquidation;
+    let handler = get_message_saving_handler::<Liquidation>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_liquidations_topic(liquidation.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::Liquidation(liquidation));
+    let messages = get_saved_messages::<Liquidation>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&liquidation));
+}
+
+#[rstest]
+fn test_process_imbalance(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_auction_imbalance: AuctionImbalance,
+) {
+    let imbalance = stub_auction_imbalance;
+    let handler = get_message_saving_handler::<AuctionImbalance>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_imbalance_topic(imbalance.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::AuctionImbalance(imbalance));
+    let messages = get_saved_messages::<AuctionImbalance>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&imbalance));
+}
+
+#[rstest]
+fn test_process_market_statistic(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_market_statistic: MarketStatistic,
+) {
+    let statistic = stub_market_statistic;
+    let handler = get_message_saving_handler::<MarketStatistic>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_statistics_topic(statistic.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::MarketStatistic(statistic));
+    let messages = get_saved_messages::<MarketStatistic>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&statistic));
+}
@@ -58,6 +58,36 @@ pub struct ExecutionClient {
 }
 
 impl ExecutionClient {
+    /// Creates a new [`ExecutionClient`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        client_id: ClientId,
+        venue: Venue,
+        oms_type: OmsType,
+        account_id: AccountId,
+        account_type: AccountType,
+        base_currency: Option<Currency>,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            venue,
+            oms_type,
+            account_id,
+            account_type,
+            base_currency,
+            is_connected: false,
+            clock,
+            cache,
+            msgbus,
+        }
+    }
+
     #[must_use]
     pub fn get_account(&self) -> AccountAny {
         let cache = self.cache.as_ref().borrow();
@@ -36,8 +36,13 @@ use nautilus_common::{
     generators::position_id::PositionIdGenerator,
     logging::{CMD, EVT, RECV},
     msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{
+    correctness::{check_positive_u64, FAILED},
+    datetime::secs_to_nanos,
+    UnixNanos, UUID4,
 };
-use nautilus_core::UUID4;
 use nautilus_model::{
     enums::{ContingencyType, OmsType, OrderSide, PositionSide},
     events::{
@@ -59,6 +64,8 @@ use crate::{
     },
 };
 
+const SNAPSHOT_POSITIONS_TIMER: &str = "ExecEngine_SNAPSHOT_POSITIONS";
+
 pub struct ExecutionEngine {
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
@@ -177,6 +184,62 @@ impl ExecutionEngine {
 
     // -- COMMANDS ------------------------------------------------------------
 
+    /// Starts the engine, including the position state snapshots timer (if configured).
+    pub fn start(&mut self) {
+        self.start_snapshot_timer();
+    }
+
+    /// Stops the engine, including the position state snapshots timer (if running).
+    pub fn stop(&mut self) {
+        self.stop_snapshot_timer();
+    }
+
+    /// Starts the position state snapshots timer, if a `snapshot_positions_interval_secs`
+    /// is configured and the timer is not already running.
+    fn start_snapshot_timer(&mut self) {
+        let interval_secs = match self.config.snapshot_positions_interval_secs {
+            Some(interval_secs) if interval_secs > 0.0 => interval_secs,
+            _ => return,
+        };
+
+        let mut clock = self.clock.borrow_mut();
+        if clock.timer_names().contains(&SNAPSHOT_POSITIONS_TIMER) {
+            return;
+        }
+
+        log::info!("Starting position snapshots timer at {interval_secs} second intervals");
+
+        let interval_ns = secs_to_nanos(interval_secs);
+        check_positive_u64(interval_ns, stringify!(interval_ns)).expect(FAILED);
+
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+        let debug = self.config.debug;
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            snapshot_open_position_states(&cache, &msgbus, event.ts_event, debug);
+        }));
+
+        let start_time_ns = clock.timestamp_ns();
+        clock
+            .set_timer_ns(
+                SNAPSHOT_POSITIONS_TIMER,
+                interval_ns,
+                start_time_ns,
+                None,
+                Some(callback),
+            )
+            .expect(FAILED);
+    }
+
+    /// Stops the position state snapshots timer (if running).
+    fn stop_snapshot_timer(&mut self) {
+        let mut clock = self.clock.borrow_mut();
+        if clock.timer_names().contains(&SNAPSHOT_POSITIONS_TIMER) {
+            log::info!("Canceling position snapshots timer");
+            clock.cancel_timer(SNAPSHOT_POSITIONS_TIMER);
+        }
+    }
+
     pub fn load_cache(&mut self) -> anyhow::Result<()> {
         let ts = SystemTime::now();
 
@@ -328,25 +391,30 @@ impl ExecutionEngine {
         let orders = command.order_list.orders.clone();
 
         // Cache orders
-        let mut cache = self.cache.borrow_mut();
-        for order in &orders {
-            if !cache.order_exists(&order.client_order_id()) {
-                if let Err(e) = cache.add_order(
-                    order.clone(),
-                    command.position_id,
-                    Some(command.client_id),
-                    true,
-                ) {
-                    log::error!("Error adding order to cache: {e}");
-                    return;
+        let mut added_orders = Vec::with_capacity(orders.len());
+        {
+            let mut cache = self.cache.borrow_mut();
+            for order in &orders {
+                if !cache.order_exists(&order.client_order_id()) {
+                    if let Err(e) = cache.add_order(
+                        order.clone(),
+                        command.position_id,
+                        Some(command.client_id),
+                        true,
+                    ) {
+                        log::error!("Error adding order to cache: {e}");
+                        return;
+                    }
+                    added_orders.push(order);
                 }
+            }
+        }
 
-                if self.config.snapshot_orders {
-                    self.create_order_state_snapshot(order);
-                }
+        if self.config.snapshot_orders {
+            for order in added_orders {
+                self.create_order_state_snapshot(order);
             }
         }
-        drop(cache);
 
         // Get instrument from cache
         let cache = self.cache.borrow();
@@ -438,11 +506,9 @@ impl ExecutionEngine {
             log::debug!("Creating order state snapshot for {order}");
         }
 
-        if self.cache.borrow().has_backing() {
-            if let Err(e) = self.cache.borrow().snapshot_order_state(order) {
-                log::error!("Failed to snapshot order state: {e}");
-                return;
-            }
+        if let Err(e) = self.cache.borrow_mut().snapshot_order_state(order) {
+            log::error!("Failed to snapshot order state: {e}");
+            return;
         }
 
         let mut msgbus = self.msgbus.borrow_mut();
@@ -455,20 +521,16 @@ impl ExecutionEngine {
     }
 
     fn create_position_state_snapshot(&self, position: &Position) {
-        if self.config.debug {
-            log::debug!("Creating position state snapshot for {position}");
-        }
-
-        // let mut position: Position = position.clone();
-        // if let Some(pnl) = self.cache.borrow().calculate_unrealized_pnl(&position) {
-        //     position.unrealized_pnl(last)
-        // }
-
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus
-            .switchboard
-            .get_positions_snapshots_topic(position.id);
-        msgbus.publish(&topic, position);
+        // Snapshot on every state change, including when the position closes
+        let ts_snapshot = self.clock.borrow().timestamp_ns();
+        create_position_state_snapshot(
+            &self.cache,
+            &self.msgbus,
+            position,
+            ts_snapshot,
+            false,
+            self.config.debug,
+        );
     }
 
     // -- EVENT HANDLERS ----------------------------------------------------
@@ -636,11 +698,13 @@ impl ExecutionEngine {
             log::error!("Error updating order in cache: {e}");
         }
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus
-            .switchboard
-            .get_event_orders_topic(event.strategy_id());
-        msgbus.publish(&topic, order);
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus
+                .switchboard
+                .get_event_orders_topic(event.strategy_id());
+            msgbus.publish(&topic, order);
+        }
 
         if self.config.snapshot_orders {
             self.create_order_state_snapshot(order);
@@ -1027,11 +1091,13 @@ impl ExecutionEngine {
             return;
         }
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus
-            .switchboard
-            .get_event_orders_topic(order.strategy_id());
-        msgbus.publish(&topic, &denied);
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus
+                .switchboard
+                .get_event_orders_topic(order.strategy_id());
+            msgbus.publish(&topic, &denied);
+        }
 
         if self.config.snapshot_orders {
             self.create_order_state_snapshot(&order);
@@ -1039,6 +1105,55 @@ impl ExecutionEngine {
     }
 }
 
+fn create_position_state_snapshot(
+    cache: &Rc<RefCell<Cache>>,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    position: &Position,
+    ts_snapshot: UnixNanos,
+    open_only: bool,
+    debug: bool,
+) {
+    if debug {
+        log::debug!("Creating position state snapshot for {position}");
+    }
+
+    let unrealized_pnl = cache.borrow().calculate_unrealized_pnl(position);
+
+    if let Err(e) = cache.borrow_mut().snapshot_position_state(
+        position,
+        ts_snapshot,
+        unrealized_pnl,
+        Some(open_only),
+    ) {
+        log::error!("Failed to snapshot position state: {e}");
+        return;
+    }
+
+    let mut msgbus = msgbus.borrow_mut();
+    let topic = msgbus
+        .switchboard
+        .get_positions_snapshots_topic(position.id);
+    msgbus.publish(&topic, position);
+}
+
+fn snapshot_open_position_states(
+    cache: &Rc<RefCell<Cache>>,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    ts_snapshot: UnixNanos,
+    debug: bool,
+) {
+    let positions: Vec<Position> = cache
+        .borrow()
+        .positions_open(None, None, None, None)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    for position in &positions {
+        create_position_state_snapshot(cache, msgbus, position, ts_snapshot, true, debug);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -1046,8 +1161,19 @@ impl ExecutionEngine {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
-    use rstest::fixture;
+    use nautilus_common::{
+        cache::Cache,
+        clock::{Clock, TestClock},
+        msgbus::MessageBus,
+    };
+    use nautilus_core::time::get_atomic_clock_static;
+    use nautilus_model::{
+        enums::{AccountType, OrderType},
+        identifiers::{AccountId, ClientOrderId, OrderListId, TraderId, VenueOrderId},
+        orders::{builder::OrderTestBuilder, OrderList},
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
 
     use super::*;
 
@@ -1077,5 +1203,129 @@ mod tests {
         ExecutionEngine::new(clock, cache, msgbus, config)
     }
 
+    #[rstest]
+    fn test_start_and_stop_with_snapshot_timer(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let clock = Rc::new(RefCell::new(clock));
+        let config = ExecutionEngineConfig {
+            snapshot_positions_interval_secs: Some(10.0),
+            ..Default::default()
+        };
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(simple_cache)),
+            clock.clone(),
+            Some(config),
+        );
+
+        engine.start();
+        assert_eq!(clock.borrow().timer_names(), vec![SNAPSHOT_POSITIONS_TIMER]);
+
+        engine.stop();
+        assert!(clock.borrow().timer_names().is_empty());
+    }
+
+    #[rstest]
+    fn test_start_when_no_snapshot_interval(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let clock = Rc::new(RefCell::new(clock));
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(simple_cache)),
+            clock.clone(),
+            None,
+        );
+
+        engine.start();
+        assert!(clock.borrow().timer_names().is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_order_list_when_snapshot_orders(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let config = ExecutionEngineConfig {
+            snapshot_orders: true,
+            ..Default::default()
+        };
+        let engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Cash,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let orders: Vec<OrderAny> = ["O-1", "O-2"]
+            .into_iter()
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(instrument_id)
+                    .client_order_id(ClientOrderId::from(client_order_id))
+                    .side(OrderSide::Buy)
+                    .price(Price::from("1.00000"))
+                    .quantity(Quantity::from(100_000))
+                    .build()
+            })
+            .collect();
+        let order_list = OrderList::new(
+            OrderListId::from("OL-1"),
+            instrument_id,
+            orders[0].strategy_id(),
+            orders.clone(),
+            UnixNanos::default(),
+        );
+        let command = SubmitOrderList::new(
+            TraderId::default(),
+            client.client_id,
+            orders[0].strategy_id(),
+            instrument_id,
+            orders[0].client_order_id(),
+            VenueOrderId::from("1"),
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        // No instrument is cached, so handling stops after the orders are cached and snapshot
+        engine.handle_submit_order_list(&client, command);
+
+        let cache = cache.borrow();
+        for order in &orders {
+            assert!(cache.order_exists(&order.client_order_id()));
+            assert_eq!(
+                cache
+                    .order_state_snapshots(Some(&order.client_order_id()))
+                    .len(),
+                1
+            );
+        }
+    }
+
     // TODO: After Implementing ExecutionClient & Strategy
 }
@@ -0,0 +1,207 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{book::microprice::depth_levels, indicator::Indicator};
+
+/// An indicator which calculates the order book imbalance over the top `depth` levels.
+///
+/// The value is `(bid_size - ask_size) / (bid_size + ask_size)` of the total sizes over the
+/// levels, ranging from -1 (all liquidity on the ask side) to 1 (all liquidity on the bid side).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookDepthImbalance {
+    pub depth: usize,
+    pub value: f64,
+    pub bid_size: f64,
+    pub ask_size: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookDepthImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for BookDepthImbalance {
+    fn name(&self) -> String {
+        stringify!(BookDepthImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        self.update_raw(
+            book.bids(Some(self.depth)).map(|level| level.size()).sum(),
+            book.asks(Some(self.depth)).map(|level| level.size()).sum(),
+        );
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth_levels(depth, self.depth);
+        self.update_raw(
+            bids.iter().map(|(_, size)| size).sum(),
+            asks.iter().map(|(_, size)| size).sum(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.bid_size = 0.0;
+        self.ask_size = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookDepthImbalance {
+    /// Creates a new [`BookDepthImbalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "`depth` must be positive, was {depth}");
+        Self {
+            depth,
+            value: 0.0,
+            bid_size: 0.0,
+            ask_size: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the total bid and ask sizes over the book levels.
+    pub fn update_raw(&mut self, bid_size: f64, ask_size: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+        self.bid_size = bid_size;
+        self.ask_size = ask_size;
+
+        let total_size = bid_size + ask_size;
+        if total_size <= 0.0 {
+            // Empty book
+            return;
+        }
+
+        self.value = (bid_size - ask_size) / total_size;
+        self.initialized = true;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::stub_depth10, identifiers::InstrumentId, stubs::stub_order_book_mbp,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_name_and_repr() {
+        let imbalance = BookDepthImbalance::new(5);
+        assert_eq!(imbalance.name(), "BookDepthImbalance");
+        assert_eq!(format!("{imbalance}"), "BookDepthImbalance(5)");
+        assert!(!imbalance.initialized());
+    }
+
+    #[rstest]
+    fn test_update_raw() {
+        let mut imbalance = BookDepthImbalance::new(5);
+        imbalance.update_raw(300.0, 100.0);
+
+        assert_eq!(imbalance.value, 0.5);
+        assert!(imbalance.initialized());
+    }
+
+    #[rstest]
+    fn test_empty_book_not_initialized() {
+        let mut imbalance = BookDepthImbalance::new(5);
+        imbalance.update_raw(0.0, 0.0);
+
+        assert!(imbalance.has_inputs());
+        assert!(!imbalance.initialized());
+        assert_eq!(imbalance.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_book_over_levels() {
+        let mut imbalance = BookDepthImbalance::new(3);
+        let book = stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            300.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        imbalance.handle_book(&book);
+
+        // Bids 300 + 400 + 500, asks 100 + 200 + 300
+        assert_eq!(imbalance.bid_size, 1200.0);
+        assert_eq!(imbalance.ask_size, 600.0);
+        assert!((imbalance.value - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_depth_balanced() {
+        let mut imbalance = BookDepthImbalance::new(10);
+        imbalance.handle_depth(&stub_depth10());
+
+        assert_eq!(imbalance.bid_size, imbalance.ask_size);
+        assert_eq!(imbalance.value, 0.0);
+        assert!(imbalance.initialized());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut imbalance = BookDepthImbalance::new(5);
+        imbalance.update_raw(300.0, 100.0);
+        imbalance.reset();
+
+        assert_eq!(imbalance.value, 0.0);
+        assert_eq!(imbalance.count, 0);
+        assert!(!imbalance.initialized());
+        assert!(!imbalance.has_inputs());
+    }
+}
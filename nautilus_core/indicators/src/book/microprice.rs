@@ -0,0 +1,279 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    data::{BookOrder, OrderBookDepth10, QuoteTick},
+    orderbook::OrderBook,
+};
+
+use crate::indicator::Indicator;
+
+/// A sequence of `(price, size)` book levels, ordered from the top of the book.
+pub(crate) type PriceLevels = Vec<(f64, f64)>;
+
+/// An indicator which calculates the microprice (size weighted mid) over the top `depth`
+/// levels of an order book.
+///
+/// The volume weighted bid and ask prices over the levels are weighted by the opposing
+/// side's total size, pulling the price towards the side with less liquidity. With a `depth`
+/// of 1 this is the classic top of book microprice.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct MicroPrice {
+    pub depth: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for MicroPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for MicroPrice {
+    fn name(&self) -> String {
+        stringify!(MicroPrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let bids: PriceLevels = book
+            .bids(Some(self.depth))
+            .map(|level| (level.price.value.as_f64(), level.size()))
+            .collect();
+        let asks: PriceLevels = book
+            .asks(Some(self.depth))
+            .map(|level| (level.price.value.as_f64(), level.size()))
+            .collect();
+        self.update_raw(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth_levels(depth, self.depth);
+        self.update_raw(&bids, &asks);
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(
+            &[(quote.bid_price.as_f64(), quote.bid_size.as_f64())],
+            &[(quote.ask_price.as_f64(), quote.ask_size.as_f64())],
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MicroPrice {
+    /// Creates a new [`MicroPrice`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "`depth` must be positive, was {depth}");
+        Self {
+            depth,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given `(price, size)` levels for each side, ordered from
+    /// the top of the book (only the first `depth` levels are used).
+    pub fn update_raw(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (bid_px, bid_size) = weighted_price(bids, self.depth);
+        let (ask_px, ask_size) = weighted_price(asks, self.depth);
+        let total_size = bid_size + ask_size;
+
+        // No two-sided market yet
+        if bid_size <= 0.0 || ask_size <= 0.0 {
+            return;
+        }
+
+        self.value = bid_px.mul_add(ask_size, ask_px * bid_size) / total_size;
+        self.initialized = true;
+    }
+}
+
+/// Returns the size weighted price and total size of the top `depth` levels.
+fn weighted_price(levels: &[(f64, f64)], depth: usize) -> (f64, f64) {
+    let (notional, size) = levels
+        .iter()
+        .take(depth)
+        .fold((0.0, 0.0), |(notional, total), &(price, size)| {
+            (price.mul_add(size, notional), total + size)
+        });
+
+    if size > 0.0 {
+        (notional / size, size)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// Returns the non-empty `(price, size)` levels for each side of the `depth` snapshot.
+pub(crate) fn depth_levels(depth: &OrderBookDepth10, levels: usize) -> (PriceLevels, PriceLevels) {
+    let collect = |orders: &[BookOrder]| {
+        orders
+            .iter()
+            .filter(|order| order.size.is_positive())
+            .take(levels)
+            .map(|order| (order.price.as_f64(), order.size.as_f64()))
+            .collect()
+    };
+    (collect(&depth.bids), collect(&depth.asks))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::stub_depth10, identifiers::InstrumentId, stubs::stub_order_book_mbp,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_quote;
+
+    #[rstest]
+    fn test_name_and_repr() {
+        let microprice = MicroPrice::new(3);
+        assert_eq!(microprice.name(), "MicroPrice");
+        assert_eq!(format!("{microprice}"), "MicroPrice(3)");
+        assert!(!microprice.initialized());
+        assert!(!microprice.has_inputs());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`depth` must be positive")]
+    fn test_new_with_zero_depth_panics() {
+        let _ = MicroPrice::new(0);
+    }
+
+    #[rstest]
+    fn test_top_of_book_weights_towards_thinner_side() {
+        let mut microprice = MicroPrice::new(1);
+        microprice.update_raw(&[(100.0, 300.0)], &[(101.0, 100.0)]);
+
+        // (100 * 100 + 101 * 300) / 400
+        assert!((microprice.value - 100.75).abs() < 1e-9);
+        assert!(microprice.initialized());
+    }
+
+    #[rstest]
+    fn test_multiple_levels() {
+        let mut microprice = MicroPrice::new(2);
+        microprice.update_raw(
+            &[(100.0, 100.0), (99.0, 100.0), (98.0, 1000.0)],
+            &[(101.0, 100.0), (102.0, 300.0)],
+        );
+
+        // Bid VWAP 99.5 (200), ask VWAP 101.75 (400)
+        let expected = (99.5 * 400.0 + 101.75 * 200.0) / 600.0;
+        assert!((microprice.value - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_one_sided_market_not_initialized() {
+        let mut microprice = MicroPrice::new(1);
+        microprice.update_raw(&[(100.0, 100.0)], &[]);
+
+        assert!(microprice.has_inputs());
+        assert!(!microprice.initialized());
+        assert_eq!(microprice.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_book() {
+        let mut microprice = MicroPrice::new(1);
+        let book = stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            300.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        microprice.handle_book(&book);
+
+        assert_eq!(microprice.count, 1);
+        assert!((microprice.value - 100.75).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_depth() {
+        let mut microprice = MicroPrice::new(2);
+        microprice.handle_depth(&stub_depth10());
+
+        // Bid VWAP (99 * 100 + 98 * 200) / 300, ask VWAP (100 * 100 + 101 * 200) / 300
+        let bid_px = 98.0_f64.mul_add(200.0, 99.0 * 100.0) / 300.0;
+        let ask_px = 101.0_f64.mul_add(200.0, 100.0 * 100.0) / 300.0;
+        assert!((microprice.value - (bid_px + ask_px) / 2.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_quote(stub_quote: QuoteTick) {
+        let mut microprice = MicroPrice::new(1);
+        microprice.handle_quote(&stub_quote);
+
+        assert!(microprice.initialized());
+        assert!((microprice.value - 1501.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut microprice = MicroPrice::new(1);
+        microprice.update_raw(&[(100.0, 300.0)], &[(101.0, 100.0)]);
+        microprice.reset();
+
+        assert_eq!(microprice.value, 0.0);
+        assert_eq!(microprice.count, 0);
+        assert!(!microprice.initialized());
+        assert!(!microprice.has_inputs());
+    }
+}
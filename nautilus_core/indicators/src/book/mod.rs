@@ -15,4 +15,6 @@
 
 //! Order book specific indicators.
 
+pub mod depth_imbalance;
 pub mod imbalance;
+pub mod microprice;
@@ -0,0 +1,163 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::TradeTick, enums::AggressorSide};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the cumulative volume delta (CVD), the running difference
+/// between aggressive buy and sell volume.
+///
+/// Trades without an aggressor side are counted in neither side.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct CumulativeVolumeDelta {
+    pub value: f64,
+    pub buy_volume: f64,
+    pub sell_volume: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for CumulativeVolumeDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for CumulativeVolumeDelta {
+    fn name(&self) -> String {
+        stringify!(CumulativeVolumeDelta).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.size.as_f64(), trade.aggressor_side);
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buy_volume = 0.0;
+        self.sell_volume = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl CumulativeVolumeDelta {
+    /// Creates a new [`CumulativeVolumeDelta`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: 0.0,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, size: f64, aggressor_side: AggressorSide) {
+        match aggressor_side {
+            AggressorSide::Buyer => self.buy_volume += size,
+            AggressorSide::Seller => self.sell_volume += size,
+            AggressorSide::NoAggressor => {}
+        }
+
+        self.value = self.buy_volume - self.sell_volume;
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_trade;
+
+    #[rstest]
+    fn test_name_and_repr() {
+        let cvd = CumulativeVolumeDelta::new();
+        assert_eq!(cvd.name(), "CumulativeVolumeDelta");
+        assert_eq!(format!("{cvd}"), "CumulativeVolumeDelta()");
+        assert!(!cvd.initialized());
+        assert!(!cvd.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_mixed_aggressors() {
+        let mut cvd = CumulativeVolumeDelta::new();
+        cvd.update_raw(10.0, AggressorSide::Buyer);
+        cvd.update_raw(4.0, AggressorSide::Seller);
+        cvd.update_raw(7.0, AggressorSide::NoAggressor);
+        cvd.update_raw(8.0, AggressorSide::Seller);
+
+        assert_eq!(cvd.buy_volume, 10.0);
+        assert_eq!(cvd.sell_volume, 12.0);
+        assert_eq!(cvd.value, -2.0);
+        assert_eq!(cvd.count, 4);
+        assert!(cvd.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_trade(stub_trade: TradeTick) {
+        let mut cvd = CumulativeVolumeDelta::new();
+        cvd.handle_trade(&stub_trade);
+        cvd.handle_trade(&stub_trade);
+
+        assert_eq!(cvd.value, 2.0);
+        assert_eq!(cvd.count, 2);
+    }
+
+    #[rstest]
+    fn test_reset(stub_trade: TradeTick) {
+        let mut cvd = CumulativeVolumeDelta::new();
+        cvd.handle_trade(&stub_trade);
+        cvd.reset();
+
+        assert_eq!(cvd.value, 0.0);
+        assert_eq!(cvd.buy_volume, 0.0);
+        assert_eq!(cvd.sell_volume, 0.0);
+        assert_eq!(cvd.count, 0);
+        assert!(!cvd.initialized());
+        assert!(!cvd.has_inputs());
+    }
+}
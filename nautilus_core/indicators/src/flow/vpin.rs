@@ -0,0 +1,245 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_model::{data::TradeTick, enums::AggressorSide};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Volume-Synchronized Probability of Informed Trading
+/// (VPIN) over a rolling window of equal volume buckets.
+///
+/// Trade volume is classified by aggressor side (trades without an aggressor are split evenly
+/// between buys and sells) and filled into buckets of `bucket_volume`, with any volume over a
+/// bucket's capacity carried into the next. The value is the mean absolute buy/sell imbalance
+/// of the last `window` completed buckets as a fraction of the bucket volume.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct VolumeSynchronizedProbabilityOfInformedTrading {
+    pub bucket_volume: f64,
+    pub window: usize,
+    pub value: f64,
+    pub bucket_count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    bucket_buy_volume: f64,
+    bucket_sell_volume: f64,
+    imbalances: VecDeque<f64>,
+}
+
+impl Display for VolumeSynchronizedProbabilityOfInformedTrading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.bucket_volume, self.window)
+    }
+}
+
+impl Indicator for VolumeSynchronizedProbabilityOfInformedTrading {
+    fn name(&self) -> String {
+        stringify!(VolumeSynchronizedProbabilityOfInformedTrading).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.size.as_f64(), trade.aggressor_side);
+    }
+
+    fn reset(&mut self) {
+        self.imbalances.clear();
+        self.bucket_buy_volume = 0.0;
+        self.bucket_sell_volume = 0.0;
+        self.value = 0.0;
+        self.bucket_count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl VolumeSynchronizedProbabilityOfInformedTrading {
+    /// Creates a new [`VolumeSynchronizedProbabilityOfInformedTrading`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `bucket_volume` is not positive (> 0).
+    /// - `window` is not positive (> 0).
+    #[must_use]
+    pub fn new(bucket_volume: f64, window: usize) -> Self {
+        assert!(
+            bucket_volume > 0.0,
+            "`bucket_volume` must be positive, was {bucket_volume}"
+        );
+        assert!(window > 0, "`window` must be positive, was {window}");
+        Self {
+            bucket_volume,
+            window,
+            value: 0.0,
+            bucket_count: 0,
+            bucket_buy_volume: 0.0,
+            bucket_sell_volume: 0.0,
+            imbalances: VecDeque::with_capacity(window),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, size: f64, aggressor_side: AggressorSide) {
+        self.has_inputs = true;
+
+        let buy_fraction = match aggressor_side {
+            AggressorSide::Buyer => 1.0,
+            AggressorSide::Seller => 0.0,
+            AggressorSide::NoAggressor => 0.5,
+        };
+
+        let mut remaining = size;
+        while remaining > 0.0 {
+            let capacity = self.bucket_volume - (self.bucket_buy_volume + self.bucket_sell_volume);
+            let filled = remaining.min(capacity);
+            self.bucket_buy_volume += filled * buy_fraction;
+            self.bucket_sell_volume += filled * (1.0 - buy_fraction);
+            remaining -= filled;
+
+            if filled >= capacity {
+                self.complete_bucket();
+            }
+        }
+    }
+
+    fn complete_bucket(&mut self) {
+        if self.imbalances.len() == self.window {
+            self.imbalances.pop_front();
+        }
+        self.imbalances
+            .push_back((self.bucket_buy_volume - self.bucket_sell_volume).abs());
+        self.bucket_buy_volume = 0.0;
+        self.bucket_sell_volume = 0.0;
+        self.bucket_count += 1;
+
+        self.value = self.imbalances.iter().sum::<f64>()
+            / (self.imbalances.len() as f64 * self.bucket_volume);
+
+        // Initialization logic
+        if !self.initialized && self.imbalances.len() >= self.window {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_trade;
+
+    #[rstest]
+    fn test_name_and_repr() {
+        let vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(10.0, 3);
+        assert_eq!(
+            vpin.name(),
+            "VolumeSynchronizedProbabilityOfInformedTrading"
+        );
+        assert_eq!(
+            format!("{vpin}"),
+            "VolumeSynchronizedProbabilityOfInformedTrading(10,3)"
+        );
+        assert!(!vpin.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`bucket_volume` must be positive")]
+    fn test_new_with_zero_bucket_volume_panics() {
+        let _ = VolumeSynchronizedProbabilityOfInformedTrading::new(0.0, 3);
+    }
+
+    #[rstest]
+    fn test_partial_bucket_has_no_value() {
+        let mut vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(10.0, 3);
+        vpin.update_raw(5.0, AggressorSide::Buyer);
+
+        assert!(vpin.has_inputs());
+        assert_eq!(vpin.bucket_count, 0);
+        assert_eq!(vpin.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_trade_spanning_buckets_carries_over() {
+        let mut vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(10.0, 3);
+        vpin.update_raw(4.0, AggressorSide::Seller);
+        vpin.update_raw(16.0, AggressorSide::Buyer);
+
+        // Buckets: (buy 6, sell 4) -> 2, (buy 10, sell 0) -> 10
+        assert_eq!(vpin.bucket_count, 2);
+        assert!((vpin.value - 12.0 / 20.0).abs() < 1e-12);
+        assert!(!vpin.initialized());
+    }
+
+    #[rstest]
+    fn test_value_over_rolling_window() {
+        let mut vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(10.0, 3);
+        vpin.update_raw(10.0, AggressorSide::Buyer); // 10
+        vpin.update_raw(10.0, AggressorSide::NoAggressor); // 0
+        vpin.update_raw(5.0, AggressorSide::Buyer);
+        vpin.update_raw(5.0, AggressorSide::Seller); // 0
+        assert!(vpin.initialized());
+        assert!((vpin.value - 10.0 / 30.0).abs() < 1e-12);
+
+        vpin.update_raw(10.0, AggressorSide::Seller); // 10, first bucket rolls off
+        assert_eq!(vpin.bucket_count, 4);
+        assert!((vpin.value - 10.0 / 30.0).abs() < 1e-12);
+
+        vpin.update_raw(10.0, AggressorSide::Seller); // 10
+        assert!((vpin.value - 20.0 / 30.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_trade(stub_trade: TradeTick) {
+        let mut vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(1.0, 1);
+        vpin.handle_trade(&stub_trade);
+
+        assert!(vpin.initialized());
+        assert_eq!(vpin.value, 1.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut vpin = VolumeSynchronizedProbabilityOfInformedTrading::new(10.0, 1);
+        vpin.update_raw(15.0, AggressorSide::Buyer);
+        vpin.reset();
+
+        assert_eq!(vpin.value, 0.0);
+        assert_eq!(vpin.bucket_count, 0);
+        assert!(!vpin.initialized());
+        assert!(!vpin.has_inputs());
+
+        vpin.update_raw(10.0, AggressorSide::Seller);
+        assert_eq!(vpin.value, 1.0);
+    }
+}
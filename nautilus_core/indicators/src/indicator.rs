@@ -69,6 +69,23 @@ pub trait MovingAverage: Indicator {
     fn update_raw(&mut self, value: f64);
 }
 
+/// An indicator which consumes a stream of scalar values, allowing it to be chained onto the
+/// output of another indicator.
+pub trait ScalarIndicator: Indicator {
+    fn value(&self) -> f64;
+    fn handle_value(&mut self, value: f64);
+}
+
+impl<T: MovingAverage + ?Sized> ScalarIndicator for T {
+    fn value(&self) -> f64 {
+        MovingAverage::value(self)
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 impl Debug for dyn Indicator + Send {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         // Implement custom formatting for the Indicator trait object
@@ -82,3 +99,9 @@ impl Debug for dyn MovingAverage + Send {
         write!(f, "MovingAverage()")
     }
 }
+
+impl Debug for dyn ScalarIndicator + Send {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ScalarIndicator({})", self.name())
+    }
+}
@@ -28,9 +28,13 @@
 
 pub mod average;
 pub mod book;
+pub mod flow;
 pub mod indicator;
 pub mod momentum;
+pub mod pipeline;
 pub mod ratio;
+pub mod rolling;
+pub mod statistics;
 pub mod testing;
 pub mod volatility;
 
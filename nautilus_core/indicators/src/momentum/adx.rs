@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Average Directional Index (ADX) along with the positive
+/// and negative Directional Indicators (+DI/-DI), using Wilder's original smoothing.
+///
+/// The smoothed directional movement and true range are seeded with a sum over the first
+/// `period` movements, and the ADX with the mean of the first `period` DX values, so the
+/// indicator is initialized after `2 * period` bars.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct AverageDirectionalIndex {
+    pub period: usize,
+    pub value: f64,
+    pub pos_di: f64,
+    pub neg_di: f64,
+    pub dx: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_high: f64,
+    previous_low: f64,
+    previous_close: f64,
+    smoothed_tr: f64,
+    smoothed_pos_dm: f64,
+    smoothed_neg_dm: f64,
+    dx_sum: f64,
+    dx_count: usize,
+}
+
+impl Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for AverageDirectionalIndex {
+    fn name(&self) -> String {
+        stringify!(AverageDirectionalIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.pos_di = 0.0;
+        self.neg_di = 0.0;
+        self.dx = 0.0;
+        self.count = 0;
+        self.previous_high = 0.0;
+        self.previous_low = 0.0;
+        self.previous_close = 0.0;
+        self.smoothed_tr = 0.0;
+        self.smoothed_pos_dm = 0.0;
+        self.smoothed_neg_dm = 0.0;
+        self.dx_sum = 0.0;
+        self.dx_count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl AverageDirectionalIndex {
+    /// Creates a new [`AverageDirectionalIndex`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            value: 0.0,
+            pos_di: 0.0,
+            neg_di: 0.0,
+            dx: 0.0,
+            count: 0,
+            previous_high: 0.0,
+            previous_low: 0.0,
+            previous_close: 0.0,
+            smoothed_tr: 0.0,
+            smoothed_pos_dm: 0.0,
+            smoothed_neg_dm: 0.0,
+            dx_sum: 0.0,
+            dx_count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if !self.has_inputs {
+            self.has_inputs = true;
+            self.previous_high = high;
+            self.previous_low = low;
+            self.previous_close = close;
+            return;
+        }
+
+        let up = high - self.previous_high;
+        let down = self.previous_low - low;
+        let pos_dm = if up > down && up > 0.0 { up } else { 0.0 };
+        let neg_dm = if down > up && down > 0.0 { down } else { 0.0 };
+        let tr = high.max(self.previous_close) - low.min(self.previous_close);
+
+        self.previous_high = high;
+        self.previous_low = low;
+        self.previous_close = close;
+        self.count += 1;
+
+        let period = self.period as f64;
+        if self.count <= self.period {
+            self.smoothed_tr += tr;
+            self.smoothed_pos_dm += pos_dm;
+            self.smoothed_neg_dm += neg_dm;
+            if self.count < self.period {
+                return;
+            }
+        } else {
+            self.smoothed_tr = self.smoothed_tr - self.smoothed_tr / period + tr;
+            self.smoothed_pos_dm = self.smoothed_pos_dm - self.smoothed_pos_dm / period + pos_dm;
+            self.smoothed_neg_dm = self.smoothed_neg_dm - self.smoothed_neg_dm / period + neg_dm;
+        }
+
+        if self.smoothed_tr > 0.0 {
+            self.pos_di = 100.0 * self.smoothed_pos_dm / self.smoothed_tr;
+            self.neg_di = 100.0 * self.smoothed_neg_dm / self.smoothed_tr;
+        } else {
+            self.pos_di = 0.0;
+            self.neg_di = 0.0;
+        }
+
+        let di_sum = self.pos_di + self.neg_di;
+        self.dx = if di_sum > 0.0 {
+            100.0 * (self.pos_di - self.neg_di).abs() / di_sum
+        } else {
+            0.0
+        };
+
+        if self.initialized {
+            self.value = self.value.mul_add(period - 1.0, self.dx) / period;
+        } else {
+            self.dx_sum += self.dx;
+            self.dx_count += 1;
+            self.value = self.dx_sum / self.dx_count as f64;
+            if self.dx_count >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{
+        adx_3, bar_ethusdt_binance_minute_bid, REFERENCE_CLOSES, REFERENCE_HIGHS, REFERENCE_LOWS,
+    };
+
+    const HIGHS: [f64; 10] = [10.0, 11.0, 12.0, 11.5, 13.0, 12.5, 12.0, 11.0, 11.5, 10.5];
+    const LOWS: [f64; 10] = [9.0, 10.0, 10.5, 10.0, 11.5, 11.0, 10.5, 9.5, 10.0, 9.0];
+    const CLOSES: [f64; 10] = [9.5, 10.8, 11.5, 10.5, 12.5, 11.5, 11.0, 10.0, 11.0, 9.5];
+
+    #[rstest]
+    fn test_name_returns_expected_string(adx_3: AverageDirectionalIndex) {
+        assert_eq!(adx_3.name(), "AverageDirectionalIndex");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(adx_3: AverageDirectionalIndex) {
+        assert_eq!(format!("{adx_3}"), "AverageDirectionalIndex(3)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(adx_3: AverageDirectionalIndex) {
+        assert!(!adx_3.initialized());
+        assert!(!adx_3.has_inputs());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`period` must be positive")]
+    fn test_new_with_zero_period_panics() {
+        let _ = AverageDirectionalIndex::new(0);
+    }
+
+    #[rstest]
+    fn test_initialized_after_two_periods(mut adx_3: AverageDirectionalIndex) {
+        for i in 0..5 {
+            adx_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+            assert!(!adx_3.initialized());
+        }
+        adx_3.update_raw(HIGHS[5], LOWS[5], CLOSES[5]);
+        assert!(adx_3.initialized());
+    }
+
+    #[rstest]
+    fn test_value_with_sample_inputs(mut adx_3: AverageDirectionalIndex) {
+        for i in 0..HIGHS.len() {
+            adx_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        // Expected values from the same reference as `test_value_matches_wilder_reference`
+        assert!((adx_3.pos_di - 13.766_372_627_639_667).abs() < 1e-9);
+        assert!((adx_3.neg_di - 33.814_488_104_784_814).abs() < 1e-9);
+        assert!((adx_3.dx - 42.134_831_460_674_15).abs() < 1e-9);
+        assert!((adx_3.value - 32.644_561_258_087_435).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_matches_wilder_reference() {
+        // Reference values from an independent implementation of Wilder's published ADX
+        // definition (sum seeded smoothing, first ADX the mean of the first `period` DX values)
+        // with a period of 14, sampled as (bar index, +DI, -DI, DX, ADX)
+        let expected = [
+            (
+                27,
+                23.598_605_308_817_362,
+                25.178_806_141_258_59,
+                3.239_616_013_774_277,
+                11.692_379_479_268_968,
+            ),
+            (
+                40,
+                25.789_422_835_796_29,
+                27.853_300_970_279_36,
+                3.847_452_157_620_138,
+                12.408_890_124_973_013,
+            ),
+            (
+                60,
+                11.373_846_968_069_422,
+                41.173_170_733_641_73,
+                56.709_828_776_071_376,
+                31.002_116_446_856_043,
+            ),
+            (
+                79,
+                20.824_517_925_045_98,
+                29.713_383_642_431_836,
+                17.588_513_653_495_305,
+                15.139_011_405_202_44,
+            ),
+        ];
+        let mut adx = AverageDirectionalIndex::new(14);
+
+        let mut outputs = Vec::with_capacity(REFERENCE_HIGHS.len());
+        for i in 0..REFERENCE_HIGHS.len() {
+            adx.update_raw(REFERENCE_HIGHS[i], REFERENCE_LOWS[i], REFERENCE_CLOSES[i]);
+            assert_eq!(adx.initialized(), i >= 27);
+            outputs.push((adx.pos_di, adx.neg_di, adx.dx, adx.value));
+        }
+
+        for (index, pos_di, neg_di, dx, value) in expected {
+            let output = outputs[index];
+            assert!((output.0 - pos_di).abs() < 1e-9, "bar {index}");
+            assert!((output.1 - neg_di).abs() < 1e-9, "bar {index}");
+            assert!((output.2 - dx).abs() < 1e-9, "bar {index}");
+            assert!((output.3 - value).abs() < 1e-9, "bar {index}");
+        }
+    }
+
+    #[rstest]
+    fn test_value_with_steady_uptrend_returns_one_hundred(mut adx_3: AverageDirectionalIndex) {
+        for i in 0..20 {
+            let base = i as f64;
+            adx_3.update_raw(base + 1.0, base, base + 0.5);
+        }
+
+        assert_eq!(adx_3.neg_di, 0.0);
+        assert!((adx_3.value - 100.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut adx_3: AverageDirectionalIndex, bar_ethusdt_binance_minute_bid: Bar) {
+        adx_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(adx_3.has_inputs());
+        assert_eq!(adx_3.count, 0);
+        assert_eq!(adx_3.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut adx_3: AverageDirectionalIndex,
+    ) {
+        for i in 0..HIGHS.len() {
+            adx_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        adx_3.reset();
+
+        assert!(!adx_3.initialized());
+        assert!(!adx_3.has_inputs());
+        assert_eq!(adx_3.value, 0.0);
+        assert_eq!(adx_3.pos_di, 0.0);
+        assert_eq!(adx_3.neg_di, 0.0);
+        assert_eq!(adx_3.count, 0);
+    }
+}
@@ -19,7 +19,7 @@ use nautilus_model::data::Bar;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, MovingAverage, ScalarIndicator},
 };
 
 #[repr(C)]
@@ -103,6 +103,16 @@ impl Bias {
     }
 }
 
+impl ScalarIndicator for Bias {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
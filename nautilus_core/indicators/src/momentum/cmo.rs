@@ -19,7 +19,7 @@ use nautilus_model::data::{Bar, QuoteTick, TradeTick};
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, MovingAverage, ScalarIndicator},
 };
 
 #[repr(C)]
@@ -131,6 +131,16 @@ impl ChandeMomentumOscillator {
     }
 }
 
+impl ScalarIndicator for ChandeMomentumOscillator {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,337 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Ichimoku Kinko Hyo (Ichimoku Cloud) lines.
+///
+/// The leading spans are calculated from the current bar and plotted `displacement` bars
+/// ahead, while `senkou_span_a` and `senkou_span_b` hold the cloud for the current bar (the
+/// leading spans calculated `displacement` bars ago). The lagging span (chikou) is the current
+/// close plotted `displacement` bars behind.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct IchimokuCloud {
+    pub tenkan_period: usize,
+    pub kijun_period: usize,
+    pub senkou_period: usize,
+    pub displacement: usize,
+    pub tenkan_sen: f64,
+    pub kijun_sen: f64,
+    pub leading_span_a: f64,
+    pub leading_span_b: f64,
+    pub senkou_span_a: f64,
+    pub senkou_span_b: f64,
+    pub chikou_span: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    leading_spans: VecDeque<(f64, f64)>,
+}
+
+impl Display for IchimokuCloud {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.tenkan_period,
+            self.kijun_period,
+            self.senkou_period,
+            self.displacement,
+        )
+    }
+}
+
+impl Indicator for IchimokuCloud {
+    fn name(&self) -> String {
+        stringify!(IchimokuCloud).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.leading_spans.clear();
+        self.tenkan_sen = 0.0;
+        self.kijun_sen = 0.0;
+        self.leading_span_a = 0.0;
+        self.leading_span_b = 0.0;
+        self.senkou_span_a = 0.0;
+        self.senkou_span_b = 0.0;
+        self.chikou_span = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl IchimokuCloud {
+    /// Creates a new [`IchimokuCloud`] instance.
+    ///
+    /// Defaults to the standard (9, 26, 52) periods with the cloud displaced by the
+    /// `kijun_period`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if any period is not positive (> 0).
+    #[must_use]
+    pub fn new(
+        tenkan_period: Option<usize>,
+        kijun_period: Option<usize>,
+        senkou_period: Option<usize>,
+        displacement: Option<usize>,
+    ) -> Self {
+        let tenkan_period = tenkan_period.unwrap_or(9);
+        let kijun_period = kijun_period.unwrap_or(26);
+        let senkou_period = senkou_period.unwrap_or(52);
+        let displacement = displacement.unwrap_or(kijun_period);
+        assert!(
+            tenkan_period > 0 && kijun_period > 0 && senkou_period > 0 && displacement > 0,
+            "periods must be positive, were ({tenkan_period},{kijun_period},{senkou_period},{displacement})"
+        );
+
+        let capacity = tenkan_period.max(kijun_period).max(senkou_period);
+        Self {
+            tenkan_period,
+            kijun_period,
+            senkou_period,
+            displacement,
+            tenkan_sen: 0.0,
+            kijun_sen: 0.0,
+            leading_span_a: 0.0,
+            leading_span_b: 0.0,
+            senkou_span_a: 0.0,
+            senkou_span_b: 0.0,
+            chikou_span: 0.0,
+            count: 0,
+            highs: VecDeque::with_capacity(capacity),
+            lows: VecDeque::with_capacity(capacity),
+            leading_spans: VecDeque::with_capacity(displacement + 1),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let max_period = self
+            .tenkan_period
+            .max(self.kijun_period)
+            .max(self.senkou_period);
+        if self.highs.len() == max_period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+        self.count += 1;
+
+        self.tenkan_sen = self.midpoint(self.tenkan_period);
+        self.kijun_sen = self.midpoint(self.kijun_period);
+        self.leading_span_a = (self.tenkan_sen + self.kijun_sen) / 2.0;
+        self.leading_span_b = self.midpoint(self.senkou_period);
+        self.chikou_span = close;
+
+        if self.leading_spans.len() > self.displacement {
+            self.leading_spans.pop_front();
+        }
+        self.leading_spans
+            .push_back((self.leading_span_a, self.leading_span_b));
+
+        // The cloud for the current bar is only available once displaced
+        if self.leading_spans.len() > self.displacement {
+            let (span_a, span_b) = self.leading_spans[0];
+            self.senkou_span_a = span_a;
+            self.senkou_span_b = span_b;
+        }
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.count >= self.senkou_period + self.displacement {
+                self.initialized = true;
+            }
+        }
+    }
+
+    /// Returns the midpoint of the highest high and lowest low over the last `period` bars.
+    fn midpoint(&self, period: usize) -> f64 {
+        let skip = self.highs.len().saturating_sub(period);
+        let highest = self
+            .highs
+            .iter()
+            .skip(skip)
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let lowest = self
+            .lows
+            .iter()
+            .skip(skip)
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        (highest + lowest) / 2.0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{
+        bar_ethusdt_binance_minute_bid, ichimoku_234, REFERENCE_CLOSES, REFERENCE_HIGHS,
+        REFERENCE_LOWS,
+    };
+
+    #[rstest]
+    fn test_name_returns_expected_string(ichimoku_234: IchimokuCloud) {
+        assert_eq!(ichimoku_234.name(), "IchimokuCloud");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(ichimoku_234: IchimokuCloud) {
+        assert_eq!(format!("{ichimoku_234}"), "IchimokuCloud(2,3,4,3)");
+    }
+
+    #[rstest]
+    fn test_new_with_defaults() {
+        let ichimoku = IchimokuCloud::new(None, None, None, None);
+        assert_eq!(format!("{ichimoku}"), "IchimokuCloud(9,26,52,26)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "periods must be positive")]
+    fn test_new_with_zero_period_panics() {
+        let _ = IchimokuCloud::new(Some(0), None, None, None);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(ichimoku_234: IchimokuCloud) {
+        assert!(!ichimoku_234.initialized());
+    }
+
+    #[rstest]
+    fn test_initialized_after_senkou_period_plus_displacement(mut ichimoku_234: IchimokuCloud) {
+        for i in 0..6 {
+            ichimoku_234.update_raw(10.0 + i as f64, 9.0 + i as f64, 9.5 + i as f64);
+            assert!(!ichimoku_234.initialized());
+        }
+        ichimoku_234.update_raw(16.0, 15.0, 15.5);
+        assert!(ichimoku_234.initialized());
+    }
+
+    #[rstest]
+    fn test_values_with_rising_inputs(mut ichimoku_234: IchimokuCloud) {
+        for i in 0..8 {
+            let base = i as f64;
+            ichimoku_234.update_raw(base + 2.0, base, base + 1.0);
+        }
+
+        // Last bar high 9 low 7
+        assert_eq!(ichimoku_234.tenkan_sen, 7.5); // (9 + 6) / 2
+        assert_eq!(ichimoku_234.kijun_sen, 7.0); // (9 + 5) / 2
+        assert_eq!(ichimoku_234.leading_span_a, 7.25);
+        assert_eq!(ichimoku_234.leading_span_b, 6.5); // (9 + 4) / 2
+        assert_eq!(ichimoku_234.chikou_span, 8.0);
+        // Cloud for the current bar was calculated 3 bars ago (high 6, low 4)
+        assert_eq!(ichimoku_234.senkou_span_a, 4.25);
+        assert_eq!(ichimoku_234.senkou_span_b, 3.5);
+    }
+
+    #[rstest]
+    fn test_values_match_reference() {
+        // Reference values from an independent implementation of Hosoda's published (9, 26, 52)
+        // lines, with the cloud for each bar taken from the leading spans 26 bars earlier
+        let mut ichimoku = IchimokuCloud::new(None, None, None, None);
+
+        for i in 0..78 {
+            assert!(!ichimoku.initialized());
+            ichimoku.update_raw(REFERENCE_HIGHS[i], REFERENCE_LOWS[i], REFERENCE_CLOSES[i]);
+        }
+
+        assert!(ichimoku.initialized());
+        assert_eq!(ichimoku.tenkan_sen, 46_351.5);
+        assert_eq!(ichimoku.kijun_sen, 46_346.0);
+        assert_eq!(ichimoku.leading_span_a, 46_348.75);
+        assert_eq!(ichimoku.leading_span_b, 46_346.0);
+        assert_eq!(ichimoku.senkou_span_a, 46_429.75);
+        assert_eq!(ichimoku.senkou_span_b, 46_416.5);
+        assert_eq!(ichimoku.chikou_span, 46_298.0);
+
+        for i in 78..REFERENCE_HIGHS.len() {
+            ichimoku.update_raw(REFERENCE_HIGHS[i], REFERENCE_LOWS[i], REFERENCE_CLOSES[i]);
+        }
+
+        assert_eq!(ichimoku.tenkan_sen, 46_324.0);
+        assert_eq!(ichimoku.kijun_sen, 46_346.0);
+        assert_eq!(ichimoku.leading_span_a, 46_335.0);
+        assert_eq!(ichimoku.leading_span_b, 46_346.0);
+        assert_eq!(ichimoku.senkou_span_a, 46_406.25);
+        assert_eq!(ichimoku.senkou_span_b, 46_417.0);
+        assert_eq!(ichimoku.chikou_span, 46_310.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut ichimoku_234: IchimokuCloud, bar_ethusdt_binance_minute_bid: Bar) {
+        ichimoku_234.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(ichimoku_234.has_inputs());
+        assert_eq!(ichimoku_234.tenkan_sen, 1522.5);
+        assert_eq!(ichimoku_234.chikou_span, 1522.0);
+        assert_eq!(ichimoku_234.senkou_span_a, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut ichimoku_234: IchimokuCloud) {
+        for i in 0..10 {
+            ichimoku_234.update_raw(10.0 + i as f64, 9.0 + i as f64, 9.5 + i as f64);
+        }
+
+        ichimoku_234.reset();
+
+        assert!(!ichimoku_234.initialized());
+        assert!(!ichimoku_234.has_inputs());
+        assert_eq!(ichimoku_234.count, 0);
+        assert_eq!(ichimoku_234.tenkan_sen, 0.0);
+        assert_eq!(ichimoku_234.senkou_span_a, 0.0);
+        assert_eq!(ichimoku_234.senkou_span_b, 0.0);
+    }
+}
@@ -0,0 +1,334 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates Wilder's Parabolic Stop and Reverse (SAR).
+///
+/// The initial trend is determined from the directional movement of the second bar, the SAR
+/// then trails the extreme point of the trend by an acceleration factor which increases by
+/// `af_step` (up to `af_max`) on each new extreme, reversing when price crosses the SAR.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct ParabolicStopAndReverse {
+    pub af_start: f64,
+    pub af_step: f64,
+    pub af_max: f64,
+    pub value: f64,
+    pub is_long: bool,
+    pub af: f64,
+    pub extreme_point: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_high: f64,
+    previous_low: f64,
+    previous_previous_high: f64,
+    previous_previous_low: f64,
+}
+
+impl Display for ParabolicStopAndReverse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.af_start,
+            self.af_step,
+            self.af_max,
+        )
+    }
+}
+
+impl Indicator for ParabolicStopAndReverse {
+    fn name(&self) -> String {
+        stringify!(ParabolicStopAndReverse).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.is_long = true;
+        self.af = self.af_start;
+        self.extreme_point = 0.0;
+        self.previous_high = 0.0;
+        self.previous_low = 0.0;
+        self.previous_previous_high = 0.0;
+        self.previous_previous_low = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ParabolicStopAndReverse {
+    /// Creates a new [`ParabolicStopAndReverse`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `af_start` or `af_step` is not positive (> 0).
+    /// - `af_max` is less than `af_start`.
+    #[must_use]
+    pub fn new(af_start: Option<f64>, af_step: Option<f64>, af_max: Option<f64>) -> Self {
+        let af_start = af_start.unwrap_or(0.02);
+        let af_step = af_step.unwrap_or(0.02);
+        let af_max = af_max.unwrap_or(0.2);
+        assert!(
+            af_start > 0.0,
+            "`af_start` must be positive, was {af_start}"
+        );
+        assert!(af_step > 0.0, "`af_step` must be positive, was {af_step}");
+        assert!(
+            af_max >= af_start,
+            "`af_max` must be >= `af_start`, was {af_max}"
+        );
+
+        Self {
+            af_start,
+            af_step,
+            af_max,
+            value: 0.0,
+            is_long: true,
+            af: af_start,
+            extreme_point: 0.0,
+            previous_high: 0.0,
+            previous_low: 0.0,
+            previous_previous_high: 0.0,
+            previous_previous_low: 0.0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if !self.has_inputs {
+            self.has_inputs = true;
+            self.set_previous(high, low);
+            self.set_previous(high, low);
+            return;
+        }
+
+        if !self.initialized {
+            // Initial trend from the directional movement of the second bar
+            let up = high - self.previous_high;
+            let down = self.previous_low - low;
+            self.is_long = down <= up;
+            if self.is_long {
+                self.value = self.previous_low;
+                self.extreme_point = high;
+            } else {
+                self.value = self.previous_high;
+                self.extreme_point = low;
+            }
+            self.af = self.af_start;
+            self.initialized = true;
+            self.set_previous(high, low);
+            return;
+        }
+
+        let mut sar = self.af.mul_add(self.extreme_point - self.value, self.value);
+
+        if self.is_long {
+            // SAR can never be above the prior two lows
+            sar = sar.min(self.previous_low).min(self.previous_previous_low);
+            if low <= sar {
+                self.is_long = false;
+                sar = self.extreme_point.max(high);
+                self.extreme_point = low;
+                self.af = self.af_start;
+            } else if high > self.extreme_point {
+                self.extreme_point = high;
+                self.af = (self.af + self.af_step).min(self.af_max);
+            }
+        } else {
+            // SAR can never be below the prior two highs
+            sar = sar.max(self.previous_high).max(self.previous_previous_high);
+            if high >= sar {
+                self.is_long = true;
+                sar = self.extreme_point.min(low);
+                self.extreme_point = high;
+                self.af = self.af_start;
+            } else if low < self.extreme_point {
+                self.extreme_point = low;
+                self.af = (self.af + self.af_step).min(self.af_max);
+            }
+        }
+
+        self.value = sar;
+        self.set_previous(high, low);
+    }
+
+    fn set_previous(&mut self, high: f64, low: f64) {
+        self.previous_previous_high = self.previous_high;
+        self.previous_previous_low = self.previous_low;
+        self.previous_high = high;
+        self.previous_low = low;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, psar, REFERENCE_HIGHS, REFERENCE_LOWS};
+
+    #[rstest]
+    fn test_name_returns_expected_string(psar: ParabolicStopAndReverse) {
+        assert_eq!(psar.name(), "ParabolicStopAndReverse");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(psar: ParabolicStopAndReverse) {
+        assert_eq!(format!("{psar}"), "ParabolicStopAndReverse(0.02,0.02,0.2)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(psar: ParabolicStopAndReverse) {
+        assert!(!psar.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`af_max` must be >= `af_start`")]
+    fn test_new_with_max_below_start_panics() {
+        let _ = ParabolicStopAndReverse::new(Some(0.2), None, Some(0.1));
+    }
+
+    #[rstest]
+    fn test_initialized_after_two_inputs(mut psar: ParabolicStopAndReverse) {
+        psar.update_raw(10.0, 9.0);
+        assert!(psar.has_inputs());
+        assert!(!psar.initialized());
+
+        psar.update_raw(11.0, 10.0);
+        assert!(psar.initialized());
+        assert!(psar.is_long);
+        assert_eq!(psar.value, 9.0);
+        assert_eq!(psar.extreme_point, 11.0);
+    }
+
+    #[rstest]
+    fn test_value_accelerates_in_uptrend(mut psar: ParabolicStopAndReverse) {
+        let highs = [10.0, 11.0, 12.0, 13.0];
+        let lows = [9.0, 10.0, 11.0, 12.0];
+        for i in 0..highs.len() {
+            psar.update_raw(highs[i], lows[i]);
+        }
+
+        // 9 + 0.02 * (11 - 9) = 9.04 is capped at the low two bars back (9.0),
+        // then 9 + 0.04 * (12 - 9) = 9.12
+        assert!(psar.is_long);
+        assert!((psar.value - 9.12).abs() < 1e-9);
+        assert!((psar.af - 0.06).abs() < 1e-12);
+        assert_eq!(psar.extreme_point, 13.0);
+    }
+
+    #[rstest]
+    fn test_value_reverses_to_extreme_point(mut psar: ParabolicStopAndReverse) {
+        let highs = [10.0, 11.0, 12.0, 13.0, 10.0];
+        let lows = [9.0, 10.0, 11.0, 12.0, 8.5];
+        for i in 0..highs.len() {
+            psar.update_raw(highs[i], lows[i]);
+        }
+
+        assert!(!psar.is_long);
+        assert_eq!(psar.value, 13.0);
+        assert_eq!(psar.extreme_point, 8.5);
+        assert_eq!(psar.af, 0.02);
+    }
+
+    #[rstest]
+    fn test_af_capped_at_max(mut psar: ParabolicStopAndReverse) {
+        for i in 0..50 {
+            let base = i as f64;
+            psar.update_raw(base + 1.0, base);
+        }
+
+        assert!(psar.is_long);
+        assert_eq!(psar.af, 0.2);
+    }
+
+    #[rstest]
+    fn test_value_matches_wilder_reference(mut psar: ParabolicStopAndReverse) {
+        // Reference values from an independent implementation of Wilder's published SAR rules
+        // (0.02 acceleration step up to 0.2, SAR kept outside the prior two bars' range),
+        // sampled as (bar index, SAR, is long) across each of the reversals in the series
+        let expected = [
+            (10, 46_364.458_492_436_985, true),
+            (17, 46_475.0, false),
+            (30, 46_442.268_115_061_706, false),
+            (45, 46_389.230_040_926_166, true),
+            (58, 46_370.568_205_352_69, false),
+            (68, 46_249.955_690_680_49, true),
+            (79, 46_474.530_082_928_446, false),
+        ];
+
+        let mut outputs = Vec::with_capacity(REFERENCE_HIGHS.len());
+        for i in 0..REFERENCE_HIGHS.len() {
+            psar.update_raw(REFERENCE_HIGHS[i], REFERENCE_LOWS[i]);
+            outputs.push((psar.value, psar.is_long));
+        }
+
+        for (index, value, is_long) in expected {
+            let (output, output_is_long) = outputs[index];
+            assert!((output - value).abs() < 1e-9, "bar {index}");
+            assert_eq!(output_is_long, is_long, "bar {index}");
+        }
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut psar: ParabolicStopAndReverse, bar_ethusdt_binance_minute_bid: Bar) {
+        psar.handle_bar(&bar_ethusdt_binance_minute_bid);
+        psar.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(psar.initialized());
+        assert_eq!(psar.value, 1495.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut psar: ParabolicStopAndReverse) {
+        psar.update_raw(10.0, 9.0);
+        psar.update_raw(11.0, 10.0);
+        psar.update_raw(12.0, 11.0);
+
+        psar.reset();
+
+        assert!(!psar.initialized());
+        assert!(!psar.has_inputs());
+        assert_eq!(psar.value, 0.0);
+        assert_eq!(psar.af, 0.02);
+        assert_eq!(psar.extreme_point, 0.0);
+    }
+}
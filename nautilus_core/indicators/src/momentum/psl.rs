@@ -19,7 +19,7 @@ use nautilus_model::data::Bar;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, MovingAverage, ScalarIndicator},
 };
 
 #[repr(C)]
@@ -112,6 +112,16 @@ impl PsychologicalLine {
     }
 }
 
+impl ScalarIndicator for PsychologicalLine {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
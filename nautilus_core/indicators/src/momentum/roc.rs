@@ -20,7 +20,7 @@ use std::{
 
 use nautilus_model::data::Bar;
 
-use crate::indicator::Indicator;
+use crate::indicator::{Indicator, ScalarIndicator};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -101,6 +101,16 @@ impl RateOfChange {
     }
 }
 
+impl ScalarIndicator for RateOfChange {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -22,7 +22,7 @@ use nautilus_model::{
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, MovingAverage, ScalarIndicator},
 };
 
 /// An indicator which calculates a relative strength index (RSI) across a rolling window.
@@ -141,6 +141,16 @@ impl RelativeStrengthIndex {
     }
 }
 
+impl ScalarIndicator for RelativeStrengthIndex {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -22,7 +22,7 @@ use nautilus_model::data::Bar;
 
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
-    indicator::{Indicator, MovingAverage},
+    indicator::{Indicator, MovingAverage, ScalarIndicator},
 };
 
 #[repr(C)]
@@ -124,6 +124,16 @@ impl VerticalHorizontalFilter {
     }
 }
 
+impl ScalarIndicator for VerticalHorizontalFilter {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
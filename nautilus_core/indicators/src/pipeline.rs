@@ -0,0 +1,786 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Composable indicator pipelines for chaining indicators onto the outputs of other indicators.
+//!
+//! A pipeline is a graph of [`PipelineNode`]s built with the [`Expr`] API, for example an EMA of
+//! an RSI, or a moving average of the spread between two instruments:
+//!
+//! ```
+//! use nautilus_indicators::{
+//!     average::{ema::ExponentialMovingAverage, sma::SimpleMovingAverage},
+//!     momentum::rsi::RelativeStrengthIndex,
+//!     pipeline::{Expr, PipelineInput},
+//! };
+//! use nautilus_model::identifiers::InstrumentId;
+//!
+//! let ema_of_rsi = Expr::indicator(
+//!     RelativeStrengthIndex::new(14, None),
+//!     PipelineInput::Bars,
+//!     None,
+//!     |rsi| rsi.value,
+//! )
+//! .chain(ExponentialMovingAverage::new(10, None))
+//! .build("EMA(RSI)");
+//!
+//! let spread = Expr::price(
+//!     PipelineInput::Quotes,
+//!     Some(InstrumentId::from("AUD/USD.SIM")),
+//!     None,
+//! ) - Expr::price(
+//!     PipelineInput::Quotes,
+//!     Some(InstrumentId::from("NZD/USD.SIM")),
+//!     None,
+//! );
+//! let spread_sma = spread.chain(SimpleMovingAverage::new(20, None)).build("SMA(SPREAD)");
+//! ```
+//!
+//! Values only propagate downstream once a node is initialized, so each stage warms up on
+//! initialized inputs and a pipeline is initialized once all of its nodes are.
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+    identifiers::InstrumentId,
+};
+
+use crate::indicator::{Indicator, ScalarIndicator};
+
+/// The market data input for a pipeline source node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PipelineInput {
+    Bars,
+    Quotes,
+    Trades,
+}
+
+/// A node in an indicator pipeline graph.
+///
+/// The `handle_*` methods return whether the node produced a new value, which is only the case
+/// once all of its inputs are initialized.
+#[allow(unused_variables)]
+pub trait PipelineNode: Debug + Send {
+    fn name(&self) -> String;
+
+    fn value(&self) -> f64;
+
+    fn has_inputs(&self) -> bool;
+
+    fn initialized(&self) -> bool;
+
+    fn handle_bar(&mut self, bar: &Bar) -> bool {
+        false
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) -> bool {
+        false
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) -> bool {
+        false
+    }
+
+    fn reset(&mut self);
+}
+
+/// A source node which feeds market data to an indicator and outputs one of its values.
+pub struct IndicatorNode<I> {
+    pub input: PipelineInput,
+    pub instrument_id: Option<InstrumentId>,
+    indicator: I,
+    output: fn(&I) -> f64,
+}
+
+impl<I: Debug> Debug for IndicatorNode<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(IndicatorNode))
+            .field("input", &self.input)
+            .field("instrument_id", &self.instrument_id)
+            .field("indicator", &self.indicator)
+            .finish()
+    }
+}
+
+impl<I: Indicator> IndicatorNode<I> {
+    /// Creates a new [`IndicatorNode`] instance.
+    ///
+    /// Only data for the given `instrument_id` is handled (all data if `None`), with the node
+    /// value read from the indicator by `output`.
+    #[must_use]
+    pub const fn new(
+        indicator: I,
+        input: PipelineInput,
+        instrument_id: Option<InstrumentId>,
+        output: fn(&I) -> f64,
+    ) -> Self {
+        Self {
+            input,
+            instrument_id,
+            indicator,
+            output,
+        }
+    }
+
+    /// Returns a reference to the wrapped indicator.
+    #[must_use]
+    pub const fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    fn accepts(&self, input: PipelineInput, instrument_id: InstrumentId) -> bool {
+        self.input == input && self.instrument_id.is_none_or(|id| id == instrument_id)
+    }
+}
+
+impl<I: Indicator + Debug + Send> PipelineNode for IndicatorNode<I> {
+    fn name(&self) -> String {
+        self.indicator.name()
+    }
+
+    fn value(&self) -> f64 {
+        (self.output)(&self.indicator)
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.indicator.has_inputs()
+    }
+
+    fn initialized(&self) -> bool {
+        self.indicator.initialized()
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) -> bool {
+        if !self.accepts(PipelineInput::Bars, bar.bar_type.instrument_id()) {
+            return false;
+        }
+        self.indicator.handle_bar(bar);
+        true
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) -> bool {
+        if !self.accepts(PipelineInput::Quotes, quote.instrument_id) {
+            return false;
+        }
+        self.indicator.handle_quote(quote);
+        true
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) -> bool {
+        if !self.accepts(PipelineInput::Trades, trade.instrument_id) {
+            return false;
+        }
+        self.indicator.handle_trade(trade);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+/// A source node which outputs the latest price (bar close, quote price or trade price).
+#[derive(Debug)]
+pub struct PriceNode {
+    pub input: PipelineInput,
+    pub instrument_id: Option<InstrumentId>,
+    pub price_type: PriceType,
+    value: f64,
+    initialized: bool,
+}
+
+impl PriceNode {
+    /// Creates a new [`PriceNode`] instance.
+    ///
+    /// The `price_type` applies to quotes only (defaults to [`PriceType::Mid`]).
+    #[must_use]
+    pub fn new(
+        input: PipelineInput,
+        instrument_id: Option<InstrumentId>,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        Self {
+            input,
+            instrument_id,
+            price_type: price_type.unwrap_or(PriceType::Mid),
+            value: 0.0,
+            initialized: false,
+        }
+    }
+
+    fn update(&mut self, input: PipelineInput, instrument_id: InstrumentId, price: f64) -> bool {
+        if self.input != input || self.instrument_id.is_some_and(|id| id != instrument_id) {
+            return false;
+        }
+        self.value = price;
+        self.initialized = true;
+        true
+    }
+}
+
+impl PipelineNode for PriceNode {
+    fn name(&self) -> String {
+        match self.instrument_id {
+            Some(instrument_id) => format!("Price({instrument_id})"),
+            None => "Price".to_string(),
+        }
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.initialized
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) -> bool {
+        self.update(
+            PipelineInput::Bars,
+            bar.bar_type.instrument_id(),
+            bar.close.as_f64(),
+        )
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) -> bool {
+        self.update(
+            PipelineInput::Quotes,
+            quote.instrument_id,
+            quote.extract_price(self.price_type).as_f64(),
+        )
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) -> bool {
+        self.update(
+            PipelineInput::Trades,
+            trade.instrument_id,
+            trade.price.as_f64(),
+        )
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.initialized = false;
+    }
+}
+
+/// A node which feeds the value of its input node to a [`ScalarIndicator`] once the input
+/// is initialized.
+#[derive(Debug)]
+pub struct ChainedNode {
+    input: Box<dyn PipelineNode>,
+    indicator: Box<dyn ScalarIndicator + Send>,
+}
+
+impl ChainedNode {
+    /// Creates a new [`ChainedNode`] instance.
+    #[must_use]
+    pub fn new(input: Box<dyn PipelineNode>, indicator: Box<dyn ScalarIndicator + Send>) -> Self {
+        Self { input, indicator }
+    }
+
+    fn propagate(&mut self, updated: bool) -> bool {
+        if !updated || !self.input.initialized() {
+            return false;
+        }
+        self.indicator.handle_value(self.input.value());
+        true
+    }
+}
+
+impl PipelineNode for ChainedNode {
+    fn name(&self) -> String {
+        format!("{}({})", self.indicator.name(), self.input.name())
+    }
+
+    fn value(&self) -> f64 {
+        self.indicator.value()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.input.has_inputs()
+    }
+
+    fn initialized(&self) -> bool {
+        self.input.initialized() && self.indicator.initialized()
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) -> bool {
+        let updated = self.input.handle_bar(bar);
+        self.propagate(updated)
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) -> bool {
+        let updated = self.input.handle_quote(quote);
+        self.propagate(updated)
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) -> bool {
+        let updated = self.input.handle_trade(trade);
+        self.propagate(updated)
+    }
+
+    fn reset(&mut self) {
+        self.input.reset();
+        self.indicator.reset();
+    }
+}
+
+/// The arithmetic operation applied by a [`BinaryNode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+/// A node which combines the values of two input nodes, updating when either input updates
+/// once both are initialized.
+///
+/// Division by a zero right-hand value is skipped, retaining the previous value.
+#[derive(Debug)]
+pub struct BinaryNode {
+    pub operator: BinaryOperator,
+    left: Box<dyn PipelineNode>,
+    right: Box<dyn PipelineNode>,
+    value: f64,
+    has_value: bool,
+}
+
+impl BinaryNode {
+    /// Creates a new [`BinaryNode`] instance.
+    #[must_use]
+    pub fn new(
+        left: Box<dyn PipelineNode>,
+        operator: BinaryOperator,
+        right: Box<dyn PipelineNode>,
+    ) -> Self {
+        Self {
+            operator,
+            left,
+            right,
+            value: 0.0,
+            has_value: false,
+        }
+    }
+
+    fn propagate(&mut self, updated: bool) -> bool {
+        if !updated || !self.left.initialized() || !self.right.initialized() {
+            return false;
+        }
+
+        let left = self.left.value();
+        let right = self.right.value();
+        self.value = match self.operator {
+            BinaryOperator::Add => left + right,
+            BinaryOperator::Subtract => left - right,
+            BinaryOperator::Multiply => left * right,
+            BinaryOperator::Divide => {
+                if right == 0.0 {
+                    return false;
+                }
+                left / right
+            }
+        };
+        self.has_value = true;
+        true
+    }
+}
+
+impl PipelineNode for BinaryNode {
+    fn name(&self) -> String {
+        format!(
+            "({} {} {})",
+            self.left.name(),
+            self.operator,
+            self.right.name()
+        )
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.left.has_inputs() || self.right.has_inputs()
+    }
+
+    fn initialized(&self) -> bool {
+        self.has_value && self.left.initialized() && self.right.initialized()
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) -> bool {
+        // Both inputs must see the data, so avoid short-circuiting
+        let updated = self.left.handle_bar(bar) | self.right.handle_bar(bar);
+        self.propagate(updated)
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) -> bool {
+        let updated = self.left.handle_quote(quote) | self.right.handle_quote(quote);
+        self.propagate(updated)
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) -> bool {
+        let updated = self.left.handle_trade(trade) | self.right.handle_trade(trade);
+        self.propagate(updated)
+    }
+
+    fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+        self.value = 0.0;
+        self.has_value = false;
+    }
+}
+
+/// An expression for building indicator pipeline graphs.
+#[derive(Debug)]
+pub struct Expr(Box<dyn PipelineNode>);
+
+impl Expr {
+    /// Creates a new source expression from the given `indicator` (see [`IndicatorNode`]).
+    #[must_use]
+    pub fn indicator<I: Indicator + Debug + Send + 'static>(
+        indicator: I,
+        input: PipelineInput,
+        instrument_id: Option<InstrumentId>,
+        output: fn(&I) -> f64,
+    ) -> Self {
+        Self(Box::new(IndicatorNode::new(
+            indicator,
+            input,
+            instrument_id,
+            output,
+        )))
+    }
+
+    /// Creates a new source expression of the latest price (see [`PriceNode`]).
+    #[must_use]
+    pub fn price(
+        input: PipelineInput,
+        instrument_id: Option<InstrumentId>,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        Self(Box::new(PriceNode::new(input, instrument_id, price_type)))
+    }
+
+    /// Creates a new expression from a custom node.
+    #[must_use]
+    pub fn node(node: Box<dyn PipelineNode>) -> Self {
+        Self(node)
+    }
+
+    /// Returns an expression feeding the value of this expression to the given `indicator`.
+    #[must_use]
+    pub fn chain<I: ScalarIndicator + Send + 'static>(self, indicator: I) -> Self {
+        Self(Box::new(ChainedNode::new(self.0, Box::new(indicator))))
+    }
+
+    /// Returns the [`Pipeline`] indicator for this expression.
+    #[must_use]
+    pub fn build(self, name: &str) -> Pipeline {
+        Pipeline::new(name, self.0)
+    }
+
+    fn binary(self, operator: BinaryOperator, rhs: Self) -> Self {
+        Self(Box::new(BinaryNode::new(self.0, operator, rhs.0)))
+    }
+}
+
+impl Add for Expr {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.binary(BinaryOperator::Add, rhs)
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(BinaryOperator::Subtract, rhs)
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(BinaryOperator::Multiply, rhs)
+    }
+}
+
+impl Div for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.binary(BinaryOperator::Divide, rhs)
+    }
+}
+
+/// An indicator which evaluates a pipeline graph of nodes.
+///
+/// Unlike most indicators, data which is not consumed by any node in the graph is ignored
+/// rather than panicking.
+#[derive(Debug)]
+pub struct Pipeline {
+    pub name: String,
+    pub value: f64,
+    /// The count of values produced by the pipeline.
+    pub count: usize,
+    pub initialized: bool,
+    root: Box<dyn PipelineNode>,
+}
+
+impl Display for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name, self.root.name())
+    }
+}
+
+impl Indicator for Pipeline {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.root.has_inputs()
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        let updated = self.root.handle_quote(quote);
+        self.update(updated);
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        let updated = self.root.handle_trade(trade);
+        self.update(updated);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        let updated = self.root.handle_bar(bar);
+        self.update(updated);
+    }
+
+    fn reset(&mut self) {
+        self.root.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.initialized = false;
+    }
+}
+
+impl Pipeline {
+    /// Creates a new [`Pipeline`] instance.
+    #[must_use]
+    pub fn new(name: &str, root: Box<dyn PipelineNode>) -> Self {
+        Self {
+            name: name.to_string(),
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            root,
+        }
+    }
+
+    fn update(&mut self, updated: bool) {
+        if !updated {
+            return;
+        }
+
+        self.count += 1;
+        self.value = self.root.value();
+        self.initialized = self.root.initialized();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::{Price, Quantity};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        average::{ema::ExponentialMovingAverage, sma::SimpleMovingAverage},
+        momentum::rsi::RelativeStrengthIndex,
+        stubs::{bar_ethusdt_binance_minute_bid, stub_trade},
+    };
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_chained_indicator_matches_manual_chaining() {
+        let closes = [1.0, 2.0, 1.5, 3.0, 2.5, 4.0, 3.5, 5.0, 4.5, 6.0];
+        let mut pipeline = Expr::indicator(
+            RelativeStrengthIndex::new(3, None),
+            PipelineInput::Trades,
+            None,
+            |rsi| rsi.value,
+        )
+        .chain(ExponentialMovingAverage::new(3, None))
+        .build("EMA(RSI)");
+
+        let mut rsi = RelativeStrengthIndex::new(3, None);
+        let mut ema = ExponentialMovingAverage::new(3, None);
+
+        for close in closes {
+            let mut trade = stub_trade();
+            trade.price = Price::new(close, 1);
+            pipeline.handle_trade(&trade);
+
+            rsi.update_raw(close);
+            if rsi.initialized {
+                ema.handle_value(rsi.value);
+            }
+        }
+
+        assert_eq!(
+            format!("{pipeline}"),
+            "EMA(RSI)(ExponentialMovingAverage(RelativeStrengthIndex))"
+        );
+        // RSI warms up over the first two closes
+        assert_eq!(pipeline.count, closes.len() - 2);
+        assert!(pipeline.initialized());
+        assert_eq!(pipeline.value, ema.value);
+    }
+
+    #[rstest]
+    fn test_chained_indicator_warms_up_after_input() {
+        let mut pipeline = Expr::price(PipelineInput::Trades, None, None)
+            .chain(SimpleMovingAverage::new(2, None))
+            .chain(SimpleMovingAverage::new(2, None))
+            .build("SMA(SMA)");
+
+        let trade = stub_trade();
+        pipeline.handle_trade(&trade);
+        assert!(pipeline.has_inputs());
+        assert!(!pipeline.initialized());
+
+        // The outer SMA only receives values once the inner SMA is initialized
+        pipeline.handle_trade(&trade);
+        assert!(!pipeline.initialized());
+        assert_eq!(pipeline.count, 1);
+
+        pipeline.handle_trade(&trade);
+        assert!(pipeline.initialized());
+        assert_eq!(pipeline.count, 2);
+        assert_eq!(pipeline.value, 1500.0);
+    }
+
+    #[rstest]
+    fn test_spread_between_instruments() {
+        let mut pipeline = (Expr::price(
+            PipelineInput::Quotes,
+            Some(InstrumentId::from("AUD/USD.SIM")),
+            None,
+        ) - Expr::price(
+            PipelineInput::Quotes,
+            Some(InstrumentId::from("NZD/USD.SIM")),
+            None,
+        ))
+        .chain(SimpleMovingAverage::new(2, None))
+        .build("SPREAD");
+
+        pipeline.handle_quote(&quote("AUD/USD.SIM", "0.65000", "0.65002"));
+        assert!(pipeline.has_inputs());
+        assert!(!pipeline.initialized());
+
+        pipeline.handle_quote(&quote("NZD/USD.SIM", "0.60000", "0.60002"));
+        pipeline.handle_quote(&quote("AUD/USD.SIM", "0.65100", "0.65102"));
+
+        // Spreads of 0.05 then 0.051
+        assert!(pipeline.initialized());
+        assert!((pipeline.value - 0.0505).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_ratio_skips_zero_denominator() {
+        let mut pipeline = (Expr::price(PipelineInput::Trades, None, None)
+            / Expr::price(PipelineInput::Quotes, None, Some(PriceType::Bid)))
+        .build("RATIO");
+
+        pipeline.handle_trade(&stub_trade());
+        pipeline.handle_quote(&quote("ETHUSDT-PERP.BINANCE", "750.0", "750.5"));
+        assert_eq!(pipeline.value, 2.0);
+
+        pipeline.handle_quote(&quote("ETHUSDT-PERP.BINANCE", "0.0", "0.5"));
+        assert_eq!(pipeline.value, 2.0);
+    }
+
+    #[rstest]
+    fn test_unconsumed_data_is_ignored(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut pipeline = Expr::price(PipelineInput::Trades, None, None).build("PRICE");
+
+        pipeline.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(!pipeline.has_inputs());
+        assert_eq!(pipeline.count, 0);
+    }
+
+    #[rstest]
+    fn test_reset_propagates_to_nodes() {
+        let mut pipeline = Expr::price(PipelineInput::Trades, None, None)
+            .chain(SimpleMovingAverage::new(1, None))
+            .build("SMA");
+        pipeline.handle_trade(&stub_trade());
+        assert!(pipeline.initialized());
+
+        pipeline.reset();
+
+        assert!(!pipeline.initialized());
+        assert!(!pipeline.has_inputs());
+        assert_eq!(pipeline.value, 0.0);
+        assert_eq!(pipeline.count, 0);
+    }
+}
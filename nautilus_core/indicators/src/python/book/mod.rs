@@ -13,4 +13,6 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod depth_imbalance;
 pub mod imbalance;
+pub mod microprice;
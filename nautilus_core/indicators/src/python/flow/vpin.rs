@@ -0,0 +1,89 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{data::TradeTick, enums::AggressorSide};
+use pyo3::prelude::*;
+
+use crate::{flow::vpin::VolumeSynchronizedProbabilityOfInformedTrading, indicator::Indicator};
+
+#[pymethods]
+impl VolumeSynchronizedProbabilityOfInformedTrading {
+    #[new]
+    #[pyo3(signature = (bucket_volume, window=50))]
+    fn py_new(bucket_volume: f64, window: usize) -> Self {
+        Self::new(bucket_volume, window)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "bucket_volume")]
+    const fn py_bucket_volume(&self) -> f64 {
+        self.bucket_volume
+    }
+
+    #[getter]
+    #[pyo3(name = "window")]
+    const fn py_window(&self) -> usize {
+        self.window
+    }
+
+    #[getter]
+    #[pyo3(name = "bucket_count")]
+    const fn py_bucket_count(&self) -> usize {
+        self.bucket_count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn py_handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.handle_trade(trade);
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, size: f64, aggressor_side: AggressorSide) {
+        self.update_raw(size, aggressor_side);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -17,8 +17,10 @@
 
 pub mod average;
 pub mod book;
+pub mod flow;
 pub mod momentum;
 pub mod ratio;
+pub mod statistics;
 pub mod volatility;
 
 use pyo3::{prelude::*, pymodule};
@@ -40,11 +42,23 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Book
     m.add_class::<crate::book::imbalance::BookImbalanceRatio>()?;
+    m.add_class::<crate::book::depth_imbalance::BookDepthImbalance>()?;
+    m.add_class::<crate::book::microprice::MicroPrice>()?;
+
+    // Flow
+    m.add_class::<crate::flow::cvd::CumulativeVolumeDelta>()?;
+    m.add_class::<crate::flow::vpin::VolumeSynchronizedProbabilityOfInformedTrading>()?;
 
     // Ratio
     m.add_class::<crate::ratio::efficiency_ratio::EfficiencyRatio>()?;
     m.add_class::<crate::ratio::spread_analyzer::SpreadAnalyzer>()?;
 
+    // Statistics
+    m.add_class::<crate::statistics::zscore::RollingZScore>()?;
+    m.add_class::<crate::statistics::percentile::RollingPercentile>()?;
+    m.add_class::<crate::statistics::correlation::RollingCorrelation>()?;
+    m.add_class::<crate::statistics::beta::RollingBeta>()?;
+
     // Momentum
     m.add_class::<crate::momentum::rsi::RelativeStrengthIndex>()?;
     m.add_class::<crate::momentum::aroon::AroonOscillator>()?;
@@ -63,6 +77,9 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::momentum::roc::RateOfChange>()?;
     m.add_class::<crate::momentum::macd::MovingAverageConvergenceDivergence>()?;
     m.add_class::<crate::momentum::obv::OnBalanceVolume>()?;
+    m.add_class::<crate::momentum::adx::AverageDirectionalIndex>()?;
+    m.add_class::<crate::momentum::psar::ParabolicStopAndReverse>()?;
+    m.add_class::<crate::momentum::ichimoku::IchimokuCloud>()?;
 
     // Volatility
     m.add_class::<crate::volatility::atr::AverageTrueRange>()?;
@@ -72,5 +89,10 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::volatility::kc::KeltnerChannel>()?;
     m.add_class::<crate::volatility::fuzzy::FuzzyCandlesticks>()?;
     m.add_class::<crate::volatility::kp::KeltnerPosition>()?;
+    m.add_class::<crate::volatility::supertrend::SuperTrend>()?;
+    m.add_class::<crate::volatility::parkinson::ParkinsonVolatility>()?;
+    m.add_class::<crate::volatility::garman_klass::GarmanKlassVolatility>()?;
+    m.add_class::<crate::volatility::rogers_satchell::RogersSatchellVolatility>()?;
+    m.add_class::<crate::volatility::yang_zhang::YangZhangVolatility>()?;
     Ok(())
 }
@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{data::Bar, identifiers::InstrumentId};
+use pyo3::prelude::*;
+
+use crate::{indicator::Indicator, statistics::correlation::RollingCorrelation};
+
+#[pymethods]
+impl RollingCorrelation {
+    #[new]
+    fn py_new(period: usize, instrument_id_x: InstrumentId, instrument_id_y: InstrumentId) -> Self {
+        Self::new(period, instrument_id_x, instrument_id_y)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RollingCorrelation({},{},{})",
+            self.period, self.instrument_id_x, self.instrument_id_y
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "period")]
+    const fn py_period(&self) -> usize {
+        self.period
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id_x")]
+    const fn py_instrument_id_x(&self) -> InstrumentId {
+        self.instrument_id_x
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id_y")]
+    const fn py_instrument_id_y(&self) -> InstrumentId {
+        self.instrument_id_y
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    const fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, x: f64, y: f64) {
+        self.update_raw(x, y);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn py_handle_bar(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
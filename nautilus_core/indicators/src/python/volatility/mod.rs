@@ -16,7 +16,12 @@
 pub mod atr;
 pub mod dc;
 pub mod fuzzy;
+pub mod garman_klass;
 pub mod kc;
 pub mod kp;
+pub mod parkinson;
+pub mod rogers_satchell;
 pub mod rvi;
+pub mod supertrend;
 pub mod vr;
+pub mod yang_zhang;
@@ -20,7 +20,7 @@ use nautilus_model::{
     enums::PriceType,
 };
 
-use crate::indicator::Indicator;
+use crate::indicator::{Indicator, ScalarIndicator};
 
 /// An indicator which calculates the efficiency ratio across a rolling window.
 ///
@@ -114,6 +114,16 @@ impl EfficiencyRatio {
     }
 }
 
+impl ScalarIndicator for EfficiencyRatio {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,294 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use super::MOMENT_RECOMPUTE_RATIO;
+
+/// A rolling covariance over a fixed-size window of paired `(x, y)` values, also providing the
+/// variance of each series, the correlation and the beta of `y` on `x`.
+#[derive(Clone, Debug)]
+pub struct RollingCovariance {
+    pub period: usize,
+    window: VecDeque<(f64, f64)>,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl RollingCovariance {
+    /// Creates a new [`RollingCovariance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            c_xy: 0.0,
+        }
+    }
+
+    /// Adds the given pair of values to the window, evicting the oldest pair when full.
+    pub fn update(&mut self, x: f64, y: f64) {
+        if self.window.len() == self.period {
+            if let Some((evicted_x, evicted_y)) = self.window.pop_front() {
+                self.remove(evicted_x, evicted_y);
+            }
+        }
+        self.window.push_back((x, y));
+
+        let n = self.window.len() as f64;
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x += delta_x / n;
+        self.mean_y += delta_y / n;
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+        self.c_xy += delta_x * (y - self.mean_y);
+    }
+
+    /// Returns the sample covariance of the window (zero with fewer than two pairs).
+    #[must_use]
+    pub fn covariance(&self) -> f64 {
+        self.sample(self.c_xy)
+    }
+
+    /// Returns the sample variance of the `x` values in the window.
+    #[must_use]
+    pub fn variance_x(&self) -> f64 {
+        self.sample(self.m2_x.max(0.0))
+    }
+
+    /// Returns the sample variance of the `y` values in the window.
+    #[must_use]
+    pub fn variance_y(&self) -> f64 {
+        self.sample(self.m2_y.max(0.0))
+    }
+
+    /// Returns the Pearson correlation of the window (zero if either series is constant).
+    #[must_use]
+    pub fn correlation(&self) -> f64 {
+        let denominator = (self.m2_x.max(0.0) * self.m2_y.max(0.0)).sqrt();
+        if denominator == 0.0 {
+            return 0.0;
+        }
+        (self.c_xy / denominator).clamp(-1.0, 1.0)
+    }
+
+    /// Returns the beta (regression slope) of `y` on `x` for the window (zero if `x` is constant).
+    #[must_use]
+    pub fn beta(&self) -> f64 {
+        if self.m2_x <= 0.0 {
+            return 0.0;
+        }
+        self.c_xy / self.m2_x
+    }
+
+    /// Returns the number of pairs in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Returns whether the window holds `period` pairs.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.m2_x = 0.0;
+        self.m2_y = 0.0;
+        self.c_xy = 0.0;
+    }
+
+    fn sample(&self, moment: f64) -> f64 {
+        let n = self.window.len();
+        if n < 2 {
+            return 0.0;
+        }
+        moment / (n - 1) as f64
+    }
+
+    // Inverts the online update, with the window length already excluding the pair
+    fn remove(&mut self, x: f64, y: f64) {
+        let n = self.window.len() as f64;
+        if n == 0.0 {
+            self.reset();
+            return;
+        }
+        let (m2_x, m2_y) = (self.m2_x, self.m2_y);
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x -= delta_x / n;
+        self.mean_y -= delta_y / n;
+        self.m2_x -= delta_x * (x - self.mean_x);
+        self.m2_y -= delta_y * (y - self.mean_y);
+        self.c_xy -= (x - self.mean_x) * delta_y;
+
+        // Removing most of a moment leaves a rounding residue (e.g. once a series becomes
+        // constant), so recompute from the window rather than report a spurious beta
+        if self.m2_x < m2_x * MOMENT_RECOMPUTE_RATIO || self.m2_y < m2_y * MOMENT_RECOMPUTE_RATIO {
+            self.recompute();
+        }
+    }
+
+    // Recomputes the moments with a two-pass over the window, shifted by the front pair so a
+    // constant series has exactly zero variance
+    fn recompute(&mut self) {
+        let Some(&(shift_x, shift_y)) = self.window.front() else {
+            return;
+        };
+        let n = self.window.len() as f64;
+        let offset_x = self.window.iter().map(|(x, _)| x - shift_x).sum::<f64>() / n;
+        let offset_y = self.window.iter().map(|(_, y)| y - shift_y).sum::<f64>() / n;
+        self.mean_x = shift_x + offset_x;
+        self.mean_y = shift_y + offset_y;
+        self.m2_x = 0.0;
+        self.m2_y = 0.0;
+        self.c_xy = 0.0;
+        for (x, y) in &self.window {
+            let dx = x - shift_x - offset_x;
+            let dy = y - shift_y - offset_y;
+            self.m2_x += dx * dx;
+            self.m2_y += dy * dy;
+            self.c_xy += dx * dy;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn naive_covariance(pairs: &[(f64, f64)]) -> (f64, f64, f64) {
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+        let cov = pairs
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>()
+            / (n - 1.0);
+        let var_x = pairs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>() / (n - 1.0);
+        let var_y = pairs.iter().map(|(_, y)| (y - mean_y).powi(2)).sum::<f64>() / (n - 1.0);
+        (cov, var_x, var_y)
+    }
+
+    #[rstest]
+    fn test_covariance_matches_naive_calculation() {
+        let pairs = [
+            (1.0, 2.0),
+            (2.0, 3.5),
+            (3.0, 2.5),
+            (4.0, 5.0),
+            (5.0, 4.0),
+            (6.0, 7.5),
+            (7.0, 6.0),
+        ];
+        let mut covariance = RollingCovariance::new(4);
+        for (i, (x, y)) in pairs.iter().enumerate() {
+            covariance.update(*x, *y);
+            if i >= 1 {
+                let window = &pairs[i.saturating_sub(3)..=i];
+                let (cov, var_x, var_y) = naive_covariance(window);
+                assert!((covariance.covariance() - cov).abs() < 1e-12);
+                assert!((covariance.variance_x() - var_x).abs() < 1e-12);
+                assert!((covariance.variance_y() - var_y).abs() < 1e-12);
+                assert!((covariance.correlation() - cov / (var_x * var_y).sqrt()).abs() < 1e-12);
+                assert!((covariance.beta() - cov / var_x).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_perfectly_correlated_series() {
+        let mut covariance = RollingCovariance::new(5);
+        for x in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            covariance.update(x, 2.0f64.mul_add(x, 1.0));
+        }
+
+        assert!(covariance.is_full());
+        assert!((covariance.correlation() - 1.0).abs() < 1e-12);
+        assert!((covariance.beta() - 2.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_constant_series_returns_zero() {
+        let mut covariance = RollingCovariance::new(3);
+        for y in [1.0, 2.0, 3.0] {
+            covariance.update(1.0, y);
+        }
+
+        assert_eq!(covariance.correlation(), 0.0);
+        assert_eq!(covariance.beta(), 0.0);
+    }
+
+    #[rstest]
+    fn test_window_becoming_constant_returns_zero() {
+        let mut covariance = RollingCovariance::new(3);
+        let pairs = [
+            (0.1, 0.2),
+            (0.7, 0.4),
+            (1.3, 0.9),
+            (0.3, 0.5),
+            (0.3, 0.6),
+            (0.3, 0.8),
+        ];
+        for (x, y) in pairs {
+            covariance.update(x, y);
+        }
+
+        assert_eq!(covariance.variance_x(), 0.0);
+        assert_eq!(covariance.covariance(), 0.0);
+        assert_eq!(covariance.correlation(), 0.0);
+        assert_eq!(covariance.beta(), 0.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut covariance = RollingCovariance::new(3);
+        covariance.update(1.0, 2.0);
+        covariance.update(2.0, 1.0);
+        covariance.reset();
+
+        assert!(covariance.is_empty());
+        assert_eq!(covariance.covariance(), 0.0);
+    }
+}
@@ -0,0 +1,247 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+/// A monotonic deque of `(index, value)` entries, where the front holds the extremum of the window.
+#[derive(Clone, Debug)]
+struct MonotonicWindow {
+    period: usize,
+    is_max: bool,
+    index: usize,
+    deque: VecDeque<(usize, f64)>,
+}
+
+impl MonotonicWindow {
+    fn new(period: usize, is_max: bool) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            is_max,
+            index: 0,
+            deque: VecDeque::with_capacity(period),
+        }
+    }
+
+    fn update(&mut self, value: f64) {
+        // Values dominated by the new value can never be the extremum again
+        while let Some(&(_, back)) = self.deque.back() {
+            let dominated = if self.is_max {
+                back <= value
+            } else {
+                back >= value
+            };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.index, value));
+        self.index += 1;
+
+        while let Some(&(front_index, _)) = self.deque.front() {
+            if front_index + self.period > self.index - 1 {
+                break;
+            }
+            self.deque.pop_front();
+        }
+    }
+
+    fn value(&self) -> f64 {
+        self.deque.front().map_or(0.0, |&(_, value)| value)
+    }
+
+    fn len(&self) -> usize {
+        self.index.min(self.period)
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.deque.clear();
+    }
+}
+
+/// A rolling maximum over a fixed-size window, using a monotonic deque for amortized constant
+/// time updates.
+#[derive(Clone, Debug)]
+pub struct RollingMax {
+    window: MonotonicWindow,
+}
+
+impl RollingMax {
+    /// Creates a new [`RollingMax`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: MonotonicWindow::new(period, true),
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        self.window.update(value);
+    }
+
+    /// Returns the maximum value in the window (zero when empty).
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.window.value()
+    }
+
+    /// Returns the window period.
+    #[must_use]
+    pub const fn period(&self) -> usize {
+        self.window.period
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.len() == 0
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.window.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+/// A rolling minimum over a fixed-size window, using a monotonic deque for amortized constant
+/// time updates.
+#[derive(Clone, Debug)]
+pub struct RollingMin {
+    window: MonotonicWindow,
+}
+
+impl RollingMin {
+    /// Creates a new [`RollingMin`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: MonotonicWindow::new(period, false),
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        self.window.update(value);
+    }
+
+    /// Returns the minimum value in the window (zero when empty).
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.window.value()
+    }
+
+    /// Returns the window period.
+    #[must_use]
+    pub const fn period(&self) -> usize {
+        self.window.period
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.len() == 0
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.window.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const VALUES: [f64; 10] = [5.0, 3.0, 8.0, 1.0, 4.0, 4.0, 9.0, 2.0, 6.0, 7.0];
+
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    #[case(4)]
+    #[case(10)]
+    fn test_extrema_match_naive_window(#[case] period: usize) {
+        let mut max = RollingMax::new(period);
+        let mut min = RollingMin::new(period);
+        for (i, value) in VALUES.iter().enumerate() {
+            max.update(*value);
+            min.update(*value);
+
+            let window = &VALUES[(i + 1).saturating_sub(period)..=i];
+            assert_eq!(max.value(), window.iter().copied().fold(f64::MIN, f64::max));
+            assert_eq!(min.value(), window.iter().copied().fold(f64::MAX, f64::min));
+            assert_eq!(max.len(), window.len());
+        }
+        assert!(max.is_full());
+        assert!(min.is_full());
+    }
+
+    #[rstest]
+    fn test_empty_window() {
+        let max = RollingMax::new(3);
+
+        assert!(max.is_empty());
+        assert_eq!(max.value(), 0.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut min = RollingMin::new(2);
+        min.update(1.0);
+        min.update(2.0);
+        min.reset();
+        min.update(3.0);
+
+        assert_eq!(min.len(), 1);
+        assert_eq!(min.value(), 3.0);
+    }
+}
@@ -0,0 +1,30 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming rolling-window statistics primitives for building indicators.
+//!
+//! Each primitive maintains a fixed-size window of the most recent values with constant time
+//! updates (logarithmic in the number of sketch buckets for quantiles), rather than recomputing
+//! over the whole window on every update.
+
+pub mod covariance;
+pub mod extremum;
+pub mod quantile;
+pub mod sum;
+pub mod variance;
+
+/// The fraction of a second moment below which removing a value from the window is treated as
+/// catastrophic cancellation, with the moments then recomputed from the window values.
+const MOMENT_RECOMPUTE_RATIO: f64 = 1e-8;
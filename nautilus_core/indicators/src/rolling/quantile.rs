@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// The default relative accuracy for quantile estimates.
+pub const DEFAULT_RELATIVE_ACCURACY: f64 = 0.01;
+
+/// A mergeable quantile sketch with logarithmically sized buckets (as in DDSketch), supporting
+/// removal of previously added values.
+///
+/// Quantile estimates are within `relative_accuracy` of the true value, with memory bounded by
+/// the number of distinct buckets rather than the number of values.
+#[derive(Clone, Debug)]
+pub struct QuantileSketch {
+    pub relative_accuracy: f64,
+    gamma: f64,
+    ln_gamma: f64,
+    positive: BTreeMap<i32, u64>,
+    negative: BTreeMap<i32, u64>,
+    zero_count: u64,
+    count: u64,
+}
+
+impl QuantileSketch {
+    /// Creates a new [`QuantileSketch`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `relative_accuracy` is not in the range (0, 1).
+    #[must_use]
+    pub fn new(relative_accuracy: f64) -> Self {
+        assert!(
+            relative_accuracy > 0.0 && relative_accuracy < 1.0,
+            "`relative_accuracy` must be in range (0, 1), was {relative_accuracy}"
+        );
+        let gamma = (1.0 + relative_accuracy) / (1.0 - relative_accuracy);
+        Self {
+            relative_accuracy,
+            gamma,
+            ln_gamma: gamma.ln(),
+            positive: BTreeMap::new(),
+            negative: BTreeMap::new(),
+            zero_count: 0,
+            count: 0,
+        }
+    }
+
+    /// Adds the given `value` to the sketch (non-finite values are ignored).
+    pub fn add(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        self.count += 1;
+        if value == 0.0 {
+            self.zero_count += 1;
+        } else if value > 0.0 {
+            *self.positive.entry(self.key(value)).or_insert(0) += 1;
+        } else {
+            *self.negative.entry(self.key(-value)).or_insert(0) += 1;
+        }
+    }
+
+    /// Removes a previously added `value` from the sketch.
+    pub fn remove(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        let removed = if value == 0.0 {
+            let removed = self.zero_count > 0;
+            self.zero_count = self.zero_count.saturating_sub(1);
+            removed
+        } else if value > 0.0 {
+            let key = self.key(value);
+            Self::decrement(&mut self.positive, key)
+        } else {
+            let key = self.key(-value);
+            Self::decrement(&mut self.negative, key)
+        };
+        if removed {
+            self.count -= 1;
+        }
+    }
+
+    /// Returns the estimated value at the given `quantile` in the range [0, 1] (zero when empty).
+    #[must_use]
+    pub fn quantile(&self, quantile: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let rank = quantile.clamp(0.0, 1.0) * (self.count - 1) as f64;
+        let mut cumulative = 0;
+
+        // Larger negative keys hold the lowest values
+        for (key, count) in self.negative.iter().rev() {
+            cumulative += count;
+            if cumulative as f64 > rank {
+                return -self.bucket_value(*key);
+            }
+        }
+
+        cumulative += self.zero_count;
+        if cumulative as f64 > rank {
+            return 0.0;
+        }
+
+        for (key, count) in &self.positive {
+            cumulative += count;
+            if cumulative as f64 > rank {
+                return self.bucket_value(*key);
+            }
+        }
+
+        self.positive
+            .keys()
+            .next_back()
+            .map_or(0.0, |key| self.bucket_value(*key))
+    }
+
+    /// Returns the number of values in the sketch.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn clear(&mut self) {
+        self.positive.clear();
+        self.negative.clear();
+        self.zero_count = 0;
+        self.count = 0;
+    }
+
+    fn key(&self, magnitude: f64) -> i32 {
+        (magnitude.ln() / self.ln_gamma).ceil() as i32
+    }
+
+    fn bucket_value(&self, key: i32) -> f64 {
+        2.0 * self.gamma.powi(key) / (self.gamma + 1.0)
+    }
+
+    fn decrement(buckets: &mut BTreeMap<i32, u64>, key: i32) -> bool {
+        match buckets.get_mut(&key) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    buckets.remove(&key);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A rolling quantile over a fixed-size window, estimated with a [`QuantileSketch`].
+#[derive(Clone, Debug)]
+pub struct RollingQuantile {
+    pub period: usize,
+    window: VecDeque<f64>,
+    sketch: QuantileSketch,
+}
+
+impl RollingQuantile {
+    /// Creates a new [`RollingQuantile`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `period` is not positive (> 0).
+    /// - `relative_accuracy` is not in the range (0, 1).
+    #[must_use]
+    pub fn new(period: usize, relative_accuracy: Option<f64>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            sketch: QuantileSketch::new(relative_accuracy.unwrap_or(DEFAULT_RELATIVE_ACCURACY)),
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        if self.window.len() == self.period {
+            if let Some(evicted) = self.window.pop_front() {
+                self.sketch.remove(evicted);
+            }
+        }
+        self.window.push_back(value);
+        self.sketch.add(value);
+    }
+
+    /// Returns the estimated value at the given `quantile` in the range [0, 1] for the window.
+    #[must_use]
+    pub fn quantile(&self, quantile: f64) -> f64 {
+        self.sketch.quantile(quantile)
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sketch.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn assert_relative_eq(actual: f64, expected: f64, accuracy: f64) {
+        assert!(
+            (actual - expected).abs() <= accuracy * expected.abs(),
+            "{actual} not within {accuracy} of {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, 1.0)]
+    #[case(0.25, 25.0)]
+    #[case(0.5, 50.0)]
+    #[case(0.9, 90.0)]
+    #[case(1.0, 100.0)]
+    fn test_sketch_quantiles_within_relative_accuracy(
+        #[case] quantile: f64,
+        #[case] expected: f64,
+    ) {
+        let mut sketch = QuantileSketch::new(0.01);
+        for value in 1..=100 {
+            sketch.add(f64::from(value));
+        }
+
+        assert_eq!(sketch.count(), 100);
+        assert_relative_eq(sketch.quantile(quantile), expected, 0.01);
+    }
+
+    #[rstest]
+    fn test_sketch_handles_negative_and_zero_values() {
+        let mut sketch = QuantileSketch::new(0.01);
+        for value in [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0] {
+            sketch.add(value);
+        }
+
+        assert_relative_eq(sketch.quantile(0.0), -3.0, 0.01);
+        assert_eq!(sketch.quantile(0.5), 0.0);
+        assert_relative_eq(sketch.quantile(1.0), 3.0, 0.01);
+    }
+
+    #[rstest]
+    fn test_sketch_remove() {
+        let mut sketch = QuantileSketch::new(0.01);
+        sketch.add(1.0);
+        sketch.add(10.0);
+        sketch.remove(10.0);
+        sketch.remove(5.0); // Never added
+
+        assert_eq!(sketch.count(), 1);
+        assert_relative_eq(sketch.quantile(1.0), 1.0, 0.01);
+    }
+
+    #[rstest]
+    fn test_sketch_ignores_non_finite_values() {
+        let mut sketch = QuantileSketch::new(0.01);
+        sketch.add(f64::NAN);
+        sketch.add(f64::INFINITY);
+
+        assert_eq!(sketch.count(), 0);
+        assert_eq!(sketch.quantile(0.5), 0.0);
+    }
+
+    #[rstest]
+    fn test_rolling_quantile_evicts_oldest_value() {
+        let mut quantile = RollingQuantile::new(3, None);
+        for value in [100.0, 1.0, 2.0, 3.0] {
+            quantile.update(value);
+        }
+
+        assert!(quantile.is_full());
+        assert_relative_eq(quantile.quantile(1.0), 3.0, DEFAULT_RELATIVE_ACCURACY);
+        assert_relative_eq(quantile.quantile(0.5), 2.0, DEFAULT_RELATIVE_ACCURACY);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`relative_accuracy` must be in range (0, 1)")]
+    fn test_new_with_invalid_accuracy_panics() {
+        let _ = QuantileSketch::new(1.0);
+    }
+}
@@ -0,0 +1,218 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+/// A rolling sum over a fixed-size window, using Neumaier compensated summation to bound
+/// floating-point drift from repeatedly adding and removing values.
+#[derive(Clone, Debug)]
+pub struct RollingSum {
+    pub period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+    compensation: f64,
+}
+
+impl RollingSum {
+    /// Creates a new [`RollingSum`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            sum: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        if self.window.len() == self.period {
+            if let Some(evicted) = self.window.pop_front() {
+                self.add(-evicted);
+            }
+        }
+        self.window.push_back(value);
+        self.add(value);
+    }
+
+    /// Returns the sum of the values in the window.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.compensation = 0.0;
+    }
+
+    fn add(&mut self, value: f64) {
+        let total = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - total) + value;
+        } else {
+            self.compensation += (value - total) + self.sum;
+        }
+        self.sum = total;
+    }
+}
+
+/// A rolling arithmetic mean over a fixed-size window.
+#[derive(Clone, Debug)]
+pub struct RollingMean {
+    sum: RollingSum,
+}
+
+impl RollingMean {
+    /// Creates a new [`RollingMean`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            sum: RollingSum::new(period),
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        self.sum.update(value);
+    }
+
+    /// Returns the mean of the values in the window (zero when empty).
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        if self.sum.is_empty() {
+            return 0.0;
+        }
+        self.sum.value() / self.sum.len() as f64
+    }
+
+    /// Returns the window period.
+    #[must_use]
+    pub const fn period(&self) -> usize {
+        self.sum.period
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sum.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sum.is_empty()
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.sum.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.sum.reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_sum_evicts_oldest_value() {
+        let mut sum = RollingSum::new(3);
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            sum.update(value);
+        }
+
+        assert!(sum.is_full());
+        assert_eq!(sum.len(), 3);
+        assert_eq!(sum.value(), 9.0);
+    }
+
+    #[rstest]
+    fn test_sum_compensates_for_drift() {
+        let mut sum = RollingSum::new(2);
+        sum.update(1e16);
+        sum.update(1.0);
+        sum.update(1.0);
+        sum.update(1.0);
+
+        assert_eq!(sum.value(), 2.0);
+    }
+
+    #[rstest]
+    fn test_mean_matches_naive_mean() {
+        let values = [0.1, 0.7, 0.3, 0.9, 0.4, 0.2, 0.8];
+        let mut mean = RollingMean::new(4);
+        for (i, value) in values.iter().enumerate() {
+            mean.update(*value);
+            let window = &values[i.saturating_sub(3)..=i];
+            let expected = window.iter().sum::<f64>() / window.len() as f64;
+            assert!((mean.value() - expected).abs() < 1e-12);
+        }
+    }
+
+    #[rstest]
+    fn test_mean_reset() {
+        let mut mean = RollingMean::new(2);
+        mean.update(1.0);
+        mean.reset();
+
+        assert!(mean.is_empty());
+        assert_eq!(mean.value(), 0.0);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`period` must be positive")]
+    fn test_new_with_zero_period_panics() {
+        let _ = RollingSum::new(0);
+    }
+}
@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use super::MOMENT_RECOMPUTE_RATIO;
+
+/// A rolling mean and variance over a fixed-size window, using Welford's online algorithm
+/// extended to remove evicted values.
+#[derive(Clone, Debug)]
+pub struct RollingVariance {
+    pub period: usize,
+    window: VecDeque<f64>,
+    mean: f64,
+    m2: f64,
+}
+
+impl RollingVariance {
+    /// Creates a new [`RollingVariance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adds the given `value` to the window, evicting the oldest value when full.
+    pub fn update(&mut self, value: f64) {
+        if self.window.len() == self.period {
+            if let Some(evicted) = self.window.pop_front() {
+                self.remove(evicted);
+            }
+        }
+        self.window.push_back(value);
+
+        let n = self.window.len() as f64;
+        let delta = value - self.mean;
+        self.mean += delta / n;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the mean of the values in the window (zero when empty).
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the sample variance of the values in the window (zero with fewer than two values).
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        let n = self.window.len();
+        if n < 2 {
+            return 0.0;
+        }
+        self.m2.max(0.0) / (n - 1) as f64
+    }
+
+    /// Returns the population variance of the values in the window (zero when empty).
+    #[must_use]
+    pub fn population_variance(&self) -> f64 {
+        if self.window.is_empty() {
+            return 0.0;
+        }
+        self.m2.max(0.0) / self.window.len() as f64
+    }
+
+    /// Returns the sample standard deviation of the values in the window.
+    #[must_use]
+    pub fn std(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Returns whether the window holds `period` values.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+
+    // Inverts the Welford update, with the window length already excluding `value`
+    fn remove(&mut self, value: f64) {
+        let n = self.window.len() as f64;
+        if n == 0.0 {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let m2 = self.m2;
+        let delta = value - self.mean;
+        self.mean -= delta / n;
+        self.m2 -= delta * (value - self.mean);
+
+        // Removing most of the moment leaves a rounding residue (e.g. once the window becomes
+        // constant), so recompute from the window rather than report a spurious variance
+        if self.m2 < m2 * MOMENT_RECOMPUTE_RATIO {
+            self.recompute();
+        }
+    }
+
+    // Recomputes the moments with a two-pass over the window, shifted by the front value so a
+    // constant window has exactly zero variance
+    fn recompute(&mut self) {
+        let Some(&shift) = self.window.front() else {
+            return;
+        };
+        let n = self.window.len() as f64;
+        let offset = self.window.iter().map(|v| v - shift).sum::<f64>() / n;
+        self.mean = shift + offset;
+        self.m2 = self
+            .window
+            .iter()
+            .map(|v| (v - shift - offset).powi(2))
+            .sum();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn naive_sample_variance(values: &[f64]) -> f64 {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    }
+
+    #[rstest]
+    fn test_variance_matches_naive_variance() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, 1.0, 3.0];
+        let mut variance = RollingVariance::new(5);
+        for (i, value) in values.iter().enumerate() {
+            variance.update(*value);
+            if i >= 1 {
+                let window = &values[i.saturating_sub(4)..=i];
+                assert!((variance.variance() - naive_sample_variance(window)).abs() < 1e-12);
+            }
+        }
+        assert!(variance.is_full());
+        assert!((variance.mean() - 5.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_population_variance() {
+        let mut variance = RollingVariance::new(8);
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            variance.update(value);
+        }
+
+        assert!((variance.population_variance() - 4.0).abs() < 1e-12);
+        assert!((variance.std() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_single_value_has_zero_variance() {
+        let mut variance = RollingVariance::new(1);
+        variance.update(3.0);
+        variance.update(5.0);
+
+        assert_eq!(variance.mean(), 5.0);
+        assert_eq!(variance.variance(), 0.0);
+        assert_eq!(variance.population_variance(), 0.0);
+    }
+
+    #[rstest]
+    fn test_window_becoming_constant_has_exactly_zero_variance() {
+        let mut variance = RollingVariance::new(3);
+        for value in [0.1, 0.7, 1.3, 0.3, 0.3, 0.3] {
+            variance.update(value);
+        }
+
+        assert_eq!(variance.mean(), 0.3);
+        assert_eq!(variance.variance(), 0.0);
+        assert_eq!(variance.std(), 0.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut variance = RollingVariance::new(3);
+        variance.update(1.0);
+        variance.update(2.0);
+        variance.reset();
+
+        assert!(variance.is_empty());
+        assert_eq!(variance.mean(), 0.0);
+        assert_eq!(variance.variance(), 0.0);
+    }
+}
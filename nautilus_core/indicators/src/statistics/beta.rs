@@ -0,0 +1,194 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{data::Bar, identifiers::InstrumentId};
+
+use crate::{
+    indicator::Indicator, rolling::covariance::RollingCovariance, statistics::PairedReturns,
+};
+
+/// An indicator which calculates the rolling beta of an instrument's bar close returns against
+/// those of a benchmark instrument, with bars aligned on `ts_event`.
+///
+/// Raw paired values (such as returns from another source) can be fed with `update_raw`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RollingBeta {
+    pub period: usize,
+    pub instrument_id: InstrumentId,
+    pub benchmark_id: InstrumentId,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    returns: PairedReturns,
+    covariance: RollingCovariance,
+}
+
+impl Display for RollingBeta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.instrument_id,
+            self.benchmark_id
+        )
+    }
+}
+
+impl Indicator for RollingBeta {
+    fn name(&self) -> String {
+        stringify!(RollingBeta).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        // The benchmark is the independent (x) series
+        let instrument_id = bar.bar_type.instrument_id();
+        let pair = if instrument_id == self.benchmark_id {
+            self.returns.update_x(bar.ts_event, bar.close.as_f64())
+        } else if instrument_id == self.instrument_id {
+            self.returns.update_y(bar.ts_event, bar.close.as_f64())
+        } else {
+            return;
+        };
+
+        if let Some((benchmark_return, instrument_return)) = pair {
+            self.update_raw(instrument_return, benchmark_return);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.returns.reset();
+        self.covariance.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RollingBeta {
+    /// Creates a new [`RollingBeta`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is less than 2.
+    #[must_use]
+    pub fn new(period: usize, instrument_id: InstrumentId, benchmark_id: InstrumentId) -> Self {
+        assert!(period >= 2, "`period` must be at least 2, was {period}");
+        Self {
+            period,
+            instrument_id,
+            benchmark_id,
+            value: 0.0,
+            count: 0,
+            returns: PairedReturns::default(),
+            covariance: RollingCovariance::new(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, instrument_value: f64, benchmark_value: f64) {
+        self.covariance.update(benchmark_value, instrument_value);
+        self.value = self.covariance.beta();
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.covariance.is_full() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_beta_initialized(beta_3: RollingBeta) {
+        let display_str = format!("{beta_3}");
+        assert_eq!(display_str, "RollingBeta(3,AUD/USD.SIM,SPX.SIM)");
+        assert_eq!(beta_3.period, 3);
+        assert!(!beta_3.initialized());
+        assert!(!beta_3.has_inputs());
+    }
+
+    #[rstest]
+    fn test_update_raw(mut beta_3: RollingBeta) {
+        beta_3.update_raw(0.02, 0.01);
+        beta_3.update_raw(-0.04, -0.02);
+        assert!(!beta_3.initialized());
+
+        beta_3.update_raw(0.06, 0.03);
+        assert!(beta_3.initialized());
+        assert!((beta_3.value - 2.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut beta_3: RollingBeta) {
+        // Instrument returns are half the benchmark returns
+        let benchmark_closes = [100.0, 110.0, 99.0, 118.8, 106.92];
+        let instrument_closes = [1.0, 1.05, 0.9975, 1.09725, 1.0424];
+        for (i, (benchmark, instrument)) in
+            benchmark_closes.iter().zip(instrument_closes).enumerate()
+        {
+            let ts_event = i as u64;
+            beta_3.handle_bar(&bar_close("SPX.SIM", *benchmark, ts_event));
+            beta_3.handle_bar(&bar_close("AUD/USD.SIM", instrument, ts_event));
+        }
+
+        assert_eq!(beta_3.count, 4);
+        assert!(beta_3.initialized());
+        assert!((beta_3.value - 0.5).abs() < 1e-4);
+    }
+
+    #[rstest]
+    fn test_reset(mut beta_3: RollingBeta) {
+        beta_3.update_raw(1.0, 2.0);
+        beta_3.update_raw(2.0, 1.0);
+        beta_3.reset();
+
+        assert_eq!(beta_3.value, 0.0);
+        assert_eq!(beta_3.count, 0);
+        assert!(!beta_3.has_inputs());
+        assert!(!beta_3.initialized());
+    }
+}
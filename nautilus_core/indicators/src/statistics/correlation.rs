@@ -0,0 +1,208 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{data::Bar, identifiers::InstrumentId};
+
+use crate::{
+    indicator::Indicator, rolling::covariance::RollingCovariance, statistics::PairedReturns,
+};
+
+/// An indicator which calculates the rolling Pearson correlation between the bar close returns
+/// of two instruments, with bars aligned on `ts_event`.
+///
+/// Raw paired values (such as returns from another source) can be fed with `update_raw`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RollingCorrelation {
+    pub period: usize,
+    pub instrument_id_x: InstrumentId,
+    pub instrument_id_y: InstrumentId,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    returns: PairedReturns,
+    covariance: RollingCovariance,
+}
+
+impl Display for RollingCorrelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.instrument_id_x,
+            self.instrument_id_y
+        )
+    }
+}
+
+impl Indicator for RollingCorrelation {
+    fn name(&self) -> String {
+        stringify!(RollingCorrelation).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        let instrument_id = bar.bar_type.instrument_id();
+        let pair = if instrument_id == self.instrument_id_x {
+            self.returns.update_x(bar.ts_event, bar.close.as_f64())
+        } else if instrument_id == self.instrument_id_y {
+            self.returns.update_y(bar.ts_event, bar.close.as_f64())
+        } else {
+            return;
+        };
+
+        if let Some((x, y)) = pair {
+            self.update_raw(x, y);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.returns.reset();
+        self.covariance.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RollingCorrelation {
+    /// Creates a new [`RollingCorrelation`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is less than 2.
+    #[must_use]
+    pub fn new(
+        period: usize,
+        instrument_id_x: InstrumentId,
+        instrument_id_y: InstrumentId,
+    ) -> Self {
+        assert!(period >= 2, "`period` must be at least 2, was {period}");
+        Self {
+            period,
+            instrument_id_x,
+            instrument_id_y,
+            value: 0.0,
+            count: 0,
+            returns: PairedReturns::default(),
+            covariance: RollingCovariance::new(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, x: f64, y: f64) {
+        self.covariance.update(x, y);
+        self.value = self.covariance.correlation();
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.covariance.is_full() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_correlation_initialized(correlation_3: RollingCorrelation) {
+        let display_str = format!("{correlation_3}");
+        assert_eq!(display_str, "RollingCorrelation(3,AUD/USD.SIM,NZD/USD.SIM)");
+        assert_eq!(correlation_3.period, 3);
+        assert!(!correlation_3.initialized());
+        assert!(!correlation_3.has_inputs());
+    }
+
+    #[rstest]
+    fn test_update_raw(mut correlation_3: RollingCorrelation) {
+        correlation_3.update_raw(1.0, -2.0);
+        correlation_3.update_raw(2.0, -4.0);
+        assert!(!correlation_3.initialized());
+
+        correlation_3.update_raw(3.0, -6.0);
+        assert!(correlation_3.initialized());
+        assert!((correlation_3.value + 1.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_bar_aligns_returns(mut correlation_3: RollingCorrelation) {
+        let closes_x = [1.0, 1.1, 1.21, 1.1, 1.2];
+        let closes_y = [2.0, 2.2, 2.42, 2.2, 2.4];
+        for (i, (close_x, close_y)) in closes_x.iter().zip(closes_y).enumerate() {
+            let ts_event = i as u64 * 60_000_000_000;
+            correlation_3.handle_bar(&bar_close("AUD/USD.SIM", *close_x, ts_event));
+            correlation_3.handle_bar(&bar_close("EUR/USD.SIM", 1.0, ts_event));
+            correlation_3.handle_bar(&bar_close("NZD/USD.SIM", close_y, ts_event));
+        }
+
+        assert_eq!(correlation_3.count, 4);
+        assert!(correlation_3.initialized());
+        assert!((correlation_3.value - 1.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_bar_drops_unaligned_returns(mut correlation_3: RollingCorrelation) {
+        correlation_3.handle_bar(&bar_close("AUD/USD.SIM", 1.0, 0));
+        correlation_3.handle_bar(&bar_close("NZD/USD.SIM", 2.0, 0));
+        correlation_3.handle_bar(&bar_close("AUD/USD.SIM", 1.1, 1));
+        correlation_3.handle_bar(&bar_close("AUD/USD.SIM", 1.2, 2));
+        correlation_3.handle_bar(&bar_close("NZD/USD.SIM", 2.1, 1));
+        assert!(!correlation_3.has_inputs());
+
+        correlation_3.handle_bar(&bar_close("NZD/USD.SIM", 2.2, 2));
+        assert_eq!(correlation_3.count, 1);
+    }
+
+    #[rstest]
+    fn test_reset(mut correlation_3: RollingCorrelation) {
+        correlation_3.update_raw(1.0, 2.0);
+        correlation_3.update_raw(2.0, 1.0);
+        correlation_3.reset();
+
+        assert_eq!(correlation_3.value, 0.0);
+        assert_eq!(correlation_3.count, 0);
+        assert!(!correlation_3.has_inputs());
+        assert!(!correlation_3.initialized());
+    }
+}
@@ -0,0 +1,85 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Statistical indicators built on the rolling-window primitives.
+
+pub mod beta;
+pub mod correlation;
+pub mod percentile;
+pub mod zscore;
+
+use nautilus_core::UnixNanos;
+
+/// The latest close and pending simple return for one leg of a [`PairedReturns`].
+#[derive(Clone, Debug, Default)]
+struct ReturnLeg {
+    last_close: Option<f64>,
+    pending: Option<(UnixNanos, f64)>,
+}
+
+impl ReturnLeg {
+    fn update(&mut self, ts_event: UnixNanos, close: f64) {
+        if let Some(last_close) = self.last_close {
+            if last_close != 0.0 {
+                self.pending = Some((ts_event, close / last_close - 1.0));
+            }
+        }
+        self.last_close = Some(close);
+    }
+}
+
+/// Pairs the simple close-to-close returns of two bar series, aligned on bar `ts_event`.
+///
+/// A return is dropped if the other series has already moved past its timestamp.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PairedReturns {
+    x: ReturnLeg,
+    y: ReturnLeg,
+}
+
+impl PairedReturns {
+    /// Updates the `x` series, returning the paired `(x, y)` returns once aligned.
+    pub fn update_x(&mut self, ts_event: UnixNanos, close: f64) -> Option<(f64, f64)> {
+        self.x.update(ts_event, close);
+        self.pair()
+    }
+
+    /// Updates the `y` series, returning the paired `(x, y)` returns once aligned.
+    pub fn update_y(&mut self, ts_event: UnixNanos, close: f64) -> Option<(f64, f64)> {
+        self.y.update(ts_event, close);
+        self.pair()
+    }
+
+    pub fn reset(&mut self) {
+        self.x = ReturnLeg::default();
+        self.y = ReturnLeg::default();
+    }
+
+    fn pair(&mut self) -> Option<(f64, f64)> {
+        let (ts_x, return_x) = self.x.pending?;
+        let (ts_y, return_y) = self.y.pending?;
+        if ts_x < ts_y {
+            self.x.pending = None;
+            return None;
+        }
+        if ts_y < ts_x {
+            self.y.pending = None;
+            return None;
+        }
+        self.x.pending = None;
+        self.y.pending = None;
+        Some((return_x, return_y))
+    }
+}
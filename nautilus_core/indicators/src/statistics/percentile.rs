@@ -0,0 +1,232 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    indicator::{Indicator, ScalarIndicator},
+    rolling::quantile::RollingQuantile,
+};
+
+/// An indicator which estimates a percentile of the values in a rolling window, using a
+/// quantile sketch with the given `relative_accuracy`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RollingPercentile {
+    pub period: usize,
+    pub percentile: f64,
+    pub price_type: PriceType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    quantile: RollingQuantile,
+}
+
+impl Display for RollingPercentile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.percentile)
+    }
+}
+
+impl Indicator for RollingPercentile {
+    fn name(&self) -> String {
+        stringify!(RollingPercentile).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).into());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.quantile.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ScalarIndicator for RollingPercentile {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl RollingPercentile {
+    /// Creates a new [`RollingPercentile`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `period` is not positive (> 0).
+    /// - `percentile` is not in the range [0, 100].
+    /// - `relative_accuracy` is not in the range (0, 1).
+    #[must_use]
+    pub fn new(
+        period: usize,
+        percentile: f64,
+        relative_accuracy: Option<f64>,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        assert!(
+            (0.0..=100.0).contains(&percentile),
+            "`percentile` must be in range [0, 100], was {percentile}"
+        );
+        Self {
+            period,
+            percentile,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            quantile: RollingQuantile::new(period, relative_accuracy),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.quantile.update(value);
+        self.value = self.quantile.quantile(self.percentile / 100.0);
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.quantile.is_full() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{Bar, QuoteTick, TradeTick};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_percentile_initialized(percentile_5: RollingPercentile) {
+        let display_str = format!("{percentile_5}");
+        assert_eq!(display_str, "RollingPercentile(5,50)");
+        assert_eq!(percentile_5.period, 5);
+        assert!(!percentile_5.initialized());
+        assert!(!percentile_5.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_rolling_window(mut percentile_5: RollingPercentile) {
+        for value in [10.0, 50.0, 20.0, 40.0] {
+            percentile_5.update_raw(value);
+        }
+        assert!(!percentile_5.initialized());
+
+        percentile_5.update_raw(30.0);
+        assert!(percentile_5.initialized());
+        assert!((percentile_5.value - 30.0).abs() <= 0.3);
+
+        // Window of [50, 20, 40, 30, 60]
+        percentile_5.update_raw(60.0);
+        assert!((percentile_5.value - 40.0).abs() <= 0.4);
+    }
+
+    #[rstest]
+    fn test_extreme_percentiles() {
+        let mut min = RollingPercentile::new(3, 0.0, Some(0.001), None);
+        let mut max = RollingPercentile::new(3, 100.0, Some(0.001), None);
+        for value in [5.0, 1.0, 3.0] {
+            min.update_raw(value);
+            max.update_raw(value);
+        }
+
+        assert!((min.value - 1.0).abs() < 0.002);
+        assert!((max.value - 5.0).abs() < 0.01);
+    }
+
+    #[rstest]
+    fn test_handle_quote(mut percentile_5: RollingPercentile, stub_quote: QuoteTick) {
+        percentile_5.handle_quote(&stub_quote);
+
+        assert!(percentile_5.has_inputs());
+        assert!((percentile_5.value - 1501.0).abs() <= 15.01);
+    }
+
+    #[rstest]
+    fn test_handle_trade(mut percentile_5: RollingPercentile, stub_trade: TradeTick) {
+        percentile_5.handle_trade(&stub_trade);
+
+        assert_eq!(percentile_5.count, 1);
+        assert!((percentile_5.value - 1500.0).abs() <= 15.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut percentile_5: RollingPercentile, bar_ethusdt_binance_minute_bid: Bar) {
+        percentile_5.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!((percentile_5.value - 1522.0).abs() <= 15.22);
+    }
+
+    #[rstest]
+    fn test_reset(mut percentile_5: RollingPercentile) {
+        percentile_5.update_raw(1.0);
+        percentile_5.reset();
+
+        assert_eq!(percentile_5.value, 0.0);
+        assert_eq!(percentile_5.count, 0);
+        assert!(!percentile_5.has_inputs());
+        assert!(!percentile_5.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`percentile` must be in range [0, 100]")]
+    fn test_new_with_invalid_percentile_panics() {
+        let _ = RollingPercentile::new(5, 101.0, None, None);
+    }
+}
@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    indicator::{Indicator, ScalarIndicator},
+    rolling::variance::RollingVariance,
+};
+
+/// An indicator which calculates the z-score of the latest value relative to the mean and
+/// sample standard deviation of a rolling window (which includes the latest value).
+///
+/// The value is zero while the window has no dispersion.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RollingZScore {
+    pub period: usize,
+    pub price_type: PriceType,
+    pub value: f64,
+    pub mean: f64,
+    pub std: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    variance: RollingVariance,
+}
+
+impl Display for RollingZScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for RollingZScore {
+    fn name(&self) -> String {
+        stringify!(RollingZScore).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).into());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.variance.reset();
+        self.value = 0.0;
+        self.mean = 0.0;
+        self.std = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ScalarIndicator for RollingZScore {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn handle_value(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+}
+
+impl RollingZScore {
+    /// Creates a new [`RollingZScore`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is less than 2.
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period >= 2, "`period` must be at least 2, was {period}");
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            mean: 0.0,
+            std: 0.0,
+            count: 0,
+            variance: RollingVariance::new(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.variance.update(value);
+        self.mean = self.variance.mean();
+        self.std = self.variance.std();
+        self.value = if self.std > 0.0 {
+            (value - self.mean) / self.std
+        } else {
+            0.0
+        };
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.variance.is_full() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{Bar, QuoteTick, TradeTick};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_zscore_initialized(zscore_3: RollingZScore) {
+        let display_str = format!("{zscore_3}");
+        assert_eq!(display_str, "RollingZScore(3)");
+        assert_eq!(zscore_3.period, 3);
+        assert!(!zscore_3.initialized());
+        assert!(!zscore_3.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_rolling_window(mut zscore_3: RollingZScore) {
+        zscore_3.update_raw(1.0);
+        zscore_3.update_raw(2.0);
+        assert!(!zscore_3.initialized());
+
+        zscore_3.update_raw(3.0);
+        assert!(zscore_3.initialized());
+        assert_eq!(zscore_3.mean, 2.0);
+        assert_eq!(zscore_3.std, 1.0);
+        assert_eq!(zscore_3.value, 1.0);
+
+        // Window of [2, 3, 7] has mean 4 and standard deviation sqrt(7)
+        zscore_3.update_raw(7.0);
+        assert!((zscore_3.value - 3.0 / 7.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_is_zero_without_dispersion(mut zscore_3: RollingZScore) {
+        for _ in 0..3 {
+            zscore_3.update_raw(5.0);
+        }
+
+        assert_eq!(zscore_3.std, 0.0);
+        assert_eq!(zscore_3.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote(mut zscore_3: RollingZScore, stub_quote: QuoteTick) {
+        zscore_3.handle_quote(&stub_quote);
+
+        assert!(zscore_3.has_inputs());
+        assert_eq!(zscore_3.mean, 1501.0);
+    }
+
+    #[rstest]
+    fn test_handle_trade(mut zscore_3: RollingZScore, stub_trade: TradeTick) {
+        zscore_3.handle_trade(&stub_trade);
+
+        assert_eq!(zscore_3.count, 1);
+        assert_eq!(zscore_3.mean, 1500.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut zscore_3: RollingZScore, bar_ethusdt_binance_minute_bid: Bar) {
+        zscore_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert_eq!(zscore_3.mean, 1522.0);
+        assert_eq!(zscore_3.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut zscore_3: RollingZScore) {
+        for value in [1.0, 2.0, 3.0] {
+            zscore_3.update_raw(value);
+        }
+        zscore_3.reset();
+
+        assert_eq!(zscore_3.value, 0.0);
+        assert_eq!(zscore_3.mean, 0.0);
+        assert_eq!(zscore_3.count, 0);
+        assert!(!zscore_3.has_inputs());
+        assert!(!zscore_3.initialized());
+    }
+}
@@ -31,17 +31,24 @@ use crate::{
         vwap::VolumeWeightedAveragePrice, wma::WeightedMovingAverage, MovingAverageType,
     },
     momentum::{
-        amat::ArcherMovingAveragesTrends, bb::BollingerBands, bias::Bias,
-        cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator, dm::DirectionalMovement,
-        kvo::KlingerVolumeOscillator, macd::MovingAverageConvergenceDivergence,
-        obv::OnBalanceVolume, pressure::Pressure, psl::PsychologicalLine, roc::RateOfChange,
+        adx::AverageDirectionalIndex, amat::ArcherMovingAveragesTrends, bb::BollingerBands,
+        bias::Bias, cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator,
+        dm::DirectionalMovement, ichimoku::IchimokuCloud, kvo::KlingerVolumeOscillator,
+        macd::MovingAverageConvergenceDivergence, obv::OnBalanceVolume, pressure::Pressure,
+        psar::ParabolicStopAndReverse, psl::PsychologicalLine, roc::RateOfChange,
         rsi::RelativeStrengthIndex, stochastics::Stochastics, swings::Swings,
         vhf::VerticalHorizontalFilter,
     },
     ratio::{efficiency_ratio::EfficiencyRatio, spread_analyzer::SpreadAnalyzer},
+    statistics::{
+        beta::RollingBeta, correlation::RollingCorrelation, percentile::RollingPercentile,
+        zscore::RollingZScore,
+    },
     volatility::{
-        dc::DonchianChannel, fuzzy::FuzzyCandlesticks, kc::KeltnerChannel, kp::KeltnerPosition,
-        rvi::RelativeVolatilityIndex, vr::VolatilityRatio,
+        dc::DonchianChannel, fuzzy::FuzzyCandlesticks, garman_klass::GarmanKlassVolatility,
+        kc::KeltnerChannel, kp::KeltnerPosition, parkinson::ParkinsonVolatility,
+        rogers_satchell::RogersSatchellVolatility, rvi::RelativeVolatilityIndex,
+        supertrend::SuperTrend, vr::VolatilityRatio, yang_zhang::YangZhangVolatility,
     },
 };
 
@@ -101,6 +108,62 @@ pub fn bar_ethusdt_binance_minute_bid(#[default("1522")] close_price: &str) -> B
     }
 }
 
+#[must_use]
+pub fn bar_close(instrument_id: &str, close: f64, ts_event: u64) -> Bar {
+    let instrument_id = InstrumentId::from(instrument_id);
+    let bar_spec = BarSpecification::new(1, BarAggregation::Minute, PriceType::Last);
+    let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
+    let close = Price::new(close, 5);
+    Bar {
+        bar_type,
+        open: close,
+        high: close,
+        low: close,
+        close,
+        volume: Quantity::from("100000"),
+        ts_event: ts_event.into(),
+        ts_init: ts_event.into(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reference series
+////////////////////////////////////////////////////////////////////////////////
+// The first 80 one-minute bars of `tests/test_data/btc-perp-20211231-20220201_1m.csv`,
+// a fixed OHLC series for asserting indicators against independently derived reference values.
+pub const REFERENCE_HIGHS: [f64; 80] = [
+    46385.0, 46411.0, 46425.0, 46425.0, 46437.0, 46435.0, 46435.0, 46420.0, 46429.0, 46456.0,
+    46448.0, 46448.0, 46438.0, 46459.0, 46475.0, 46456.0, 46445.0, 46471.0, 46474.0, 46478.0,
+    46459.0, 46426.0, 46383.0, 46398.0, 46408.0, 46407.0, 46432.0, 46432.0, 46433.0, 46411.0,
+    46402.0, 46402.0, 46411.0, 46514.0, 46484.0, 46475.0, 46439.0, 46438.0, 46415.0, 46408.0,
+    46432.0, 46439.0, 46448.0, 46444.0, 46507.0, 46471.0, 46438.0, 46440.0, 46421.0, 46401.0,
+    46400.0, 46373.0, 46372.0, 46373.0, 46327.0, 46298.0, 46289.0, 46288.0, 46262.0, 46247.0,
+    46265.0, 46342.0, 46390.0, 46387.0, 46338.0, 46446.0, 46542.0, 46542.0, 46444.0, 46418.0,
+    46394.0, 46394.0, 46328.0, 46372.0, 46361.0, 46350.0, 46345.0, 46339.0, 46304.0, 46321.0,
+];
+
+pub const REFERENCE_LOWS: [f64; 80] = [
+    46319.0, 46377.0, 46411.0, 46420.0, 46420.0, 46424.0, 46379.0, 46405.0, 46372.0, 46415.0,
+    46420.0, 46396.0, 46407.0, 46435.0, 46425.0, 46431.0, 46412.0, 46412.0, 46453.0, 46458.0,
+    46422.0, 46373.0, 46363.0, 46365.0, 46383.0, 46380.0, 46391.0, 46413.0, 46410.0, 46355.0,
+    46355.0, 46391.0, 46390.0, 46374.0, 46463.0, 46416.0, 46415.0, 46388.0, 46408.0, 46389.0,
+    46389.0, 46430.0, 46397.0, 46393.0, 46437.0, 46421.0, 46401.0, 46411.0, 46393.0, 46386.0,
+    46361.0, 46349.0, 46363.0, 46320.0, 46284.0, 46252.0, 46263.0, 46256.0, 46150.0, 46195.0,
+    46198.0, 46243.0, 46301.0, 46325.0, 46305.0, 46287.0, 46443.0, 46443.0, 46401.0, 46357.0,
+    46361.0, 46303.0, 46285.0, 46294.0, 46330.0, 46325.0, 46322.0, 46292.0, 46285.0, 46254.0,
+];
+
+pub const REFERENCE_CLOSES: [f64; 80] = [
+    46377.0, 46411.0, 46425.0, 46420.0, 46427.0, 46435.0, 46420.0, 46411.0, 46415.0, 46435.0,
+    46448.0, 46420.0, 46435.0, 46456.0, 46431.0, 46441.0, 46412.0, 46470.0, 46467.0, 46458.0,
+    46426.0, 46376.0, 46366.0, 46384.0, 46407.0, 46391.0, 46426.0, 46432.0, 46410.0, 46356.0,
+    46392.0, 46402.0, 46390.0, 46476.0, 46475.0, 46416.0, 46435.0, 46415.0, 46408.0, 46390.0,
+    46430.0, 46437.0, 46407.0, 46443.0, 46465.0, 46438.0, 46413.0, 46421.0, 46393.0, 46400.0,
+    46361.0, 46367.0, 46372.0, 46325.0, 46295.0, 46264.0, 46264.0, 46257.0, 46198.0, 46224.0,
+    46247.0, 46320.0, 46384.0, 46336.0, 46319.0, 46444.0, 46541.0, 46444.0, 46402.0, 46383.0,
+    46391.0, 46328.0, 46299.0, 46336.0, 46349.0, 46345.0, 46339.0, 46298.0, 46298.0, 46310.0,
+];
+
 ////////////////////////////////////////////////////////////////////////////////
 // Average
 ////////////////////////////////////////////////////////////////////////////////
@@ -251,6 +314,21 @@ pub fn obv_10() -> OnBalanceVolume {
     OnBalanceVolume::new(10)
 }
 
+#[fixture]
+pub fn adx_3() -> AverageDirectionalIndex {
+    AverageDirectionalIndex::new(3)
+}
+
+#[fixture]
+pub fn psar() -> ParabolicStopAndReverse {
+    ParabolicStopAndReverse::new(None, None, None)
+}
+
+#[fixture]
+pub fn ichimoku_234() -> IchimokuCloud {
+    IchimokuCloud::new(Some(2), Some(3), Some(4), None)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Volatility
 ////////////////////////////////////////////////////////////////////////////////
@@ -299,6 +377,31 @@ pub fn kp_10() -> KeltnerPosition {
     )
 }
 
+#[fixture]
+pub fn supertrend_3() -> SuperTrend {
+    SuperTrend::new(3, 2.0, Some(MovingAverageType::Simple))
+}
+
+#[fixture]
+pub fn parkinson_3() -> ParkinsonVolatility {
+    ParkinsonVolatility::new(3, None, None)
+}
+
+#[fixture]
+pub fn garman_klass_3() -> GarmanKlassVolatility {
+    GarmanKlassVolatility::new(3, None, None)
+}
+
+#[fixture]
+pub fn rogers_satchell_3() -> RogersSatchellVolatility {
+    RogersSatchellVolatility::new(3, None, None)
+}
+
+#[fixture]
+pub fn yang_zhang_3() -> YangZhangVolatility {
+    YangZhangVolatility::new(3, None, None)
+}
+
 #[fixture]
 pub fn roc_10() -> RateOfChange {
     RateOfChange::new(10, Some(true))
@@ -308,3 +411,34 @@ pub fn roc_10() -> RateOfChange {
 pub fn fuzzy_candlesticks_10() -> FuzzyCandlesticks {
     FuzzyCandlesticks::new(10, 0.1, 0.15, 0.2, 0.3)
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Statistics
+////////////////////////////////////////////////////////////////////////////////
+#[fixture]
+pub fn zscore_3() -> RollingZScore {
+    RollingZScore::new(3, Some(PriceType::Mid))
+}
+
+#[fixture]
+pub fn percentile_5() -> RollingPercentile {
+    RollingPercentile::new(5, 50.0, None, Some(PriceType::Mid))
+}
+
+#[fixture]
+pub fn correlation_3() -> RollingCorrelation {
+    RollingCorrelation::new(
+        3,
+        InstrumentId::from("AUD/USD.SIM"),
+        InstrumentId::from("NZD/USD.SIM"),
+    )
+}
+
+#[fixture]
+pub fn beta_3() -> RollingBeta {
+    RollingBeta::new(
+        3,
+        InstrumentId::from("AUD/USD.SIM"),
+        InstrumentId::from("SPX.SIM"),
+    )
+}
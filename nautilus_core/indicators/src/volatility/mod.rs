@@ -18,7 +18,72 @@
 pub mod atr;
 pub mod dc;
 pub mod fuzzy;
+pub mod garman_klass;
 pub mod kc;
 pub mod kp;
+pub mod parkinson;
+pub mod rogers_satchell;
 pub mod rvi;
+pub mod supertrend;
 pub mod vr;
+pub mod yang_zhang;
+
+use nautilus_model::{data::BarSpecification, enums::BarAggregation};
+
+/// The default number of days per year used to annualize realized volatility.
+pub const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Returns the number of bars per year for the given bar `spec`, used to annualize realized
+/// volatility estimators.
+///
+/// Intraday and daily bars are scaled by `days_per_year` (e.g. 252.0 for exchange trading days),
+/// while weekly and monthly bars use 52 and 12 periods per year respectively. Returns `None` for
+/// bars which are not time aggregated (tick, volume and value bars).
+#[must_use]
+pub fn bars_per_year(spec: &BarSpecification, days_per_year: f64) -> Option<f64> {
+    let step = spec.step.get() as f64;
+    match spec.aggregation {
+        BarAggregation::Millisecond
+        | BarAggregation::Second
+        | BarAggregation::Minute
+        | BarAggregation::Hour
+        | BarAggregation::Day => {
+            let interval_secs = spec.timedelta().num_milliseconds() as f64 / 1_000.0;
+            Some(days_per_year * 86_400.0 / interval_secs)
+        }
+        BarAggregation::Week => Some(52.0 / step),
+        BarAggregation::Month => Some(12.0 / step),
+        _ => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::PriceType;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1, BarAggregation::Day, DAYS_PER_YEAR, Some(365.0))]
+    #[case(1, BarAggregation::Day, 252.0, Some(252.0))]
+    #[case(1, BarAggregation::Hour, DAYS_PER_YEAR, Some(8_760.0))]
+    #[case(5, BarAggregation::Minute, DAYS_PER_YEAR, Some(105_120.0))]
+    #[case(500, BarAggregation::Millisecond, 1.0, Some(172_800.0))]
+    #[case(1, BarAggregation::Week, DAYS_PER_YEAR, Some(52.0))]
+    #[case(3, BarAggregation::Month, DAYS_PER_YEAR, Some(4.0))]
+    #[case(100, BarAggregation::Tick, DAYS_PER_YEAR, None)]
+    #[case(1_000, BarAggregation::Volume, DAYS_PER_YEAR, None)]
+    fn test_bars_per_year(
+        #[case] step: usize,
+        #[case] aggregation: BarAggregation,
+        #[case] days_per_year: f64,
+        #[case] expected: Option<f64>,
+    ) {
+        let spec = BarSpecification::new(step, aggregation, PriceType::Last);
+        assert_eq!(bars_per_year(&spec, days_per_year), expected);
+    }
+}
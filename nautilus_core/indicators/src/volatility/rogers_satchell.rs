@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{
+    indicator::Indicator,
+    rolling::sum::RollingMean,
+    volatility::{bars_per_year, DAYS_PER_YEAR},
+};
+
+/// An indicator which calculates the Rogers-Satchell realized volatility estimator over a rolling
+/// window of bars, using the open, high, low and close of each bar.
+///
+/// Unlike the Parkinson and Garman-Klass estimators it allows for a non-zero drift, but does not
+/// account for opening gaps.
+///
+/// The value is annualized using `bars_per_year` when provided, otherwise derived from the bar
+/// specification of the first handled bar (see [`bars_per_year`]).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RogersSatchellVolatility {
+    pub period: usize,
+    pub days_per_year: f64,
+    pub bars_per_year: Option<f64>,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    variances: RollingMean,
+}
+
+impl Display for RogersSatchellVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for RogersSatchellVolatility {
+    fn name(&self) -> String {
+        stringify!(RogersSatchellVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        if self.bars_per_year.is_none() {
+            self.bars_per_year = bars_per_year(&bar.bar_type.spec(), self.days_per_year);
+        }
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.variances.reset();
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RogersSatchellVolatility {
+    /// Creates a new [`RogersSatchellVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, bars_per_year: Option<f64>, days_per_year: Option<f64>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        Self {
+            period,
+            days_per_year: days_per_year.unwrap_or(DAYS_PER_YEAR),
+            bars_per_year,
+            value: 0.0,
+            count: 0,
+            variances: RollingMean::new(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, open: f64, high: f64, low: f64, close: f64) {
+        self.variances
+            .update(rogers_satchell_variance(open, high, low, close));
+
+        let variance = self.variances.value();
+        self.value = (variance.max(0.0) * self.bars_per_year.unwrap_or(1.0)).sqrt();
+        self.count += 1;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.variances.is_full() {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+/// Returns the Rogers-Satchell variance estimate for a single bar.
+pub(crate) fn rogers_satchell_variance(open: f64, high: f64, low: f64, close: f64) -> f64 {
+    (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, rogers_satchell_3};
+
+    const OPENS: [f64; 5] = [100.0, 101.0, 102.5, 101.5, 103.0];
+    const HIGHS: [f64; 5] = [102.0, 103.0, 104.0, 103.5, 105.0];
+    const LOWS: [f64; 5] = [99.0, 100.5, 101.0, 100.0, 102.0];
+    const CLOSES: [f64; 5] = [101.0, 102.5, 101.5, 103.0, 104.5];
+
+    #[rstest]
+    fn test_name_returns_expected_string(rogers_satchell_3: RogersSatchellVolatility) {
+        assert_eq!(rogers_satchell_3.name(), "RogersSatchellVolatility");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(rogers_satchell_3: RogersSatchellVolatility) {
+        assert_eq!(
+            format!("{rogers_satchell_3}"),
+            "RogersSatchellVolatility(3)"
+        );
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(rogers_satchell_3: RogersSatchellVolatility) {
+        assert!(!rogers_satchell_3.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`period` must be positive")]
+    fn test_new_with_zero_period_panics() {
+        let _ = RogersSatchellVolatility::new(0, None, None);
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(
+        mut rogers_satchell_3: RogersSatchellVolatility,
+    ) {
+        for i in 0..3 {
+            assert!(!rogers_satchell_3.initialized());
+            rogers_satchell_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+        assert!(rogers_satchell_3.initialized());
+    }
+
+    #[rstest]
+    fn test_value_with_reference_inputs(mut rogers_satchell_3: RogersSatchellVolatility) {
+        for i in 0..OPENS.len() {
+            rogers_satchell_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        // Per bar volatility over the last three bars
+        assert!((rogers_satchell_3.value - 0.020_727_748_859_180_772).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_annualized_with_bars_per_year() {
+        let mut indicator = RogersSatchellVolatility::new(3, Some(252.0), None);
+        for i in 0..OPENS.len() {
+            indicator.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        assert!((indicator.value - 0.020_727_748_859_180_772 * 252.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_bar_annualizes_from_bar_spec(
+        mut rogers_satchell_3: RogersSatchellVolatility,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        rogers_satchell_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert_eq!(rogers_satchell_3.bars_per_year, Some(525_600.0));
+        assert!((rogers_satchell_3.value - 18.589_989_565_976_857).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut rogers_satchell_3: RogersSatchellVolatility,
+    ) {
+        for i in 0..OPENS.len() {
+            rogers_satchell_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        rogers_satchell_3.reset();
+
+        assert!(!rogers_satchell_3.initialized());
+        assert!(!rogers_satchell_3.has_inputs());
+        assert_eq!(rogers_satchell_3.value, 0.0);
+        assert_eq!(rogers_satchell_3.count, 0);
+    }
+}
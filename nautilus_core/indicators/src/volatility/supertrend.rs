@@ -0,0 +1,265 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{average::MovingAverageType, indicator::Indicator, volatility::atr::AverageTrueRange};
+
+/// An indicator which calculates the SuperTrend, a trailing stop placed a multiple of the
+/// Average True Range (ATR) away from the bar median price.
+///
+/// The upper band only ratchets down and the lower band only ratchets up while price remains
+/// inside them, with the trend flipping when the close crosses the opposing band.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct SuperTrend {
+    pub period: usize,
+    pub multiplier: f64,
+    pub ma_type: MovingAverageType,
+    pub upper: f64,
+    pub lower: f64,
+    pub value: f64,
+    pub is_uptrend: bool,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_close: f64,
+    atr: AverageTrueRange,
+}
+
+impl Display for SuperTrend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.multiplier)
+    }
+}
+
+impl Indicator for SuperTrend {
+    fn name(&self) -> String {
+        stringify!(SuperTrend).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.upper = 0.0;
+        self.lower = 0.0;
+        self.value = 0.0;
+        self.is_uptrend = true;
+        self.previous_close = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl SuperTrend {
+    /// Creates a new [`SuperTrend`] instance.
+    #[must_use]
+    pub fn new(period: usize, multiplier: f64, ma_type: Option<MovingAverageType>) -> Self {
+        Self {
+            period,
+            multiplier,
+            ma_type: ma_type.unwrap_or(MovingAverageType::Simple),
+            upper: 0.0,
+            lower: 0.0,
+            value: 0.0,
+            is_uptrend: true,
+            previous_close: 0.0,
+            atr: AverageTrueRange::new(period, ma_type, Some(true), None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.atr.update_raw(high, low, close);
+
+        let median = (high + low) / 2.0;
+        let basic_upper = self.atr.value.mul_add(self.multiplier, median);
+        let basic_lower = self.atr.value.mul_add(-self.multiplier, median);
+
+        if self.has_inputs {
+            let previous_upper = self.upper;
+            let previous_lower = self.lower;
+
+            self.upper = if self.previous_close < previous_upper {
+                basic_upper.min(previous_upper)
+            } else {
+                basic_upper
+            };
+            self.lower = if self.previous_close > previous_lower {
+                basic_lower.max(previous_lower)
+            } else {
+                basic_lower
+            };
+
+            if self.is_uptrend && close < previous_lower {
+                self.is_uptrend = false;
+            } else if !self.is_uptrend && close > previous_upper {
+                self.is_uptrend = true;
+            }
+        } else {
+            self.has_inputs = true;
+            self.upper = basic_upper;
+            self.lower = basic_lower;
+        }
+
+        self.value = if self.is_uptrend {
+            self.lower
+        } else {
+            self.upper
+        };
+        self.previous_close = close;
+
+        // Initialization logic
+        if !self.initialized && self.atr.initialized {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{
+        bar_ethusdt_binance_minute_bid, supertrend_3, REFERENCE_CLOSES, REFERENCE_HIGHS,
+        REFERENCE_LOWS,
+    };
+
+    const HIGHS: [f64; 8] = [10.0, 11.0, 12.0, 12.5, 12.0, 10.5, 9.5, 9.0];
+    const LOWS: [f64; 8] = [9.0, 10.0, 11.0, 11.5, 10.5, 9.0, 8.0, 7.5];
+    const CLOSES: [f64; 8] = [9.5, 10.8, 11.8, 12.0, 10.8, 9.2, 8.2, 8.0];
+
+    #[rstest]
+    fn test_name_returns_expected_string(supertrend_3: SuperTrend) {
+        assert_eq!(supertrend_3.name(), "SuperTrend");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(supertrend_3: SuperTrend) {
+        assert_eq!(format!("{supertrend_3}"), "SuperTrend(3,2)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(supertrend_3: SuperTrend) {
+        assert!(!supertrend_3.initialized());
+    }
+
+    #[rstest]
+    fn test_initialized_with_required_inputs_returns_true(mut supertrend_3: SuperTrend) {
+        for i in 0..3 {
+            assert!(!supertrend_3.initialized());
+            supertrend_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+        assert!(supertrend_3.initialized());
+    }
+
+    #[rstest]
+    fn test_value_trails_below_price_in_uptrend(mut supertrend_3: SuperTrend) {
+        for i in 0..4 {
+            supertrend_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        assert!(supertrend_3.is_uptrend);
+        assert_eq!(supertrend_3.value, supertrend_3.lower);
+        assert!((supertrend_3.lower - 9.533_333_333_333_333).abs() < 1e-9);
+        assert!((supertrend_3.upper - 14.466_666_666_666_667).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_flips_to_upper_band_in_downtrend(mut supertrend_3: SuperTrend) {
+        for i in 0..HIGHS.len() {
+            supertrend_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        assert!(!supertrend_3.is_uptrend);
+        assert_eq!(supertrend_3.value, supertrend_3.upper);
+        assert!((supertrend_3.upper - 11.45).abs() < 1e-9);
+        assert!((supertrend_3.lower - 5.55).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_matches_reference() {
+        // Reference values from an independent implementation of the published SuperTrend
+        // (final bands of the median price -/+ 3 x a 10 bar simple ATR), sampled as
+        // (bar index, value, is uptrend) across each of the trend flips in the series
+        let expected = [
+            (9, 46_356.0, true),
+            (20, 46_365.1, true),
+            (29, 46_475.6, false),
+            (40, 46_351.7, true),
+            (53, 46_452.7, false),
+            (62, 46_159.8, true),
+            (79, 46_251.3, true),
+        ];
+        let mut supertrend = SuperTrend::new(10, 3.0, None);
+
+        let mut outputs = Vec::with_capacity(REFERENCE_HIGHS.len());
+        for i in 0..REFERENCE_HIGHS.len() {
+            supertrend.update_raw(REFERENCE_HIGHS[i], REFERENCE_LOWS[i], REFERENCE_CLOSES[i]);
+            outputs.push((supertrend.value, supertrend.is_uptrend));
+        }
+
+        for (index, value, is_uptrend) in expected {
+            let (output, output_is_uptrend) = outputs[index];
+            assert!((output - value).abs() < 1e-6, "bar {index}");
+            assert_eq!(output_is_uptrend, is_uptrend, "bar {index}");
+        }
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut supertrend_3: SuperTrend, bar_ethusdt_binance_minute_bid: Bar) {
+        supertrend_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(supertrend_3.has_inputs());
+        assert!(supertrend_3.is_uptrend);
+        assert_eq!(supertrend_3.value, 1412.5);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut supertrend_3: SuperTrend) {
+        for i in 0..HIGHS.len() {
+            supertrend_3.update_raw(HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        supertrend_3.reset();
+
+        assert!(!supertrend_3.initialized());
+        assert!(!supertrend_3.has_inputs());
+        assert!(supertrend_3.is_uptrend);
+        assert_eq!(supertrend_3.value, 0.0);
+        assert_eq!(supertrend_3.upper, 0.0);
+        assert_eq!(supertrend_3.lower, 0.0);
+    }
+}
@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{
+    indicator::Indicator,
+    rolling::{sum::RollingMean, variance::RollingVariance},
+    volatility::{bars_per_year, rogers_satchell::rogers_satchell_variance, DAYS_PER_YEAR},
+};
+
+/// An indicator which calculates the Yang-Zhang realized volatility estimator over a rolling
+/// window of bars.
+///
+/// The estimator combines the overnight (previous close to open) variance, the open to close
+/// variance and the Rogers-Satchell variance, making it independent of both drift and opening
+/// gaps. The first bar only provides the previous close, so the indicator is initialized after
+/// `period + 1` bars.
+///
+/// The value is annualized using `bars_per_year` when provided, otherwise derived from the bar
+/// specification of the first handled bar (see [`bars_per_year`]).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct YangZhangVolatility {
+    pub period: usize,
+    pub days_per_year: f64,
+    pub bars_per_year: Option<f64>,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_close: f64,
+    overnight_returns: RollingVariance,
+    open_close_returns: RollingVariance,
+    rs_variances: RollingMean,
+}
+
+impl Display for YangZhangVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for YangZhangVolatility {
+    fn name(&self) -> String {
+        stringify!(YangZhangVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        if self.bars_per_year.is_none() {
+            self.bars_per_year = bars_per_year(&bar.bar_type.spec(), self.days_per_year);
+        }
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.overnight_returns.reset();
+        self.open_close_returns.reset();
+        self.rs_variances.reset();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl YangZhangVolatility {
+    /// Creates a new [`YangZhangVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `period` is less than 2.
+    #[must_use]
+    pub fn new(period: usize, bars_per_year: Option<f64>, days_per_year: Option<f64>) -> Self {
+        assert!(period >= 2, "`period` must be >= 2, was {period}");
+        Self {
+            period,
+            days_per_year: days_per_year.unwrap_or(DAYS_PER_YEAR),
+            bars_per_year,
+            value: 0.0,
+            count: 0,
+            previous_close: 0.0,
+            overnight_returns: RollingVariance::new(period),
+            open_close_returns: RollingVariance::new(period),
+            rs_variances: RollingMean::new(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update_raw(&mut self, open: f64, high: f64, low: f64, close: f64) {
+        self.count += 1;
+
+        if !self.has_inputs {
+            self.has_inputs = true;
+            self.previous_close = close;
+            return;
+        }
+
+        self.overnight_returns
+            .update((open / self.previous_close).ln());
+        self.open_close_returns.update((close / open).ln());
+        self.rs_variances
+            .update(rogers_satchell_variance(open, high, low, close));
+        self.previous_close = close;
+
+        let n = self.rs_variances.len();
+        if n < 2 {
+            return;
+        }
+
+        let n_f64 = n as f64;
+        let k = 0.34 / (1.34 + (n_f64 + 1.0) / (n_f64 - 1.0));
+        let overnight_variance = self.overnight_returns.variance();
+        let open_close_variance = self.open_close_returns.variance();
+        let rs_variance = self.rs_variances.value();
+
+        let variance = overnight_variance + k * open_close_variance + (1.0 - k) * rs_variance;
+        self.value = (variance.max(0.0) * self.bars_per_year.unwrap_or(1.0)).sqrt();
+
+        // Initialization logic
+        if !self.initialized && n >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, yang_zhang_3};
+
+    const OPENS: [f64; 5] = [100.0, 101.0, 102.5, 101.5, 103.0];
+    const HIGHS: [f64; 5] = [102.0, 103.0, 104.0, 103.5, 105.0];
+    const LOWS: [f64; 5] = [99.0, 100.5, 101.0, 100.0, 102.0];
+    const CLOSES: [f64; 5] = [101.0, 102.5, 101.5, 103.0, 104.5];
+
+    #[rstest]
+    fn test_name_returns_expected_string(yang_zhang_3: YangZhangVolatility) {
+        assert_eq!(yang_zhang_3.name(), "YangZhangVolatility");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(yang_zhang_3: YangZhangVolatility) {
+        assert_eq!(format!("{yang_zhang_3}"), "YangZhangVolatility(3)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`period` must be >= 2")]
+    fn test_new_with_period_one_panics() {
+        let _ = YangZhangVolatility::new(1, None, None);
+    }
+
+    #[rstest]
+    fn test_initialized_after_period_plus_one_inputs(mut yang_zhang_3: YangZhangVolatility) {
+        for i in 0..4 {
+            assert!(!yang_zhang_3.initialized());
+            yang_zhang_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+        assert!(yang_zhang_3.initialized());
+    }
+
+    #[rstest]
+    fn test_value_with_reference_inputs(mut yang_zhang_3: YangZhangVolatility) {
+        for i in 0..OPENS.len() {
+            yang_zhang_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        // Per bar volatility over the last three bars
+        assert!((yang_zhang_3.value - 0.020_150_783_143_368_156).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_bar_annualizes_from_bar_spec(
+        mut yang_zhang_3: YangZhangVolatility,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        yang_zhang_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert_eq!(yang_zhang_3.bars_per_year, Some(525_600.0));
+        assert!(yang_zhang_3.has_inputs());
+        assert_eq!(yang_zhang_3.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut yang_zhang_3: YangZhangVolatility,
+    ) {
+        for i in 0..OPENS.len() {
+            yang_zhang_3.update_raw(OPENS[i], HIGHS[i], LOWS[i], CLOSES[i]);
+        }
+
+        yang_zhang_3.reset();
+
+        assert!(!yang_zhang_3.initialized());
+        assert!(!yang_zhang_3.has_inputs());
+        assert_eq!(yang_zhang_3.value, 0.0);
+        assert_eq!(yang_zhang_3.count, 0);
+    }
+}
@@ -0,0 +1,869 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use futures::stream::Stream;
+use nautilus_common::{
+    msgbus::{
+        database::{BusMessage, MessageBusConfig, MessageBusDatabaseAdapter},
+        CLOSE_TOPIC,
+    },
+    runtime::get_runtime,
+};
+use nautilus_core::{
+    time::{duration_since_unix_epoch, get_atomic_clock_realtime},
+    UUID4,
+};
+use nautilus_model::identifiers::TraderId;
+
+use crate::msgbus::{await_handle, get_stream_key, run_heartbeat, STREAM_KEY_DELIMITER};
+
+const MSGBUS_PUBLISH: &str = "msgbus-publish";
+const MSGBUS_STREAM: &str = "msgbus-stream";
+const MSGBUS_HEARTBEAT: &str = "msgbus-heartbeat";
+const SEGMENT_EXTENSION: &str = "seg";
+const SEGMENT_DURATION_MS: u64 = 60_000;
+const RECORD_HEADER_LEN: usize = 16; // ts_ms (u64) + topic_len (u32) + payload_len (u32)
+const STREAM_POLL_INTERVAL_MS: u64 = 100;
+const TRIM_BUFFER_SECS: u64 = 60;
+
+/// Provides a message bus database backed by append-only segment files on the local filesystem.
+///
+/// Each stream key maps to a directory under the configured database `path` (one directory level
+/// per `:` delimited key component). Messages are appended to segment files which roll over at
+/// most once per minute, and `autotrim_mins` removes whole segments which fall outside the
+/// lookback window.
+///
+/// Entries in `types_filter` are matched against the topic, and each of its `.` delimited
+/// components, with matching messages not persisted.
+pub struct FileMessageBusDatabase {
+    /// The trader ID for this message bus database.
+    pub trader_id: TraderId,
+    /// The instance ID for this message bus database.
+    pub instance_id: UUID4,
+    pub_tx: tokio::sync::mpsc::UnboundedSender<BusMessage>,
+    pub_handle: Option<tokio::task::JoinHandle<()>>,
+    stream_rx: Option<tokio::sync::mpsc::Receiver<BusMessage>>,
+    stream_handle: Option<tokio::task::JoinHandle<()>>,
+    stream_signal: Arc<AtomicBool>,
+    heartbeat_handle: Option<tokio::task::JoinHandle<()>>,
+    heartbeat_signal: Arc<AtomicBool>,
+}
+
+impl MessageBusDatabaseAdapter for FileMessageBusDatabase {
+    type DatabaseType = FileMessageBusDatabase;
+
+    /// Creates a new [`FileMessageBusDatabase`] instance.
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: MessageBusConfig,
+    ) -> anyhow::Result<Self> {
+        let base_dir = get_base_dir(&config)?;
+        let base_dir_clone = base_dir.clone();
+        let config_clone = config.clone();
+
+        let (pub_tx, pub_rx) = tokio::sync::mpsc::unbounded_channel::<BusMessage>();
+
+        // Create publish task
+        let pub_handle = Some(get_runtime().spawn(async move {
+            publish_messages(pub_rx, trader_id, instance_id, base_dir_clone, config_clone)
+                .await
+                .expect("Error spawning task '{MSGBUS_PUBLISH}'");
+        }));
+
+        // Conditionally create stream task and channel if external streams configured
+        let external_streams = config.external_streams.clone().unwrap_or_default();
+        let stream_signal = Arc::new(AtomicBool::new(false));
+        let (stream_rx, stream_handle) = if !external_streams.is_empty() {
+            let stream_signal_clone = stream_signal.clone();
+            let (stream_tx, stream_rx) = tokio::sync::mpsc::channel::<BusMessage>(100_000);
+            (
+                Some(stream_rx),
+                Some(get_runtime().spawn(async move {
+                    stream_messages(stream_tx, base_dir, external_streams, stream_signal_clone)
+                        .await
+                        .expect("Error spawning task '{MSGBUS_STREAM}'");
+                })),
+            )
+        } else {
+            (None, None)
+        };
+
+        // Create heartbeat task
+        let heartbeat_signal = Arc::new(AtomicBool::new(false));
+        let heartbeat_handle = if let Some(heartbeat_interval_secs) = config.heartbeat_interval_secs
+        {
+            let signal = heartbeat_signal.clone();
+            let pub_tx_clone = pub_tx.clone();
+
+            Some(get_runtime().spawn(async move {
+                run_heartbeat(heartbeat_interval_secs, signal, pub_tx_clone).await
+            }))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            trader_id,
+            instance_id,
+            pub_tx,
+            pub_handle,
+            stream_rx,
+            stream_handle,
+            stream_signal,
+            heartbeat_handle,
+            heartbeat_signal,
+        })
+    }
+
+    /// Returns whether the message bus database adapter publishing channel is closed.
+    fn is_closed(&self) -> bool {
+        self.pub_tx.is_closed()
+    }
+
+    /// Publishes a message with the given `topic` and `payload`.
+    fn publish(&self, topic: String, payload: Bytes) {
+        let msg = BusMessage { topic, payload };
+        if let Err(e) = self.pub_tx.send(msg) {
+            log::error!("Failed to send message: {e}");
+        }
+    }
+
+    /// Closes the message bus database adapter.
+    fn close(&mut self) {
+        log::debug!("Closing");
+
+        self.stream_signal.store(true, Ordering::Relaxed);
+        self.heartbeat_signal.store(true, Ordering::Relaxed);
+
+        if !self.pub_tx.is_closed() {
+            let msg = BusMessage {
+                topic: CLOSE_TOPIC.to_string(),
+                payload: Bytes::new(), // Empty
+            };
+            if let Err(e) = self.pub_tx.send(msg) {
+                log::error!("Failed to send close message: {e:?}");
+            }
+        }
+
+        // Keep close sync for now to avoid async trait method
+        tokio::task::block_in_place(|| {
+            get_runtime().block_on(async {
+                self.close_async().await;
+            });
+        });
+
+        log::debug!("Closed");
+    }
+}
+
+impl FileMessageBusDatabase {
+    /// Gets the stream receiver for this instance.
+    pub fn get_stream_receiver(
+        &mut self,
+    ) -> anyhow::Result<tokio::sync::mpsc::Receiver<BusMessage>> {
+        self.stream_rx
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("Stream receiver already taken"))
+    }
+
+    /// Streams messages arriving on the stream receiver channel.
+    pub fn stream(
+        mut stream_rx: tokio::sync::mpsc::Receiver<BusMessage>,
+    ) -> impl Stream<Item = BusMessage> + 'static {
+        async_stream::stream! {
+            while let Some(msg) = stream_rx.recv().await {
+                yield msg;
+            }
+        }
+    }
+
+    pub async fn close_async(&mut self) {
+        await_handle(self.pub_handle.take(), MSGBUS_PUBLISH).await;
+        await_handle(self.stream_handle.take(), MSGBUS_STREAM).await;
+        await_handle(self.heartbeat_handle.take(), MSGBUS_HEARTBEAT).await;
+    }
+}
+
+/// Returns the directory holding the segment files for the given `stream_key`.
+#[must_use]
+pub fn get_stream_path(base_dir: &Path, stream_key: &str) -> PathBuf {
+    let mut path = base_dir.to_path_buf();
+    for component in stream_key.split(STREAM_KEY_DELIMITER) {
+        path.push(sanitize_path_component(component));
+    }
+    path
+}
+
+/// Reads all messages currently persisted for the given `stream_key`, in publish order.
+pub fn read_stream(base_dir: &Path, stream_key: &str) -> anyhow::Result<Vec<BusMessage>> {
+    let mut messages = Vec::new();
+    for (_, path) in list_segments(&get_stream_path(base_dir, stream_key))? {
+        let (records, _) = read_records(&path, 0)?;
+        messages.extend(records.into_iter().map(|(_, msg)| msg));
+    }
+    Ok(messages)
+}
+
+pub async fn publish_messages(
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<BusMessage>,
+    trader_id: TraderId,
+    instance_id: UUID4,
+    base_dir: PathBuf,
+    config: MessageBusConfig,
+) -> anyhow::Result<()> {
+    tracing::debug!("Starting message publishing");
+
+    let stream_key = get_stream_key(trader_id, instance_id, &config);
+    let mut writer = StreamWriter::new(base_dir, stream_key, &config);
+
+    // Buffering
+    let mut buffer: VecDeque<BusMessage> = VecDeque::new();
+    let mut last_drain = Instant::now();
+    let buffer_interval = Duration::from_millis(config.buffer_interval_ms.unwrap_or(0) as u64);
+
+    loop {
+        if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            writer.drain_buffer(&mut buffer)?;
+            last_drain = Instant::now();
+        } else {
+            match rx.recv().await {
+                Some(msg) => {
+                    if msg.topic == CLOSE_TOPIC {
+                        tracing::debug!("Received close message");
+                        drop(rx);
+                        break;
+                    }
+                    buffer.push_back(msg);
+                }
+                None => {
+                    tracing::debug!("Channel hung up");
+                    break;
+                }
+            }
+        }
+    }
+
+    // Drain any remaining messages
+    if !buffer.is_empty() {
+        writer.drain_buffer(&mut buffer)?;
+    }
+
+    tracing::debug!("Stopped message publishing");
+    Ok(())
+}
+
+pub async fn stream_messages(
+    tx: tokio::sync::mpsc::Sender<BusMessage>,
+    base_dir: PathBuf,
+    stream_keys: Vec<String>,
+    stream_signal: Arc<AtomicBool>,
+) -> anyhow::Result<()> {
+    tracing::info!("Starting message streaming");
+    tracing::debug!("Listening to streams: [{}]", stream_keys.join(", "));
+
+    let mut cursors: Vec<StreamCursor> = stream_keys
+        .iter()
+        .map(|stream_key| StreamCursor::new(get_stream_path(&base_dir, stream_key)))
+        .collect();
+
+    // Start streaming from current timestamp
+    let clock = get_atomic_clock_realtime();
+    let from_ts_ms = clock.get_time_ms();
+    let poll_interval = Duration::from_millis(STREAM_POLL_INTERVAL_MS);
+
+    'outer: loop {
+        if stream_signal.load(Ordering::Relaxed) {
+            tracing::debug!("Received streaming terminate signal");
+            break;
+        }
+
+        for cursor in &mut cursors {
+            for msg in cursor.poll(from_ts_ms)? {
+                if let Err(e) = tx.send(msg).await {
+                    tracing::debug!("Channel closed: {e:?}");
+                    break 'outer; // End streaming
+                }
+            }
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+
+    tracing::debug!("Stopped message streaming");
+    Ok(())
+}
+
+/// Writes messages to the segment files of one or more streams.
+struct StreamWriter {
+    base_dir: PathBuf,
+    stream_key: String,
+    stream_per_topic: bool,
+    autotrim_duration: Option<Duration>,
+    types_filter: Vec<String>,
+    segments: HashMap<String, SegmentWriter>,
+    last_trim_index: HashMap<String, u64>,
+}
+
+impl StreamWriter {
+    fn new(base_dir: PathBuf, stream_key: String, config: &MessageBusConfig) -> Self {
+        Self {
+            base_dir,
+            stream_key,
+            stream_per_topic: config.stream_per_topic,
+            autotrim_duration: config
+                .autotrim_mins
+                .filter(|&mins| mins > 0)
+                .map(|mins| Duration::from_secs(mins as u64 * 60)),
+            types_filter: config.types_filter.clone().unwrap_or_default(),
+            segments: HashMap::new(),
+            last_trim_index: HashMap::new(),
+        }
+    }
+
+    fn drain_buffer(&mut self, buffer: &mut VecDeque<BusMessage>) -> anyhow::Result<()> {
+        let clock = get_atomic_clock_realtime();
+
+        for msg in buffer.drain(..) {
+            if is_filtered(&msg.topic, &self.types_filter) {
+                continue;
+            }
+
+            let stream_key = match self.stream_per_topic {
+                true => format!("{}{STREAM_KEY_DELIMITER}{}", self.stream_key, &msg.topic),
+                false => self.stream_key.clone(),
+            };
+
+            let segment = self.segments.entry(stream_key.clone()).or_insert_with(|| {
+                SegmentWriter::new(get_stream_path(&self.base_dir, &stream_key))
+            });
+            segment.append(clock.get_time_ms(), &msg)?;
+
+            let Some(autotrim_duration) = self.autotrim_duration else {
+                continue; // Nothing else to do
+            };
+
+            // Autotrim stream
+            let last_trim_ms = self.last_trim_index.entry(stream_key).or_insert(0);
+            let unix_duration_now = duration_since_unix_epoch();
+            let trim_buffer = Duration::from_secs(TRIM_BUFFER_SECS);
+
+            if *last_trim_ms < (unix_duration_now - trim_buffer).as_millis() as u64 {
+                let min_timestamp_ms = (unix_duration_now - autotrim_duration).as_millis() as u64;
+                match trim_segments(&segment.dir, min_timestamp_ms) {
+                    Ok(_) => *last_trim_ms = unix_duration_now.as_millis() as u64,
+                    Err(e) => tracing::error!("Error trimming stream {:?}: {e}", segment.dir),
+                }
+            }
+        }
+
+        for segment in self.segments.values_mut() {
+            segment.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends records to the current segment file of a single stream.
+struct SegmentWriter {
+    dir: PathBuf,
+    segment_start_ms: u64,
+    writer: Option<BufWriter<File>>,
+}
+
+impl SegmentWriter {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            segment_start_ms: 0,
+            writer: None,
+        }
+    }
+
+    fn append(&mut self, ts_ms: u64, msg: &BusMessage) -> anyhow::Result<()> {
+        if self.writer.is_none() || ts_ms >= self.segment_start_ms + SEGMENT_DURATION_MS {
+            self.roll(ts_ms)?;
+        }
+
+        let writer = self
+            .writer
+            .as_mut()
+            .expect("Segment writer not initialized");
+        writer.write_all(&encode_record(ts_ms, msg))?;
+        Ok(())
+    }
+
+    fn roll(&mut self, ts_ms: u64) -> anyhow::Result<()> {
+        self.flush()?;
+        fs::create_dir_all(&self.dir)?;
+
+        let path = self.dir.join(format!("{ts_ms:020}.{SEGMENT_EXTENSION}"));
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.writer = Some(BufWriter::new(file));
+        self.segment_start_ms = ts_ms;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Tracks the read position within the segment files of a single stream.
+struct StreamCursor {
+    dir: PathBuf,
+    segment_start_ms: Option<u64>,
+    offset: u64,
+}
+
+impl StreamCursor {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            segment_start_ms: None,
+            offset: 0,
+        }
+    }
+
+    fn poll(&mut self, from_ts_ms: u64) -> anyhow::Result<Vec<BusMessage>> {
+        let mut messages = Vec::new();
+
+        for (start_ms, path) in list_segments(&self.dir)? {
+            match self.segment_start_ms {
+                Some(current) if start_ms < current => continue, // Already read
+                Some(current) if start_ms == current => {}
+                _ => {
+                    self.segment_start_ms = Some(start_ms);
+                    self.offset = 0;
+                }
+            }
+
+            let (records, consumed) = read_records(&path, self.offset)?;
+            self.offset += consumed as u64;
+            messages.extend(
+                records
+                    .into_iter()
+                    .filter(|(ts_ms, _)| *ts_ms >= from_ts_ms)
+                    .map(|(_, msg)| msg),
+            );
+        }
+
+        Ok(messages)
+    }
+}
+
+fn get_base_dir(config: &MessageBusConfig) -> anyhow::Result<PathBuf> {
+    match config.database.as_ref().and_then(|db| db.path.as_ref()) {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+fn sanitize_path_component(component: &str) -> String {
+    match component {
+        "" | "." | ".." => "_".to_string(),
+        _ => component.replace(['/', '\\'], "_"),
+    }
+}
+
+fn is_filtered(topic: &str, types_filter: &[String]) -> bool {
+    types_filter
+        .iter()
+        .any(|filter| filter == topic || topic.split('.').any(|component| component == filter))
+}
+
+fn encode_record(ts_ms: u64, msg: &BusMessage) -> Vec<u8> {
+    let topic = msg.topic.as_bytes();
+    let mut record = Vec::with_capacity(RECORD_HEADER_LEN + topic.len() + msg.payload.len());
+    record.extend_from_slice(&ts_ms.to_le_bytes());
+    record.extend_from_slice(&(topic.len() as u32).to_le_bytes());
+    record.extend_from_slice(&(msg.payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(topic);
+    record.extend_from_slice(&msg.payload);
+    record
+}
+
+/// Decodes the complete records in `buf`, returning them with the number of bytes consumed
+/// (a trailing partial record is left for a later read).
+fn decode_records(buf: &[u8]) -> anyhow::Result<(Vec<(u64, BusMessage)>, usize)> {
+    let mut records = Vec::new();
+    let mut pos = 0;
+
+    while buf.len() - pos >= RECORD_HEADER_LEN {
+        let header = &buf[pos..pos + RECORD_HEADER_LEN];
+        let ts_ms = u64::from_le_bytes(header[0..8].try_into()?);
+        let topic_len = u32::from_le_bytes(header[8..12].try_into()?) as usize;
+        let payload_len = u32::from_le_bytes(header[12..16].try_into()?) as usize;
+
+        let topic_start = pos + RECORD_HEADER_LEN;
+        let payload_start = topic_start + topic_len;
+        let end = payload_start + payload_len;
+        if end > buf.len() {
+            break; // Partial record
+        }
+
+        let topic = std::str::from_utf8(&buf[topic_start..payload_start])
+            .map_err(|e| anyhow::anyhow!("Invalid topic in record at offset {pos}: {e}"))?
+            .to_string();
+        let payload = Bytes::copy_from_slice(&buf[payload_start..end]);
+
+        records.push((ts_ms, BusMessage { topic, payload }));
+        pos = end;
+    }
+
+    Ok((records, pos))
+}
+
+fn read_records(path: &Path, offset: u64) -> anyhow::Result<(Vec<(u64, BusMessage)>, usize)> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((Vec::new(), 0)),
+        Err(e) => return Err(e.into()),
+    };
+    file.seek(SeekFrom::Start(offset))?;
+
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    decode_records(&buf)
+}
+
+/// Returns the segment files in `dir` with their start timestamps, in ascending order.
+fn list_segments(dir: &Path) -> anyhow::Result<Vec<(u64, PathBuf)>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut segments = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(SEGMENT_EXTENSION) {
+            continue;
+        }
+        if let Some(start_ms) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u64>().ok())
+        {
+            segments.push((start_ms, path));
+        }
+    }
+
+    segments.sort_unstable_by_key(|(start_ms, _)| *start_ms);
+    Ok(segments)
+}
+
+/// Removes the segments in `dir` which only hold records older than `min_timestamp_ms`,
+/// returning the number of segments removed (the latest segment is always retained).
+fn trim_segments(dir: &Path, min_timestamp_ms: u64) -> anyhow::Result<usize> {
+    let segments = list_segments(dir)?;
+    let mut removed = 0;
+
+    for window in segments.windows(2) {
+        let (_, path) = &window[0];
+        let (next_start_ms, _) = window[1];
+        if next_start_ms > min_timestamp_ms {
+            break;
+        }
+        fs::remove_file(path)?;
+        removed += 1;
+    }
+
+    Ok(removed)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{msgbus::database::DatabaseConfig, testing::wait_until};
+    use rstest::*;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn message(topic: &str, payload: &'static str) -> BusMessage {
+        BusMessage {
+            topic: topic.to_string(),
+            payload: Bytes::from(payload),
+        }
+    }
+
+    fn config_with_path(dir: &TempDir) -> MessageBusConfig {
+        MessageBusConfig {
+            database: Some(DatabaseConfig {
+                database_type: "file".to_string(),
+                path: Some(dir.path().to_string_lossy().to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_encode_decode_records_round_trip() {
+        let mut buf = encode_record(1, &message("topic1", "data1"));
+        buf.extend(encode_record(2, &message("topic2", "data2")));
+
+        let (records, consumed) = decode_records(&buf).unwrap();
+
+        assert_eq!(consumed, buf.len());
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, 1);
+        assert_eq!(records[0].1.topic, "topic1");
+        assert_eq!(records[0].1.payload, Bytes::from("data1"));
+        assert_eq!(records[1].0, 2);
+        assert_eq!(records[1].1.topic, "topic2");
+    }
+
+    #[rstest]
+    fn test_decode_records_with_partial_record() {
+        let first = encode_record(1, &message("topic1", "data1"));
+        let mut buf = first.clone();
+        let second = encode_record(2, &message("topic2", "data2"));
+        buf.extend_from_slice(&second[..second.len() - 1]);
+
+        let (records, consumed) = decode_records(&buf).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(consumed, first.len());
+    }
+
+    #[rstest]
+    fn test_decode_records_invalid_topic() {
+        let mut buf = encode_record(1, &message("ab", "data"));
+        buf[RECORD_HEADER_LEN] = 0xFF;
+
+        assert!(decode_records(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_get_stream_path() {
+        let path = get_stream_path(Path::new("/tmp"), "trader-tester-001:stream:data/quotes");
+        assert_eq!(
+            path,
+            PathBuf::from("/tmp/trader-tester-001/stream/data_quotes")
+        );
+    }
+
+    #[rstest]
+    #[case("data.quotes.BINANCE.BTCUSDT", vec![], false)]
+    #[case("data.quotes.BINANCE.BTCUSDT", vec!["quotes"], true)]
+    #[case("data.quotes.BINANCE.BTCUSDT", vec!["trades"], false)]
+    #[case("events.order.S-001", vec!["events.order.S-001"], true)]
+    fn test_is_filtered(#[case] topic: &str, #[case] filter: Vec<&str>, #[case] expected: bool) {
+        let types_filter: Vec<String> = filter.into_iter().map(String::from).collect();
+        assert_eq!(is_filtered(topic, &types_filter), expected);
+    }
+
+    #[rstest]
+    fn test_segment_writer_rolls_and_trims_segments() {
+        let dir = TempDir::new().unwrap();
+        let mut writer = SegmentWriter::new(dir.path().to_path_buf());
+
+        writer.append(0, &message("topic", "a")).unwrap();
+        writer.append(1, &message("topic", "b")).unwrap();
+        writer
+            .append(SEGMENT_DURATION_MS, &message("topic", "c"))
+            .unwrap();
+        writer
+            .append(SEGMENT_DURATION_MS * 2, &message("topic", "d"))
+            .unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(list_segments(dir.path()).unwrap().len(), 3);
+
+        let removed = trim_segments(dir.path(), SEGMENT_DURATION_MS * 2).unwrap();
+        let (records, _) = read_records(&list_segments(dir.path()).unwrap()[0].1, 0).unwrap();
+
+        assert_eq!(removed, 2);
+        assert_eq!(list_segments(dir.path()).unwrap().len(), 1);
+        assert_eq!(records[0].1.payload, Bytes::from("d"));
+    }
+
+    #[rstest]
+    fn test_stream_cursor_reads_appended_records_once() {
+        let dir = TempDir::new().unwrap();
+        let mut writer = SegmentWriter::new(dir.path().to_path_buf());
+        let mut cursor = StreamCursor::new(dir.path().to_path_buf());
+
+        writer.append(10, &message("topic", "a")).unwrap();
+        writer.flush().unwrap();
+        let first = cursor.poll(0).unwrap();
+
+        writer.append(11, &message("topic", "b")).unwrap();
+        writer
+            .append(SEGMENT_DURATION_MS + 10, &message("topic", "c"))
+            .unwrap();
+        writer.flush().unwrap();
+        let second = cursor.poll(0).unwrap();
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(
+            second
+                .iter()
+                .map(|msg| msg.payload.clone())
+                .collect::<Vec<_>>(),
+            vec![Bytes::from("b"), Bytes::from("c")]
+        );
+        assert!(cursor.poll(0).unwrap().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_publish_messages() {
+        let dir = TempDir::new().unwrap();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<BusMessage>();
+
+        let trader_id = TraderId::from("tester-001");
+        let instance_id = UUID4::new();
+        let mut config = config_with_path(&dir);
+        config.stream_per_topic = false;
+        config.types_filter = Some(vec!["filtered".to_string()]);
+        let stream_key = get_stream_key(trader_id, instance_id, &config);
+        let base_dir = dir.path().to_path_buf();
+
+        // Start the publish_messages task
+        let handle = tokio::spawn(async move {
+            publish_messages(rx, trader_id, instance_id, base_dir, config)
+                .await
+                .unwrap();
+        });
+
+        tx.send(message("test_topic", "test_payload")).unwrap();
+        tx.send(message("data.filtered", "skipped")).unwrap();
+
+        // Stop publishing task
+        tx.send(message(CLOSE_TOPIC, "")).unwrap();
+        handle.await.unwrap();
+
+        let messages = read_stream(dir.path(), &stream_key).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].topic, "test_topic");
+        assert_eq!(messages[0].payload, Bytes::from("test_payload"));
+    }
+
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_publish_messages_stream_per_topic() {
+        let dir = TempDir::new().unwrap();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<BusMessage>();
+
+        let trader_id = TraderId::from("tester-001");
+        let instance_id = UUID4::new();
+        let config = config_with_path(&dir);
+        let stream_key = get_stream_key(trader_id, instance_id, &config);
+        let base_dir = dir.path().to_path_buf();
+
+        let handle = tokio::spawn(async move {
+            publish_messages(rx, trader_id, instance_id, base_dir, config)
+                .await
+                .unwrap();
+        });
+
+        tx.send(message("topic1", "data1")).unwrap();
+        tx.send(message("topic2", "data2")).unwrap();
+        tx.send(message(CLOSE_TOPIC, "")).unwrap();
+        handle.await.unwrap();
+
+        let topic1 = read_stream(dir.path(), &format!("{stream_key}:topic1")).unwrap();
+        let topic2 = read_stream(dir.path(), &format!("{stream_key}:topic2")).unwrap();
+        assert_eq!(topic1.len(), 1);
+        assert_eq!(topic1[0].payload, Bytes::from("data1"));
+        assert_eq!(topic2.len(), 1);
+        assert_eq!(topic2[0].payload, Bytes::from("data2"));
+    }
+
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stream_messages() {
+        let dir = TempDir::new().unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<BusMessage>(100);
+
+        let stream_key = "trader-tester-001:stream".to_string();
+        let stream_signal = Arc::new(AtomicBool::new(false));
+        let stream_signal_clone = stream_signal.clone();
+        let base_dir = dir.path().to_path_buf();
+        let external_streams = vec![stream_key.clone()];
+
+        // Start the message streaming task
+        let handle = tokio::spawn(async move {
+            stream_messages(tx, base_dir, external_streams, stream_signal_clone)
+                .await
+                .unwrap();
+        });
+
+        // Use a timestamp in the future, as streaming begins
+        // around the timestamp the task is spawned.
+        let clock = get_atomic_clock_realtime();
+        let mut writer = SegmentWriter::new(get_stream_path(dir.path(), &stream_key));
+        writer
+            .append(clock.get_time_ms() + 1_000_000, &message("topic1", "data1"))
+            .unwrap();
+        writer.flush().unwrap();
+
+        // Receive and verify the message
+        let msg = rx.recv().await.unwrap();
+        assert_eq!(msg.topic, "topic1");
+        assert_eq!(msg.payload, Bytes::from("data1"));
+
+        // Shutdown
+        rx.close();
+        stream_signal.store(true, Ordering::Relaxed);
+        handle.await.unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_publish_and_close() {
+        let dir = TempDir::new().unwrap();
+        let trader_id = TraderId::from("tester-001");
+        let instance_id = UUID4::new();
+        let mut config = config_with_path(&dir);
+        config.stream_per_topic = false;
+        let stream_key = get_stream_key(trader_id, instance_id, &config);
+
+        let mut db = FileMessageBusDatabase::new(trader_id, instance_id, config).unwrap();
+        db.publish("topic1".to_string(), Bytes::from("data1"));
+
+        wait_until(
+            || !read_stream(dir.path(), &stream_key).unwrap().is_empty(),
+            Duration::from_secs(2),
+        );
+
+        // Close the message bus database (test should not hang)
+        db.close();
+        assert!(db.is_closed());
+    }
+}
@@ -28,6 +28,9 @@
 //! - `redis`: Enables the Redis cache database and message bus backing implementations.
 //! - `sql`: Enables the SQL models and cache database.
 
+pub mod file;
+mod msgbus;
+
 #[cfg(feature = "python")]
 pub mod python;
 
@@ -0,0 +1,119 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Common components shared by the `MessageBusDatabase` backing implementations.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bytes::Bytes;
+use nautilus_common::msgbus::database::{BusMessage, MessageBusConfig};
+use nautilus_core::UUID4;
+use nautilus_model::identifiers::TraderId;
+
+pub(crate) const HEARTBEAT_TOPIC: &str = "health:heartbeat";
+pub(crate) const STREAM_KEY_DELIMITER: char = ':';
+
+/// Parse the stream key from the given identifiers and config.
+pub fn get_stream_key(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &MessageBusConfig,
+) -> String {
+    let mut stream_key = String::new();
+
+    if config.use_trader_prefix {
+        stream_key.push_str("trader-");
+    }
+
+    if config.use_trader_id {
+        stream_key.push_str(trader_id.as_str());
+        stream_key.push(STREAM_KEY_DELIMITER);
+    }
+
+    if config.use_instance_id {
+        stream_key.push_str(&format!("{instance_id}"));
+        stream_key.push(STREAM_KEY_DELIMITER);
+    }
+
+    stream_key.push_str(&config.streams_prefix);
+    stream_key
+}
+
+pub(crate) async fn await_handle(handle: Option<tokio::task::JoinHandle<()>>, task_name: &str) {
+    if let Some(handle) = handle {
+        tracing::debug!("Awaiting task '{task_name}'");
+        let timeout = Duration::from_secs(2);
+        match tokio::time::timeout(timeout, handle).await {
+            Ok(result) => {
+                if let Err(e) = result {
+                    log::error!("Error awaiting task '{task_name}': {e:?}");
+                }
+            }
+            Err(_) => {
+                log::error!("Timeout {timeout:?} awaiting task '{task_name}'");
+            }
+        }
+    }
+}
+
+pub(crate) async fn run_heartbeat(
+    heartbeat_interval_secs: u16,
+    signal: Arc<AtomicBool>,
+    pub_tx: tokio::sync::mpsc::UnboundedSender<BusMessage>,
+) {
+    tracing::debug!("Starting heartbeat at {heartbeat_interval_secs} second intervals");
+
+    let heartbeat_interval = Duration::from_secs(heartbeat_interval_secs as u64);
+    let heartbeat_timer = tokio::time::interval(heartbeat_interval);
+
+    let check_interval = Duration::from_millis(100);
+    let check_timer = tokio::time::interval(check_interval);
+
+    tokio::pin!(heartbeat_timer);
+    tokio::pin!(check_timer);
+
+    loop {
+        if signal.load(Ordering::Relaxed) {
+            tracing::debug!("Received heartbeat terminate signal");
+            break;
+        }
+
+        tokio::select! {
+            _ = heartbeat_timer.tick() => {
+                let heartbeat = create_heartbeat_msg();
+                if let Err(e) = pub_tx.send(heartbeat) {
+                    // We expect an error if the channel is closed during shutdown
+                    tracing::debug!("Error sending heartbeat: {e}");
+                }
+            },
+            _ = check_timer.tick() => {}
+        }
+    }
+
+    tracing::debug!("Stopped heartbeat");
+}
+
+fn create_heartbeat_msg() -> BusMessage {
+    BusMessage {
+        topic: HEARTBEAT_TOPIC.to_string(),
+        payload: Bytes::from(chrono::Utc::now().to_rfc3339().into_bytes()),
+    }
+}
@@ -16,6 +16,7 @@
 use std::{
     collections::{HashMap, VecDeque},
     str::FromStr,
+    sync::Mutex,
     time::{Duration, Instant},
 };
 
@@ -27,12 +28,18 @@ use nautilus_common::{
     runtime::get_runtime,
     signal::Signal,
 };
-use nautilus_core::{correctness::check_slice_not_empty, UnixNanos, UUID4};
+use nautilus_core::{
+    correctness::check_slice_not_empty, datetime::unix_nanos_to_iso8601, UnixNanos, UUID4,
+};
 use nautilus_cryptography::providers::install_cryptographic_provider;
 use nautilus_model::{
     accounts::AccountAny,
     data::{Bar, DataType, QuoteTick, TradeTick},
-    events::{position::snapshot::PositionSnapshot, OrderEventAny, OrderSnapshot},
+    enums::CurrencyType,
+    events::{
+        position::snapshot::PositionSnapshot, AccountState, OrderEventAny, OrderFilled,
+        OrderSnapshot,
+    },
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, InstrumentId, PositionId, StrategyId,
         TraderId, VenueOrderId,
@@ -44,6 +51,7 @@ use nautilus_model::{
     types::Currency,
 };
 use redis::{Commands, Connection, Pipeline, RedisError};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{REDIS_DELIMITER, REDIS_FLUSHDB};
@@ -70,6 +78,11 @@ const STRATEGIES: &str = "strategies";
 const SNAPSHOTS: &str = "snapshots";
 const HEALTH: &str = "health";
 
+// Snapshot and health keys
+const SNAPSHOTS_ORDERS: &str = "snapshots:orders";
+const SNAPSHOTS_POSITIONS: &str = "snapshots:positions";
+const HEARTBEAT: &str = "health:heartbeat";
+
 // Index keys
 const INDEX_ORDER_IDS: &str = "index:order_ids";
 const INDEX_ORDER_POSITION: &str = "index:order_position";
@@ -132,7 +145,7 @@ impl DatabaseCommand {
 pub struct RedisCacheDatabase {
     pub trader_id: TraderId,
     trader_key: String,
-    con: Connection,
+    con: Mutex<Connection>,
     tx: tokio::sync::mpsc::UnboundedSender<DatabaseCommand>,
     handle: tokio::task::JoinHandle<()>,
 }
@@ -165,7 +178,7 @@ impl RedisCacheDatabase {
         Ok(RedisCacheDatabase {
             trader_id,
             trader_key,
-            con,
+            con: Mutex::new(con),
             tx,
             handle,
         })
@@ -189,37 +202,53 @@ impl RedisCacheDatabase {
     }
 
     pub fn flushdb(&mut self) {
-        if let Err(e) = redis::cmd(REDIS_FLUSHDB).query::<()>(&mut self.con) {
+        let con = match self.con.get_mut() {
+            Ok(con) => con,
+            Err(e) => {
+                log::error!("Failed to acquire connection: {e}");
+                return;
+            }
+        };
+        if let Err(e) = redis::cmd(REDIS_FLUSHDB).query::<()>(con) {
             log::error!("Failed to flush database: {e:?}");
         }
     }
 
-    pub fn keys(&mut self, pattern: &str) -> anyhow::Result<Vec<String>> {
+    pub fn keys(&self, pattern: &str) -> anyhow::Result<Vec<String>> {
         let pattern = format!("{}{REDIS_DELIMITER}{pattern}", self.trader_key);
         log::debug!("Querying keys: {pattern}");
-        Ok(scan_keys(&mut self.con, pattern)?)
+        Ok(scan_keys(&mut *self.lock_con()?, pattern)?)
     }
 
-    pub fn read(&mut self, key: &str) -> anyhow::Result<Vec<Bytes>> {
+    pub fn read(&self, key: &str) -> anyhow::Result<Vec<Bytes>> {
         let collection = get_collection_key(key)?;
         let key = format!("{}{REDIS_DELIMITER}{}", self.trader_key, key);
+        let mut con = self.lock_con()?;
 
         match collection {
-            INDEX => read_index(&mut self.con, &key),
-            GENERAL => read_string(&mut self.con, &key),
-            CURRENCIES => read_string(&mut self.con, &key),
-            INSTRUMENTS => read_string(&mut self.con, &key),
-            SYNTHETICS => read_string(&mut self.con, &key),
-            ACCOUNTS => read_list(&mut self.con, &key),
-            ORDERS => read_list(&mut self.con, &key),
-            POSITIONS => read_list(&mut self.con, &key),
-            ACTORS => read_string(&mut self.con, &key),
-            STRATEGIES => read_string(&mut self.con, &key),
+            INDEX => read_index(&mut con, &key),
+            GENERAL => read_string(&mut con, &key),
+            CURRENCIES => read_string(&mut con, &key),
+            INSTRUMENTS => read_string(&mut con, &key),
+            SYNTHETICS => read_string(&mut con, &key),
+            ACCOUNTS => read_list(&mut con, &key),
+            ORDERS => read_list(&mut con, &key),
+            POSITIONS => read_list(&mut con, &key),
+            ACTORS => read_string(&mut con, &key),
+            STRATEGIES => read_string(&mut con, &key),
+            SNAPSHOTS => read_list(&mut con, &key),
+            HEALTH => read_string(&mut con, &key),
             _ => anyhow::bail!("Unsupported operation: `read` for collection '{collection}'"),
         }
     }
 
-    pub fn insert(&mut self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
+    fn lock_con(&self) -> anyhow::Result<std::sync::MutexGuard<'_, Connection>> {
+        self.con
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to acquire connection: {e}"))
+    }
+
+    pub fn insert(&self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
         let op = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -227,7 +256,7 @@ impl RedisCacheDatabase {
         }
     }
 
-    pub fn update(&mut self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
+    pub fn update(&self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
         let op = DatabaseCommand::new(DatabaseOperation::Update, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -235,7 +264,7 @@ impl RedisCacheDatabase {
         }
     }
 
-    pub fn delete(&mut self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
+    pub fn delete(&self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
         let op = DatabaseCommand::new(DatabaseOperation::Delete, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -346,7 +375,7 @@ fn scan_keys(con: &mut Connection, pattern: String) -> Result<Vec<String>, Redis
 fn read_index(conn: &mut Connection, key: &str) -> anyhow::Result<Vec<Bytes>> {
     let index_key = get_index_key(key)?;
     match index_key {
-        INDEX_ORDER_IDS => read_set(conn, key),
+        INDEX_ORDER_IDS => read_hset(conn, key),
         INDEX_ORDER_POSITION => read_hset(conn, key),
         INDEX_ORDER_CLIENT => read_hset(conn, key),
         INDEX_ORDERS => read_set(conn, key),
@@ -449,7 +478,7 @@ fn insert_index(pipe: &mut Pipeline, key: &str, value: &[Bytes]) -> anyhow::Resu
     let index_key = get_index_key(key)?;
     match index_key {
         INDEX_ORDER_IDS => {
-            insert_set(pipe, key, value[0].as_ref());
+            insert_hset(pipe, key, value[0].as_ref(), value[1].as_ref());
             Ok(())
         }
         INDEX_ORDER_POSITION => {
@@ -549,6 +578,14 @@ fn delete(
 ) -> anyhow::Result<()> {
     match collection {
         INDEX => remove_index(pipe, key, value),
+        ORDERS => {
+            delete_string(pipe, key);
+            Ok(())
+        }
+        POSITIONS => {
+            delete_string(pipe, key);
+            Ok(())
+        }
         ACTORS => {
             delete_string(pipe, key);
             Ok(())
@@ -566,6 +603,22 @@ fn remove_index(pipe: &mut Pipeline, key: &str, value: Option<Vec<Bytes>>) -> an
     let index_key = get_index_key(key)?;
 
     match index_key {
+        INDEX_ORDER_IDS => {
+            remove_from_hset(pipe, key, value[0].as_ref());
+            Ok(())
+        }
+        INDEX_ORDER_POSITION => {
+            remove_from_hset(pipe, key, value[0].as_ref());
+            Ok(())
+        }
+        INDEX_ORDER_CLIENT => {
+            remove_from_hset(pipe, key, value[0].as_ref());
+            Ok(())
+        }
+        INDEX_ORDERS => {
+            remove_from_set(pipe, key, value[0].as_ref());
+            Ok(())
+        }
         INDEX_ORDERS_OPEN => {
             remove_from_set(pipe, key, value[0].as_ref());
             Ok(())
@@ -582,6 +635,10 @@ fn remove_index(pipe: &mut Pipeline, key: &str, value: Option<Vec<Bytes>>) -> an
             remove_from_set(pipe, key, value[0].as_ref());
             Ok(())
         }
+        INDEX_POSITIONS => {
+            remove_from_set(pipe, key, value[0].as_ref());
+            Ok(())
+        }
         INDEX_POSITIONS_OPEN => {
             remove_from_set(pipe, key, value[0].as_ref());
             Ok(())
@@ -598,6 +655,10 @@ fn remove_from_set(pipe: &mut Pipeline, key: &str, member: &[u8]) {
     pipe.srem(key, member);
 }
 
+fn remove_from_hset(pipe: &mut Pipeline, key: &str, field: &[u8]) {
+    pipe.hdel(key, field);
+}
+
 fn delete_string(pipe: &mut Pipeline, key: &str) {
     pipe.del(key);
 }
@@ -660,14 +721,112 @@ fn deserialize_payload(
     }
 }
 
-#[allow(dead_code)] // Under development
+/// The persisted representation of a [`Currency`], matching the Python cache layout.
+#[derive(Serialize, Deserialize)]
+struct CurrencyMap {
+    precision: u8,
+    iso4217: u16,
+    name: String,
+    currency_type: String,
+}
+
+/// The persisted representation of a [`SyntheticInstrument`] (the compiled formula is rebuilt
+/// on load).
+#[derive(Serialize, Deserialize)]
+struct SyntheticMap {
+    id: InstrumentId,
+    price_precision: u8,
+    components: Vec<InstrumentId>,
+    formula: String,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+/// Provides a [`CacheDatabaseAdapter`] backed by Redis.
+///
+/// Objects are persisted using the key layout of the Python Redis cache, with payloads
+/// serialized using the configured [`SerializationEncoding`].
 pub struct RedisCacheDatabaseAdapter {
     pub encoding: SerializationEncoding,
     database: RedisCacheDatabase,
 }
 
-#[allow(dead_code)] // Under development
-#[allow(unused)] // Under development
+impl RedisCacheDatabaseAdapter {
+    /// Creates a new [`RedisCacheDatabaseAdapter`] instance.
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: CacheConfig,
+    ) -> anyhow::Result<Self> {
+        let encoding = config.encoding;
+        let database = RedisCacheDatabase::new(trader_id, instance_id, config)?;
+        Ok(Self { encoding, database })
+    }
+
+    /// Loads the last heartbeat timestamp (ISO 8601) written to the database (if found).
+    pub fn load_heartbeat(&self) -> anyhow::Result<Option<String>> {
+        let result = self.database.read(HEARTBEAT)?;
+        match result.first() {
+            Some(value) => Ok(Some(String::from_utf8(value.to_vec())?)),
+            None => Ok(None),
+        }
+    }
+
+    fn serialize<T: Serialize>(&self, value: &T) -> anyhow::Result<Bytes> {
+        serialize_payload(self.encoding, value)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, payload: &[u8]) -> anyhow::Result<T> {
+        deserialize_object(self.encoding, payload)
+    }
+
+    fn read_index_map(&self, index_key: &str) -> anyhow::Result<HashMap<String, String>> {
+        let result = self.database.read(index_key)?;
+        match result.first() {
+            Some(value) => Ok(serde_json::from_slice(value)?),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    fn update_order_indexes(&self, order_event: &OrderEventAny) -> anyhow::Result<()> {
+        let payload = vec![Bytes::from(order_event.client_order_id().to_string())];
+
+        if is_inflight_event(order_event) {
+            self.database
+                .insert(INDEX_ORDERS_INFLIGHT.to_string(), Some(payload.clone()))?;
+        } else {
+            self.database
+                .delete(INDEX_ORDERS_INFLIGHT.to_string(), Some(payload.clone()))?;
+        }
+
+        match order_status_index(order_event) {
+            Some(INDEX_ORDERS_OPEN) => {
+                self.database
+                    .delete(INDEX_ORDERS_CLOSED.to_string(), Some(payload.clone()))?;
+                self.database
+                    .insert(INDEX_ORDERS_OPEN.to_string(), Some(payload.clone()))?;
+            }
+            Some(INDEX_ORDERS_CLOSED) => {
+                self.database
+                    .delete(INDEX_ORDERS_OPEN.to_string(), Some(payload.clone()))?;
+                self.database
+                    .insert(INDEX_ORDERS_CLOSED.to_string(), Some(payload.clone()))?;
+            }
+            _ => {}
+        }
+
+        match order_event {
+            OrderEventAny::Emulated(_) => self
+                .database
+                .insert(INDEX_ORDERS_EMULATED.to_string(), Some(payload)),
+            OrderEventAny::Released(_) => self
+                .database
+                .delete(INDEX_ORDERS_EMULATED.to_string(), Some(payload)),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     fn close(&mut self) -> anyhow::Result<()> {
         self.database.close();
@@ -680,15 +839,26 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn load(&self) -> anyhow::Result<HashMap<String, Bytes>> {
-        // self.database.load()
-        Ok(HashMap::new()) // TODO
+        let mut general = HashMap::new();
+        let prefix = format!("{GENERAL}{REDIS_DELIMITER}");
+
+        for key in self.database.keys(&format!("{GENERAL}*"))? {
+            let Some((_, name)) = key.split_once(&prefix) else {
+                continue;
+            };
+            if let Some(value) = self.database.read(&format!("{prefix}{name}"))?.pop() {
+                general.insert(name.to_string(), value);
+            }
+        }
+
+        Ok(general)
     }
 
     fn load_currencies(&mut self) -> anyhow::Result<HashMap<Ustr, Currency>> {
         let mut currencies = HashMap::new();
         let pattern = format!("{CURRENCIES}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let currency_code = Ustr::from(parts.first().unwrap());
             let result = self.load_currency(&currency_code)?;
@@ -708,7 +878,7 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         let mut instruments = HashMap::new();
         let pattern = format!("{INSTRUMENTS}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let instrument_id = InstrumentId::from_str(parts.first().unwrap())?;
             let result = self.load_instrument(&instrument_id)?;
@@ -729,7 +899,7 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         let mut synthetics = HashMap::new();
         let pattern = format!("{SYNTHETICS}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let instrument_id = InstrumentId::from_str(parts.first().unwrap())?;
             let synthetic = self.load_synthetic(&instrument_id)?;
@@ -743,7 +913,7 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         let mut accounts = HashMap::new();
         let pattern = format!("{ACCOUNTS}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let account_id = AccountId::from(*parts.first().unwrap());
             let result = self.load_account(&account_id)?;
@@ -764,7 +934,7 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         let mut orders = HashMap::new();
         let pattern = format!("{ORDERS}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let client_order_id = ClientOrderId::from(*parts.first().unwrap());
             let result = self.load_order(&client_order_id)?;
@@ -784,7 +954,7 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         let mut positions = HashMap::new();
         let pattern = format!("{POSITIONS}*");
 
-        for key in scan_keys(&mut self.database.con, pattern)? {
+        for key in self.database.keys(&pattern)? {
             let parts: Vec<&str> = key.as_str().rsplitn(2, ':').collect();
             let position_id = PositionId::from(*parts.first().unwrap());
             let position = self.load_position(&position_id)?;
@@ -795,133 +965,378 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn load_index_order_position(&self) -> anyhow::Result<HashMap<ClientOrderId, Position>> {
-        todo!()
+        let mut index = HashMap::new();
+        let mut positions: HashMap<PositionId, Position> = HashMap::new();
+
+        for (client_order_id, position_id) in self.read_index_map(INDEX_ORDER_POSITION)? {
+            let position_id = PositionId::from(position_id.as_str());
+            let position = match positions.get(&position_id) {
+                Some(position) => position.clone(),
+                None => match self.load_position(&position_id) {
+                    Ok(position) => {
+                        positions.insert(position_id, position.clone());
+                        position
+                    }
+                    Err(e) => {
+                        log::error!("Failed to load position {position_id}: {e}");
+                        continue;
+                    }
+                },
+            };
+            index.insert(ClientOrderId::from(client_order_id.as_str()), position);
+        }
+
+        Ok(index)
     }
 
     fn load_index_order_client(&self) -> anyhow::Result<HashMap<ClientOrderId, ClientId>> {
-        todo!()
+        Ok(self
+            .read_index_map(INDEX_ORDER_CLIENT)?
+            .into_iter()
+            .map(|(client_order_id, client_id)| {
+                (
+                    ClientOrderId::from(client_order_id.as_str()),
+                    ClientId::from(client_id.as_str()),
+                )
+            })
+            .collect())
     }
 
     fn load_currency(&self, code: &Ustr) -> anyhow::Result<Option<Currency>> {
-        todo!()
+        let key = format!("{CURRENCIES}{REDIS_DELIMITER}{code}");
+        let result = self.database.read(&key)?;
+        let Some(payload) = result.first() else {
+            return Ok(None);
+        };
+
+        let map: CurrencyMap = self.deserialize(payload)?;
+        let currency_type = CurrencyType::from_str(&map.currency_type)?;
+        Ok(Some(Currency::new_checked(
+            code.as_str(),
+            map.precision,
+            map.iso4217,
+            map.name.as_str(),
+            currency_type,
+        )?))
     }
 
     fn load_instrument(
         &self,
         instrument_id: &InstrumentId,
     ) -> anyhow::Result<Option<InstrumentAny>> {
-        todo!()
+        let key = format!("{INSTRUMENTS}{REDIS_DELIMITER}{instrument_id}");
+        let result = self.database.read(&key)?;
+        result
+            .first()
+            .map(|payload| self.deserialize(payload))
+            .transpose()
     }
 
     fn load_synthetic(&self, instrument_id: &InstrumentId) -> anyhow::Result<SyntheticInstrument> {
-        todo!()
+        let key = format!("{SYNTHETICS}{REDIS_DELIMITER}{instrument_id}");
+        let result = self.database.read(&key)?;
+        let Some(payload) = result.first() else {
+            anyhow::bail!("Synthetic instrument {instrument_id} not found");
+        };
+
+        let map: SyntheticMap = self.deserialize(payload)?;
+        SyntheticInstrument::new_checked(
+            map.id.symbol,
+            map.price_precision,
+            map.components,
+            map.formula,
+            map.ts_event,
+            map.ts_init,
+        )
     }
 
     fn load_account(&self, account_id: &AccountId) -> anyhow::Result<Option<AccountAny>> {
-        todo!()
+        let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{account_id}");
+        let result = self.database.read(&key)?;
+        if result.is_empty() {
+            return Ok(None);
+        }
+
+        let events = result
+            .iter()
+            .map(|payload| self.deserialize::<AccountState>(payload))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Some(AccountAny::from_events(events)?))
     }
 
     fn load_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<Option<OrderAny>> {
-        todo!()
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        let result = self.database.read(&key)?;
+        if result.is_empty() {
+            return Ok(None);
+        }
+
+        let mut events: Vec<OrderEventAny> = Vec::with_capacity(result.len());
+        for payload in &result {
+            let event: OrderEventAny = self.deserialize(payload)?;
+            if events.contains(&event) {
+                anyhow::bail!("Corrupt cache with duplicate event for order {event}");
+            }
+            events.push(event);
+        }
+        Ok(Some(OrderAny::from_events(events)?))
     }
 
     fn load_position(&self, position_id: &PositionId) -> anyhow::Result<Position> {
-        todo!()
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{position_id}");
+        let result = self.database.read(&key)?;
+        let Some((initial, rest)) = result.split_first() else {
+            anyhow::bail!("Position {position_id} not found");
+        };
+
+        let initial_fill: OrderFilled = self.deserialize(initial)?;
+        let instrument = match self.load_instrument(&initial_fill.instrument_id)? {
+            Some(instrument) => instrument,
+            None => anyhow::bail!(
+                "Cannot load position {position_id}: no instrument found for {}",
+                initial_fill.instrument_id
+            ),
+        };
+
+        let mut position = Position::new(&instrument, initial_fill);
+        for payload in rest {
+            let fill: OrderFilled = self.deserialize(payload)?;
+            if position.events.contains(&fill) {
+                anyhow::bail!("Corrupt cache with duplicate event for position {fill}");
+            }
+            position.apply(&fill);
+        }
+        Ok(position)
     }
 
     fn load_actor(&self, component_id: &ComponentId) -> anyhow::Result<HashMap<String, Bytes>> {
-        todo!()
+        let key = format!("{ACTORS}{REDIS_DELIMITER}{component_id}{REDIS_DELIMITER}state");
+        let result = self.database.read(&key)?;
+        match result.first() {
+            Some(payload) => self.deserialize(payload),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    fn delete_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        self.database.delete(key, None)?;
+
+        let payload = vec![Bytes::from(client_order_id.to_string())];
+        for index_key in [
+            INDEX_ORDERS,
+            INDEX_ORDERS_OPEN,
+            INDEX_ORDERS_CLOSED,
+            INDEX_ORDERS_EMULATED,
+            INDEX_ORDERS_INFLIGHT,
+            INDEX_ORDER_IDS,
+            INDEX_ORDER_POSITION,
+            INDEX_ORDER_CLIENT,
+        ] {
+            self.database
+                .delete(index_key.to_string(), Some(payload.clone()))?;
+        }
+
+        log::debug!("Deleted order {client_order_id}");
+        Ok(())
+    }
+
+    fn delete_position(&self, position_id: &PositionId) -> anyhow::Result<()> {
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{position_id}");
+        self.database.delete(key, None)?;
+
+        let payload = vec![Bytes::from(position_id.to_string())];
+        for index_key in [
+            INDEX_POSITIONS,
+            INDEX_POSITIONS_OPEN,
+            INDEX_POSITIONS_CLOSED,
+        ] {
+            self.database
+                .delete(index_key.to_string(), Some(payload.clone()))?;
+        }
+
+        log::debug!("Deleted position {position_id}");
+        Ok(())
     }
 
     fn delete_actor(&self, component_id: &ComponentId) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{ACTORS}{REDIS_DELIMITER}{component_id}{REDIS_DELIMITER}state");
+        self.database.delete(key, None)?;
+        log::info!("Deleted {component_id}");
+        Ok(())
     }
 
     fn load_strategy(&self, strategy_id: &StrategyId) -> anyhow::Result<HashMap<String, Bytes>> {
-        todo!()
+        let key = format!("{STRATEGIES}{REDIS_DELIMITER}{strategy_id}{REDIS_DELIMITER}state");
+        let result = self.database.read(&key)?;
+        match result.first() {
+            Some(payload) => self.deserialize(payload),
+            None => Ok(HashMap::new()),
+        }
     }
 
     fn delete_strategy(&self, component_id: &StrategyId) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{STRATEGIES}{REDIS_DELIMITER}{component_id}{REDIS_DELIMITER}state");
+        self.database.delete(key, None)?;
+        log::info!("Deleted {component_id}");
+        Ok(())
     }
 
     fn add(&self, key: String, value: Bytes) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{GENERAL}{REDIS_DELIMITER}{key}");
+        self.database.insert(key, Some(vec![value]))
     }
 
     fn add_currency(&self, currency: &Currency) -> anyhow::Result<()> {
-        todo!()
+        let map = CurrencyMap {
+            precision: currency.precision,
+            iso4217: currency.iso4217,
+            name: currency.name.to_string(),
+            currency_type: currency.currency_type.to_string(),
+        };
+        let key = format!("{CURRENCIES}{REDIS_DELIMITER}{}", currency.code);
+        self.database
+            .insert(key, Some(vec![self.serialize(&map)?]))?;
+        log::debug!("Added currency {}", currency.code);
+        Ok(())
     }
 
     fn add_instrument(&self, instrument: &InstrumentAny) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{INSTRUMENTS}{REDIS_DELIMITER}{}", instrument.id());
+        self.database
+            .insert(key, Some(vec![self.serialize(instrument)?]))?;
+        log::debug!("Added instrument {}", instrument.id());
+        Ok(())
     }
 
     fn add_synthetic(&self, synthetic: &SyntheticInstrument) -> anyhow::Result<()> {
-        todo!()
+        let map = SyntheticMap {
+            id: synthetic.id,
+            price_precision: synthetic.price_precision,
+            components: synthetic.components.clone(),
+            formula: synthetic.formula.clone(),
+            ts_event: synthetic.ts_event,
+            ts_init: synthetic.ts_init,
+        };
+        let key = format!("{SYNTHETICS}{REDIS_DELIMITER}{}", synthetic.id);
+        self.database
+            .insert(key, Some(vec![self.serialize(&map)?]))?;
+        log::debug!("Added synthetic instrument {}", synthetic.id);
+        Ok(())
     }
 
     fn add_account(&self, account: &AccountAny) -> anyhow::Result<()> {
-        todo!()
+        let Some(event) = account.last_event() else {
+            anyhow::bail!("Cannot add account {} with no events", account.id());
+        };
+        let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{}", account.id());
+        self.database
+            .insert(key, Some(vec![self.serialize(&event)?]))?;
+        log::debug!("Added account {}", account.id());
+        Ok(())
     }
 
     fn add_order(&self, order: &OrderAny, client_id: Option<ClientId>) -> anyhow::Result<()> {
-        todo!()
+        let client_order_id = order.client_order_id();
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        self.database
+            .insert(key, Some(vec![self.serialize(order.last_event())?]))?;
+
+        let payload = vec![Bytes::from(client_order_id.to_string())];
+        self.database
+            .insert(INDEX_ORDERS.to_string(), Some(payload.clone()))?;
+
+        if order.emulation_trigger().is_some() {
+            self.database
+                .insert(INDEX_ORDERS_EMULATED.to_string(), Some(payload.clone()))?;
+        }
+
+        if let Some(client_id) = client_id {
+            let mut payload = payload;
+            payload.push(Bytes::from(client_id.to_string()));
+            self.database
+                .insert(INDEX_ORDER_CLIENT.to_string(), Some(payload))?;
+            log::debug!("Indexed {client_order_id} -> {client_id}");
+        }
+
+        log::debug!("Added order {client_order_id}");
+        Ok(())
     }
 
     fn add_order_snapshot(&self, snapshot: &OrderSnapshot) -> anyhow::Result<()> {
-        todo!()
+        let key = format!(
+            "{SNAPSHOTS_ORDERS}{REDIS_DELIMITER}{}",
+            snapshot.client_order_id
+        );
+        self.database
+            .insert(key, Some(vec![self.serialize(snapshot)?]))
     }
 
     fn add_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{}", position.id);
+        self.database
+            .insert(key, Some(vec![self.serialize(&position.last_event())?]))?;
+
+        let payload = vec![Bytes::from(position.id.to_string())];
+        self.database
+            .insert(INDEX_POSITIONS.to_string(), Some(payload.clone()))?;
+        self.database
+            .insert(INDEX_POSITIONS_OPEN.to_string(), Some(payload))?;
+
+        log::debug!("Added position {}", position.id);
+        Ok(())
     }
 
     fn add_position_snapshot(&self, snapshot: &PositionSnapshot) -> anyhow::Result<()> {
-        todo!()
+        let key = format!(
+            "{SNAPSHOTS_POSITIONS}{REDIS_DELIMITER}{}",
+            snapshot.position_id
+        );
+        self.database
+            .insert(key, Some(vec![self.serialize(snapshot)?]))
     }
 
-    fn add_order_book(&self, order_book: &OrderBook) -> anyhow::Result<()> {
+    fn add_order_book(&self, _order_book: &OrderBook) -> anyhow::Result<()> {
         anyhow::bail!("Saving market data for Redis cache adapter not supported")
     }
 
-    fn add_quote(&self, quote: &QuoteTick) -> anyhow::Result<()> {
+    fn add_quote(&self, _quote: &QuoteTick) -> anyhow::Result<()> {
         anyhow::bail!("Saving market data for Redis cache adapter not supported")
     }
 
-    fn load_quotes(&self, instrument_id: &InstrumentId) -> anyhow::Result<Vec<QuoteTick>> {
+    fn load_quotes(&self, _instrument_id: &InstrumentId) -> anyhow::Result<Vec<QuoteTick>> {
         anyhow::bail!("Loading quote data for Redis cache adapter not supported")
     }
 
-    fn add_trade(&self, trade: &TradeTick) -> anyhow::Result<()> {
+    fn add_trade(&self, _trade: &TradeTick) -> anyhow::Result<()> {
         anyhow::bail!("Saving market data for Redis cache adapter not supported")
     }
 
-    fn load_trades(&self, instrument_id: &InstrumentId) -> anyhow::Result<Vec<TradeTick>> {
+    fn load_trades(&self, _instrument_id: &InstrumentId) -> anyhow::Result<Vec<TradeTick>> {
         anyhow::bail!("Loading market data for Redis cache adapter not supported")
     }
 
-    fn add_bar(&self, bar: &Bar) -> anyhow::Result<()> {
+    fn add_bar(&self, _bar: &Bar) -> anyhow::Result<()> {
         anyhow::bail!("Saving market data for Redis cache adapter not supported")
     }
 
-    fn load_bars(&self, instrument_id: &InstrumentId) -> anyhow::Result<Vec<Bar>> {
+    fn load_bars(&self, _instrument_id: &InstrumentId) -> anyhow::Result<Vec<Bar>> {
         anyhow::bail!("Loading market data for Redis cache adapter not supported")
     }
 
-    fn add_signal(&self, signal: &Signal) -> anyhow::Result<()> {
+    fn add_signal(&self, _signal: &Signal) -> anyhow::Result<()> {
         anyhow::bail!("Saving signals for Redis cache adapter not supported")
     }
 
-    fn load_signals(&self, name: &str) -> anyhow::Result<Vec<Signal>> {
+    fn load_signals(&self, _name: &str) -> anyhow::Result<Vec<Signal>> {
         anyhow::bail!("Loading signals from Redis cache adapter not supported")
     }
 
-    fn add_custom_data(&self, data: &CustomData) -> anyhow::Result<()> {
+    fn add_custom_data(&self, _data: &CustomData) -> anyhow::Result<()> {
         anyhow::bail!("Saving custom data for Redis cache adapter not supported")
     }
 
-    fn load_custom_data(&self, data_type: &DataType) -> anyhow::Result<Vec<CustomData>> {
+    fn load_custom_data(&self, _data_type: &DataType) -> anyhow::Result<Vec<CustomData>> {
         anyhow::bail!("Loading custom data from Redis cache adapter not supported")
     }
 
@@ -929,14 +1344,24 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         &self,
         client_order_id: &ClientOrderId,
     ) -> anyhow::Result<Option<OrderSnapshot>> {
-        anyhow::bail!("Loading order snapshots from Redis cache adapter not supported")
+        let key = format!("{SNAPSHOTS_ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        let result = self.database.read(&key)?;
+        result
+            .last()
+            .map(|payload| self.deserialize(payload))
+            .transpose()
     }
 
     fn load_position_snapshot(
         &self,
         position_id: &PositionId,
     ) -> anyhow::Result<Option<PositionSnapshot>> {
-        anyhow::bail!("Loading position snapshots from Redis cache adapter not supported")
+        let key = format!("{SNAPSHOTS_POSITIONS}{REDIS_DELIMITER}{position_id}");
+        let result = self.database.read(&key)?;
+        result
+            .last()
+            .map(|payload| self.deserialize(payload))
+            .transpose()
     }
 
     fn index_venue_order_id(
@@ -944,7 +1369,14 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
     ) -> anyhow::Result<()> {
-        todo!()
+        let payload = vec![
+            Bytes::from(client_order_id.to_string()),
+            Bytes::from(venue_order_id.to_string()),
+        ];
+        self.database
+            .insert(INDEX_ORDER_IDS.to_string(), Some(payload))?;
+        log::debug!("Indexed {client_order_id} -> {venue_order_id}");
+        Ok(())
     }
 
     fn index_order_position(
@@ -952,39 +1384,156 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         client_order_id: ClientOrderId,
         position_id: PositionId,
     ) -> anyhow::Result<()> {
-        todo!()
+        let payload = vec![
+            Bytes::from(client_order_id.to_string()),
+            Bytes::from(position_id.to_string()),
+        ];
+        self.database
+            .insert(INDEX_ORDER_POSITION.to_string(), Some(payload))?;
+        log::debug!("Indexed {client_order_id} -> {position_id}");
+        Ok(())
     }
 
-    fn update_actor(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let key = format!("{ACTORS}{REDIS_DELIMITER}{component_id}{REDIS_DELIMITER}state");
+        self.database
+            .insert(key, Some(vec![self.serialize(state)?]))?;
+        log::debug!("Saved actor state for {component_id}");
+        Ok(())
     }
 
-    fn update_strategy(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let key = format!("{STRATEGIES}{REDIS_DELIMITER}{strategy_id}{REDIS_DELIMITER}state");
+        self.database
+            .insert(key, Some(vec![self.serialize(state)?]))?;
+        log::debug!("Saved strategy state for {strategy_id}");
+        Ok(())
     }
 
     fn update_account(&self, account: &AccountAny) -> anyhow::Result<()> {
-        todo!()
+        let Some(event) = account.last_event() else {
+            anyhow::bail!("Cannot update account {} with no events", account.id());
+        };
+        let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{}", account.id());
+        self.database
+            .update(key, Some(vec![self.serialize(&event)?]))?;
+        log::debug!("Updated account {}", account.id());
+        Ok(())
     }
 
     fn update_order(&self, order_event: &OrderEventAny) -> anyhow::Result<()> {
-        todo!()
+        let client_order_id = order_event.client_order_id();
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        self.database
+            .update(key, Some(vec![self.serialize(order_event)?]))?;
+
+        if let Some(venue_order_id) = order_event.venue_order_id() {
+            // Assumes the venue order ID does not change
+            self.index_venue_order_id(client_order_id, venue_order_id)?;
+        }
+
+        self.update_order_indexes(order_event)?;
+        log::debug!("Updated order {client_order_id}");
+        Ok(())
     }
 
     fn update_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{}", position.id);
+        self.database
+            .update(key, Some(vec![self.serialize(&position.last_event())?]))?;
+
+        let payload = vec![Bytes::from(position.id.to_string())];
+        if position.is_open() {
+            self.database
+                .insert(INDEX_POSITIONS_OPEN.to_string(), Some(payload.clone()))?;
+            self.database
+                .delete(INDEX_POSITIONS_CLOSED.to_string(), Some(payload))?;
+        } else if position.is_closed() {
+            self.database
+                .insert(INDEX_POSITIONS_CLOSED.to_string(), Some(payload.clone()))?;
+            self.database
+                .delete(INDEX_POSITIONS_OPEN.to_string(), Some(payload))?;
+        }
+
+        log::debug!("Updated position {}", position.id);
+        Ok(())
     }
 
     fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()> {
-        todo!()
+        self.add_order_snapshot(&OrderSnapshot::from(order.clone()))
     }
 
     fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        self.add_position_snapshot(&PositionSnapshot::from(position, None))
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
-        todo!()
+        let timestamp = unix_nanos_to_iso8601(timestamp);
+        self.database.insert(
+            HEARTBEAT.to_string(),
+            Some(vec![Bytes::from(timestamp.clone())]),
+        )?;
+        log::debug!("Set last heartbeat {timestamp}");
+        Ok(())
+    }
+}
+
+fn serialize_payload<T: Serialize>(
+    encoding: SerializationEncoding,
+    value: &T,
+) -> anyhow::Result<Bytes> {
+    let payload = match encoding {
+        SerializationEncoding::MsgPack => rmp_serde::to_vec_named(value)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize msgpack `payload`: {e}"))?,
+        SerializationEncoding::Json => serde_json::to_vec(value)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize json `payload`: {e}"))?,
+    };
+    Ok(Bytes::from(payload))
+}
+
+fn deserialize_object<T: DeserializeOwned>(
+    encoding: SerializationEncoding,
+    payload: &[u8],
+) -> anyhow::Result<T> {
+    match encoding {
+        SerializationEncoding::MsgPack => rmp_serde::from_slice(payload)
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize msgpack `payload`: {e}")),
+        SerializationEncoding::Json => serde_json::from_slice(payload)
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize json `payload`: {e}")),
+    }
+}
+
+fn is_inflight_event(order_event: &OrderEventAny) -> bool {
+    matches!(
+        order_event,
+        OrderEventAny::Submitted(_)
+            | OrderEventAny::PendingUpdate(_)
+            | OrderEventAny::PendingCancel(_)
+    )
+}
+
+/// Returns the open/closed index the order belongs in after applying `order_event`, if the
+/// event determines it.
+fn order_status_index(order_event: &OrderEventAny) -> Option<&'static str> {
+    match order_event {
+        OrderEventAny::Accepted(_)
+        | OrderEventAny::Triggered(_)
+        | OrderEventAny::Updated(_)
+        | OrderEventAny::PartiallyFilled(_) => Some(INDEX_ORDERS_OPEN),
+        OrderEventAny::Denied(_)
+        | OrderEventAny::Rejected(_)
+        | OrderEventAny::Canceled(_)
+        | OrderEventAny::Expired(_)
+        | OrderEventAny::Filled(_) => Some(INDEX_ORDERS_CLOSED),
+        _ => None,
     }
 }
 
@@ -993,10 +1542,25 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::stubs::account_id,
+        instruments::stubs::currency_pair_ethusdt,
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Quantity,
+    };
     use rstest::rstest;
 
     use super::*;
 
+    fn market_order(instrument: &InstrumentAny) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.0"))
+            .build()
+    }
+
     #[rstest]
     fn test_get_trader_key_with_prefix_and_instance_id() {
         let trader_id = TraderId::from("tester-123");
@@ -1032,4 +1596,125 @@ mod tests {
         let key = "no_delimiter";
         assert!(get_index_key(key).is_err());
     }
+
+    #[rstest]
+    fn test_order_events_round_trip(
+        #[values(SerializationEncoding::MsgPack, SerializationEncoding::Json)]
+        encoding: SerializationEncoding,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let mut order = market_order(&instrument);
+        let initialized = order.last_event().clone();
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id());
+        order.apply(submitted.clone()).unwrap();
+
+        let events = [initialized, submitted]
+            .iter()
+            .map(|event| {
+                let payload = serialize_payload(encoding, event).unwrap();
+                deserialize_object::<OrderEventAny>(encoding, &payload).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let result = OrderAny::from_events(events).unwrap();
+
+        assert_eq!(result.client_order_id(), order.client_order_id());
+        assert_eq!(result.status(), order.status());
+        assert_eq!(result.last_event(), order.last_event());
+    }
+
+    #[rstest]
+    fn test_instrument_round_trip(
+        #[values(SerializationEncoding::MsgPack, SerializationEncoding::Json)]
+        encoding: SerializationEncoding,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+
+        let payload = serialize_payload(encoding, &instrument).unwrap();
+        let result: InstrumentAny = deserialize_object(encoding, &payload).unwrap();
+
+        assert_eq!(result, instrument);
+    }
+
+    #[rstest]
+    fn test_deserialize_object_when_invalid_payload() {
+        let result = deserialize_object::<OrderEventAny>(SerializationEncoding::Json, b"{}");
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_order_indexes_for_submitted_event() {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let order = market_order(&instrument);
+        let event = TestOrderEventStubs::order_submitted(&order, account_id());
+
+        assert!(is_inflight_event(&event));
+        assert_eq!(order_status_index(&event), None);
+    }
+
+    #[rstest]
+    fn test_order_indexes_for_accepted_event() {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let order = market_order(&instrument);
+        let event =
+            TestOrderEventStubs::order_accepted(&order, account_id(), VenueOrderId::new("1"));
+
+        assert!(!is_inflight_event(&event));
+        assert_eq!(order_status_index(&event), Some(INDEX_ORDERS_OPEN));
+    }
+
+    #[rstest]
+    fn test_order_indexes_for_filled_event() {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let order = market_order(&instrument);
+        let event = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert!(!is_inflight_event(&event));
+        assert_eq!(order_status_index(&event), Some(INDEX_ORDERS_CLOSED));
+    }
+
+    #[rstest]
+    fn test_insert_and_remove_order_ids_index() {
+        let mut pipe = redis::pipe();
+        let key = format!("trader-tester-123{REDIS_DELIMITER}{INDEX_ORDER_IDS}");
+        let payload = vec![Bytes::from("O-123"), Bytes::from("V-123")];
+
+        insert(&mut pipe, INDEX, &key, payload.clone()).unwrap();
+        assert_eq!(
+            pipeline_args(&pipe),
+            vec![vec!["HSET", key.as_str(), "O-123", "V-123"]]
+        );
+
+        remove_index(&mut pipe, &key, Some(payload)).unwrap();
+        assert_eq!(
+            pipeline_args(&pipe),
+            vec![
+                vec!["HSET", key.as_str(), "O-123", "V-123"],
+                vec!["HDEL", key.as_str(), "O-123"],
+            ]
+        );
+    }
+
+    fn pipeline_args(pipe: &Pipeline) -> Vec<Vec<&str>> {
+        pipe.cmd_iter()
+            .map(|cmd| {
+                cmd.args_iter()
+                    .map(|arg| match arg {
+                        redis::Arg::Simple(bytes) => std::str::from_utf8(bytes).unwrap(),
+                        redis::Arg::Cursor => "CURSOR",
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
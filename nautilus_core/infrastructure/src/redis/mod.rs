@@ -20,35 +20,18 @@ pub mod msgbus;
 
 use std::time::Duration;
 
-use nautilus_common::msgbus::database::{DatabaseConfig, MessageBusConfig};
-use nautilus_core::UUID4;
-use nautilus_model::identifiers::TraderId;
+use nautilus_common::msgbus::database::DatabaseConfig;
 use redis::*;
 use semver::Version;
 
+pub use crate::msgbus::get_stream_key;
+
 const REDIS_MIN_VERSION: &str = "6.2.0";
 const REDIS_DELIMITER: char = ':';
 const REDIS_XTRIM: &str = "XTRIM";
 const REDIS_MINID: &str = "MINID";
 const REDIS_FLUSHDB: &str = "FLUSHDB";
 
-async fn await_handle(handle: Option<tokio::task::JoinHandle<()>>, task_name: &str) {
-    if let Some(handle) = handle {
-        tracing::debug!("Awaiting task '{task_name}'");
-        let timeout = Duration::from_secs(2);
-        match tokio::time::timeout(timeout, handle).await {
-            Ok(result) => {
-                if let Err(e) = result {
-                    log::error!("Error awaiting task '{task_name}': {e:?}");
-                }
-            }
-            Err(_) => {
-                log::error!("Timeout {timeout:?} awaiting task '{task_name}'");
-            }
-        }
-    }
-}
-
 /// Parse a Redis connection url from the given database config.
 pub fn get_redis_url(config: DatabaseConfig) -> (String, String) {
     let host = config.host.unwrap_or("127.0.0.1".to_string());
@@ -126,32 +109,6 @@ pub fn flush_redis(con: &mut redis::Connection) -> anyhow::Result<(), RedisError
     redis::cmd(REDIS_FLUSHDB).exec(con)
 }
 
-/// Parse the stream key from the given identifiers and config.
-pub fn get_stream_key(
-    trader_id: TraderId,
-    instance_id: UUID4,
-    config: &MessageBusConfig,
-) -> String {
-    let mut stream_key = String::new();
-
-    if config.use_trader_prefix {
-        stream_key.push_str("trader-");
-    }
-
-    if config.use_trader_id {
-        stream_key.push_str(trader_id.as_str());
-        stream_key.push(REDIS_DELIMITER);
-    }
-
-    if config.use_instance_id {
-        stream_key.push_str(&format!("{instance_id}"));
-        stream_key.push(REDIS_DELIMITER);
-    }
-
-    stream_key.push_str(&config.streams_prefix);
-    stream_key
-}
-
 /// Parses the Redis version from the "INFO" command output.
 pub fn get_redis_version(conn: &mut Connection) -> anyhow::Result<Version> {
     let info: String = redis::cmd("INFO").query(conn)?;
@@ -184,6 +141,9 @@ fn parse_redis_version(version_str: &str) -> anyhow::Result<Version> {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_common::msgbus::database::MessageBusConfig;
+    use nautilus_core::UUID4;
+    use nautilus_model::identifiers::TraderId;
     use rstest::rstest;
     use serde_json::json;
 
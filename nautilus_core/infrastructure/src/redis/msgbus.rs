@@ -40,13 +40,15 @@ use nautilus_model::identifiers::TraderId;
 use redis::*;
 use streams::StreamReadOptions;
 
-use super::{await_handle, REDIS_MINID, REDIS_XTRIM};
-use crate::redis::{create_redis_connection, get_stream_key};
+use super::{REDIS_MINID, REDIS_XTRIM};
+use crate::{
+    msgbus::{await_handle, run_heartbeat},
+    redis::{create_redis_connection, get_stream_key},
+};
 
 const MSGBUS_PUBLISH: &str = "msgbus-publish";
 const MSGBUS_STREAM: &str = "msgbus-stream";
 const MSGBUS_HEARTBEAT: &str = "msgbus-heartbeat";
-const HEARTBEAT_TOPIC: &str = "health:heartbeat";
 const TRIM_BUFFER_SECS: u64 = 60;
 
 type RedisStreamBulk = Vec<HashMap<String, Vec<HashMap<String, redis::Value>>>>;
@@ -434,50 +436,6 @@ fn decode_bus_message(stream_msg: &redis::Value) -> anyhow::Result<BusMessage> {
     }
 }
 
-async fn run_heartbeat(
-    heartbeat_interval_secs: u16,
-    signal: Arc<AtomicBool>,
-    pub_tx: tokio::sync::mpsc::UnboundedSender<BusMessage>,
-) {
-    tracing::debug!("Starting heartbeat at {heartbeat_interval_secs} second intervals");
-
-    let heartbeat_interval = Duration::from_secs(heartbeat_interval_secs as u64);
-    let heartbeat_timer = tokio::time::interval(heartbeat_interval);
-
-    let check_interval = Duration::from_millis(100);
-    let check_timer = tokio::time::interval(check_interval);
-
-    tokio::pin!(heartbeat_timer);
-    tokio::pin!(check_timer);
-
-    loop {
-        if signal.load(Ordering::Relaxed) {
-            tracing::debug!("Received heartbeat terminate signal");
-            break;
-        }
-
-        tokio::select! {
-            _ = heartbeat_timer.tick() => {
-                let heartbeat = create_heartbeat_msg();
-                if let Err(e) = pub_tx.send(heartbeat) {
-                    // We expect an error if the channel is closed during shutdown
-                    tracing::debug!("Error sending heartbeat: {e}");
-                }
-            },
-            _ = check_timer.tick() => {}
-        }
-    }
-
-    tracing::debug!("Stopped heartbeat");
-}
-
-fn create_heartbeat_msg() -> BusMessage {
-    BusMessage {
-        topic: HEARTBEAT_TOPIC.to_string(),
-        payload: Bytes::from(chrono::Utc::now().to_rfc3339().into_bytes()),
-    }
-}
-
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -574,7 +532,7 @@ mod serial_tests {
     use rstest::*;
 
     use super::*;
-    use crate::redis::flush_redis;
+    use crate::{msgbus::HEARTBEAT_TOPIC, redis::flush_redis};
 
     #[fixture]
     fn redis_connection() -> redis::Connection {
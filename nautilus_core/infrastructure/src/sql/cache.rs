@@ -14,7 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{hash_map::Entry, HashMap, VecDeque},
     time::{Duration, Instant},
 };
 
@@ -63,6 +63,7 @@ pub enum DatabaseQuery {
     Add(String, Vec<u8>),
     AddCurrency(Currency),
     AddInstrument(InstrumentAny),
+    AddSynthetic(SyntheticInstrument),
     AddOrder(OrderAny, Option<ClientId>, bool),
     AddOrderSnapshot(OrderSnapshot),
     AddPositionSnapshot(PositionSnapshot),
@@ -73,6 +74,15 @@ pub enum DatabaseQuery {
     AddTrade(TradeTick),
     AddBar(Bar),
     UpdateOrder(OrderEventAny),
+    IndexVenueOrderId(ClientOrderId, VenueOrderId),
+    IndexOrderPosition(ClientOrderId, PositionId),
+    UpdateActor(ComponentId, HashMap<String, Bytes>),
+    DeleteOrder(ClientOrderId),
+    DeletePosition(PositionId),
+    DeleteActor(ComponentId),
+    UpdateStrategy(StrategyId, HashMap<String, Bytes>),
+    DeleteStrategy(StrategyId),
+    Heartbeat(UnixNanos),
 }
 
 impl PostgresCacheDatabase {
@@ -278,7 +288,29 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn load_synthetics(&mut self) -> anyhow::Result<HashMap<InstrumentId, SyntheticInstrument>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_synthetics(&pool).await;
+            match result {
+                Ok(synthetics) => {
+                    let mapping = synthetics
+                        .into_iter()
+                        .map(|synthetic| (synthetic.id, synthetic))
+                        .collect();
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send synthetics: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load synthetics: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty synthetics: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_accounts(&mut self) -> anyhow::Result<HashMap<AccountId, AccountAny>> {
@@ -334,11 +366,51 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn load_positions(&mut self) -> anyhow::Result<HashMap<PositionId, Position>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_positions(&pool).await;
+            match result {
+                Ok(positions) => {
+                    let mapping = positions
+                        .into_iter()
+                        .map(|position| (position.id, position))
+                        .collect();
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send positions: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load positions: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty positions: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_index_order_position(&self) -> anyhow::Result<HashMap<ClientOrderId, Position>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = load_index_order_position(&pool).await;
+            match result {
+                Ok(mapping) => {
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send load_index_order_position result: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load index order position: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty load_index_order_position result: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_index_order_client(&self) -> anyhow::Result<HashMap<ClientOrderId, ClientId>> {
@@ -413,7 +485,19 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn load_synthetic(&self, instrument_id: &InstrumentId) -> anyhow::Result<SyntheticInstrument> {
-        todo!()
+        let pool = self.pool.clone();
+        let instrument_id = instrument_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_synthetic(&pool, &instrument_id).await;
+            if let Err(e) = tx.send(result) {
+                log::error!("Failed to send synthetic {instrument_id}: {e:?}");
+            }
+        });
+        match rx.recv()?? {
+            Some(synthetic) => Ok(synthetic),
+            None => anyhow::bail!("Synthetic instrument {instrument_id} not found"),
+        }
     }
 
     fn load_account(&self, account_id: &AccountId) -> anyhow::Result<Option<AccountAny>> {
@@ -461,23 +545,93 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn load_position(&self, position_id: &PositionId) -> anyhow::Result<Position> {
-        todo!()
+        let pool = self.pool.clone();
+        let position_id = position_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_position(&pool, &position_id).await;
+            if let Err(e) = tx.send(result) {
+                log::error!("Failed to send position {position_id}: {e:?}");
+            }
+        });
+        match rx.recv()?? {
+            Some(position) => Ok(position),
+            None => anyhow::bail!("Position {position_id} not found"),
+        }
     }
 
     fn load_actor(&self, component_id: &ComponentId) -> anyhow::Result<HashMap<String, Bytes>> {
-        todo!()
+        let pool = self.pool.clone();
+        let component_id = component_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_actor(&pool, &component_id).await;
+            match result {
+                Ok(state) => {
+                    if let Err(e) = tx.send(state) {
+                        log::error!("Failed to send actor state {component_id}: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load actor state {component_id}: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty actor state {component_id}: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
+    }
+
+    fn delete_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let query = DatabaseQuery::DeleteOrder(client_order_id.to_owned());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query delete_order to database message handler: {e}")
+        })
+    }
+
+    fn delete_position(&self, position_id: &PositionId) -> anyhow::Result<()> {
+        let query = DatabaseQuery::DeletePosition(position_id.to_owned());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query delete_position to database message handler: {e}")
+        })
     }
 
     fn delete_actor(&self, component_id: &ComponentId) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::DeleteActor(component_id.to_owned());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query delete_actor to database message handler: {e}")
+        })
     }
 
     fn load_strategy(&self, strategy_id: &StrategyId) -> anyhow::Result<HashMap<String, Bytes>> {
-        todo!()
+        let pool = self.pool.clone();
+        let strategy_id = strategy_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_strategy(&pool, &strategy_id).await;
+            match result {
+                Ok(state) => {
+                    if let Err(e) = tx.send(state) {
+                        log::error!("Failed to send strategy state {strategy_id}: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load strategy state {strategy_id}: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty strategy state {strategy_id}: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn delete_strategy(&self, component_id: &StrategyId) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::DeleteStrategy(component_id.to_owned());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query delete_strategy to database message handler: {e}")
+        })
     }
 
     fn add(&self, key: String, value: Bytes) -> anyhow::Result<()> {
@@ -502,7 +656,10 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_synthetic(&self, synthetic: &SyntheticInstrument) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddSynthetic(synthetic.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query add_synthetic to database message handler: {e}")
+        })
     }
 
     fn add_account(&self, account: &AccountAny) -> anyhow::Result<()> {
@@ -529,7 +686,10 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddPositionSnapshot(PositionSnapshot::from(position, None));
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query add_position to database message handler: {e}")
+        })
     }
 
     fn add_position_snapshot(&self, snapshot: &PositionSnapshot) -> anyhow::Result<()> {
@@ -542,7 +702,7 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_order_book(&self, order_book: &OrderBook) -> anyhow::Result<()> {
-        todo!()
+        anyhow::bail!("Persisting order books is not supported for Postgres")
     }
 
     fn add_quote(&self, quote: &QuoteTick) -> anyhow::Result<()> {
@@ -762,7 +922,12 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
     ) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::IndexVenueOrderId(client_order_id, venue_order_id);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to send query index_venue_order_id to database message handler: {e}"
+            )
+        })
     }
 
     fn index_order_position(
@@ -770,15 +935,34 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         client_order_id: ClientOrderId,
         position_id: PositionId,
     ) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::IndexOrderPosition(client_order_id, position_id);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to send query index_order_position to database message handler: {e}"
+            )
+        })
     }
 
-    fn update_actor(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let query = DatabaseQuery::UpdateActor(component_id.to_owned(), state.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query update_actor to database message handler: {e}")
+        })
     }
 
-    fn update_strategy(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        state: &HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let query = DatabaseQuery::UpdateStrategy(strategy_id.to_owned(), state.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query update_strategy to database message handler: {e}")
+        })
     }
 
     fn update_account(&self, account: &AccountAny) -> anyhow::Result<()> {
@@ -796,22 +980,55 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn update_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddPositionSnapshot(PositionSnapshot::from(position, None));
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query update_position to database message handler: {e}")
+        })
     }
 
     fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddOrderSnapshot(OrderSnapshot::from(order.clone()));
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to send query snapshot_order_state to database message handler: {e}"
+            )
+        })
     }
 
     fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddPositionSnapshot(PositionSnapshot::from(position, None));
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to send query snapshot_position_state to database message handler: {e}"
+            )
+        })
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::Heartbeat(timestamp);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query heartbeat to database message handler: {e}")
+        })
     }
 }
 
+async fn load_index_order_position(
+    pool: &PgPool,
+) -> anyhow::Result<HashMap<ClientOrderId, Position>> {
+    let index = DatabaseQueries::load_index_order_position(pool).await?;
+    let mut positions: HashMap<PositionId, Option<Position>> = HashMap::new();
+    let mut mapping = HashMap::new();
+    for (client_order_id, position_id) in index {
+        if let Entry::Vacant(e) = positions.entry(position_id) {
+            e.insert(DatabaseQueries::load_position(pool, &position_id).await?);
+        }
+        if let Some(Some(position)) = positions.get(&position_id) {
+            mapping.insert(client_order_id, position.clone());
+        }
+    }
+    Ok(mapping)
+}
+
 async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
     for cmd in buffer.drain(..) {
         let result: anyhow::Result<()> = match cmd {
@@ -824,42 +1041,108 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                 .map_err(anyhow::Error::from),
             DatabaseQuery::AddInstrument(instrument_any) => match instrument_any {
                 InstrumentAny::Betting(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "BETTING", Box::new(instrument)).await
+                    DatabaseQueries::add_instrument(pool, "BETTING", Box::new(instrument), None)
+                        .await
                 }
                 InstrumentAny::BinaryOption(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "BINARY_OPTION", Box::new(instrument))
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "BINARY_OPTION",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
+                }
+                InstrumentAny::Cfd(instrument) => {
+                    DatabaseQueries::add_instrument(pool, "CFD", Box::new(instrument), None).await
+                }
+                InstrumentAny::Commodity(instrument) => {
+                    DatabaseQueries::add_instrument(pool, "COMMODITY", Box::new(instrument), None)
                         .await
                 }
                 InstrumentAny::CryptoFuture(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_FUTURE", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CRYPTO_FUTURE",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::CryptoPerpetual(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_PERPETUAL", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CRYPTO_PERPETUAL",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::CurrencyPair(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CURRENCY_PAIR", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CURRENCY_PAIR",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::Equity(equity) => {
-                    DatabaseQueries::add_instrument(pool, "EQUITY", Box::new(equity)).await
+                    DatabaseQueries::add_instrument(pool, "EQUITY", Box::new(equity), None).await
                 }
                 InstrumentAny::FuturesContract(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "FUTURES_CONTRACT", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "FUTURES_CONTRACT",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::FuturesSpread(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "FUTURES_SPREAD", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "FUTURES_SPREAD",
+                        Box::new(instrument),
+                        Some(instrument.strategy_type),
+                    )
+                    .await
+                }
+                InstrumentAny::IndexInstrument(instrument) => {
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "INDEX_INSTRUMENT",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::OptionsContract(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "OPTIONS_CONTRACT", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "OPTIONS_CONTRACT",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentAny::OptionsSpread(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "OPTIONS_SPREAD", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "OPTIONS_SPREAD",
+                        Box::new(instrument),
+                        Some(instrument.strategy_type),
+                    )
+                    .await
+                }
+                InstrumentAny::PerpetualOption(instrument) => {
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "PERPETUAL_OPTION",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
             },
             DatabaseQuery::AddOrder(order_any, client_id, updated) => match order_any {
@@ -955,6 +1238,9 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                 AccountAny::Margin(account) => {
                     DatabaseQueries::add_account(pool, "MARGIN", updated, Box::new(account)).await
                 }
+                AccountAny::Betting(account) => {
+                    DatabaseQueries::add_account(pool, "BETTING", updated, Box::new(account)).await
+                }
             },
             DatabaseQuery::AddSignal(signal) => DatabaseQueries::add_signal(pool, &signal).await,
             DatabaseQuery::AddCustom(data) => DatabaseQueries::add_custom_data(pool, &data).await,
@@ -962,7 +1248,38 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
             DatabaseQuery::AddTrade(trade) => DatabaseQueries::add_trade(pool, &trade).await,
             DatabaseQuery::AddBar(bar) => DatabaseQueries::add_bar(pool, &bar).await,
             DatabaseQuery::UpdateOrder(event) => {
-                DatabaseQueries::add_order_event(pool, event.into_boxed(), None).await
+                DatabaseQueries::add_order_event(pool, event, None).await
+            }
+            DatabaseQuery::AddSynthetic(synthetic) => {
+                DatabaseQueries::add_synthetic(pool, &synthetic).await
+            }
+            DatabaseQuery::IndexVenueOrderId(client_order_id, venue_order_id) => {
+                DatabaseQueries::add_index_venue_order_id(pool, client_order_id, venue_order_id)
+                    .await
+            }
+            DatabaseQuery::IndexOrderPosition(client_order_id, position_id) => {
+                DatabaseQueries::add_index_order_position(pool, client_order_id, position_id).await
+            }
+            DatabaseQuery::UpdateActor(component_id, state) => {
+                DatabaseQueries::update_actor(pool, &component_id, state).await
+            }
+            DatabaseQuery::DeleteOrder(client_order_id) => {
+                DatabaseQueries::delete_order(pool, &client_order_id).await
+            }
+            DatabaseQuery::DeletePosition(position_id) => {
+                DatabaseQueries::delete_position(pool, &position_id).await
+            }
+            DatabaseQuery::DeleteActor(component_id) => {
+                DatabaseQueries::delete_actor(pool, &component_id).await
+            }
+            DatabaseQuery::UpdateStrategy(strategy_id, state) => {
+                DatabaseQueries::update_strategy(pool, &strategy_id, state).await
+            }
+            DatabaseQuery::DeleteStrategy(strategy_id) => {
+                DatabaseQueries::delete_strategy(pool, &strategy_id).await
+            }
+            DatabaseQuery::Heartbeat(timestamp) => {
+                DatabaseQueries::add_heartbeat(pool, timestamp).await
             }
         };
 
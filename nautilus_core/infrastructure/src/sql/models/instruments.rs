@@ -24,8 +24,9 @@ use nautilus_model::{
     enums::OptionKind,
     identifiers::{InstrumentId, Symbol},
     instruments::{
-        BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
-        FuturesContract, FuturesSpread, InstrumentAny, OptionsContract, OptionsSpread,
+        BettingInstrument, BinaryOption, Cfd, Commodity, CryptoFuture, CryptoPerpetual,
+        CurrencyPair, Equity, FuturesContract, FuturesSpread, IndexInstrument, InstrumentAny,
+        OptionsContract, OptionsSpread, PerpetualOption, SyntheticInstrument,
     },
     types::{Currency, Money, Price, Quantity},
 };
@@ -38,14 +39,19 @@ use crate::sql::models::enums::AssetClassModel;
 pub struct InstrumentAnyModel(pub InstrumentAny);
 pub struct BettingInstrumentModel(pub BettingInstrument);
 pub struct BinaryOptionModel(pub BinaryOption);
+pub struct CfdModel(pub Cfd);
+pub struct CommodityModel(pub Commodity);
 pub struct CryptoFutureModel(pub CryptoFuture);
 pub struct CryptoPerpetualModel(pub CryptoPerpetual);
 pub struct CurrencyPairModel(pub CurrencyPair);
 pub struct EquityModel(pub Equity);
 pub struct FuturesContractModel(pub FuturesContract);
 pub struct FuturesSpreadModel(pub FuturesSpread);
+pub struct IndexInstrumentModel(pub IndexInstrument);
 pub struct OptionsContractModel(pub OptionsContract);
 pub struct OptionsSpreadModel(pub OptionsSpread);
+pub struct PerpetualOptionModel(pub PerpetualOption);
+pub struct SyntheticInstrumentModel(pub SyntheticInstrument);
 
 // TBD
 impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
@@ -59,6 +65,14 @@ impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
             Ok(InstrumentAnyModel(InstrumentAny::BinaryOption(
                 BinaryOptionModel::from_row(row).unwrap().0,
             )))
+        } else if kind == "CFD" {
+            Ok(InstrumentAnyModel(InstrumentAny::Cfd(
+                CfdModel::from_row(row).unwrap().0,
+            )))
+        } else if kind == "COMMODITY" {
+            Ok(InstrumentAnyModel(InstrumentAny::Commodity(
+                CommodityModel::from_row(row).unwrap().0,
+            )))
         } else if kind == "CRYPTO_FUTURE" {
             Ok(InstrumentAnyModel(InstrumentAny::CryptoFuture(
                 CryptoFutureModel::from_row(row).unwrap().0,
@@ -83,6 +97,10 @@ impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
             Ok(InstrumentAnyModel(InstrumentAny::FuturesSpread(
                 FuturesSpreadModel::from_row(row).unwrap().0,
             )))
+        } else if kind == "INDEX_INSTRUMENT" {
+            Ok(InstrumentAnyModel(InstrumentAny::IndexInstrument(
+                IndexInstrumentModel::from_row(row).unwrap().0,
+            )))
         } else if kind == "OPTIONS_CONTRACT" {
             Ok(InstrumentAnyModel(InstrumentAny::OptionsContract(
                 OptionsContractModel::from_row(row).unwrap().0,
@@ -91,6 +109,10 @@ impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
             Ok(InstrumentAnyModel(InstrumentAny::OptionsSpread(
                 OptionsSpreadModel::from_row(row).unwrap().0,
             )))
+        } else if kind == "PERPETUAL_OPTION" {
+            Ok(InstrumentAnyModel(InstrumentAny::PerpetualOption(
+                PerpetualOptionModel::from_row(row).unwrap().0,
+            )))
         } else {
             panic!("Unknown instrument type")
         }
@@ -332,6 +354,182 @@ impl<'r> FromRow<'r, PgRow> for BinaryOptionModel {
     }
 }
 
+impl<'r> FromRow<'r, PgRow> for CfdModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let base_currency = row
+            .try_get::<Option<String>, _>("base_currency")
+            .map(|res| res.map(|s| Currency::from(s.as_str())))?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_notional = row
+            .try_get::<Option<String>, _>("max_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let min_notional = row
+            .try_get::<Option<String>, _>("min_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = Cfd::new(
+            id,
+            raw_symbol,
+            asset_class,
+            base_currency,
+            quote_currency,
+            price_precision as u8,
+            size_precision as u8,
+            price_increment,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(CfdModel(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for CommodityModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_notional = row
+            .try_get::<Option<String>, _>("max_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let min_notional = row
+            .try_get::<Option<String>, _>("min_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = Commodity::new(
+            id,
+            raw_symbol,
+            quote_currency,
+            price_precision as u8,
+            size_precision as u8,
+            price_increment,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(CommodityModel(inst))
+    }
+}
+
 impl<'r> FromRow<'r, PgRow> for CryptoFutureModel {
     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
         let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
@@ -784,8 +982,130 @@ impl<'r> FromRow<'r, PgRow> for FuturesContractModel {
 }
 
 impl<'r> FromRow<'r, PgRow> for FuturesSpreadModel {
-    fn from_row(_row: &'r PgRow) -> Result<Self, sqlx::Error> {
-        todo!("Implement FromRow for FuturesSpread")
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::new)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let underlying = row
+            .try_get::<String, _>("underlying")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let strategy_type = row
+            .try_get::<String, _>("strategy_type")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let activation_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(UnixNanos::from)?;
+        let expiration_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(UnixNanos::from)?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<String, _>("lot_size")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = FuturesSpread::new(
+            id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            underlying,
+            strategy_type,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_precision as u8,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(FuturesSpreadModel(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for IndexInstrumentModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = IndexInstrument::new(
+            id,
+            raw_symbol,
+            currency,
+            price_precision as u8,
+            size_precision as u8,
+            price_increment,
+            size_increment,
+            ts_event,
+            ts_init,
+        );
+        Ok(IndexInstrumentModel(inst))
     }
 }
 
@@ -890,7 +1210,214 @@ impl<'r> FromRow<'r, PgRow> for OptionsContractModel {
 }
 
 impl<'r> FromRow<'r, PgRow> for OptionsSpreadModel {
-    fn from_row(_row: &'r PgRow) -> Result<Self, sqlx::Error> {
-        todo!("Implement FromRow for OptionsSpread")
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::new)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let underlying = row
+            .try_get::<String, _>("underlying")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let strategy_type = row
+            .try_get::<String, _>("strategy_type")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let activation_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(UnixNanos::from)?;
+        let expiration_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(UnixNanos::from)?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<String, _>("lot_size")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = OptionsSpread::new(
+            id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            underlying,
+            strategy_type,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_precision as u8,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(OptionsSpreadModel(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for PerpetualOptionModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::new)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let underlying = row
+            .try_get::<String, _>("underlying")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let option_kind = row
+            .try_get::<String, _>("option_kind")
+            .map(|res| OptionKind::from_str(res.as_str()).unwrap())?;
+        let strike_price = row
+            .try_get::<String, _>("strike_price")
+            .map(|res| Price::from_str(res.as_str()).unwrap())?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision").unwrap();
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from_str(res.as_str()).unwrap())?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<String, _>("lot_size")
+            .map(|res| Quantity::from(res.as_str()))
+            .unwrap();
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = PerpetualOption::new(
+            id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            underlying,
+            option_kind,
+            strike_price,
+            currency,
+            price_precision as u8,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(PerpetualOptionModel(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for SyntheticInstrumentModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let components = row
+            .try_get::<Vec<String>, _>("components")?
+            .into_iter()
+            .map(InstrumentId::from)
+            .collect::<Vec<InstrumentId>>();
+        let formula = row.try_get::<String, _>("formula")?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let synthetic = SyntheticInstrument::new_checked(
+            id.symbol,
+            price_precision as u8,
+            components,
+            formula,
+            ts_event,
+            ts_init,
+        )
+        .map_err(|e| sqlx::Error::Decode(e.into()))?;
+        Ok(SyntheticInstrumentModel(synthetic))
     }
 }
@@ -15,23 +15,31 @@
 
 use std::collections::HashMap;
 
+use bytes::Bytes;
 use nautilus_common::{custom::CustomData, signal::Signal};
+use nautilus_core::UnixNanos;
 use nautilus_model::{
     accounts::{any::AccountAny, base::Account},
     data::{Bar, DataType, QuoteTick, TradeTick},
     events::{
-        position::snapshot::PositionSnapshot, AccountState, OrderEvent, OrderEventAny,
+        position::snapshot::PositionSnapshot, AccountState, OrderEventAny, OrderFilled,
         OrderSnapshot,
     },
-    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, PositionId},
-    instruments::{Instrument, InstrumentAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, ComponentId, InstrumentId, PositionId, StrategyId,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentAny, SyntheticInstrument},
     orders::{Order, OrderAny},
+    position::Position,
     types::{AccountBalance, Currency, MarginBalance},
 };
 use sqlx::{PgPool, Row};
+use ustr::Ustr;
 
 use super::models::{
-    orders::OrderSnapshotModel,
+    instruments::SyntheticInstrumentModel,
+    orders::{OrderFilledModel, OrderSnapshotModel},
     positions::PositionSnapshotModel,
     types::{CustomDataModel, SignalModel},
 };
@@ -119,21 +127,22 @@ impl DatabaseQueries {
         pool: &PgPool,
         kind: &str,
         instrument: Box<dyn Instrument>,
+        strategy_type: Option<Ustr>,
     ) -> anyhow::Result<()> {
         sqlx::query(r#"
             INSERT INTO "instrument" (
                 id, kind, raw_symbol, base_currency, underlying, quote_currency, settlement_currency, isin, asset_class, exchange,
                 multiplier, option_kind, is_inverse, strike_price, activation_ns, expiration_ns, price_precision, size_precision,
                 price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, lot_size, max_quantity, min_quantity, max_notional,
-                min_notional, max_price, min_price, ts_init, ts_event, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                min_notional, max_price, min_price, ts_init, ts_event, strategy_type, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, $34, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
             ON CONFLICT (id)
             DO UPDATE
             SET
                 kind = $2, raw_symbol = $3, base_currency= $4, underlying = $5, quote_currency = $6, settlement_currency = $7, isin = $8, asset_class = $9, exchange = $10,
                  multiplier = $11, option_kind = $12, is_inverse = $13, strike_price = $14, activation_ns = $15, expiration_ns = $16 , price_precision = $17, size_precision = $18,
                  price_increment = $19, size_increment = $20, maker_fee = $21, taker_fee = $22, margin_init = $23, margin_maint = $24, lot_size = $25, max_quantity = $26,
-                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33, updated_at = CURRENT_TIMESTAMP
+                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33, strategy_type = $34, updated_at = CURRENT_TIMESTAMP
             "#)
             .bind(instrument.id().to_string())
             .bind(kind)
@@ -168,6 +177,7 @@ impl DatabaseQueries {
             .bind(instrument.min_price().map(|x| x.to_string()))
             .bind(instrument.ts_init().to_string())
             .bind(instrument.ts_event().to_string())
+            .bind(strategy_type.map(|x| x.to_string()))
             .execute(pool)
             .await
             .map(|_| ())
@@ -196,6 +206,62 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to load instruments: {e}"))
     }
 
+    pub async fn add_synthetic(
+        pool: &PgPool,
+        synthetic: &SyntheticInstrument,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "synthetic" (
+                id, price_precision, components, formula, ts_event, ts_init, created_at, updated_at
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            ON CONFLICT (id)
+            DO UPDATE
+            SET
+                price_precision = $2, components = $3, formula = $4, ts_event = $5, ts_init = $6,
+                updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(synthetic.id.to_string())
+        .bind(i32::from(synthetic.price_precision))
+        .bind(
+            synthetic
+                .components
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>(),
+        )
+        .bind(synthetic.formula.clone())
+        .bind(synthetic.ts_event.to_string())
+        .bind(synthetic.ts_init.to_string())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into synthetic table: {e}"))
+    }
+
+    pub async fn load_synthetic(
+        pool: &PgPool,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Option<SyntheticInstrument>> {
+        sqlx::query_as::<_, SyntheticInstrumentModel>(r#"SELECT * FROM "synthetic" WHERE id = $1"#)
+            .bind(instrument_id.to_string())
+            .fetch_optional(pool)
+            .await
+            .map(|model| model.map(|m| m.0))
+            .map_err(|e| anyhow::anyhow!("Failed to load synthetic: {e}"))
+    }
+
+    pub async fn load_synthetics(pool: &PgPool) -> anyhow::Result<Vec<SyntheticInstrument>> {
+        sqlx::query_as::<_, SyntheticInstrumentModel>(r#"SELECT * FROM "synthetic""#)
+            .fetch_all(pool)
+            .await
+            .map(|rows| rows.into_iter().map(|row| row.0).collect())
+            .map_err(|e| anyhow::anyhow!("Failed to load synthetics: {e}"))
+    }
+
     pub async fn add_order(
         pool: &PgPool,
         _kind: &str,
@@ -215,59 +281,7 @@ impl DatabaseQueries {
                 );
             }
         }
-        match order.last_event().clone() {
-            OrderEventAny::Accepted(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::CancelRejected(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Canceled(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Denied(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Emulated(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Expired(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Filled(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Initialized(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::ModifyRejected(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::PendingCancel(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::PendingUpdate(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Rejected(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Released(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Submitted(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Updated(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::Triggered(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-            OrderEventAny::PartiallyFilled(event) => {
-                DatabaseQueries::add_order_event(pool, Box::new(event), client_id).await
-            }
-        }
+        DatabaseQueries::add_order_event(pool, order.last_event().clone(), client_id).await
     }
 
     pub async fn add_order_snapshot(pool: &PgPool, snapshot: OrderSnapshot) -> anyhow::Result<()> {
@@ -498,6 +512,159 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to load position snapshot: {e}"))
     }
 
+    pub async fn load_position_fills(
+        pool: &PgPool,
+        position_id: &PositionId,
+    ) -> anyhow::Result<Vec<OrderFilled>> {
+        sqlx::query_as::<_, OrderFilledModel>(
+            r#"SELECT * FROM "order_event" WHERE position_id = $1 AND kind = 'OrderFilled' ORDER BY ts_event ASC, created_at ASC"#,
+        )
+        .bind(position_id.to_string())
+        .fetch_all(pool)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.0).collect())
+        .map_err(|e| anyhow::anyhow!("Failed to load position fills: {e}"))
+    }
+
+    /// Loads the position with the given `position_id` by replaying its persisted fills.
+    pub async fn load_position(
+        pool: &PgPool,
+        position_id: &PositionId,
+    ) -> anyhow::Result<Option<Position>> {
+        let fills = DatabaseQueries::load_position_fills(pool, position_id).await?;
+        let Some(first_fill) = fills.first() else {
+            return Ok(None);
+        };
+
+        let instrument =
+            match DatabaseQueries::load_instrument(pool, &first_fill.instrument_id).await? {
+                Some(instrument) => instrument,
+                None => anyhow::bail!(
+                    "Cannot load position {position_id}: no instrument {} found",
+                    first_fill.instrument_id
+                ),
+            };
+
+        let mut position = Position::new(&instrument, *first_fill);
+        for fill in fills.iter().skip(1) {
+            position.apply(fill);
+        }
+        Ok(Some(position))
+    }
+
+    pub async fn load_positions(pool: &PgPool) -> anyhow::Result<Vec<Position>> {
+        let mut positions: Vec<Position> = Vec::new();
+        let position_ids: Vec<PositionId> = sqlx::query(
+            r#"
+            SELECT DISTINCT position_id FROM "order_event" WHERE position_id IS NOT NULL AND kind = 'OrderFilled'
+        "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| PositionId::from(row.get::<&str, _>(0)))
+                .collect()
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to load position ids: {e}"))?;
+        for id in position_ids {
+            if let Some(position) = DatabaseQueries::load_position(pool, &id).await? {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+
+    pub async fn add_index_order_position(
+        pool: &PgPool,
+        client_order_id: ClientOrderId,
+        position_id: PositionId,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "index_order_position" (
+                client_order_id, position_id, created_at, updated_at
+            ) VALUES (
+                $1, $2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            ON CONFLICT (client_order_id)
+            DO UPDATE
+            SET
+                position_id = $2, updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(client_order_id.to_string())
+        .bind(position_id.to_string())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into index_order_position table: {e}"))
+    }
+
+    pub async fn load_index_order_position(
+        pool: &PgPool,
+    ) -> anyhow::Result<HashMap<ClientOrderId, PositionId>> {
+        sqlx::query(r#"SELECT client_order_id, position_id FROM "index_order_position""#)
+            .fetch_all(pool)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| {
+                        (
+                            ClientOrderId::from(row.get::<&str, _>(0)),
+                            PositionId::from(row.get::<&str, _>(1)),
+                        )
+                    })
+                    .collect()
+            })
+            .map_err(|e| anyhow::anyhow!("Failed to load index_order_position: {e}"))
+    }
+
+    pub async fn add_index_venue_order_id(
+        pool: &PgPool,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "index_venue_order_id" (
+                client_order_id, venue_order_id, created_at, updated_at
+            ) VALUES (
+                $1, $2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            ON CONFLICT (client_order_id)
+            DO UPDATE
+            SET
+                venue_order_id = $2, updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(client_order_id.to_string())
+        .bind(venue_order_id.to_string())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into index_venue_order_id table: {e}"))
+    }
+
+    pub async fn load_index_venue_order_ids(
+        pool: &PgPool,
+    ) -> anyhow::Result<HashMap<ClientOrderId, VenueOrderId>> {
+        sqlx::query(r#"SELECT client_order_id, venue_order_id FROM "index_venue_order_id""#)
+            .fetch_all(pool)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| {
+                        (
+                            ClientOrderId::from(row.get::<&str, _>(0)),
+                            VenueOrderId::from(row.get::<&str, _>(1)),
+                        )
+                    })
+                    .collect()
+            })
+            .map_err(|e| anyhow::anyhow!("Failed to load index_venue_order_id: {e}"))
+    }
+
     pub async fn check_if_order_initialized_exists(
         pool: &PgPool,
         client_order_id: ClientOrderId,
@@ -530,9 +697,16 @@ impl DatabaseQueries {
 
     pub async fn add_order_event(
         pool: &PgPool,
-        order_event: Box<dyn OrderEvent>,
+        order_event: OrderEventAny,
         client_id: Option<ClientId>,
     ) -> anyhow::Result<()> {
+        // The released price is persisted in the `price` column for `OrderReleased` events
+        let released_price = match &order_event {
+            OrderEventAny::Released(event) => Some(event.released_price),
+            _ => None,
+        };
+        let order_event = order_event.into_boxed();
+        let price = released_price.or_else(|| order_event.price());
         let mut transaction = pool.begin().await?;
 
         // Insert trader if it does not exist
@@ -569,11 +743,11 @@ impl DatabaseQueries {
                 post_only, reduce_only, quote_quantity, reconciliation, price, last_px, last_qty, trigger_price, trigger_type, limit_offset, trailing_offset,
                 trailing_offset_type, expire_time, display_qty, emulation_trigger, trigger_instrument_id, contingency_type,
                 order_list_id, linked_order_ids, parent_order_id,
-                exec_algorithm_id, exec_spawn_id, venue_order_id, account_id, position_id, commission, ts_event, ts_init, created_at, updated_at
+                exec_algorithm_id, exec_spawn_id, venue_order_id, account_id, position_id, commission, ts_event, ts_init, reason, created_at, updated_at
             ) VALUES (
                 $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20,
                 $21, $22, $23, $24, $25::trailing_offset_type, $26, $27, $28, $29, $30, $31, $32, $33, $34,
-                $35, $36, $37, $38, $39, $40, $41, $42,  CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+                $35, $36, $37, $38, $39, $40, $41, $42, $43, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
             )
             ON CONFLICT (id)
             DO UPDATE
@@ -582,7 +756,7 @@ impl DatabaseQueries {
                 quantity = $12, time_in_force = $13, liquidity_side = $14, post_only = $15, reduce_only = $16, quote_quantity = $17, reconciliation = $18, price = $19, last_px = $20,
                 last_qty = $21, trigger_price = $22, trigger_type = $23, limit_offset = $24, trailing_offset = $25, trailing_offset_type = $26, expire_time = $27, display_qty = $28,
                 emulation_trigger = $29, trigger_instrument_id = $30, contingency_type = $31, order_list_id = $32, linked_order_ids = $33, parent_order_id = $34, exec_algorithm_id = $35,
-                exec_spawn_id = $36, venue_order_id = $37, account_id = $38, position_id = $39, commission = $40, ts_event = $41, ts_init = $42, updated_at = CURRENT_TIMESTAMP
+                exec_spawn_id = $36, venue_order_id = $37, account_id = $38, position_id = $39, commission = $40, ts_event = $41, ts_init = $42, reason = $43, updated_at = CURRENT_TIMESTAMP
 
         "#)
             .bind(order_event.id().to_string())
@@ -603,7 +777,7 @@ impl DatabaseQueries {
             .bind(order_event.reduce_only())
             .bind(order_event.quote_quantity())
             .bind(order_event.reconciliation())
-            .bind(order_event.price().map(|x| x.to_string()))
+            .bind(price.map(|x| x.to_string()))
             .bind(order_event.last_px().map(|x| x.to_string()))
             .bind(order_event.last_qty().map(|x| x.to_string()))
             .bind(order_event.trigger_price().map(|x| x.to_string()))
@@ -627,6 +801,7 @@ impl DatabaseQueries {
             .bind(order_event.commission().map(|x| x.to_string()))
             .bind(order_event.ts_event().to_string())
             .bind(order_event.ts_init().to_string())
+            .bind(order_event.reason().map(|x| x.to_string()))
             .execute(&mut *transaction)
             .await
             .map(|_| ())
@@ -1053,4 +1228,236 @@ impl DatabaseQueries {
         .map(|rows| rows.into_iter().map(|row| row.0).collect())
         .map_err(|e| anyhow::anyhow!("Failed to load custom data: {e}"))
     }
+
+    /// Upserts each key-value pair of the given `state` into the given component `table`.
+    async fn update_component_state(
+        pool: &PgPool,
+        table: &str,
+        id_column: &str,
+        component_id: String,
+        state: HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = pool.begin().await?;
+
+        let query = format!(
+            r#"
+            INSERT INTO "{table}" (
+                {id_column}, key, value, created_at, updated_at
+            ) VALUES (
+                $1, $2, $3, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            ON CONFLICT ({id_column}, key)
+            DO UPDATE
+            SET
+                value = $3, updated_at = CURRENT_TIMESTAMP
+        "#
+        );
+        for (key, value) in state {
+            sqlx::query(&query)
+                .bind(component_id.as_str())
+                .bind(key)
+                .bind(value.to_vec())
+                .execute(&mut *transaction)
+                .await
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!("Failed to insert into {table} table: {e}"))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to commit {table} transaction: {e}"))
+    }
+
+    async fn load_component_state(
+        pool: &PgPool,
+        table: &str,
+        id_column: &str,
+        component_id: &str,
+    ) -> anyhow::Result<HashMap<String, Bytes>> {
+        sqlx::query(&format!(
+            r#"SELECT key, value FROM "{table}" WHERE {id_column} = $1"#
+        ))
+        .bind(component_id)
+        .fetch_all(pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| {
+                    (
+                        row.get::<String, _>(0),
+                        Bytes::from(row.get::<Vec<u8>, _>(1)),
+                    )
+                })
+                .collect()
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to load from {table} table: {e}"))
+    }
+
+    async fn delete_component_state(
+        pool: &PgPool,
+        table: &str,
+        id_column: &str,
+        component_id: &str,
+    ) -> anyhow::Result<()> {
+        sqlx::query(&format!(r#"DELETE FROM "{table}" WHERE {id_column} = $1"#))
+            .bind(component_id)
+            .execute(pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to delete from {table} table: {e}"))
+    }
+
+    pub async fn update_actor(
+        pool: &PgPool,
+        component_id: &ComponentId,
+        state: HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        DatabaseQueries::update_component_state(
+            pool,
+            "actor_state",
+            "actor_id",
+            component_id.to_string(),
+            state,
+        )
+        .await
+    }
+
+    pub async fn load_actor(
+        pool: &PgPool,
+        component_id: &ComponentId,
+    ) -> anyhow::Result<HashMap<String, Bytes>> {
+        DatabaseQueries::load_component_state(
+            pool,
+            "actor_state",
+            "actor_id",
+            component_id.as_str(),
+        )
+        .await
+    }
+
+    pub async fn delete_actor(pool: &PgPool, component_id: &ComponentId) -> anyhow::Result<()> {
+        DatabaseQueries::delete_component_state(
+            pool,
+            "actor_state",
+            "actor_id",
+            component_id.as_str(),
+        )
+        .await
+    }
+
+    pub async fn update_strategy(
+        pool: &PgPool,
+        strategy_id: &StrategyId,
+        state: HashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        DatabaseQueries::update_component_state(
+            pool,
+            "strategy_state",
+            "strategy_id",
+            strategy_id.to_string(),
+            state,
+        )
+        .await
+    }
+
+    pub async fn load_strategy(
+        pool: &PgPool,
+        strategy_id: &StrategyId,
+    ) -> anyhow::Result<HashMap<String, Bytes>> {
+        DatabaseQueries::load_component_state(
+            pool,
+            "strategy_state",
+            "strategy_id",
+            strategy_id.as_str(),
+        )
+        .await
+    }
+
+    pub async fn delete_strategy(pool: &PgPool, strategy_id: &StrategyId) -> anyhow::Result<()> {
+        DatabaseQueries::delete_component_state(
+            pool,
+            "strategy_state",
+            "strategy_id",
+            strategy_id.as_str(),
+        )
+        .await
+    }
+
+    /// Deletes the order with the given `client_order_id`, including its events, snapshots
+    /// and index entries.
+    pub async fn delete_order(
+        pool: &PgPool,
+        client_order_id: &ClientOrderId,
+    ) -> anyhow::Result<()> {
+        let mut transaction = pool.begin().await?;
+        for table in [
+            "order_event",
+            "order",
+            "index_venue_order_id",
+            "index_order_position",
+        ] {
+            sqlx::query(&format!(
+                r#"DELETE FROM "{table}" WHERE client_order_id = $1"#
+            ))
+            .bind(client_order_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to delete from {table} table: {e}"))?;
+        }
+        transaction
+            .commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to commit transaction: {e}"))
+    }
+
+    /// Deletes the position with the given `position_id`, including its snapshots and
+    /// index entries.
+    ///
+    /// Positions are rebuilt from the fill events of their orders, so these orders should
+    /// also be deleted for the position to no longer be loaded.
+    pub async fn delete_position(pool: &PgPool, position_id: &PositionId) -> anyhow::Result<()> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query(r#"DELETE FROM "position" WHERE id = $1"#)
+            .bind(position_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to delete from position table: {e}"))?;
+        sqlx::query(r#"DELETE FROM "index_order_position" WHERE position_id = $1"#)
+            .bind(position_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!("Failed to delete from index_order_position table: {e}")
+            })?;
+        transaction
+            .commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to commit transaction: {e}"))
+    }
+
+    pub async fn add_heartbeat(pool: &PgPool, timestamp: UnixNanos) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "heartbeat" (id, ts, updated_at) VALUES ('heartbeat', $1, CURRENT_TIMESTAMP)
+            ON CONFLICT (id)
+            DO UPDATE
+            SET
+                ts = $1, updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(timestamp.to_string())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into heartbeat table: {e}"))
+    }
+
+    pub async fn load_heartbeat(pool: &PgPool) -> anyhow::Result<Option<UnixNanos>> {
+        sqlx::query(r#"SELECT ts FROM "heartbeat" WHERE id = 'heartbeat'"#)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.map(|row| UnixNanos::from(row.get::<&str, _>(0))))
+            .map_err(|e| anyhow::anyhow!("Failed to load heartbeat: {e}"))
+    }
 }
@@ -16,7 +16,10 @@
 #[cfg(test)]
 #[cfg(target_os = "linux")] // Databases only supported on Linux
 mod serial_tests {
-    use std::{collections::HashSet, time::Duration};
+    use std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    };
 
     use bytes::Bytes;
     use indexmap::indexmap;
@@ -33,17 +36,22 @@ mod serial_tests {
             DataType,
         },
         enums::{CurrencyType, OrderSide, OrderStatus, OrderType},
-        events::{account::stubs::cash_account_state_million_usd, PositionSnapshot},
+        events::{
+            account::stubs::cash_account_state_million_usd, OrderCanceled, OrderEventAny,
+            PositionSnapshot,
+        },
         identifiers::{
-            stubs::account_id, AccountId, ClientId, ClientOrderId, InstrumentId, TradeId,
-            VenueOrderId,
+            stubs::account_id, AccountId, ClientId, ClientOrderId, ComponentId, InstrumentId,
+            PositionId, StrategyId, TradeId, VenueOrderId,
         },
         instruments::{
             stubs::{
-                audusd_sim, binary_option, crypto_future_btcusdt, crypto_perpetual_ethusdt,
-                currency_pair_ethusdt, equity_aapl, futures_contract_es, options_contract_appl,
+                audusd_sim, binary_option, cfd_gold, commodity_gold, crypto_future_btcusdt,
+                crypto_perpetual_ethusdt, currency_pair_ethusdt, equity_aapl, futures_contract_es,
+                futures_spread_es, index_spx, options_contract_appl, options_spread,
+                perpetual_option_btc,
             },
-            Instrument, InstrumentAny,
+            Instrument, InstrumentAny, SyntheticInstrument,
         },
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
@@ -115,6 +123,12 @@ mod serial_tests {
         let equity = equity_aapl();
         let futures_contract = futures_contract_es(None, None);
         let options_contract = options_contract_appl();
+        let futures_spread = futures_spread_es();
+        let options_spread = options_spread();
+        let cfd = cfd_gold();
+        let commodity = commodity_gold();
+        let index = index_spx();
+        let perpetual_option = perpetual_option_btc();
 
         // Insert all instruments
         pg_cache
@@ -138,13 +152,29 @@ mod serial_tests {
         pg_cache
             .add_instrument(&InstrumentAny::OptionsContract(options_contract))
             .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::FuturesSpread(futures_spread))
+            .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::OptionsSpread(options_spread))
+            .unwrap();
+        pg_cache.add_instrument(&InstrumentAny::Cfd(cfd)).unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::Commodity(commodity))
+            .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::IndexInstrument(index))
+            .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::PerpetualOption(perpetual_option))
+            .unwrap();
 
         // Wait for cache to update
         wait_until(
             || {
                 let currencies = pg_cache.load_currencies().unwrap();
                 let instruments = pg_cache.load_instruments().unwrap();
-                currencies.len() >= 6 && instruments.len() >= 7
+                currencies.len() >= 6 && instruments.len() >= 13
             },
             Duration::from_secs(2),
         );
@@ -248,10 +278,43 @@ mod serial_tests {
                 .unwrap(),
             InstrumentAny::OptionsContract(options_contract)
         );
+        assert_eq!(
+            pg_cache
+                .load_instrument(&futures_spread.id())
+                .unwrap()
+                .unwrap(),
+            InstrumentAny::FuturesSpread(futures_spread)
+        );
+        assert_eq!(
+            pg_cache
+                .load_instrument(&options_spread.id())
+                .unwrap()
+                .unwrap(),
+            InstrumentAny::OptionsSpread(options_spread)
+        );
+        assert_eq!(
+            pg_cache.load_instrument(&cfd.id()).unwrap().unwrap(),
+            InstrumentAny::Cfd(cfd)
+        );
+        assert_eq!(
+            pg_cache.load_instrument(&commodity.id()).unwrap().unwrap(),
+            InstrumentAny::Commodity(commodity)
+        );
+        assert_eq!(
+            pg_cache.load_instrument(&index.id()).unwrap().unwrap(),
+            InstrumentAny::IndexInstrument(index)
+        );
+        assert_eq!(
+            pg_cache
+                .load_instrument(&perpetual_option.id())
+                .unwrap()
+                .unwrap(),
+            InstrumentAny::PerpetualOption(perpetual_option)
+        );
 
         // Check instrument list is correct
         let instruments = pg_cache.load_instruments().unwrap();
-        assert_eq!(instruments.len(), 7);
+        assert_eq!(instruments.len(), 13);
         assert_eq!(
             instruments.into_keys().collect::<HashSet<InstrumentId>>(),
             vec![
@@ -261,7 +324,13 @@ mod serial_tests {
                 currency_pair.id(),
                 equity.id(),
                 futures_contract.id(),
-                options_contract.id()
+                options_contract.id(),
+                futures_spread.id(),
+                options_spread.id(),
+                cfd.id(),
+                commodity.id(),
+                index.id(),
+                perpetual_option.id()
             ]
             .into_iter()
             .collect::<HashSet<InstrumentId>>()
@@ -478,7 +547,7 @@ mod serial_tests {
         // Update account
         let new_account_state_event =
             cash_account_state_million_usd("1000000 USD", "100000 USD", "900000 USD");
-        account.apply(new_account_state_event);
+        account.apply(new_account_state_event).unwrap();
         pg_cache.update_account(&account).unwrap();
         wait_until(
             || {
@@ -721,4 +790,249 @@ mod serial_tests {
         pg_cache.flush().unwrap();
         pg_cache.close().unwrap();
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_and_load_synthetic() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let synthetic = SyntheticInstrument::default();
+        pg_cache.add_synthetic(&synthetic).unwrap();
+        wait_until(
+            || pg_cache.load_synthetics().unwrap().len() == 1,
+            Duration::from_secs(2),
+        );
+
+        let result = pg_cache.load_synthetic(&synthetic.id).unwrap();
+        assert_eq!(result.id, synthetic.id);
+        assert_eq!(result.price_precision, synthetic.price_precision);
+        assert_eq!(result.components, synthetic.components);
+        assert_eq!(result.formula, synthetic.formula);
+        assert!(pg_cache
+            .load_synthetic(&InstrumentId::from("ETH-LTC.SYNTH"))
+            .is_err());
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_load_position_from_fills_and_index() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let client_order_id = ClientOrderId::new("O-19700101-000000-001-003-1");
+        let position_id = PositionId::new("P-1");
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let account = account_id();
+
+        // Add foreign key dependencies: instrument and currencies
+        pg_cache
+            .add_currency(&instrument.base_currency().unwrap())
+            .unwrap();
+        pg_cache.add_currency(&instrument.quote_currency()).unwrap();
+        pg_cache.add_instrument(&instrument).unwrap();
+
+        let mut market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.0"))
+            .client_order_id(client_order_id)
+            .build();
+        pg_cache.add_order(&market_order, None).unwrap();
+
+        let submitted = TestOrderEventStubs::order_submitted(&market_order, account);
+        market_order.apply(submitted).unwrap();
+        pg_cache.update_order(market_order.last_event()).unwrap();
+
+        let accepted =
+            TestOrderEventStubs::order_accepted(&market_order, account, VenueOrderId::new("002"));
+        market_order.apply(accepted).unwrap();
+        pg_cache.update_order(market_order.last_event()).unwrap();
+
+        let filled = TestOrderEventStubs::order_filled(
+            &market_order,
+            &instrument,
+            Some(TradeId::new("T-19700101-000000-001-003-1")),
+            Some(position_id),
+            Some(Price::from("100.0")),
+            Some(Quantity::from("1.0")),
+            None,
+            None,
+            None,
+            Some(account),
+        );
+        market_order.apply(filled.clone()).unwrap();
+        pg_cache.update_order(market_order.last_event()).unwrap();
+
+        let OrderEventAny::Filled(fill) = filled else {
+            panic!("Expected fill event");
+        };
+        let expected = Position::new(&instrument, fill);
+        pg_cache.add_position(&expected).unwrap();
+        pg_cache
+            .index_venue_order_id(client_order_id, VenueOrderId::new("002"))
+            .unwrap();
+        pg_cache
+            .index_order_position(client_order_id, position_id)
+            .unwrap();
+        wait_until(
+            || {
+                pg_cache.load_positions().unwrap().len() == 1
+                    && pg_cache.load_index_order_position().unwrap().len() == 1
+            },
+            Duration::from_secs(2),
+        );
+
+        let position = pg_cache.load_position(&position_id).unwrap();
+        assert_eq!(position.id, expected.id);
+        assert_eq!(position.quantity, expected.quantity);
+        assert_eq!(position.avg_px_open, expected.avg_px_open);
+        assert_eq!(position.events.len(), 1);
+
+        let index = pg_cache.load_index_order_position().unwrap();
+        assert_eq!(index.get(&client_order_id).unwrap().id, position_id);
+        assert!(pg_cache.load_position(&PositionId::new("P-2")).is_err());
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_update_order_when_canceled() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let client_order_id = ClientOrderId::new("O-19700101-000000-001-004-1");
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+        let account = account_id();
+
+        // Add foreign key dependencies: instrument and currencies
+        pg_cache
+            .add_currency(&instrument.base_currency().unwrap())
+            .unwrap();
+        pg_cache.add_currency(&instrument.quote_currency()).unwrap();
+        pg_cache.add_instrument(&instrument).unwrap();
+
+        let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("100.0"))
+            .quantity(Quantity::from("1.0"))
+            .client_order_id(client_order_id)
+            .build();
+        pg_cache.add_order(&limit_order, None).unwrap();
+
+        let submitted = TestOrderEventStubs::order_submitted(&limit_order, account);
+        limit_order.apply(submitted).unwrap();
+        pg_cache.update_order(limit_order.last_event()).unwrap();
+
+        let accepted =
+            TestOrderEventStubs::order_accepted(&limit_order, account, VenueOrderId::new("003"));
+        limit_order.apply(accepted).unwrap();
+        pg_cache.update_order(limit_order.last_event()).unwrap();
+
+        let canceled = OrderEventAny::Canceled(OrderCanceled::new(
+            limit_order.trader_id(),
+            limit_order.strategy_id(),
+            limit_order.instrument_id(),
+            limit_order.client_order_id(),
+            Default::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            limit_order.venue_order_id(),
+            Some(account),
+        ));
+        limit_order.apply(canceled).unwrap();
+        pg_cache.update_order(limit_order.last_event()).unwrap();
+        wait_until(
+            || {
+                let result = pg_cache.load_order(&client_order_id).unwrap();
+                result.is_some() && result.unwrap().status() == OrderStatus::Canceled
+            },
+            Duration::from_secs(2),
+        );
+
+        let result = pg_cache.load_order(&client_order_id).unwrap();
+        assert_entirely_equal(result.unwrap(), limit_order);
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_delete_order() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let client_order_id = ClientOrderId::new("O-19700101-000000-001-004-1");
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_ethusdt());
+
+        // Add foreign key dependencies: instrument and currencies
+        pg_cache
+            .add_currency(&instrument.base_currency().unwrap())
+            .unwrap();
+        pg_cache.add_currency(&instrument.quote_currency()).unwrap();
+        pg_cache.add_instrument(&instrument).unwrap();
+
+        let market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.0"))
+            .client_order_id(client_order_id)
+            .build();
+
+        pg_cache.add_order(&market_order, None).unwrap();
+        wait_until(
+            || pg_cache.load_order(&client_order_id).unwrap().is_some(),
+            Duration::from_secs(2),
+        );
+
+        pg_cache.delete_order(&client_order_id).unwrap();
+        wait_until(
+            || pg_cache.load_order(&client_order_id).unwrap().is_none(),
+            Duration::from_secs(2),
+        );
+        assert!(!pg_cache
+            .load_index_order_client()
+            .unwrap()
+            .contains_key(&client_order_id));
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_update_load_and_delete_actor_and_strategy_state() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let component_id = ComponentId::new("MyActor-001");
+        let strategy_id = StrategyId::new("S-001");
+        let state = HashMap::from([
+            (String::from("a"), Bytes::from("1")),
+            (String::from("b"), Bytes::from("2")),
+        ]);
+
+        pg_cache.update_actor(&component_id, &state).unwrap();
+        pg_cache.update_strategy(&strategy_id, &state).unwrap();
+        wait_until(
+            || {
+                pg_cache.load_actor(&component_id).unwrap().len() == 2
+                    && pg_cache.load_strategy(&strategy_id).unwrap().len() == 2
+            },
+            Duration::from_secs(2),
+        );
+        assert_eq!(pg_cache.load_actor(&component_id).unwrap(), state);
+        assert_eq!(pg_cache.load_strategy(&strategy_id).unwrap(), state);
+
+        pg_cache.delete_actor(&component_id).unwrap();
+        pg_cache.delete_strategy(&strategy_id).unwrap();
+        wait_until(
+            || {
+                pg_cache.load_actor(&component_id).unwrap().is_empty()
+                    && pg_cache.load_strategy(&strategy_id).unwrap().is_empty()
+            },
+            Duration::from_secs(2),
+        );
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
 }
@@ -18,7 +18,10 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{
+        base::Account, betting::BettingAccount, cash::CashAccount, error::AccountError,
+        margin::MarginAccount,
+    },
     enums::{AccountType, LiquiditySide},
     events::{AccountState, OrderFilled},
     identifiers::AccountId,
@@ -30,6 +33,7 @@ use crate::{
 pub enum AccountAny {
     Margin(MarginAccount),
     Cash(CashAccount),
+    Betting(BettingAccount),
 }
 
 impl AccountAny {
@@ -38,6 +42,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.id,
             AccountAny::Cash(cash) => cash.id,
+            AccountAny::Betting(betting) => betting.id,
         }
     }
 
@@ -45,6 +50,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.last_event(),
             AccountAny::Cash(cash) => cash.last_event(),
+            AccountAny::Betting(betting) => betting.last_event(),
         }
     }
 
@@ -52,13 +58,15 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.events(),
             AccountAny::Cash(cash) => cash.events(),
+            AccountAny::Betting(betting) => betting.events(),
         }
     }
 
-    pub fn apply(&mut self, event: AccountState) {
+    pub fn apply(&mut self, event: AccountState) -> Result<(), AccountError> {
         match self {
             AccountAny::Margin(margin) => margin.apply(event),
             AccountAny::Cash(cash) => cash.apply(event),
+            AccountAny::Betting(betting) => betting.apply(event),
         }
     }
 
@@ -66,6 +74,31 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances(),
             AccountAny::Cash(cash) => cash.balances(),
+            AccountAny::Betting(betting) => betting.balances(),
+        }
+    }
+
+    pub fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balance_total(currency),
+            AccountAny::Cash(cash) => cash.balance_total(currency),
+            AccountAny::Betting(betting) => betting.balance_total(currency),
+        }
+    }
+
+    pub fn balance_free(&self, currency: Option<Currency>) -> Option<Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balance_free(currency),
+            AccountAny::Cash(cash) => cash.balance_free(currency),
+            AccountAny::Betting(betting) => betting.balance_free(currency),
+        }
+    }
+
+    pub fn balance_locked(&self, currency: Option<Currency>) -> Option<Money> {
+        match self {
+            AccountAny::Margin(margin) => margin.balance_locked(currency),
+            AccountAny::Cash(cash) => cash.balance_locked(currency),
+            AccountAny::Betting(betting) => betting.balance_locked(currency),
         }
     }
 
@@ -73,6 +106,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances_locked(),
             AccountAny::Cash(cash) => cash.balances_locked(),
+            AccountAny::Betting(betting) => betting.balances_locked(),
         }
     }
 
@@ -80,6 +114,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.base_currency(),
             AccountAny::Cash(cash) => cash.base_currency(),
+            AccountAny::Betting(betting) => betting.base_currency(),
         }
     }
 
@@ -91,7 +126,7 @@ impl AccountAny {
         let init_event = events.first().unwrap();
         let mut account = Self::from(init_event.clone());
         for event in events.iter().skip(1) {
-            account.apply(event.clone());
+            account.apply(event.clone())?;
         }
         Ok(account)
     }
@@ -105,6 +140,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.calculate_pnls(instrument, fill, position),
             AccountAny::Cash(cash) => cash.calculate_pnls(instrument, fill, position),
+            AccountAny::Betting(betting) => betting.calculate_pnls(instrument, fill, position),
         }
     }
 
@@ -131,6 +167,13 @@ impl AccountAny {
                 liquidity_side,
                 use_quote_for_inverse,
             ),
+            AccountAny::Betting(betting) => betting.calculate_commission(
+                instrument,
+                last_qty,
+                last_px,
+                liquidity_side,
+                use_quote_for_inverse,
+            ),
         }
     }
 }
@@ -140,11 +183,29 @@ impl From<AccountState> for AccountAny {
         match event.account_type {
             AccountType::Margin => AccountAny::Margin(MarginAccount::new(event, false)),
             AccountType::Cash => AccountAny::Cash(CashAccount::new(event, false)),
-            AccountType::Betting => todo!("Betting account not implemented"),
+            AccountType::Betting => AccountAny::Betting(BettingAccount::new(event, false)),
         }
     }
 }
 
+impl From<CashAccount> for AccountAny {
+    fn from(account: CashAccount) -> Self {
+        AccountAny::Cash(account)
+    }
+}
+
+impl From<MarginAccount> for AccountAny {
+    fn from(account: MarginAccount) -> Self {
+        AccountAny::Margin(account)
+    }
+}
+
+impl From<BettingAccount> for AccountAny {
+    fn from(account: BettingAccount) -> Self {
+        AccountAny::Betting(account)
+    }
+}
+
 impl Default for AccountAny {
     /// Creates a new default [`AccountAny`] instance.
     fn default() -> Self {
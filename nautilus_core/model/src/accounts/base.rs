@@ -19,6 +19,7 @@ use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    accounts::error::AccountError,
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{AccountState, OrderFilled},
     identifiers::AccountId,
@@ -120,17 +121,24 @@ impl BaseAccount {
         self.events.last().cloned()
     }
 
-    pub fn update_balances(&mut self, balances: Vec<AccountBalance>) {
+    /// Updates the account balances, only the balances for the given currencies are updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AccountError::BalanceNegative`] error if any balance total is negative, in
+    /// which case no balances are updated.
+    pub fn update_balances(&mut self, balances: Vec<AccountBalance>) -> Result<(), AccountError> {
+        if let Some(balance) = balances.iter().find(|balance| balance.total.raw < 0) {
+            return Err(AccountError::BalanceNegative {
+                balance: balance.total.as_decimal(),
+                currency: balance.currency,
+            });
+        }
+
         for balance in balances {
-            // clone real balance without reference
-            if balance.total.raw < 0 {
-                // TODO raise AccountBalanceNegative event
-                panic!("Cannot update balances with total less than 0.0")
-            } else {
-                // clear asset balance
-                self.balances.insert(balance.currency, balance);
-            }
+            self.balances.insert(balance.currency, balance);
         }
+        Ok(())
     }
 
     pub fn update_commissions(&mut self, commission: Money) {
@@ -145,9 +153,45 @@ impl BaseAccount {
             .insert(currency, total_commissions + commission.as_f64());
     }
 
-    pub fn base_apply(&mut self, event: AccountState) {
-        self.update_balances(event.balances.clone());
+    /// Applies the given account state `event`, updating the balances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the balances cannot be updated, in which case the event is not applied.
+    pub fn base_apply(&mut self, event: AccountState) -> Result<(), AccountError> {
+        self.update_balances(event.balances.clone())?;
         self.events.push(event);
+        Ok(())
+    }
+
+    pub fn base_recalculate_balance(&mut self, currency: Currency) {
+        let current_balance = match self.balances.get(&currency) {
+            Some(balance) => *balance,
+            None => {
+                return;
+            }
+        };
+
+        let total_locked = self
+            .balances
+            .values()
+            .filter(|balance| balance.currency == currency)
+            .fold(Decimal::ZERO, |acc, balance| {
+                acc + balance.locked.as_decimal()
+            });
+
+        let new_balance = AccountBalance::new(
+            current_balance.total,
+            Money::new(total_locked.to_f64().unwrap(), currency),
+            Money::new(
+                (current_balance.total.as_decimal() - total_locked)
+                    .to_f64()
+                    .unwrap(),
+                currency,
+            ),
+        );
+
+        self.balances.insert(currency, new_balance);
     }
 
     pub fn base_calculate_balance_locked(
@@ -276,7 +320,7 @@ pub trait Account: 'static + Send {
     fn currencies(&self) -> Vec<Currency>;
     fn starting_balances(&self) -> HashMap<Currency, Money>;
     fn balances(&self) -> HashMap<Currency, AccountBalance>;
-    fn apply(&mut self, event: AccountState);
+    fn apply(&mut self, event: AccountState) -> Result<(), AccountError>;
     fn calculate_balance_locked(
         &mut self,
         instrument: InstrumentAny,
@@ -0,0 +1,558 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    accounts::{
+        base::{Account, BaseAccount},
+        error::AccountError,
+    },
+    enums::{AccountType, LiquiditySide, OrderSide},
+    events::{AccountState, OrderFilled},
+    identifiers::{
+        stubs::{account_id, uuid4},
+        AccountId,
+    },
+    instruments::InstrumentAny,
+    position::Position,
+    types::{AccountBalance, Currency, Money, Price, Quantity},
+};
+
+/// Represents an account on a betting exchange.
+///
+/// Balances are held as cash, with the liability of each open bet locked until the bet is
+/// matched. Following betting exchange conventions a `SELL` order is a back bet and a `BUY`
+/// order is a lay bet, with prices quoted as decimal odds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct BettingAccount {
+    pub base: BaseAccount,
+}
+
+impl BettingAccount {
+    /// Creates a new [`BettingAccount`] instance.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Self {
+        Self {
+            base: BaseAccount::new(event, calculate_account_state),
+        }
+    }
+
+    #[must_use]
+    pub fn is_betting_account(&self) -> bool {
+        self.account_type == AccountType::Betting
+    }
+
+    pub fn recalculate_balance(&mut self, currency: Currency) {
+        self.base_recalculate_balance(currency);
+    }
+
+    /// Returns the change in free balance from an order with the given parameters being matched.
+    ///
+    /// A back (`SELL`) reduces the balance by the stake, whereas a lay (`BUY`) reduces the
+    /// balance by the liability of the bet.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `side` is `NoOrderSide`.
+    #[must_use]
+    pub fn balance_impact(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        side: OrderSide,
+    ) -> Money {
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let impact = match side {
+            OrderSide::Sell => -notional.as_decimal(),
+            OrderSide::Buy => -notional.as_decimal() * (price.as_decimal() - Decimal::ONE),
+            OrderSide::NoOrderSide => panic!("Invalid `OrderSide` in `balance_impact`"),
+        };
+        Money::new(impact.to_f64().unwrap(), notional.currency)
+    }
+}
+
+/// Returns the stake for a bet of `quantity` at decimal odds `price`, which is the amount won
+/// by the backer (and lost by the layer) if the selection wins.
+#[must_use]
+pub fn stake(quantity: Quantity, price: Price) -> Decimal {
+    quantity.as_decimal() * (price.as_decimal() - Decimal::ONE)
+}
+
+/// Returns the liability of a bet, which is the amount locked while the bet is open.
+///
+/// # Panics
+///
+/// This function panics if `side` is `NoOrderSide`.
+#[must_use]
+pub fn liability(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Sell => quantity.as_decimal(),
+        OrderSide::Buy => stake(quantity, price),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` in `liability`"),
+    }
+}
+
+/// Returns the payoff of a bet if the selection wins, signed by order side (positive for `BUY`).
+///
+/// # Panics
+///
+/// This function panics if `side` is `NoOrderSide`.
+#[must_use]
+pub fn win_payoff(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Buy => stake(quantity, price),
+        OrderSide::Sell => -stake(quantity, price),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` in `win_payoff`"),
+    }
+}
+
+/// Returns the payoff of a bet if the selection loses, signed by order side (negative for `BUY`).
+///
+/// # Panics
+///
+/// This function panics if `side` is `NoOrderSide`.
+#[must_use]
+pub fn lose_payoff(quantity: Quantity, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Buy => -quantity.as_decimal(),
+        OrderSide::Sell => quantity.as_decimal(),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` in `lose_payoff`"),
+    }
+}
+
+/// Returns the exposure of a bet, being the difference between the win and lose payoffs
+/// (equal to `quantity * price`, signed by order side).
+///
+/// # Panics
+///
+/// This function panics if `side` is `NoOrderSide`.
+#[must_use]
+pub fn exposure(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    win_payoff(quantity, price, side) - lose_payoff(quantity, side)
+}
+
+impl Account for BettingAccount {
+    fn id(&self) -> AccountId {
+        self.id
+    }
+
+    fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        self.base_currency
+    }
+
+    fn is_cash_account(&self) -> bool {
+        self.account_type == AccountType::Cash
+    }
+
+    fn is_margin_account(&self) -> bool {
+        self.account_type == AccountType::Margin
+    }
+
+    fn calculated_account_state(&self) -> bool {
+        self.calculate_account_state
+    }
+
+    fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_total(currency)
+    }
+
+    fn balances_total(&self) -> HashMap<Currency, Money> {
+        self.base_balances_total()
+    }
+
+    fn balance_free(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_free(currency)
+    }
+
+    fn balances_free(&self) -> HashMap<Currency, Money> {
+        self.base_balances_free()
+    }
+
+    fn balance_locked(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_locked(currency)
+    }
+
+    fn balances_locked(&self) -> HashMap<Currency, Money> {
+        self.base_balances_locked()
+    }
+
+    fn last_event(&self) -> Option<AccountState> {
+        self.base_last_event()
+    }
+
+    fn events(&self) -> Vec<AccountState> {
+        self.events.clone()
+    }
+
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    fn currencies(&self) -> Vec<Currency> {
+        self.balances.keys().copied().collect()
+    }
+
+    fn starting_balances(&self) -> HashMap<Currency, Money> {
+        self.balances_starting.clone()
+    }
+
+    fn balances(&self) -> HashMap<Currency, AccountBalance> {
+        self.balances.clone()
+    }
+
+    fn apply(&mut self, event: AccountState) -> Result<(), AccountError> {
+        self.base_apply(event)
+    }
+
+    /// Calculates the balance locked for an order, being the liability of the bet in the
+    /// quote currency of the instrument.
+    fn calculate_balance_locked(
+        &mut self,
+        instrument: InstrumentAny,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        if use_quote_for_inverse == Some(true) {
+            anyhow::bail!("`use_quote_for_inverse` is not applicable for betting accounts");
+        }
+        if side == OrderSide::NoOrderSide {
+            anyhow::bail!("Invalid `OrderSide` in `calculate_balance_locked`");
+        }
+
+        let locked = liability(quantity, price, side);
+        Ok(Money::new(
+            locked.to_f64().unwrap(),
+            instrument.quote_currency(),
+        ))
+    }
+
+    /// Calculates the PnL for a fill, being the cash committed to the matched bet (the stake
+    /// for a back, or the liability for a lay). Bets are settled outside of the account.
+    fn calculate_pnls(
+        &self,
+        instrument: InstrumentAny,
+        fill: OrderFilled,
+        position: Option<Position>,
+    ) -> anyhow::Result<Vec<Money>> {
+        if fill.order_side == OrderSide::NoOrderSide {
+            anyhow::bail!("Invalid `OrderSide` in `calculate_pnls`");
+        }
+
+        let fill_qty = position.map_or(fill.last_qty, |pos| {
+            if pos.quantity.is_positive() && pos.entry != fill.order_side {
+                // Only book open quantity towards realized PnL
+                pos.quantity.min(fill.last_qty)
+            } else {
+                fill.last_qty
+            }
+        });
+        let pnl = self.balance_impact(&instrument, fill_qty, fill.last_px, fill.order_side);
+        Ok(vec![pnl])
+    }
+
+    fn calculate_commission(
+        &self,
+        instrument: InstrumentAny,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        self.base_calculate_commission(
+            instrument,
+            last_qty,
+            last_px,
+            liquidity_side,
+            use_quote_for_inverse,
+        )
+    }
+}
+
+impl Deref for BettingAccount {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BettingAccount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl PartialEq for BettingAccount {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BettingAccount {}
+
+impl Display for BettingAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BettingAccount(id={}, type={}, base={})",
+            self.id,
+            self.account_type,
+            self.base_currency.map_or_else(
+                || "None".to_string(),
+                |base_currency| format!("{}", base_currency.code)
+            ),
+        )
+    }
+}
+
+impl Default for BettingAccount {
+    /// Creates a new default [`BettingAccount`] instance.
+    fn default() -> Self {
+        let init_event = AccountState::new(
+            account_id(),
+            AccountType::Betting,
+            vec![AccountBalance::new(
+                Money::from("1000 GBP"),
+                Money::from("0 GBP"),
+                Money::from("1000 GBP"),
+            )],
+            vec![],
+            true,
+            uuid4(),
+            0.into(),
+            0.into(),
+            Some(Currency::GBP()),
+        );
+        Self::new(init_event, false)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        accounts::{any::AccountAny, stubs::*},
+        enums::OrderType,
+        events::account::stubs::*,
+        identifiers::position_id::PositionId,
+        instruments::{stubs::*, BettingInstrument, Instrument},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    };
+
+    fn fill(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: &str,
+        price: &str,
+    ) -> OrderFilled {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+            Some(AccountId::from("SIM-001")),
+        )
+        .into()
+    }
+
+    #[rstest]
+    fn test_display(betting_account: BettingAccount) {
+        assert_eq!(
+            format!("{betting_account}"),
+            "BettingAccount(id=SIM-001, type=BETTING, base=GBP)"
+        );
+    }
+
+    #[rstest]
+    fn test_instantiate_betting_account(
+        betting_account: BettingAccount,
+        betting_account_state: AccountState,
+    ) {
+        assert_eq!(betting_account.id, AccountId::from("SIM-001"));
+        assert_eq!(betting_account.account_type, AccountType::Betting);
+        assert!(betting_account.is_betting_account());
+        assert!(!betting_account.is_cash_account());
+        assert!(!betting_account.is_margin_account());
+        assert_eq!(betting_account.base_currency, Some(Currency::GBP()));
+        assert_eq!(betting_account.last_event(), Some(betting_account_state));
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("0 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_account_any_from_betting_account_state(betting_account_state: AccountState) {
+        let account = AccountAny::from(betting_account_state);
+        assert!(matches!(account, AccountAny::Betting(_)));
+        assert_eq!(account.base_currency(), Some(Currency::GBP()));
+    }
+
+    #[rstest]
+    #[case("10.00", "3.50", dec!(25))]
+    #[case("10.00", "1.01", dec!(0.1))]
+    fn test_stake(#[case] quantity: &str, #[case] price: &str, #[case] expected: Decimal) {
+        assert_eq!(
+            stake(Quantity::from(quantity), Price::from(price)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, dec!(10), dec!(-25), dec!(10), dec!(-35))]
+    #[case(OrderSide::Buy, dec!(25), dec!(25), dec!(-10), dec!(35))]
+    fn test_liability_and_payoffs(
+        #[case] side: OrderSide,
+        #[case] expected_liability: Decimal,
+        #[case] expected_win: Decimal,
+        #[case] expected_lose: Decimal,
+        #[case] expected_exposure: Decimal,
+    ) {
+        let quantity = Quantity::from("10.00");
+        let price = Price::from("3.50");
+        assert_eq!(liability(quantity, price, side), expected_liability);
+        assert_eq!(win_payoff(quantity, price, side), expected_win);
+        assert_eq!(lose_payoff(quantity, side), expected_lose);
+        assert_eq!(exposure(quantity, price, side), expected_exposure);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, Money::from("10.00 GBP"))]
+    #[case(OrderSide::Buy, Money::from("25.00 GBP"))]
+    fn test_calculate_balance_locked(
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let locked = betting_account
+            .calculate_balance_locked(
+                betting.into_any(),
+                side,
+                Quantity::from("10.00"),
+                Price::from("3.50"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(locked, expected);
+    }
+
+    #[rstest]
+    fn test_calculate_balance_locked_with_use_quote_for_inverse_returns_error(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let result = betting_account.calculate_balance_locked(
+            betting.into_any(),
+            OrderSide::Sell,
+            Quantity::from("10.00"),
+            Price::from("3.50"),
+            Some(true),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, Money::from("-10.00 GBP"))]
+    #[case(OrderSide::Buy, Money::from("-25.00 GBP"))]
+    fn test_balance_impact(
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let impact = betting_account.balance_impact(
+            &betting.into_any(),
+            Quantity::from("10.00"),
+            Price::from("3.50"),
+            side,
+        );
+        assert_eq!(impact, expected);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, Money::from("-10.00 GBP"))]
+    #[case(OrderSide::Buy, Money::from("-25.00 GBP"))]
+    fn test_calculate_pnls(
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let instrument = betting.into_any();
+        let fill = fill(&instrument, side, "10.00", "3.50");
+        let pnls = betting_account
+            .calculate_pnls(instrument, fill, None)
+            .unwrap();
+        assert_eq!(pnls, vec![expected]);
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_when_closing_only_books_open_quantity(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let instrument = betting.into_any();
+        let open_fill = fill(&instrument, OrderSide::Sell, "4.00", "3.00");
+        let position = Position::new(&instrument, open_fill);
+        let close_fill = fill(&instrument, OrderSide::Buy, "10.00", "2.50");
+        let pnls = betting_account
+            .calculate_pnls(instrument, close_fill, Some(position))
+            .unwrap();
+        assert_eq!(pnls, vec![Money::from("-6.00 GBP")]);
+    }
+}
@@ -19,11 +19,14 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+use nautilus_core::{datetime::nanos_to_secs, UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::base::{Account, BaseAccount},
+    accounts::{
+        base::{Account, BaseAccount},
+        error::AccountError,
+    },
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{AccountState, OrderFilled},
     identifiers::{
@@ -35,6 +38,8 @@ use crate::{
     types::{AccountBalance, Currency, Money, Price, Quantity},
 };
 
+const SECONDS_IN_YEAR: f64 = 365.0 * 86_400.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -42,6 +47,16 @@ use crate::{
 )]
 pub struct CashAccount {
     pub base: BaseAccount,
+    /// If the account may borrow (spot margin), allowing negative balances.
+    pub allow_borrowing: bool,
+    /// The annual borrow interest rates per currency.
+    pub borrow_rates: HashMap<Currency, f64>,
+    /// The maximum amounts which may be borrowed per currency (unlimited if not set).
+    pub borrow_limits: HashMap<Currency, Money>,
+    /// The cumulative borrow interest charged per currency.
+    pub interest: HashMap<Currency, Money>,
+    /// UNIX timestamp (nanoseconds) when interest was last accrued.
+    pub last_interest_accrual: Option<UnixNanos>,
 }
 
 impl CashAccount {
@@ -49,7 +64,134 @@ impl CashAccount {
     pub fn new(event: AccountState, calculate_account_state: bool) -> Self {
         Self {
             base: BaseAccount::new(event, calculate_account_state),
+            allow_borrowing: false,
+            borrow_rates: HashMap::new(),
+            borrow_limits: HashMap::new(),
+            interest: HashMap::new(),
+            last_interest_accrual: None,
+        }
+    }
+
+    pub fn set_allow_borrowing(&mut self, allow_borrowing: bool) {
+        self.allow_borrowing = allow_borrowing;
+    }
+
+    pub fn set_borrow_rate(&mut self, currency: Currency, rate: f64) {
+        self.borrow_rates.insert(currency, rate);
+    }
+
+    #[must_use]
+    pub fn get_borrow_rate(&self, currency: &Currency) -> f64 {
+        *self.borrow_rates.get(currency).unwrap_or(&0.0)
+    }
+
+    /// Sets the maximum amount which may be borrowed in the currency of `limit`.
+    pub fn set_borrow_limit(&mut self, limit: Money) {
+        self.borrow_limits.insert(limit.currency, limit);
+    }
+
+    /// Returns the maximum amount which may be borrowed for the given `currency`, or `None` if
+    /// borrowing is unlimited.
+    #[must_use]
+    pub fn get_borrow_limit(&self, currency: &Currency) -> Option<Money> {
+        self.borrow_limits.get(currency).copied()
+    }
+
+    /// Returns the balance available to trade for the given `currency`, being the free balance
+    /// plus any remaining borrowing capacity, or `None` if borrowing is unlimited.
+    ///
+    /// As the free balance of a borrowed currency is already reduced by the amount borrowed,
+    /// the remaining capacity is the free balance plus the borrow limit.
+    #[must_use]
+    pub fn balance_available(&self, currency: Currency) -> Option<Money> {
+        let free = self
+            .base_balance_free(Some(currency))
+            .unwrap_or_else(|| Money::new(0.0, currency));
+        if !self.allow_borrowing {
+            return Some(free);
         }
+        self.get_borrow_limit(&currency).map(|limit| free + limit)
+    }
+
+    /// Returns the amount currently borrowed for the given `currency` (zero if the balance
+    /// total is not negative).
+    #[must_use]
+    pub fn borrowed(&self, currency: Currency) -> Money {
+        match self.balances.get(&currency) {
+            Some(balance) if balance.total.raw < 0 => -balance.total,
+            _ => Money::new(0.0, currency),
+        }
+    }
+
+    /// Returns the cumulative borrow interest charged for the given `currency`.
+    #[must_use]
+    pub fn interest(&self, currency: Currency) -> Money {
+        self.interest
+            .get(&currency)
+            .copied()
+            .unwrap_or_else(|| Money::new(0.0, currency))
+    }
+
+    /// Updates the account balances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AccountError::BalanceNegative`] error if any balance total is negative and
+    /// the account does not allow borrowing.
+    pub fn update_balances(&mut self, balances: Vec<AccountBalance>) -> Result<(), AccountError> {
+        if !self.allow_borrowing {
+            return self.base.update_balances(balances);
+        }
+
+        for balance in balances {
+            self.balances.insert(balance.currency, balance);
+        }
+        Ok(())
+    }
+
+    /// Accrues borrow interest on all negative balances for the time elapsed since the last
+    /// accrual, deducting it from the balances and returning the interest charged per currency.
+    ///
+    /// Interest accrues linearly on the annual borrow rate (365 day year). The first call only
+    /// records `ts` as the accrual start.
+    pub fn accrue_interest(&mut self, ts: UnixNanos) -> Vec<Money> {
+        let last_accrual = self.last_interest_accrual.replace(ts);
+        let Some(last_accrual) = last_accrual else {
+            return Vec::new();
+        };
+        if ts <= last_accrual {
+            self.last_interest_accrual = Some(last_accrual);
+            return Vec::new();
+        }
+
+        let elapsed_years = nanos_to_secs(ts.as_u64() - last_accrual.as_u64()) / SECONDS_IN_YEAR;
+        let mut charged = Vec::new();
+        for balance in self.base.balances.values_mut() {
+            let currency = balance.currency;
+            let rate = *self.borrow_rates.get(&currency).unwrap_or(&0.0);
+            if balance.total.raw >= 0 || rate <= 0.0 {
+                continue;
+            }
+
+            let amount = Money::new(-balance.total.as_f64() * rate * elapsed_years, currency);
+            if amount.is_zero() {
+                continue;
+            }
+
+            *balance = AccountBalance::new(
+                balance.total - amount,
+                balance.locked,
+                balance.free - amount,
+            );
+            let total_interest = self
+                .interest
+                .get(&currency)
+                .copied()
+                .unwrap_or_else(|| Money::new(0.0, currency));
+            self.interest.insert(currency, total_interest + amount);
+            charged.push(amount);
+        }
+        charged
     }
 
     #[must_use]
@@ -67,33 +209,7 @@ impl CashAccount {
     }
 
     pub fn recalculate_balance(&mut self, currency: Currency) {
-        let current_balance = match self.balances.get(&currency) {
-            Some(balance) => *balance,
-            None => {
-                return;
-            }
-        };
-
-        let total_locked = self
-            .balances
-            .values()
-            .filter(|balance| balance.currency == currency)
-            .fold(Decimal::ZERO, |acc, balance| {
-                acc + balance.locked.as_decimal()
-            });
-
-        let new_balance = AccountBalance::new(
-            current_balance.total,
-            Money::new(total_locked.to_f64().unwrap(), currency),
-            Money::new(
-                (current_balance.total.as_decimal() - total_locked)
-                    .to_f64()
-                    .unwrap(),
-                currency,
-            ),
-        );
-
-        self.balances.insert(currency, new_balance);
+        self.base_recalculate_balance(currency);
     }
 }
 
@@ -119,7 +235,7 @@ impl Account for CashAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -170,8 +286,10 @@ impl Account for CashAccount {
         self.balances.clone()
     }
 
-    fn apply(&mut self, event: AccountState) {
-        self.base_apply(event);
+    fn apply(&mut self, event: AccountState) -> Result<(), AccountError> {
+        self.update_balances(event.balances.clone())?;
+        self.events.push(event);
+        Ok(())
     }
 
     fn calculate_balance_locked(
@@ -279,10 +397,12 @@ impl Default for CashAccount {
 mod tests {
     use std::collections::{HashMap, HashSet};
 
+    use nautilus_core::UnixNanos;
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     use crate::{
-        accounts::{base::Account, cash::CashAccount, stubs::*},
+        accounts::{base::Account, cash::CashAccount, error::AccountError, stubs::*},
         enums::{AccountType, LiquiditySide, OrderSide, OrderType},
         events::{account::stubs::*, AccountState},
         identifiers::{position_id::PositionId, AccountId},
@@ -396,7 +516,9 @@ mod tests {
         cash_account_state_multi_changed_btc: AccountState,
     ) {
         // apply second account event
-        cash_account_multi.apply(cash_account_state_multi_changed_btc.clone());
+        cash_account_multi
+            .apply(cash_account_state_multi_changed_btc.clone())
+            .unwrap();
         assert_eq!(
             cash_account_multi.last_event(),
             Some(cash_account_state_multi_changed_btc.clone())
@@ -655,4 +777,115 @@ mod tests {
             .unwrap();
         assert_eq!(result, Money::from("5294 JPY"));
     }
+
+    #[rstest]
+    fn test_apply_negative_balance_when_borrowing_not_allowed_returns_error(
+        mut cash_account_million_usd: CashAccount,
+    ) {
+        let event = cash_account_state_million_usd("-1000 USD", "0 USD", "-1000 USD");
+        let result = cash_account_million_usd.apply(event);
+
+        assert_eq!(
+            result,
+            Err(AccountError::BalanceNegative {
+                balance: dec!(-1000),
+                currency: Currency::USD(),
+            })
+        );
+        assert_eq!(cash_account_million_usd.event_count(), 1);
+        assert_eq!(
+            cash_account_million_usd.balance_total(None),
+            Some(Money::from("1000000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_negative_balance_when_borrowing_allowed(
+        mut cash_account_million_usd: CashAccount,
+    ) {
+        cash_account_million_usd.set_allow_borrowing(true);
+        let event = cash_account_state_million_usd("-1000 USD", "0 USD", "-1000 USD");
+        cash_account_million_usd.apply(event).unwrap();
+
+        assert_eq!(cash_account_million_usd.event_count(), 2);
+        assert_eq!(
+            cash_account_million_usd.balance_total(None),
+            Some(Money::from("-1000 USD"))
+        );
+        assert_eq!(
+            cash_account_million_usd.borrowed(Currency::USD()),
+            Money::from("1000 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_accrue_interest_on_borrowed_balance(mut cash_account_million_usd: CashAccount) {
+        cash_account_million_usd.set_allow_borrowing(true);
+        cash_account_million_usd.set_borrow_rate(Currency::USD(), 0.10);
+        let event = cash_account_state_million_usd("-1000 USD", "0 USD", "-1000 USD");
+        cash_account_million_usd.apply(event).unwrap();
+
+        assert!(cash_account_million_usd
+            .accrue_interest(UnixNanos::default())
+            .is_empty());
+        let one_year = UnixNanos::from(365 * 86_400 * 1_000_000_000_u64);
+        let charged = cash_account_million_usd.accrue_interest(one_year);
+
+        assert_eq!(charged, vec![Money::from("100 USD")]);
+        assert_eq!(
+            cash_account_million_usd.balance_total(None),
+            Some(Money::from("-1100 USD"))
+        );
+        assert_eq!(
+            cash_account_million_usd.balance_free(None),
+            Some(Money::from("-1100 USD"))
+        );
+        assert_eq!(
+            cash_account_million_usd.interest(Currency::USD()),
+            Money::from("100 USD")
+        );
+        assert_eq!(
+            cash_account_million_usd.last_interest_accrual,
+            Some(one_year)
+        );
+    }
+
+    #[rstest]
+    fn test_balance_available_with_borrow_limit(mut cash_account_million_usd: CashAccount) {
+        assert_eq!(
+            cash_account_million_usd.balance_available(Currency::USD()),
+            Some(Money::from("1000000 USD"))
+        );
+
+        cash_account_million_usd.set_allow_borrowing(true);
+        assert_eq!(
+            cash_account_million_usd.balance_available(Currency::USD()),
+            None
+        );
+
+        cash_account_million_usd.set_borrow_limit(Money::from("5000 USD"));
+        let event = cash_account_state_million_usd("-1000 USD", "0 USD", "-1000 USD");
+        cash_account_million_usd.apply(event).unwrap();
+        assert_eq!(
+            cash_account_million_usd.balance_available(Currency::USD()),
+            Some(Money::from("4000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_accrue_interest_when_no_borrowed_balance(mut cash_account_million_usd: CashAccount) {
+        cash_account_million_usd.set_borrow_rate(Currency::USD(), 0.10);
+        cash_account_million_usd.accrue_interest(UnixNanos::default());
+        let charged = cash_account_million_usd.accrue_interest(UnixNanos::from(1_000_000_000));
+
+        assert!(charged.is_empty());
+        assert_eq!(
+            cash_account_million_usd.balance_total(None),
+            Some(Money::from("1000000 USD"))
+        );
+        assert_eq!(
+            cash_account_million_usd.interest(Currency::USD()),
+            Money::from("0 USD")
+        );
+    }
 }
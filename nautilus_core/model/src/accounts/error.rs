@@ -0,0 +1,77 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Errors associated with account balance and margin operations.
+
+use rust_decimal::Decimal;
+
+use crate::types::Currency;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum AccountError {
+    /// The account balance for a currency would become negative.
+    #[error("AccountBalanceNegative(balance={balance}, currency={currency})")]
+    BalanceNegative {
+        balance: Decimal,
+        currency: Currency,
+    },
+    /// The account margin for a currency would exceed the balance, in which case some form of
+    /// liquidation event will occur.
+    #[error(
+        "AccountMarginExceeded(balance={balance}, margin={margin}, free={free}, currency={currency})",
+        free = .balance - .margin
+    )]
+    MarginExceeded {
+        balance: Decimal,
+        margin: Decimal,
+        currency: Currency,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    fn test_balance_negative_display() {
+        let error = AccountError::BalanceNegative {
+            balance: dec!(-10.5),
+            currency: Currency::USD(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "AccountBalanceNegative(balance=-10.5, currency=USD)"
+        );
+    }
+
+    #[rstest]
+    fn test_margin_exceeded_display() {
+        let error = AccountError::MarginExceeded {
+            balance: dec!(100),
+            margin: dec!(150),
+            currency: Currency::USD(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "AccountMarginExceeded(balance=100, margin=150, free=-50, currency=USD)"
+        );
+    }
+}
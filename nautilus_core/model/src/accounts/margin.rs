@@ -26,7 +26,10 @@ use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::base::{Account, BaseAccount},
+    accounts::{
+        base::{Account, BaseAccount},
+        error::AccountError,
+    },
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{AccountState, OrderFilled},
     identifiers::{AccountId, InstrumentId},
@@ -106,7 +109,17 @@ impl MarginAccount {
         maintenance_margins
     }
 
-    pub fn update_initial_margin(&mut self, instrument_id: InstrumentId, margin_init: Money) {
+    /// Updates the initial (order) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AccountError::MarginExceeded`] error if the total margin would exceed the
+    /// balance total, in which case the balance is not updated.
+    pub fn update_initial_margin(
+        &mut self,
+        instrument_id: InstrumentId,
+        margin_init: Money,
+    ) -> Result<(), AccountError> {
         let margin_balance = self.margins.get(&instrument_id);
         if margin_balance.is_none() {
             self.margins.insert(
@@ -123,7 +136,7 @@ impl MarginAccount {
             new_margin_balance.initial = margin_init;
             self.margins.insert(instrument_id, new_margin_balance);
         }
-        self.recalculate_balance(margin_init.currency);
+        self.recalculate_balance(margin_init.currency)
     }
 
     #[must_use]
@@ -136,11 +149,17 @@ impl MarginAccount {
         margin_balance.unwrap().initial
     }
 
+    /// Updates the maintenance (position) margin for the given instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AccountError::MarginExceeded`] error if the total margin would exceed the
+    /// balance total, in which case the balance is not updated.
     pub fn update_maintenance_margin(
         &mut self,
         instrument_id: InstrumentId,
         margin_maintenance: Money,
-    ) {
+    ) -> Result<(), AccountError> {
         let margin_balance = self.margins.get(&instrument_id);
         if margin_balance.is_none() {
             self.margins.insert(
@@ -157,7 +176,7 @@ impl MarginAccount {
             new_margin_balance.maintenance = margin_maintenance;
             self.margins.insert(instrument_id, new_margin_balance);
         }
-        self.recalculate_balance(margin_maintenance.currency);
+        self.recalculate_balance(margin_maintenance.currency)
     }
 
     #[must_use]
@@ -222,7 +241,17 @@ impl MarginAccount {
         }
     }
 
-    pub fn recalculate_balance(&mut self, currency: Currency) {
+    /// Recalculates the locked and free balance for the given `currency` from the margins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AccountError::MarginExceeded`] error if the total margin exceeds the balance
+    /// total.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there is no balance for the given `currency`.
+    pub fn recalculate_balance(&mut self, currency: Currency) -> Result<(), AccountError> {
         let current_balance = match self.balances.get(&currency) {
             Some(balance) => balance,
             None => panic!("Cannot recalculate balance when no starting balance"),
@@ -237,17 +266,21 @@ impl MarginAccount {
             }
         });
         let total_free = current_balance.total.raw - total_margin;
-        // TODO error handle this with AccountMarginExceeded
-        assert!(
-            total_free >= 0,
-            "Cannot recalculate balance when total_free is less than 0.0"
-        );
+        if total_free < 0 {
+            return Err(AccountError::MarginExceeded {
+                balance: current_balance.total.as_decimal(),
+                margin: Money::from_raw(total_margin, currency).as_decimal(),
+                currency,
+            });
+        }
+
         let new_balance = AccountBalance::new(
             current_balance.total,
             Money::from_raw(total_margin, currency),
             Money::from_raw(total_free, currency),
         );
         self.balances.insert(currency, new_balance);
+        Ok(())
     }
 }
 
@@ -287,7 +320,7 @@ impl Account for MarginAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -328,8 +361,8 @@ impl Account for MarginAccount {
     fn balances(&self) -> HashMap<Currency, AccountBalance> {
         self.balances.clone()
     }
-    fn apply(&mut self, event: AccountState) {
-        self.base_apply(event);
+    fn apply(&mut self, event: AccountState) -> Result<(), AccountError> {
+        self.base_apply(event)
     }
     fn calculate_balance_locked(
         &mut self,
@@ -404,9 +437,10 @@ mod tests {
     use std::collections::HashMap;
 
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     use crate::{
-        accounts::{stubs::*, Account, MarginAccount},
+        accounts::{stubs::*, Account, AccountError, MarginAccount},
         events::{account::stubs::*, AccountState},
         identifiers::{stubs::*, InstrumentId},
         instruments::{stubs::*, CryptoPerpetual, CurrencyPair},
@@ -518,7 +552,9 @@ mod tests {
     ) {
         assert_eq!(margin_account.margins.len(), 0);
         let margin = Money::from("10000 USD");
-        margin_account.update_initial_margin(instrument_id_aud_usd_sim, margin);
+        margin_account
+            .update_initial_margin(instrument_id_aud_usd_sim, margin)
+            .unwrap();
         assert_eq!(
             margin_account.initial_margin(instrument_id_aud_usd_sim),
             margin
@@ -531,13 +567,37 @@ mod tests {
         assert_eq!(margins, vec![margin]);
     }
 
+    #[rstest]
+    fn test_update_margin_init_when_margin_exceeds_balance_returns_error(
+        mut margin_account: MarginAccount,
+        instrument_id_aud_usd_sim: InstrumentId,
+    ) {
+        let result = margin_account
+            .update_initial_margin(instrument_id_aud_usd_sim, Money::from("2000000 USD"));
+
+        assert_eq!(
+            result,
+            Err(AccountError::MarginExceeded {
+                balance: dec!(1525000),
+                margin: dec!(2000000),
+                currency: Currency::USD(),
+            })
+        );
+        assert_eq!(
+            margin_account.balance_free(None),
+            Some(Money::from("1500000 USD"))
+        );
+    }
+
     #[rstest]
     fn test_update_margin_maintenance(
         mut margin_account: MarginAccount,
         instrument_id_aud_usd_sim: InstrumentId,
     ) {
         let margin = Money::from("10000 USD");
-        margin_account.update_maintenance_margin(instrument_id_aud_usd_sim, margin);
+        margin_account
+            .update_maintenance_margin(instrument_id_aud_usd_sim, margin)
+            .unwrap();
         assert_eq!(
             margin_account.maintenance_margin(instrument_id_aud_usd_sim),
             margin
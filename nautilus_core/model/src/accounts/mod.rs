@@ -13,11 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Account types such as `CashAccount` and `MarginAccount`.
+//! Account types such as `CashAccount`, `MarginAccount` and `BettingAccount`.
 
 pub mod any;
 pub mod base;
+pub mod betting;
 pub mod cash;
+pub mod error;
 pub mod margin;
 
 #[cfg(feature = "stubs")]
@@ -27,6 +29,8 @@ pub mod stubs;
 pub use crate::accounts::{
     any::AccountAny,
     base::{Account, BaseAccount},
+    betting::BettingAccount,
     cash::CashAccount,
+    error::AccountError,
     margin::MarginAccount,
 };
@@ -16,7 +16,7 @@
 use rstest::fixture;
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{base::Account, betting::BettingAccount, cash::CashAccount, margin::MarginAccount},
     enums::LiquiditySide,
     events::account::{state::AccountState, stubs::*},
     instruments::InstrumentAny,
@@ -43,6 +43,11 @@ pub fn cash_account_multi(cash_account_state_multi: AccountState) -> CashAccount
     CashAccount::new(cash_account_state_multi, true)
 }
 
+#[fixture]
+pub fn betting_account(betting_account_state: AccountState) -> BettingAccount {
+    BettingAccount::new(betting_account_state, true)
+}
+
 #[must_use]
 pub fn calculate_commission(
     instrument: InstrumentAny,
@@ -13,7 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::fmt::Display;
+
 use implied_vol::{implied_black_volatility, norm_cdf, norm_pdf};
+use nautilus_core::UnixNanos;
+
+use super::GetTsInit;
+use crate::identifiers::InstrumentId;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -117,6 +123,101 @@ pub fn imply_vol_and_greeks(
     }
 }
 
+/// Represents the greeks and implied volatilities of an option, as published by a venue.
+///
+/// Implied volatilities are kept in the units provided by the venue (for example, Deribit
+/// publishes them as percentages).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct OptionGreeks {
+    /// The option instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The rate of change of the option price with respect to the underlying price.
+    pub delta: f64,
+    /// The rate of change of delta with respect to the underlying price.
+    pub gamma: f64,
+    /// The change of the option price for a one percent change in implied volatility.
+    pub vega: f64,
+    /// The change of the option price per calendar day.
+    pub theta: f64,
+    /// The change of the option price for a one percent change in the interest rate.
+    pub rho: f64,
+    /// The implied volatility of the mark price.
+    pub mark_iv: f64,
+    /// The implied volatility of the best bid, if available.
+    pub bid_iv: Option<f64>,
+    /// The implied volatility of the best ask, if available.
+    pub ask_iv: Option<f64>,
+    /// The underlying price used to compute the greeks.
+    pub underlying_price: f64,
+    /// UNIX timestamp (nanoseconds) when the greeks event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OptionGreeks {
+    /// Creates a new [`OptionGreeks`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        delta: f64,
+        gamma: f64,
+        vega: f64,
+        theta: f64,
+        rho: f64,
+        mark_iv: f64,
+        bid_iv: Option<f64>,
+        ask_iv: Option<f64>,
+        underlying_price: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+            mark_iv,
+            bid_iv,
+            ask_iv,
+            underlying_price,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for OptionGreeks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},delta={},gamma={},vega={},theta={},rho={},mark_iv={},underlying_price={},{}",
+            self.instrument_id,
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+            self.rho,
+            self.mark_iv,
+            self.underlying_price,
+            self.ts_event,
+        )
+    }
+}
+
+impl GetTsInit for OptionGreeks {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -300,4 +401,28 @@ mod tests {
             "Theta difference exceeds tolerance"
         );
     }
+
+    #[rstest]
+    fn test_option_greeks_display() {
+        let greeks = OptionGreeks::new(
+            InstrumentId::from("BTC-9JUN20-9875-P.DERIBIT"),
+            -0.66961,
+            0.00117,
+            3.27047,
+            -46.99823,
+            -0.66548,
+            63.9,
+            Some(62.23),
+            None,
+            9733.72,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(
+            greeks.to_string(),
+            "BTC-9JUN20-9875-P.DERIBIT,delta=-0.66961,gamma=0.00117,vega=3.27047,theta=-46.99823,rho=-0.66548,mark_iv=63.9,underlying_price=9733.72,1"
+        );
+        assert_eq!(greeks.ts_init(), UnixNanos::from(2));
+    }
 }
@@ -0,0 +1,184 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `AuctionImbalance` data type representing an order imbalance ahead of an auction.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    enums::OrderSide,
+    identifiers::InstrumentId,
+    types::{Price, Quantity},
+};
+
+/// Represents an order imbalance published by a venue ahead of an opening or closing auction.
+///
+/// The near and far prices follow the common exchange imbalance feed conventions, where the
+/// near price includes continuous book orders and the far price includes auction orders only.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct AuctionImbalance {
+    /// The instrument ID for the imbalance.
+    pub instrument_id: InstrumentId,
+    /// The reference price at which the paired and imbalance quantities are calculated.
+    pub ref_price: Price,
+    /// The hypothetical auction clearing price for both auction and continuous book orders.
+    pub near_price: Price,
+    /// The hypothetical auction clearing price for auction orders only.
+    pub far_price: Price,
+    /// The quantity which is eligible to be matched at `ref_price`.
+    pub paired_qty: Quantity,
+    /// The quantity which is not paired at `ref_price`.
+    pub imbalance_qty: Quantity,
+    /// The side of the `imbalance_qty` (can be `NO_ORDER_SIDE`).
+    pub side: OrderSide,
+    /// UNIX timestamp (nanoseconds) when the imbalance event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl AuctionImbalance {
+    /// Creates a new [`AuctionImbalance`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        ref_price: Price,
+        near_price: Price,
+        far_price: Price,
+        paired_qty: Quantity,
+        imbalance_qty: Quantity,
+        side: OrderSide,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            ref_price,
+            near_price,
+            far_price,
+            paired_qty,
+            imbalance_qty,
+            side,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("ref_price".to_string(), "Int64".to_string());
+        metadata.insert("near_price".to_string(), "Int64".to_string());
+        metadata.insert("far_price".to_string(), "Int64".to_string());
+        metadata.insert("paired_qty".to_string(), "UInt64".to_string());
+        metadata.insert("imbalance_qty".to_string(), "UInt64".to_string());
+        metadata.insert("side".to_string(), "UInt8".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for AuctionImbalance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.instrument_id,
+            self.ref_price,
+            self.near_price,
+            self.far_price,
+            self.paired_qty,
+            self.imbalance_qty,
+            self.side,
+            self.ts_event,
+        )
+    }
+}
+
+impl Serializable for AuctionImbalance {}
+
+impl GetTsInit for AuctionImbalance {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_auction_imbalance;
+
+    #[rstest]
+    fn test_imbalance_to_string(stub_auction_imbalance: AuctionImbalance) {
+        assert_eq!(
+            stub_auction_imbalance.to_string(),
+            "AAPL.XNAS,150.25,150.30,150.20,1000,250,BUY,1"
+        );
+    }
+
+    #[rstest]
+    fn test_imbalance_json_serialization(stub_auction_imbalance: AuctionImbalance) {
+        let serialized = stub_auction_imbalance.as_json_bytes().unwrap();
+        let deserialized = AuctionImbalance::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_auction_imbalance);
+    }
+
+    #[rstest]
+    fn test_get_metadata() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = AuctionImbalance::get_metadata(&instrument_id, 2, 0);
+        assert_eq!(metadata["instrument_id"], "AAPL.XNAS");
+        assert_eq!(metadata["price_precision"], "2");
+        assert_eq!(metadata["size_precision"], "0");
+    }
+}
@@ -0,0 +1,226 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `Liquidation` data type representing a forced position closure by a venue.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use derive_builder::Builder;
+use indexmap::IndexMap;
+use nautilus_core::{correctness::FAILED, serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    enums::OrderSide,
+    identifiers::{InstrumentId, TradeId},
+    types::{quantity::check_positive_quantity, Price, Quantity},
+};
+
+/// Represents a liquidation event in a market.
+///
+/// The `side` is the side of the liquidation order, so a `BUY` liquidation closed a short
+/// position and a `SELL` liquidation closed a long position.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct Liquidation {
+    /// The liquidation instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The liquidation order side.
+    pub side: OrderSide,
+    /// The liquidation price.
+    pub price: Price,
+    /// The liquidated quantity.
+    pub quantity: Quantity,
+    /// The liquidation trade match ID (assigned by the venue).
+    pub trade_id: TradeId,
+    /// UNIX timestamp (nanoseconds) when the liquidation event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Liquidation {
+    /// Creates a new [`Liquidation`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `quantity` is not positive (> 0).
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    pub fn new_checked(
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        trade_id: TradeId,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_positive_quantity(quantity.raw, "quantity.raw")?;
+
+        Ok(Self {
+            instrument_id,
+            side,
+            price,
+            quantity,
+            trade_id,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`Liquidation`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `quantity` is not positive (> 0).
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        trade_id: TradeId,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            instrument_id,
+            side,
+            price,
+            quantity,
+            trade_id,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("side".to_string(), "UInt8".to_string());
+        metadata.insert("price".to_string(), "Int64".to_string());
+        metadata.insert("quantity".to_string(), "UInt64".to_string());
+        metadata.insert("trade_id".to_string(), "Utf8".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for Liquidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.instrument_id, self.side, self.price, self.quantity, self.trade_id, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for Liquidation {}
+
+impl GetTsInit for Liquidation {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_liquidation;
+
+    #[rstest]
+    #[should_panic(expected = "not positive")]
+    fn test_liquidation_new_with_zero_quantity_panics() {
+        let _ = Liquidation::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            OrderSide::Sell,
+            Price::from("100000.50"),
+            Quantity::from("0.000"),
+            TradeId::from("123456"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+    }
+
+    #[rstest]
+    fn test_liquidation_to_string(stub_liquidation: Liquidation) {
+        assert_eq!(
+            stub_liquidation.to_string(),
+            "BTCUSDT-PERP.BINANCE,SELL,100000.50,1.500,123456,1"
+        );
+    }
+
+    #[rstest]
+    fn test_liquidation_json_serialization(stub_liquidation: Liquidation) {
+        let serialized = stub_liquidation.as_json_bytes().unwrap();
+        let deserialized = Liquidation::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_liquidation);
+    }
+
+    #[rstest]
+    fn test_liquidation_msgpack_serialization(stub_liquidation: Liquidation) {
+        let serialized = stub_liquidation.as_msgpack_bytes().unwrap();
+        let deserialized = Liquidation::from_msgpack_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_liquidation);
+    }
+
+    #[rstest]
+    fn test_get_metadata() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = Liquidation::get_metadata(&instrument_id, 2, 3);
+        assert_eq!(metadata["instrument_id"], "BTCUSDT-PERP.BINANCE");
+        assert_eq!(metadata["price_precision"], "2");
+        assert_eq!(metadata["size_precision"], "3");
+    }
+}
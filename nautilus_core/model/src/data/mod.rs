@@ -20,8 +20,13 @@ pub mod delta;
 pub mod deltas;
 pub mod depth;
 pub mod greeks;
+pub mod imbalance;
+pub mod liquidation;
+pub mod open_interest;
 pub mod order;
+pub mod prices;
 pub mod quote;
+pub mod statistics;
 pub mod status;
 pub mod trade;
 
@@ -46,9 +51,14 @@ pub use bar::{Bar, BarSpecification, BarType};
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{OrderBookDepth10, DEPTH10_LEN};
-pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult};
+pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult, OptionGreeks};
+pub use imbalance::AuctionImbalance;
+pub use liquidation::Liquidation;
+pub use open_interest::OpenInterestUpdate;
 pub use order::{BookOrder, NULL_ORDER};
+pub use prices::{IndexPriceUpdate, MarkPriceUpdate};
 pub use quote::QuoteTick;
+pub use statistics::MarketStatistic;
 pub use status::InstrumentStatus;
 pub use trade::TradeTick;
 
@@ -71,6 +81,12 @@ pub enum Data {
     Quote(QuoteTick),
     Trade(TradeTick),
     Bar(Bar),
+    MarkPriceUpdate(MarkPriceUpdate),
+    IndexPriceUpdate(IndexPriceUpdate),
+    OpenInterestUpdate(OpenInterestUpdate),
+    Liquidation(Liquidation),
+    AuctionImbalance(AuctionImbalance),
+    MarketStatistic(MarketStatistic),
 }
 
 macro_rules! impl_try_from_data {
@@ -94,6 +110,12 @@ impl_try_from_data!(Depth10, OrderBookDepth10);
 impl_try_from_data!(Quote, QuoteTick);
 impl_try_from_data!(Trade, TradeTick);
 impl_try_from_data!(Bar, Bar);
+impl_try_from_data!(MarkPriceUpdate, MarkPriceUpdate);
+impl_try_from_data!(IndexPriceUpdate, IndexPriceUpdate);
+impl_try_from_data!(OpenInterestUpdate, OpenInterestUpdate);
+impl_try_from_data!(Liquidation, Liquidation);
+impl_try_from_data!(AuctionImbalance, AuctionImbalance);
+impl_try_from_data!(MarketStatistic, MarketStatistic);
 
 pub fn to_variant<T: TryFrom<Data>>(data: Vec<Data>) -> Vec<T> {
     data.into_iter()
@@ -111,6 +133,12 @@ impl Data {
             Self::Quote(quote) => quote.instrument_id,
             Self::Trade(trade) => trade.instrument_id,
             Self::Bar(bar) => bar.bar_type.instrument_id(),
+            Self::MarkPriceUpdate(mark_price) => mark_price.instrument_id,
+            Self::IndexPriceUpdate(index_price) => index_price.instrument_id,
+            Self::OpenInterestUpdate(open_interest) => open_interest.instrument_id,
+            Self::Liquidation(liquidation) => liquidation.instrument_id,
+            Self::AuctionImbalance(imbalance) => imbalance.instrument_id,
+            Self::MarketStatistic(statistic) => statistic.instrument_id,
         }
     }
 
@@ -133,6 +161,12 @@ impl GetTsInit for Data {
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::MarkPriceUpdate(p) => p.ts_init,
+            Self::IndexPriceUpdate(p) => p.ts_init,
+            Self::OpenInterestUpdate(o) => o.ts_init,
+            Self::Liquidation(l) => l.ts_init,
+            Self::AuctionImbalance(i) => i.ts_init,
+            Self::MarketStatistic(s) => s.ts_init,
         }
     }
 }
@@ -178,6 +212,42 @@ impl From<Bar> for Data {
     }
 }
 
+impl From<MarkPriceUpdate> for Data {
+    fn from(value: MarkPriceUpdate) -> Self {
+        Self::MarkPriceUpdate(value)
+    }
+}
+
+impl From<IndexPriceUpdate> for Data {
+    fn from(value: IndexPriceUpdate) -> Self {
+        Self::IndexPriceUpdate(value)
+    }
+}
+
+impl From<OpenInterestUpdate> for Data {
+    fn from(value: OpenInterestUpdate) -> Self {
+        Self::OpenInterestUpdate(value)
+    }
+}
+
+impl From<Liquidation> for Data {
+    fn from(value: Liquidation) -> Self {
+        Self::Liquidation(value)
+    }
+}
+
+impl From<AuctionImbalance> for Data {
+    fn from(value: AuctionImbalance) -> Self {
+        Self::AuctionImbalance(value)
+    }
+}
+
+impl From<MarketStatistic> for Data {
+    fn from(value: MarketStatistic) -> Self {
+        Self::MarketStatistic(value)
+    }
+}
+
 // TODO: https://blog.rust-lang.org/2024/03/30/i128-layout-update.html
 // i128 and u128 is now FFI compatible. However, since the clippy lint
 // hasn't been removed yet. We'll suppress with #[cfg_attr(feature = "high-precision", allow(improper_ctypes_definitions))]
@@ -0,0 +1,141 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OpenInterestUpdate` data type representing the open interest of a derivative instrument.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use derive_builder::Builder;
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{identifiers::InstrumentId, types::Quantity};
+
+/// Represents an open interest update for a derivative instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct OpenInterestUpdate {
+    /// The instrument ID for the open interest.
+    pub instrument_id: InstrumentId,
+    /// The total number of outstanding contracts.
+    pub value: Quantity,
+    /// UNIX timestamp (nanoseconds) when the open interest event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OpenInterestUpdate {
+    /// Creates a new [`OpenInterestUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("value".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for OpenInterestUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for OpenInterestUpdate {}
+
+impl GetTsInit for OpenInterestUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_open_interest_update;
+
+    #[rstest]
+    fn test_to_string(stub_open_interest_update: OpenInterestUpdate) {
+        assert_eq!(
+            stub_open_interest_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,12345.678,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_open_interest_update: OpenInterestUpdate) {
+        let serialized = stub_open_interest_update.as_json_bytes().unwrap();
+        let deserialized = OpenInterestUpdate::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_open_interest_update);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_open_interest_update: OpenInterestUpdate) {
+        let serialized = stub_open_interest_update.as_msgpack_bytes().unwrap();
+        let deserialized = OpenInterestUpdate::from_msgpack_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_open_interest_update);
+    }
+}
@@ -0,0 +1,226 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `MarketStatistic` data type representing a statistic published by a venue.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    enums::MarketStatisticType,
+    identifiers::InstrumentId,
+    types::{Price, Quantity, PRICE_UNDEF, QUANTITY_UNDEF},
+};
+
+/// Represents a market statistic published by a venue, such as a settlement price or the
+/// cleared volume for a session.
+///
+/// Depending on the `stat_type` either the price or the quantity is populated, with the other
+/// value left undefined.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct MarketStatistic {
+    /// The instrument ID for the statistic.
+    pub instrument_id: InstrumentId,
+    /// The type of the statistic.
+    pub stat_type: MarketStatisticType,
+    /// The statistic price (undefined if not populated for the `stat_type`).
+    #[serde(with = "optional_price")]
+    pub price: Price,
+    /// The statistic value for non-price statistics (undefined if not populated for the `stat_type`).
+    #[serde(with = "optional_quantity")]
+    pub quantity: Quantity,
+    /// UNIX timestamp (nanoseconds) when the statistic event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl MarketStatistic {
+    /// Creates a new [`MarketStatistic`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        stat_type: MarketStatisticType,
+        price: Option<Price>,
+        quantity: Option<Quantity>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            stat_type,
+            price: price.unwrap_or_else(|| Price::from_raw(PRICE_UNDEF, 0)),
+            quantity: quantity.unwrap_or_else(|| Quantity::from_raw(QUANTITY_UNDEF, 0)),
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the statistic price, if populated.
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        (!self.price.is_undefined()).then_some(self.price)
+    }
+
+    /// Returns the statistic value for non-price statistics, if populated.
+    #[must_use]
+    pub fn quantity(&self) -> Option<Quantity> {
+        (!self.quantity.is_undefined()).then_some(self.quantity)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("stat_type".to_string(), "UInt8".to_string());
+        metadata.insert("price".to_string(), "Int64".to_string());
+        metadata.insert("quantity".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for MarketStatistic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id,
+            self.stat_type,
+            self.price().map_or("None".to_string(), |p| p.to_string()),
+            self.quantity()
+                .map_or("None".to_string(), |q| q.to_string()),
+            self.ts_event,
+        )
+    }
+}
+
+impl Serializable for MarketStatistic {}
+
+impl GetTsInit for MarketStatistic {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Serializes an undefined statistic price as `null`, since the sentinel raw value
+/// is not a valid price.
+mod optional_price {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::{Price, PRICE_UNDEF};
+
+    pub fn serialize<S: Serializer>(price: &Price, serializer: S) -> Result<S::Ok, S::Error> {
+        (!price.is_undefined())
+            .then_some(*price)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Price, D::Error> {
+        Ok(Option::<Price>::deserialize(deserializer)?
+            .unwrap_or_else(|| Price::from_raw(PRICE_UNDEF, 0)))
+    }
+}
+
+/// Serializes an undefined statistic quantity as `null`, since the sentinel raw value
+/// is not a valid quantity.
+mod optional_quantity {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::{Quantity, QUANTITY_UNDEF};
+
+    pub fn serialize<S: Serializer>(quantity: &Quantity, serializer: S) -> Result<S::Ok, S::Error> {
+        (!quantity.is_undefined())
+            .then_some(*quantity)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Quantity, D::Error> {
+        Ok(Option::<Quantity>::deserialize(deserializer)?
+            .unwrap_or_else(|| Quantity::from_raw(QUANTITY_UNDEF, 0)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{data::stubs::stub_market_statistic, enums::FromU8};
+
+    #[rstest]
+    fn test_statistic_to_string(stub_market_statistic: MarketStatistic) {
+        assert_eq!(
+            stub_market_statistic.to_string(),
+            "ESM4.GLBX,SETTLEMENT_PRICE,5250.25,None,1"
+        );
+    }
+
+    #[rstest]
+    fn test_statistic_json_serialization(stub_market_statistic: MarketStatistic) {
+        let serialized = stub_market_statistic.as_json_bytes().unwrap();
+        let deserialized = MarketStatistic::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_market_statistic);
+    }
+
+    #[rstest]
+    fn test_statistic_type_from_u8() {
+        assert_eq!(
+            MarketStatisticType::from_u8(3),
+            Some(MarketStatisticType::SettlementPrice)
+        );
+        assert_eq!(MarketStatisticType::from_u8(0), None);
+    }
+
+    #[rstest]
+    fn test_statistic_optional_values(stub_market_statistic: MarketStatistic) {
+        assert_eq!(stub_market_statistic.price(), Some(Price::from("5250.25")));
+        assert_eq!(stub_market_statistic.quantity(), None);
+        assert!(stub_market_statistic.quantity.is_undefined());
+    }
+}
@@ -19,14 +19,15 @@ use nautilus_core::UnixNanos;
 use rstest::fixture;
 
 use super::{
-    Bar, BarSpecification, BarType, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
-    OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN,
+    AuctionImbalance, Bar, BarSpecification, BarType, IndexPriceUpdate, InstrumentStatus,
+    Liquidation, MarkPriceUpdate, MarketStatistic, OpenInterestUpdate, OrderBookDelta,
+    OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN,
 };
 use crate::{
     data::order::BookOrder,
     enums::{
-        AggregationSource, AggressorSide, BarAggregation, BookAction, MarketStatusAction,
-        OrderSide, PriceType,
+        AggregationSource, AggressorSide, BarAggregation, BookAction, MarketStatisticType,
+        MarketStatusAction, OrderSide, PriceType,
     },
     identifiers::{InstrumentId, Symbol, TradeId, Venue},
     types::{Price, Quantity},
@@ -359,3 +360,73 @@ pub fn stub_instrument_status() -> InstrumentStatus {
         None,
     )
 }
+
+#[fixture]
+pub fn stub_mark_price_update() -> MarkPriceUpdate {
+    MarkPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("100000.50"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_index_price_update() -> IndexPriceUpdate {
+    IndexPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("100000.25"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_open_interest_update() -> OpenInterestUpdate {
+    OpenInterestUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Quantity::from("12345.678"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_liquidation() -> Liquidation {
+    Liquidation::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        OrderSide::Sell,
+        Price::from("100000.50"),
+        Quantity::from("1.500"),
+        TradeId::from("123456"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_auction_imbalance() -> AuctionImbalance {
+    AuctionImbalance::new(
+        InstrumentId::from("AAPL.XNAS"),
+        Price::from("150.25"),
+        Price::from("150.30"),
+        Price::from("150.20"),
+        Quantity::from(1_000),
+        Quantity::from(250),
+        OrderSide::Buy,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_market_statistic() -> MarketStatistic {
+    MarketStatistic::new(
+        InstrumentId::from("ESM4.GLBX"),
+        MarketStatisticType::SettlementPrice,
+        Some(Price::from("5250.25")),
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
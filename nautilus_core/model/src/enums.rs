@@ -560,6 +560,79 @@ pub enum LiquiditySide {
     Taker = 2,
 }
 
+/// The type of a market statistic published by a venue.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum MarketStatisticType {
+    /// The price of the first trade of the session.
+    OpeningPrice = 1,
+    /// The indicative price at which the opening auction would currently match.
+    IndicativeOpeningPrice = 2,
+    /// The settlement price determined by the venue.
+    SettlementPrice = 3,
+    /// The lowest trade price of the session.
+    SessionLowPrice = 4,
+    /// The highest trade price of the session.
+    SessionHighPrice = 5,
+    /// The total volume traded (or cleared) for the session.
+    ClearedVolume = 6,
+    /// The lowest offer price of the session.
+    LowestOffer = 7,
+    /// The highest bid price of the session.
+    HighestBid = 8,
+    /// The total number of outstanding contracts.
+    OpenInterest = 9,
+    /// The reference price fixed by the venue.
+    FixingPrice = 10,
+    /// The last trade price of the session.
+    ClosePrice = 11,
+    /// The change in price from the previous session.
+    NetChange = 12,
+    /// The volume-weighted average price of the session.
+    Vwap = 13,
+}
+
+impl FromU8 for MarketStatisticType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::OpeningPrice),
+            2 => Some(Self::IndicativeOpeningPrice),
+            3 => Some(Self::SettlementPrice),
+            4 => Some(Self::SessionLowPrice),
+            5 => Some(Self::SessionHighPrice),
+            6 => Some(Self::ClearedVolume),
+            7 => Some(Self::LowestOffer),
+            8 => Some(Self::HighestBid),
+            9 => Some(Self::OpenInterest),
+            10 => Some(Self::FixingPrice),
+            11 => Some(Self::ClosePrice),
+            12 => Some(Self::NetChange),
+            13 => Some(Self::Vwap),
+            _ => None,
+        }
+    }
+}
+
 /// The status of an individual market on a trading venue.
 #[repr(C)]
 #[derive(
@@ -1248,6 +1321,7 @@ enum_strum_serde!(ContingencyType);
 enum_strum_serde!(CurrencyType);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
+enum_strum_serde!(MarketStatisticType);
 enum_strum_serde!(MarketStatus);
 enum_strum_serde!(MarketStatusAction);
 enum_strum_serde!(OmsType);
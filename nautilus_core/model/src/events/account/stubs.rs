@@ -147,3 +147,22 @@ pub fn margin_account_state() -> AccountState {
         Some(Currency::USD()),
     )
 }
+
+#[fixture]
+pub fn betting_account_state() -> AccountState {
+    AccountState::new(
+        account_id(),
+        AccountType::Betting,
+        vec![AccountBalance::new(
+            Money::from("1000 GBP"),
+            Money::from("0 GBP"),
+            Money::from("1000 GBP"),
+        )],
+        vec![],
+        true,
+        uuid4(),
+        0.into(),
+        0.into(),
+        Some(Currency::GBP()),
+    )
+}
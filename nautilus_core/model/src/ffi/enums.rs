@@ -20,9 +20,9 @@ use nautilus_core::ffi::string::{cstr_as_str, str_to_cstr};
 use crate::enums::{
     AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
     BookType, ContingencyType, CurrencyType, InstrumentClass, InstrumentCloseType, LiquiditySide,
-    MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-    PositionSide, PriceType, RecordFlag, TimeInForce, TradingState, TrailingOffsetType,
-    TriggerType,
+    MarketStatisticType, MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide,
+    OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce, TradingState,
+    TrailingOffsetType, TriggerType,
 };
 
 #[no_mangle]
@@ -232,6 +232,26 @@ pub unsafe extern "C" fn liquidity_side_from_cstr(ptr: *const c_char) -> Liquidi
         .unwrap_or_else(|_| panic!("invalid `LiquiditySide` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn market_statistic_type_to_cstr(value: MarketStatisticType) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn market_statistic_type_from_cstr(
+    ptr: *const c_char,
+) -> MarketStatisticType {
+    let value = cstr_as_str(ptr);
+    MarketStatisticType::from_str(value).unwrap_or_else(|_| {
+        panic!("invalid `MarketStatisticType` enum string value, was '{value}'")
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn market_status_to_cstr(value: MarketStatus) -> *const c_char {
     str_to_cstr(value.as_ref())
@@ -15,13 +15,15 @@
 
 use nautilus_core::UnixNanos;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, crypto_future::CryptoFuture,
-    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
-    futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
+    betting::BettingInstrument, binary_option::BinaryOption, cfd::Cfd, commodity::Commodity,
+    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    index_instrument::IndexInstrument, options_contract::OptionsContract,
+    options_spread::OptionsSpread, perpetual_option::PerpetualOption, Instrument,
 };
 use crate::{
     enums::InstrumentClass,
@@ -29,18 +31,22 @@ use crate::{
     types::{Currency, Money, Price, Quantity},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum InstrumentAny {
     Betting(BettingInstrument),
     BinaryOption(BinaryOption),
+    Cfd(Cfd),
+    Commodity(Commodity),
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
     Equity(Equity),
     FuturesContract(FuturesContract),
     FuturesSpread(FuturesSpread),
+    IndexInstrument(IndexInstrument),
     OptionsContract(OptionsContract),
     OptionsSpread(OptionsSpread),
+    PerpetualOption(PerpetualOption),
 }
 
 impl InstrumentAny {
@@ -50,14 +56,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => Box::new(inst),
             Self::BinaryOption(inst) => Box::new(inst),
+            Self::Cfd(inst) => Box::new(inst),
+            Self::Commodity(inst) => Box::new(inst),
             Self::CryptoFuture(inst) => Box::new(inst),
             Self::CryptoPerpetual(inst) => Box::new(inst),
             Self::CurrencyPair(inst) => Box::new(inst),
             Self::Equity(inst) => Box::new(inst),
             Self::FuturesContract(inst) => Box::new(inst),
             Self::FuturesSpread(inst) => Box::new(inst),
+            Self::IndexInstrument(inst) => Box::new(inst),
             Self::OptionsContract(inst) => Box::new(inst),
             Self::OptionsSpread(inst) => Box::new(inst),
+            Self::PerpetualOption(inst) => Box::new(inst),
         }
     }
 
@@ -66,14 +76,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.instrument_class(),
             Self::BinaryOption(inst) => inst.instrument_class(),
+            Self::Cfd(inst) => inst.instrument_class(),
+            Self::Commodity(inst) => inst.instrument_class(),
             Self::CryptoFuture(inst) => inst.instrument_class(),
             Self::CryptoPerpetual(inst) => inst.instrument_class(),
             Self::CurrencyPair(inst) => inst.instrument_class(),
             Self::Equity(inst) => inst.instrument_class(),
             Self::FuturesContract(inst) => inst.instrument_class(),
             Self::FuturesSpread(inst) => inst.instrument_class(),
+            Self::IndexInstrument(inst) => inst.instrument_class(),
             Self::OptionsContract(inst) => inst.instrument_class(),
             Self::OptionsSpread(inst) => inst.instrument_class(),
+            Self::PerpetualOption(inst) => inst.instrument_class(),
         }
     }
 
@@ -82,14 +96,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id,
             Self::BinaryOption(inst) => inst.id,
+            Self::Cfd(inst) => inst.id,
+            Self::Commodity(inst) => inst.id,
             Self::CryptoFuture(inst) => inst.id,
             Self::CryptoPerpetual(inst) => inst.id,
             Self::CurrencyPair(inst) => inst.id,
             Self::Equity(inst) => inst.id,
             Self::FuturesContract(inst) => inst.id,
             Self::FuturesSpread(inst) => inst.id,
+            Self::IndexInstrument(inst) => inst.id,
             Self::OptionsContract(inst) => inst.id,
             Self::OptionsSpread(inst) => inst.id,
+            Self::PerpetualOption(inst) => inst.id,
         }
     }
 
@@ -98,14 +116,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id.symbol,
             Self::BinaryOption(inst) => inst.id.symbol,
+            Self::Cfd(inst) => inst.id.symbol,
+            Self::Commodity(inst) => inst.id.symbol,
             Self::CryptoFuture(inst) => inst.id.symbol,
             Self::CryptoPerpetual(inst) => inst.id.symbol,
             Self::CurrencyPair(inst) => inst.id.symbol,
             Self::Equity(inst) => inst.id.symbol,
             Self::FuturesContract(inst) => inst.id.symbol,
             Self::FuturesSpread(inst) => inst.id.symbol,
+            Self::IndexInstrument(inst) => inst.id.symbol,
             Self::OptionsContract(inst) => inst.id.symbol,
             Self::OptionsSpread(inst) => inst.id.symbol,
+            Self::PerpetualOption(inst) => inst.id.symbol,
         }
     }
 
@@ -114,14 +136,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id.venue,
             Self::BinaryOption(inst) => inst.id.venue,
+            Self::Cfd(inst) => inst.id.venue,
+            Self::Commodity(inst) => inst.id.venue,
             Self::CryptoFuture(inst) => inst.id.venue,
             Self::CryptoPerpetual(inst) => inst.id.venue,
             Self::CurrencyPair(inst) => inst.id.venue,
             Self::Equity(inst) => inst.id.venue,
             Self::FuturesContract(inst) => inst.id.venue,
             Self::FuturesSpread(inst) => inst.id.venue,
+            Self::IndexInstrument(inst) => inst.id.venue,
             Self::OptionsContract(inst) => inst.id.venue,
             Self::OptionsSpread(inst) => inst.id.venue,
+            Self::PerpetualOption(inst) => inst.id.venue,
         }
     }
 
@@ -130,14 +156,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.raw_symbol(),
             Self::BinaryOption(inst) => inst.raw_symbol(),
+            Self::Cfd(inst) => inst.raw_symbol(),
+            Self::Commodity(inst) => inst.raw_symbol(),
             Self::CryptoFuture(inst) => inst.raw_symbol(),
             Self::CryptoPerpetual(inst) => inst.raw_symbol(),
             Self::CurrencyPair(inst) => inst.raw_symbol(),
             Self::Equity(inst) => inst.raw_symbol(),
             Self::FuturesContract(inst) => inst.raw_symbol(),
             Self::FuturesSpread(inst) => inst.raw_symbol(),
+            Self::IndexInstrument(inst) => inst.raw_symbol(),
             Self::OptionsContract(inst) => inst.raw_symbol(),
             Self::OptionsSpread(inst) => inst.raw_symbol(),
+            Self::PerpetualOption(inst) => inst.raw_symbol(),
         }
     }
 
@@ -146,14 +176,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(_) => None,
             Self::BinaryOption(_) => None,
+            Self::Cfd(_) => None,
+            Self::Commodity(_) => None,
             Self::CryptoFuture(inst) => Some(&inst.underlying.code),
             Self::CryptoPerpetual(_) => None,
             Self::CurrencyPair(_) => None,
             Self::Equity(_) => None,
             Self::FuturesContract(inst) => Some(&inst.underlying),
             Self::FuturesSpread(inst) => Some(&inst.underlying),
+            Self::IndexInstrument(_) => None,
             Self::OptionsContract(inst) => Some(&inst.underlying),
             Self::OptionsSpread(inst) => Some(&inst.underlying),
+            Self::PerpetualOption(inst) => Some(&inst.underlying),
         }
     }
 
@@ -162,14 +196,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.base_currency(),
             Self::BinaryOption(inst) => inst.base_currency(),
+            Self::Cfd(inst) => inst.base_currency(),
+            Self::Commodity(inst) => inst.base_currency(),
             Self::CryptoFuture(inst) => inst.base_currency(),
             Self::CryptoPerpetual(inst) => inst.base_currency(),
             Self::CurrencyPair(inst) => inst.base_currency(),
             Self::Equity(inst) => inst.base_currency(),
             Self::FuturesContract(inst) => inst.base_currency(),
             Self::FuturesSpread(inst) => inst.base_currency(),
+            Self::IndexInstrument(inst) => inst.base_currency(),
             Self::OptionsContract(inst) => inst.base_currency(),
             Self::OptionsSpread(inst) => inst.base_currency(),
+            Self::PerpetualOption(inst) => inst.base_currency(),
         }
     }
 
@@ -178,14 +216,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.quote_currency(),
             Self::BinaryOption(inst) => inst.quote_currency(),
+            Self::Cfd(inst) => inst.quote_currency(),
+            Self::Commodity(inst) => inst.quote_currency(),
             Self::CryptoFuture(inst) => inst.quote_currency(),
             Self::CryptoPerpetual(inst) => inst.quote_currency(),
             Self::CurrencyPair(inst) => inst.quote_currency(),
             Self::Equity(inst) => inst.quote_currency(),
             Self::FuturesContract(inst) => inst.quote_currency(),
             Self::FuturesSpread(inst) => inst.quote_currency(),
+            Self::IndexInstrument(inst) => inst.quote_currency(),
             Self::OptionsContract(inst) => inst.quote_currency(),
             Self::OptionsSpread(inst) => inst.quote_currency(),
+            Self::PerpetualOption(inst) => inst.quote_currency(),
         }
     }
 
@@ -194,14 +236,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.settlement_currency(),
             Self::BinaryOption(inst) => inst.settlement_currency(),
+            Self::Cfd(inst) => inst.settlement_currency(),
+            Self::Commodity(inst) => inst.settlement_currency(),
             Self::CryptoFuture(inst) => inst.settlement_currency(),
             Self::CryptoPerpetual(inst) => inst.settlement_currency(),
             Self::CurrencyPair(inst) => inst.settlement_currency(),
             Self::Equity(inst) => inst.settlement_currency(),
             Self::FuturesContract(inst) => inst.settlement_currency(),
             Self::FuturesSpread(inst) => inst.settlement_currency(),
+            Self::IndexInstrument(inst) => inst.settlement_currency(),
             Self::OptionsContract(inst) => inst.settlement_currency(),
             Self::OptionsSpread(inst) => inst.settlement_currency(),
+            Self::PerpetualOption(inst) => inst.settlement_currency(),
         }
     }
 
@@ -210,14 +256,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.is_inverse(),
             Self::BinaryOption(inst) => inst.is_inverse(),
+            Self::Cfd(inst) => inst.is_inverse(),
+            Self::Commodity(inst) => inst.is_inverse(),
             Self::CryptoFuture(inst) => inst.is_inverse(),
             Self::CryptoPerpetual(inst) => inst.is_inverse(),
             Self::CurrencyPair(inst) => inst.is_inverse(),
             Self::Equity(inst) => inst.is_inverse(),
             Self::FuturesContract(inst) => inst.is_inverse(),
             Self::FuturesSpread(inst) => inst.is_inverse(),
+            Self::IndexInstrument(inst) => inst.is_inverse(),
             Self::OptionsContract(inst) => inst.is_inverse(),
             Self::OptionsSpread(inst) => inst.is_inverse(),
+            Self::PerpetualOption(inst) => inst.is_inverse(),
         }
     }
 
@@ -226,14 +276,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_precision(),
             Self::BinaryOption(inst) => inst.price_precision(),
+            Self::Cfd(inst) => inst.price_precision(),
+            Self::Commodity(inst) => inst.price_precision(),
             Self::CryptoFuture(inst) => inst.price_precision(),
             Self::CryptoPerpetual(inst) => inst.price_precision(),
             Self::CurrencyPair(inst) => inst.price_precision(),
             Self::Equity(inst) => inst.price_precision(),
             Self::FuturesContract(inst) => inst.price_precision(),
             Self::FuturesSpread(inst) => inst.price_precision(),
+            Self::IndexInstrument(inst) => inst.price_precision(),
             Self::OptionsContract(inst) => inst.price_precision(),
             Self::OptionsSpread(inst) => inst.price_precision(),
+            Self::PerpetualOption(inst) => inst.price_precision(),
         }
     }
 
@@ -242,14 +296,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_precision(),
             Self::BinaryOption(inst) => inst.size_precision(),
+            Self::Cfd(inst) => inst.size_precision(),
+            Self::Commodity(inst) => inst.size_precision(),
             Self::CryptoFuture(inst) => inst.size_precision(),
             Self::CryptoPerpetual(inst) => inst.size_precision(),
             Self::CurrencyPair(inst) => inst.size_precision(),
             Self::Equity(inst) => inst.size_precision(),
             Self::FuturesContract(inst) => inst.size_precision(),
             Self::FuturesSpread(inst) => inst.size_precision(),
+            Self::IndexInstrument(inst) => inst.size_precision(),
             Self::OptionsContract(inst) => inst.size_precision(),
             Self::OptionsSpread(inst) => inst.size_precision(),
+            Self::PerpetualOption(inst) => inst.size_precision(),
         }
     }
 
@@ -258,14 +316,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_increment(),
             Self::BinaryOption(inst) => inst.price_increment(),
+            Self::Cfd(inst) => inst.price_increment(),
+            Self::Commodity(inst) => inst.price_increment(),
             Self::CryptoFuture(inst) => inst.price_increment(),
             Self::CryptoPerpetual(inst) => inst.price_increment(),
             Self::CurrencyPair(inst) => inst.price_increment(),
             Self::Equity(inst) => inst.price_increment(),
             Self::FuturesContract(inst) => inst.price_increment(),
             Self::FuturesSpread(inst) => inst.price_increment(),
+            Self::IndexInstrument(inst) => inst.price_increment(),
             Self::OptionsContract(inst) => inst.price_increment(),
             Self::OptionsSpread(inst) => inst.price_increment(),
+            Self::PerpetualOption(inst) => inst.price_increment(),
         }
     }
 
@@ -274,14 +336,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_increment(),
             Self::BinaryOption(inst) => inst.size_increment(),
+            Self::Cfd(inst) => inst.size_increment(),
+            Self::Commodity(inst) => inst.size_increment(),
             Self::CryptoFuture(inst) => inst.size_increment(),
             Self::CryptoPerpetual(inst) => inst.size_increment(),
             Self::CurrencyPair(inst) => inst.size_increment(),
             Self::Equity(inst) => inst.size_increment(),
             Self::FuturesContract(inst) => inst.size_increment(),
             Self::FuturesSpread(inst) => inst.size_increment(),
+            Self::IndexInstrument(inst) => inst.size_increment(),
             Self::OptionsContract(inst) => inst.size_increment(),
             Self::OptionsSpread(inst) => inst.size_increment(),
+            Self::PerpetualOption(inst) => inst.size_increment(),
         }
     }
 
@@ -290,14 +356,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.multiplier(),
             Self::BinaryOption(inst) => inst.multiplier(),
+            Self::Cfd(inst) => inst.multiplier(),
+            Self::Commodity(inst) => inst.multiplier(),
             Self::CryptoFuture(inst) => inst.multiplier(),
             Self::CryptoPerpetual(inst) => inst.multiplier(),
             Self::CurrencyPair(inst) => inst.multiplier(),
             Self::Equity(inst) => inst.multiplier(),
             Self::FuturesContract(inst) => inst.multiplier(),
             Self::FuturesSpread(inst) => inst.multiplier(),
+            Self::IndexInstrument(inst) => inst.multiplier(),
             Self::OptionsContract(inst) => inst.multiplier(),
             Self::OptionsSpread(inst) => inst.multiplier(),
+            Self::PerpetualOption(inst) => inst.multiplier(),
         }
     }
 
@@ -306,14 +376,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.activation_ns(),
             Self::BinaryOption(inst) => inst.activation_ns(),
+            Self::Cfd(inst) => inst.activation_ns(),
+            Self::Commodity(inst) => inst.activation_ns(),
             Self::CryptoFuture(inst) => inst.activation_ns(),
             Self::CryptoPerpetual(inst) => inst.activation_ns(),
             Self::CurrencyPair(inst) => inst.activation_ns(),
             Self::Equity(inst) => inst.activation_ns(),
             Self::FuturesContract(inst) => inst.activation_ns(),
             Self::FuturesSpread(inst) => inst.activation_ns(),
+            Self::IndexInstrument(inst) => inst.activation_ns(),
             Self::OptionsContract(inst) => inst.activation_ns(),
             Self::OptionsSpread(inst) => inst.activation_ns(),
+            Self::PerpetualOption(inst) => inst.activation_ns(),
         }
     }
 
@@ -322,14 +396,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.expiration_ns(),
             Self::BinaryOption(inst) => inst.expiration_ns(),
+            Self::Cfd(inst) => inst.expiration_ns(),
+            Self::Commodity(inst) => inst.expiration_ns(),
             Self::CryptoFuture(inst) => inst.expiration_ns(),
             Self::CryptoPerpetual(inst) => inst.expiration_ns(),
             Self::CurrencyPair(inst) => inst.expiration_ns(),
             Self::Equity(inst) => inst.expiration_ns(),
             Self::FuturesContract(inst) => inst.expiration_ns(),
             Self::FuturesSpread(inst) => inst.expiration_ns(),
+            Self::IndexInstrument(inst) => inst.expiration_ns(),
             Self::OptionsContract(inst) => inst.expiration_ns(),
             Self::OptionsSpread(inst) => inst.expiration_ns(),
+            Self::PerpetualOption(inst) => inst.expiration_ns(),
         }
     }
 
@@ -337,14 +415,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_quantity(),
             Self::BinaryOption(inst) => inst.max_quantity(),
+            Self::Cfd(inst) => inst.max_quantity(),
+            Self::Commodity(inst) => inst.max_quantity(),
             Self::CryptoFuture(inst) => inst.max_quantity(),
             Self::CryptoPerpetual(inst) => inst.max_quantity(),
             Self::CurrencyPair(inst) => inst.max_quantity(),
             Self::Equity(inst) => inst.max_quantity(),
             Self::FuturesContract(inst) => inst.max_quantity(),
             Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::IndexInstrument(inst) => inst.max_quantity(),
             Self::OptionsContract(inst) => inst.max_quantity(),
             Self::OptionsSpread(inst) => inst.max_quantity(),
+            Self::PerpetualOption(inst) => inst.max_quantity(),
         }
     }
 
@@ -352,14 +434,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_quantity(),
             Self::BinaryOption(inst) => inst.min_quantity(),
+            Self::Cfd(inst) => inst.min_quantity(),
+            Self::Commodity(inst) => inst.min_quantity(),
             Self::CryptoFuture(inst) => inst.min_quantity(),
             Self::CryptoPerpetual(inst) => inst.min_quantity(),
             Self::CurrencyPair(inst) => inst.min_quantity(),
             Self::Equity(inst) => inst.min_quantity(),
             Self::FuturesContract(inst) => inst.min_quantity(),
             Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::IndexInstrument(inst) => inst.min_quantity(),
             Self::OptionsContract(inst) => inst.min_quantity(),
             Self::OptionsSpread(inst) => inst.min_quantity(),
+            Self::PerpetualOption(inst) => inst.min_quantity(),
         }
     }
 
@@ -367,14 +453,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_notional(),
             Self::BinaryOption(inst) => inst.max_notional(),
+            Self::Cfd(inst) => inst.max_notional(),
+            Self::Commodity(inst) => inst.max_notional(),
             Self::CryptoFuture(inst) => inst.max_notional(),
             Self::CryptoPerpetual(inst) => inst.max_notional(),
             Self::CurrencyPair(inst) => inst.max_notional(),
             Self::Equity(inst) => inst.max_notional(),
             Self::FuturesContract(inst) => inst.max_notional(),
             Self::FuturesSpread(inst) => inst.max_notional(),
+            Self::IndexInstrument(inst) => inst.max_notional(),
             Self::OptionsContract(inst) => inst.max_notional(),
             Self::OptionsSpread(inst) => inst.max_notional(),
+            Self::PerpetualOption(inst) => inst.max_notional(),
         }
     }
 
@@ -382,14 +472,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_notional(),
             Self::BinaryOption(inst) => inst.min_notional(),
+            Self::Cfd(inst) => inst.min_notional(),
+            Self::Commodity(inst) => inst.min_notional(),
             Self::CryptoFuture(inst) => inst.min_notional(),
             Self::CryptoPerpetual(inst) => inst.min_notional(),
             Self::CurrencyPair(inst) => inst.min_notional(),
             Self::Equity(inst) => inst.min_notional(),
             Self::FuturesContract(inst) => inst.min_notional(),
             Self::FuturesSpread(inst) => inst.min_notional(),
+            Self::IndexInstrument(inst) => inst.min_notional(),
             Self::OptionsContract(inst) => inst.min_notional(),
             Self::OptionsSpread(inst) => inst.min_notional(),
+            Self::PerpetualOption(inst) => inst.min_notional(),
         }
     }
 
@@ -397,14 +491,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_price(value),
             Self::BinaryOption(inst) => inst.make_price(value),
+            Self::Cfd(inst) => inst.make_price(value),
+            Self::Commodity(inst) => inst.make_price(value),
             Self::CryptoFuture(inst) => inst.make_price(value),
             Self::CryptoPerpetual(inst) => inst.make_price(value),
             Self::CurrencyPair(inst) => inst.make_price(value),
             Self::Equity(inst) => inst.make_price(value),
             Self::FuturesContract(inst) => inst.make_price(value),
             Self::FuturesSpread(inst) => inst.make_price(value),
+            Self::IndexInstrument(inst) => inst.make_price(value),
             Self::OptionsContract(inst) => inst.make_price(value),
             Self::OptionsSpread(inst) => inst.make_price(value),
+            Self::PerpetualOption(inst) => inst.make_price(value),
         }
     }
 
@@ -412,14 +510,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_qty(value),
             Self::BinaryOption(inst) => inst.make_qty(value),
+            Self::Cfd(inst) => inst.make_qty(value),
+            Self::Commodity(inst) => inst.make_qty(value),
             Self::CryptoFuture(inst) => inst.make_qty(value),
             Self::CryptoPerpetual(inst) => inst.make_qty(value),
             Self::CurrencyPair(inst) => inst.make_qty(value),
             Self::Equity(inst) => inst.make_qty(value),
             Self::FuturesContract(inst) => inst.make_qty(value),
             Self::FuturesSpread(inst) => inst.make_qty(value),
+            Self::IndexInstrument(inst) => inst.make_qty(value),
             Self::OptionsContract(inst) => inst.make_qty(value),
             Self::OptionsSpread(inst) => inst.make_qty(value),
+            Self::PerpetualOption(inst) => inst.make_qty(value),
         }
     }
 
@@ -437,6 +539,12 @@ impl InstrumentAny {
             Self::BinaryOption(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::Cfd(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
+            Self::Commodity(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoFuture(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -455,12 +563,18 @@ impl InstrumentAny {
             Self::FuturesSpread(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::IndexInstrument(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::OptionsContract(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
             Self::OptionsSpread(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::PerpetualOption(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
         }
     }
 
@@ -470,14 +584,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.maker_fee(),
             Self::BinaryOption(inst) => inst.maker_fee(),
+            Self::Cfd(inst) => inst.maker_fee(),
+            Self::Commodity(inst) => inst.maker_fee(),
             Self::CryptoFuture(inst) => inst.maker_fee(),
             Self::CryptoPerpetual(inst) => inst.maker_fee(),
             Self::CurrencyPair(inst) => inst.maker_fee(),
             Self::Equity(inst) => inst.maker_fee(),
             Self::FuturesContract(inst) => inst.maker_fee(),
             Self::FuturesSpread(inst) => inst.maker_fee(),
+            Self::IndexInstrument(inst) => inst.maker_fee(),
             Self::OptionsContract(inst) => inst.maker_fee(),
             Self::OptionsSpread(inst) => inst.maker_fee(),
+            Self::PerpetualOption(inst) => inst.maker_fee(),
         }
     }
 
@@ -487,14 +605,38 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.taker_fee(),
             Self::BinaryOption(inst) => inst.taker_fee(),
+            Self::Cfd(inst) => inst.taker_fee(),
+            Self::Commodity(inst) => inst.taker_fee(),
             Self::CryptoFuture(inst) => inst.taker_fee(),
             Self::CryptoPerpetual(inst) => inst.taker_fee(),
             Self::CurrencyPair(inst) => inst.taker_fee(),
             Self::Equity(inst) => inst.taker_fee(),
             Self::FuturesContract(inst) => inst.taker_fee(),
             Self::FuturesSpread(inst) => inst.taker_fee(),
+            Self::IndexInstrument(inst) => inst.taker_fee(),
             Self::OptionsContract(inst) => inst.taker_fee(),
             Self::OptionsSpread(inst) => inst.taker_fee(),
+            Self::PerpetualOption(inst) => inst.taker_fee(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_tradable(&self) -> bool {
+        match self {
+            Self::Betting(inst) => inst.is_tradable(),
+            Self::BinaryOption(inst) => inst.is_tradable(),
+            Self::Cfd(inst) => inst.is_tradable(),
+            Self::Commodity(inst) => inst.is_tradable(),
+            Self::CryptoFuture(inst) => inst.is_tradable(),
+            Self::CryptoPerpetual(inst) => inst.is_tradable(),
+            Self::CurrencyPair(inst) => inst.is_tradable(),
+            Self::Equity(inst) => inst.is_tradable(),
+            Self::FuturesContract(inst) => inst.is_tradable(),
+            Self::FuturesSpread(inst) => inst.is_tradable(),
+            Self::IndexInstrument(inst) => inst.is_tradable(),
+            Self::OptionsContract(inst) => inst.is_tradable(),
+            Self::OptionsSpread(inst) => inst.is_tradable(),
+            Self::PerpetualOption(inst) => inst.is_tradable(),
         }
     }
 
@@ -502,14 +644,18 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::BinaryOption(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Cfd(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Commodity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::IndexInstrument(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionsContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionsSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::PerpetualOption(inst) => inst.calculate_base_quantity(quantity, last_px),
         }
     }
 }
@@ -428,6 +428,19 @@ impl Instrument for BettingInstrument {
     fn min_notional(&self) -> Option<Money> {
         self.min_notional
     }
+
+    /// Calculates the notional value of a bet, which is its stake (independent of the odds).
+    fn calculate_notional_value(
+        &self,
+        quantity: Quantity,
+        _price: Price,
+        _use_quote_for_inverse: Option<bool>,
+    ) -> Money {
+        Money::new(
+            quantity.as_f64() * self.multiplier().as_f64(),
+            self.currency,
+        )
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -437,11 +450,21 @@ impl Instrument for BettingInstrument {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{stubs::*, BettingInstrument};
+    use crate::{
+        instruments::{stubs::*, BettingInstrument, Instrument},
+        types::{Money, Price, Quantity},
+    };
 
     #[rstest]
     fn test_equality(betting: BettingInstrument) {
         let cloned = betting;
         assert_eq!(betting, cloned);
     }
+
+    #[rstest]
+    fn test_notional_value_is_stake(betting: BettingInstrument) {
+        let notional =
+            betting.calculate_notional_value(Quantity::from("10.00"), Price::from("3.50"), None);
+        assert_eq!(notional, Money::from("10.00 GBP"));
+    }
 }
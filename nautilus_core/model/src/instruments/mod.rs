@@ -18,14 +18,18 @@
 pub mod any;
 pub mod betting;
 pub mod binary_option;
+pub mod cfd;
+pub mod commodity;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod index_instrument;
 pub mod options_contract;
 pub mod options_spread;
+pub mod perpetual_option;
 pub mod synthetic;
 
 #[cfg(feature = "stubs")]
@@ -38,11 +42,12 @@ use ustr::Ustr;
 
 // Re-exports
 pub use crate::instruments::{
-    any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption,
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption, cfd::Cfd,
+    commodity::Commodity, crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
+    currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+    futures_spread::FuturesSpread, index_instrument::IndexInstrument,
     options_contract::OptionsContract, options_spread::OptionsSpread,
-    synthetic::SyntheticInstrument,
+    perpetual_option::PerpetualOption, synthetic::SyntheticInstrument,
 };
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
@@ -103,6 +108,11 @@ pub trait Instrument: 'static + Send {
     fn ts_event(&self) -> UnixNanos;
     fn ts_init(&self) -> UnixNanos;
 
+    /// Returns whether orders can be submitted for the instrument.
+    fn is_tradable(&self) -> bool {
+        true
+    }
+
     /// Creates a new `Price` from the given `value` with the correct price precision for the instrument.
     fn make_price(&self, value: f64) -> Price {
         Price::new(value, self.price_precision())
@@ -28,7 +28,8 @@ use crate::{
     enums::{AssetClass, OptionKind},
     identifiers::{InstrumentId, Symbol, Venue},
     instruments::{
-        CryptoFuture, CryptoPerpetual, CurrencyPair, Equity, FuturesContract, OptionsContract,
+        Cfd, Commodity, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity, FuturesContract,
+        IndexInstrument, OptionsContract, PerpetualOption,
     },
     types::{Currency, Money, Price, Quantity},
 };
@@ -315,6 +316,87 @@ pub fn equity_aapl() -> Equity {
     )
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Cfd
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn cfd_gold() -> Cfd {
+    Cfd::new(
+        InstrumentId::from("XAUUSD.OANDA"),
+        Symbol::from("XAU_USD"),
+        AssetClass::Commodity,
+        None,
+        Currency::USD(),
+        2,
+        1,
+        Price::from("0.01"),
+        Quantity::from("0.1"),
+        None,
+        None,
+        Some(Quantity::from("0.1")),
+        None,
+        None,
+        None,
+        None,
+        Some(dec!(0.05)),
+        Some(dec!(0.025)),
+        Some(dec!(0)),
+        Some(dec!(0)),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Commodity
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn commodity_gold() -> Commodity {
+    Commodity::new(
+        InstrumentId::from("XAUUSD.SIM"),
+        Symbol::from("XAUUSD"),
+        Currency::USD(),
+        2,
+        3,
+        Price::from("0.01"),
+        Quantity::from("0.001"),
+        None,
+        None,
+        Some(Quantity::from("0.001")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IndexInstrument
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn index_spx() -> IndexInstrument {
+    IndexInstrument::new(
+        InstrumentId::from("SPX.CBOE"),
+        Symbol::from("SPX"),
+        Currency::USD(),
+        2,
+        0,
+        Price::from("0.01"),
+        Quantity::from("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // FuturesContract
 ////////////////////////////////////////////////////////////////////////////////
@@ -467,6 +549,38 @@ pub fn options_spread() -> OptionsSpread {
     )
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// PerpetualOption
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn perpetual_option_btc() -> PerpetualOption {
+    PerpetualOption::new(
+        InstrumentId::from("BTC-PERP-100000-C.SIM"),
+        Symbol::from("BTC-PERP-100000-C"),
+        AssetClass::Cryptocurrency,
+        None,
+        Ustr::from("BTC"),
+        OptionKind::Call,
+        Price::from("100000.0"),
+        Currency::USD(),
+        1,
+        Price::from("0.1"),
+        Quantity::from(1),
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // BettingInstrument
 ////////////////////////////////////////////////////////////////////////////////
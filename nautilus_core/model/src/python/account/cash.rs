@@ -31,8 +31,15 @@ use crate::{
 #[pymethods]
 impl CashAccount {
     #[new]
-    pub fn py_new(event: AccountState, calculate_account_state: bool) -> Self {
-        Self::new(event, calculate_account_state)
+    #[pyo3(signature = (event, calculate_account_state, allow_borrowing=false))]
+    pub fn py_new(
+        event: AccountState,
+        calculate_account_state: bool,
+        allow_borrowing: bool,
+    ) -> Self {
+        let mut account = Self::new(event, calculate_account_state);
+        account.set_allow_borrowing(allow_borrowing);
+        account
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -103,6 +110,52 @@ impl CashAccount {
         self.calculate_account_state
     }
 
+    #[getter]
+    #[pyo3(name = "allow_borrowing")]
+    fn py_allow_borrowing(&self) -> bool {
+        self.allow_borrowing
+    }
+
+    #[pyo3(name = "set_borrow_rate")]
+    fn py_set_borrow_rate(&mut self, currency: Currency, rate: f64) {
+        self.set_borrow_rate(currency, rate);
+    }
+
+    #[pyo3(name = "get_borrow_rate")]
+    fn py_get_borrow_rate(&self, currency: Currency) -> f64 {
+        self.get_borrow_rate(&currency)
+    }
+
+    #[pyo3(name = "set_borrow_limit")]
+    fn py_set_borrow_limit(&mut self, limit: Money) {
+        self.set_borrow_limit(limit);
+    }
+
+    #[pyo3(name = "get_borrow_limit")]
+    fn py_get_borrow_limit(&self, currency: Currency) -> Option<Money> {
+        self.get_borrow_limit(&currency)
+    }
+
+    #[pyo3(name = "balance_available")]
+    fn py_balance_available(&self, currency: Currency) -> Option<Money> {
+        self.balance_available(currency)
+    }
+
+    #[pyo3(name = "borrowed")]
+    fn py_borrowed(&self, currency: Currency) -> Money {
+        self.borrowed(currency)
+    }
+
+    #[pyo3(name = "interest")]
+    fn py_interest(&self, currency: Currency) -> Money {
+        self.interest(currency)
+    }
+
+    #[pyo3(name = "accrue_interest")]
+    fn py_accrue_interest(&mut self, ts: u64) -> Vec<Money> {
+        self.accrue_interest(ts.into())
+    }
+
     #[pyo3(name = "balance_total")]
     #[pyo3(signature = (currency=None))]
     fn py_balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -136,8 +189,8 @@ impl CashAccount {
     }
 
     #[pyo3(name = "apply")]
-    fn py_apply(&mut self, event: AccountState) {
-        self.apply(event);
+    fn py_apply(&mut self, event: AccountState) -> PyResult<()> {
+        self.apply(event).map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "calculate_balance_locked")]
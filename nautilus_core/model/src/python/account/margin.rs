@@ -128,8 +128,8 @@ impl MarginAccount {
         instrument_id: InstrumentId,
         initial_margin: Money,
     ) -> PyResult<()> {
-        self.update_initial_margin(instrument_id, initial_margin);
-        Ok(())
+        self.update_initial_margin(instrument_id, initial_margin)
+            .map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "initial_margin")]
@@ -143,8 +143,8 @@ impl MarginAccount {
         instrument_id: InstrumentId,
         maintenance_margin: Money,
     ) -> PyResult<()> {
-        self.update_maintenance_margin(instrument_id, maintenance_margin);
-        Ok(())
+        self.update_maintenance_margin(instrument_id, maintenance_margin)
+            .map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "maintenance_margin")]
@@ -170,6 +170,12 @@ impl MarginAccount {
             InstrumentAny::CryptoPerpetual(inst) => {
                 Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::Cfd(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
+            InstrumentAny::Commodity(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CurrencyPair(inst) => {
                 Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
             }
@@ -182,6 +188,9 @@ impl MarginAccount {
             InstrumentAny::OptionsContract(inst) => {
                 Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::PerpetualOption(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             _ => Err(to_pyvalue_err("Unsupported instrument type")),
         }
     }
@@ -204,6 +213,12 @@ impl MarginAccount {
             InstrumentAny::CryptoPerpetual(inst) => {
                 Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::Cfd(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
+            InstrumentAny::Commodity(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CurrencyPair(inst) => {
                 Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
             }
@@ -216,6 +231,9 @@ impl MarginAccount {
             InstrumentAny::OptionsContract(inst) => {
                 Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::PerpetualOption(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             _ => Err(to_pyvalue_err("Unsupported instrument type")),
         }
     }
@@ -17,7 +17,7 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{prelude::*, types::PyDict};
 
 use crate::{
-    accounts::{Account, CashAccount, MarginAccount},
+    accounts::{Account, BettingAccount, CashAccount, MarginAccount},
     events::AccountState,
 };
 
@@ -37,7 +37,7 @@ pub fn cash_account_from_account_events(
     let init_event = account_events[0].clone();
     let mut cash_account = CashAccount::new(init_event, calculate_account_state);
     for event in account_events.iter().skip(1) {
-        cash_account.apply(event.clone());
+        cash_account.apply(event.clone()).map_err(to_pyvalue_err)?;
     }
     Ok(cash_account)
 }
@@ -58,7 +58,32 @@ pub fn margin_account_from_account_events(
     let init_event = account_events[0].clone();
     let mut margin_account = MarginAccount::new(init_event, calculate_account_state);
     for event in account_events.iter().skip(1) {
-        margin_account.apply(event.clone());
+        margin_account
+            .apply(event.clone())
+            .map_err(to_pyvalue_err)?;
     }
     Ok(margin_account)
 }
+
+#[pyfunction]
+pub fn betting_account_from_account_events(
+    events: Vec<Bound<'_, PyDict>>,
+    calculate_account_state: bool,
+) -> PyResult<BettingAccount> {
+    let account_events = events
+        .into_iter()
+        .map(|obj| AccountState::py_from_dict(&obj))
+        .collect::<PyResult<Vec<AccountState>>>()
+        .unwrap();
+    if account_events.is_empty() {
+        return Err(to_pyvalue_err("No account events"));
+    }
+    let init_event = account_events[0].clone();
+    let mut betting_account = BettingAccount::new(init_event, calculate_account_state);
+    for event in account_events.iter().skip(1) {
+        betting_account
+            .apply(event.clone())
+            .map_err(to_pyvalue_err)?;
+    }
+    Ok(betting_account)
+}
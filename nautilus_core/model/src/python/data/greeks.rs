@@ -13,11 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_core::UnixNanos;
 use pyo3::prelude::*;
 
-use crate::data::greeks::{
-    black_scholes_greeks, imply_vol, imply_vol_and_greeks, BlackScholesGreeksResult,
-    ImplyVolAndGreeksResult,
+use crate::{
+    data::greeks::{
+        black_scholes_greeks, imply_vol, imply_vol_and_greeks, BlackScholesGreeksResult,
+        ImplyVolAndGreeksResult, OptionGreeks,
+    },
+    identifiers::InstrumentId,
 };
 
 #[pymethods]
@@ -125,6 +129,123 @@ impl BlackScholesGreeksResult {
     }
 }
 
+#[pymethods]
+impl OptionGreeks {
+    /// Creates a new [`OptionGreeks`] instance.
+    #[new]
+    #[pyo3(signature = (instrument_id, delta, gamma, vega, theta, rho, mark_iv, bid_iv, ask_iv, underlying_price, ts_event, ts_init))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        instrument_id: InstrumentId,
+        delta: f64,
+        gamma: f64,
+        vega: f64,
+        theta: f64,
+        rho: f64,
+        mark_iv: f64,
+        bid_iv: Option<f64>,
+        ask_iv: Option<f64>,
+        underlying_price: f64,
+        ts_event: u64,
+        ts_init: u64,
+    ) -> Self {
+        Self::new(
+            instrument_id,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+            mark_iv,
+            bid_iv,
+            ask_iv,
+            underlying_price,
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_init),
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "delta")]
+    fn py_delta(&self) -> f64 {
+        self.delta
+    }
+
+    #[getter]
+    #[pyo3(name = "gamma")]
+    fn py_gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    #[getter]
+    #[pyo3(name = "vega")]
+    fn py_vega(&self) -> f64 {
+        self.vega
+    }
+
+    #[getter]
+    #[pyo3(name = "theta")]
+    fn py_theta(&self) -> f64 {
+        self.theta
+    }
+
+    #[getter]
+    #[pyo3(name = "rho")]
+    fn py_rho(&self) -> f64 {
+        self.rho
+    }
+
+    #[getter]
+    #[pyo3(name = "mark_iv")]
+    fn py_mark_iv(&self) -> f64 {
+        self.mark_iv
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_iv")]
+    fn py_bid_iv(&self) -> Option<f64> {
+        self.bid_iv
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_iv")]
+    fn py_ask_iv(&self) -> Option<f64> {
+        self.ask_iv
+    }
+
+    #[getter]
+    #[pyo3(name = "underlying_price")]
+    fn py_underlying_price(&self) -> f64 {
+        self.underlying_price
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
 #[pyfunction]
 #[pyo3(name = "black_scholes_greeks")]
 #[allow(clippy::too_many_arguments)]
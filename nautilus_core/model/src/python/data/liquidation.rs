@@ -0,0 +1,312 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyDict},
+};
+
+use crate::{
+    data::liquidation::Liquidation,
+    enums::{FromU8, OrderSide},
+    identifiers::{InstrumentId, TradeId},
+    python::common::PY_MODULE_MODEL,
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+
+impl Liquidation {
+    /// Create a new [`Liquidation`] extracted from the given [`PyAny`].
+    pub fn from_pyobject(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let instrument_id_obj: Bound<'_, PyAny> = obj.getattr("instrument_id")?.extract()?;
+        let instrument_id_str: String = instrument_id_obj.getattr("value")?.extract()?;
+        let instrument_id =
+            InstrumentId::from_str(instrument_id_str.as_str()).map_err(to_pyvalue_err)?;
+
+        let side_obj: Bound<'_, PyAny> = obj.getattr("side")?.extract()?;
+        let side_u8 = side_obj.getattr("value")?.extract()?;
+        let side = OrderSide::from_u8(side_u8).unwrap();
+
+        let price_py: Bound<'_, PyAny> = obj.getattr("price")?.extract()?;
+        let price_raw: PriceRaw = price_py.getattr("raw")?.extract()?;
+        let price_prec: u8 = price_py.getattr("precision")?.extract()?;
+        let price = Price::from_raw(price_raw, price_prec);
+
+        let quantity_py: Bound<'_, PyAny> = obj.getattr("quantity")?.extract()?;
+        let quantity_raw: QuantityRaw = quantity_py.getattr("raw")?.extract()?;
+        let quantity_prec: u8 = quantity_py.getattr("precision")?.extract()?;
+        let quantity = Quantity::from_raw(quantity_raw, quantity_prec);
+
+        let trade_id_obj: Bound<'_, PyAny> = obj.getattr("trade_id")?.extract()?;
+        let trade_id_str: String = trade_id_obj.getattr("value")?.extract()?;
+        let trade_id = TradeId::from(trade_id_str.as_str());
+
+        let ts_event: u64 = obj.getattr("ts_event")?.extract()?;
+        let ts_init: u64 = obj.getattr("ts_init")?.extract()?;
+
+        Self::new_checked(
+            instrument_id,
+            side,
+            price,
+            quantity,
+            trade_id,
+            ts_event.into(),
+            ts_init.into(),
+        )
+        .map_err(to_pyvalue_err)
+    }
+}
+
+#[pymethods]
+impl Liquidation {
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        trade_id: TradeId,
+        ts_event: u64,
+        ts_init: u64,
+    ) -> PyResult<Self> {
+        Self::new_checked(
+            instrument_id,
+            side,
+            price,
+            quantity,
+            trade_id,
+            ts_event.into(),
+            ts_init.into(),
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(Liquidation), self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "side")]
+    fn py_side(&self) -> OrderSide {
+        self.side
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Price {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "trade_id")]
+    fn py_trade_id(&self) -> TradeId {
+        self.trade_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(Liquidation))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_metadata")]
+    fn py_get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> PyResult<HashMap<String, String>> {
+        Ok(Self::get_metadata(
+            instrument_id,
+            price_precision,
+            size_precision,
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_fields")]
+    fn py_get_fields(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let py_dict = PyDict::new_bound(py);
+        for (k, v) in Self::get_fields() {
+            py_dict.set_item(k, v)?;
+        }
+
+        Ok(py_dict)
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let json_bytes: Vec<u8> = serde_json::to_vec(self).map_err(to_pyvalue_err)?;
+
+        // Parse JSON into a Python dictionary
+        let py_bytes = PyBytes::new_bound(py, &json_bytes);
+        let py_dict: Py<PyDict> = PyModule::import_bound(py, "msgspec.json")?
+            .call_method("decode", (py_bytes,), None)?
+            .extract()?;
+
+        Ok(py_dict)
+    }
+
+    /// Returns a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(&data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(&data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{IntoPy, Python};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_liquidation;
+
+    #[rstest]
+    fn test_liquidation_py_new_with_zero_quantity() {
+        pyo3::prepare_freethreaded_python();
+
+        let result = Liquidation::py_new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            OrderSide::Sell,
+            Price::from("100000.50"),
+            Quantity::from(0),
+            TradeId::from("123456"),
+            1,
+            2,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_as_dict(stub_liquidation: Liquidation) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict_string = stub_liquidation.py_as_dict(py).unwrap().to_string();
+            let expected_string = r"{'type': 'Liquidation', 'instrument_id': 'BTCUSDT-PERP.BINANCE', 'side': 'SELL', 'price': '100000.50', 'quantity': '1.500', 'trade_id': '123456', 'ts_event': 1, 'ts_init': 2}";
+            assert_eq!(dict_string, expected_string);
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_liquidation: Liquidation) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict = stub_liquidation.py_as_dict(py).unwrap();
+            let parsed = Liquidation::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, stub_liquidation);
+        });
+    }
+
+    #[rstest]
+    fn test_from_pyobject(stub_liquidation: Liquidation) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let pyobject = stub_liquidation.into_py(py);
+            let parsed = Liquidation::from_pyobject(pyobject.bind(py)).unwrap();
+            assert_eq!(parsed, stub_liquidation);
+        });
+    }
+}
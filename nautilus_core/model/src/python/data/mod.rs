@@ -20,8 +20,13 @@ pub mod delta;
 pub mod deltas;
 pub mod depth;
 pub mod greeks;
+pub mod imbalance;
+pub mod liquidation;
+pub mod open_interest;
 pub mod order;
+pub mod prices;
 pub mod quote;
+pub mod statistics;
 pub mod status;
 pub mod trade;
 
@@ -0,0 +1,236 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyDict},
+};
+
+use crate::{
+    data::open_interest::OpenInterestUpdate,
+    identifiers::InstrumentId,
+    python::common::PY_MODULE_MODEL,
+    types::quantity::{Quantity, QuantityRaw},
+};
+
+impl OpenInterestUpdate {
+    /// Create a new [`OpenInterestUpdate`] extracted from the given [`PyAny`].
+    pub fn from_pyobject(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let instrument_id_obj: Bound<'_, PyAny> = obj.getattr("instrument_id")?.extract()?;
+        let instrument_id_str: String = instrument_id_obj.getattr("value")?.extract()?;
+        let instrument_id =
+            InstrumentId::from_str(instrument_id_str.as_str()).map_err(to_pyvalue_err)?;
+
+        let value_py: Bound<'_, PyAny> = obj.getattr("value")?.extract()?;
+        let value_raw: QuantityRaw = value_py.getattr("raw")?.extract()?;
+        let value_prec: u8 = value_py.getattr("precision")?.extract()?;
+        let value = Quantity::from_raw(value_raw, value_prec);
+
+        let ts_event: u64 = obj.getattr("ts_event")?.extract()?;
+        let ts_init: u64 = obj.getattr("ts_init")?.extract()?;
+
+        Ok(Self::new(
+            instrument_id,
+            value,
+            ts_event.into(),
+            ts_init.into(),
+        ))
+    }
+}
+
+#[pymethods]
+impl OpenInterestUpdate {
+    #[new]
+    fn py_new(instrument_id: InstrumentId, value: Quantity, ts_event: u64, ts_init: u64) -> Self {
+        Self::new(instrument_id, value, ts_event.into(), ts_init.into())
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(OpenInterestUpdate), self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn py_value(&self) -> Quantity {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(OpenInterestUpdate))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_metadata")]
+    fn py_get_metadata(
+        instrument_id: &InstrumentId,
+        size_precision: u8,
+    ) -> PyResult<HashMap<String, String>> {
+        Ok(Self::get_metadata(instrument_id, size_precision))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_fields")]
+    fn py_get_fields(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let py_dict = PyDict::new_bound(py);
+        for (k, v) in Self::get_fields() {
+            py_dict.set_item(k, v)?;
+        }
+
+        Ok(py_dict)
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let json_bytes: Vec<u8> = serde_json::to_vec(self).map_err(to_pyvalue_err)?;
+
+        // Parse JSON into a Python dictionary
+        let py_bytes = PyBytes::new_bound(py, &json_bytes);
+        let py_dict: Py<PyDict> = PyModule::import_bound(py, "msgspec.json")?
+            .call_method("decode", (py_bytes,), None)?
+            .extract()?;
+
+        Ok(py_dict)
+    }
+
+    /// Returns a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(&data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(&data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{IntoPy, Python};
+    use rstest::rstest;
+
+    use crate::data::{open_interest::OpenInterestUpdate, stubs::stub_open_interest_update};
+
+    #[rstest]
+    fn test_as_dict(stub_open_interest_update: OpenInterestUpdate) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict_string = stub_open_interest_update
+                .py_as_dict(py)
+                .unwrap()
+                .to_string();
+            let expected_string = r"{'type': 'OpenInterestUpdate', 'instrument_id': 'BTCUSDT-PERP.BINANCE', 'value': '12345.678', 'ts_event': 1, 'ts_init': 2}";
+            assert_eq!(dict_string, expected_string);
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_open_interest_update: OpenInterestUpdate) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict = stub_open_interest_update.py_as_dict(py).unwrap();
+            let parsed = OpenInterestUpdate::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, stub_open_interest_update);
+        });
+    }
+
+    #[rstest]
+    fn test_from_pyobject(stub_open_interest_update: OpenInterestUpdate) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let pyobject = stub_open_interest_update.into_py(py);
+            let parsed = OpenInterestUpdate::from_pyobject(pyobject.bind(py)).unwrap();
+            assert_eq!(parsed, stub_open_interest_update);
+        });
+    }
+}
@@ -24,9 +24,9 @@ use crate::{
     enums::{
         AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
         BookType, ContingencyType, CurrencyType, InstrumentClass, InstrumentCloseType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide,
-        OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce, TradingState,
-        TrailingOffsetType, TriggerType,
+        LiquiditySide, MarketStatisticType, MarketStatus, MarketStatusAction, OmsType, OptionKind,
+        OrderSide, OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce,
+        TradingState, TrailingOffsetType, TriggerType,
     },
     python::common::EnumIterator,
 };
@@ -927,6 +927,135 @@ impl LiquiditySide {
     }
 }
 
+#[pymethods]
+impl MarketStatisticType {
+    #[new]
+    fn py_new(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let t = Self::type_object_bound(py);
+        Self::py_from_str(&t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(MarketStatisticType),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &Bound<'_, PyType>, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &Bound<'_, PyType>, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let data_str: &str = data.extract()?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "OPENING_PRICE")]
+    fn py_opening_price() -> Self {
+        Self::OpeningPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "INDICATIVE_OPENING_PRICE")]
+    fn py_indicative_opening_price() -> Self {
+        Self::IndicativeOpeningPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "SETTLEMENT_PRICE")]
+    fn py_settlement_price() -> Self {
+        Self::SettlementPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "SESSION_LOW_PRICE")]
+    fn py_session_low_price() -> Self {
+        Self::SessionLowPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "SESSION_HIGH_PRICE")]
+    fn py_session_high_price() -> Self {
+        Self::SessionHighPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "CLEARED_VOLUME")]
+    fn py_cleared_volume() -> Self {
+        Self::ClearedVolume
+    }
+
+    #[classattr]
+    #[pyo3(name = "LOWEST_OFFER")]
+    fn py_lowest_offer() -> Self {
+        Self::LowestOffer
+    }
+
+    #[classattr]
+    #[pyo3(name = "HIGHEST_BID")]
+    fn py_highest_bid() -> Self {
+        Self::HighestBid
+    }
+
+    #[classattr]
+    #[pyo3(name = "OPEN_INTEREST")]
+    fn py_open_interest() -> Self {
+        Self::OpenInterest
+    }
+
+    #[classattr]
+    #[pyo3(name = "FIXING_PRICE")]
+    fn py_fixing_price() -> Self {
+        Self::FixingPrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "CLOSE_PRICE")]
+    fn py_close_price() -> Self {
+        Self::ClosePrice
+    }
+
+    #[classattr]
+    #[pyo3(name = "NET_CHANGE")]
+    fn py_net_change() -> Self {
+        Self::NetChange
+    }
+
+    #[classattr]
+    #[pyo3(name = "VWAP")]
+    fn py_vwap() -> Self {
+        Self::Vwap
+    }
+}
+
 #[pymethods]
 impl MarketStatus {
     #[new]
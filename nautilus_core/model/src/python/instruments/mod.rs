@@ -19,33 +19,42 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{IntoPy, PyObject, PyResult, Python};
 
 use crate::instruments::{
-    BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
-    FuturesContract, FuturesSpread, InstrumentAny, OptionsContract, OptionsSpread,
+    BettingInstrument, BinaryOption, Cfd, Commodity, CryptoFuture, CryptoPerpetual, CurrencyPair,
+    Equity, FuturesContract, FuturesSpread, IndexInstrument, InstrumentAny, OptionsContract,
+    OptionsSpread, PerpetualOption,
 };
 
 pub mod betting;
 pub mod binary_option;
+pub mod cfd;
+pub mod commodity;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod index_instrument;
 pub mod options_contract;
 pub mod options_spread;
+pub mod perpetual_option;
 
 pub fn instrument_any_to_pyobject(py: Python, instrument: InstrumentAny) -> PyResult<PyObject> {
     match instrument {
         InstrumentAny::Betting(inst) => Ok(inst.into_py(py)),
         InstrumentAny::BinaryOption(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Cfd(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Commodity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoFuture(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoPerpetual(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CurrencyPair(inst) => Ok(inst.into_py(py)),
         InstrumentAny::Equity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesSpread(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::IndexInstrument(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionsContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionsSpread(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::PerpetualOption(inst) => Ok(inst.into_py(py)),
     }
 }
 
@@ -57,6 +66,10 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(BinaryOption) => Ok(InstrumentAny::BinaryOption(
             instrument.extract::<BinaryOption>(py)?,
         )),
+        stringify!(Cfd) => Ok(InstrumentAny::Cfd(instrument.extract::<Cfd>(py)?)),
+        stringify!(Commodity) => Ok(InstrumentAny::Commodity(
+            instrument.extract::<Commodity>(py)?,
+        )),
         stringify!(CryptoFuture) => Ok(InstrumentAny::CryptoFuture(
             instrument.extract::<CryptoFuture>(py)?,
         )),
@@ -73,12 +86,18 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(FuturesSpread) => Ok(InstrumentAny::FuturesSpread(
             instrument.extract::<FuturesSpread>(py)?,
         )),
+        stringify!(IndexInstrument) => Ok(InstrumentAny::IndexInstrument(
+            instrument.extract::<IndexInstrument>(py)?,
+        )),
         stringify!(OptionsContract) => Ok(InstrumentAny::OptionsContract(
             instrument.extract::<OptionsContract>(py)?,
         )),
         stringify!(OptionsSpread) => Ok(InstrumentAny::OptionsSpread(
             instrument.extract::<OptionsSpread>(py)?,
         )),
+        stringify!(PerpetualOption) => Ok(InstrumentAny::PerpetualOption(
+            instrument.extract::<PerpetualOption>(py)?,
+        )),
         _ => Err(to_pyvalue_err(
             "Error in conversion from `PyObject` to `InstrumentAny`",
         )),
@@ -55,6 +55,13 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::data::depth::OrderBookDepth10>()?;
     m.add_class::<crate::data::greeks::BlackScholesGreeksResult>()?;
     m.add_class::<crate::data::greeks::ImplyVolAndGreeksResult>()?;
+    m.add_class::<crate::data::greeks::OptionGreeks>()?;
+    m.add_class::<crate::data::prices::MarkPriceUpdate>()?;
+    m.add_class::<crate::data::prices::IndexPriceUpdate>()?;
+    m.add_class::<crate::data::open_interest::OpenInterestUpdate>()?;
+    m.add_class::<crate::data::liquidation::Liquidation>()?;
+    m.add_class::<crate::data::imbalance::AuctionImbalance>()?;
+    m.add_class::<crate::data::statistics::MarketStatistic>()?;
     m.add_class::<crate::data::quote::QuoteTick>()?;
     m.add_class::<crate::data::status::InstrumentStatus>()?;
     m.add_class::<crate::data::trade::TradeTick>()?;
@@ -83,6 +90,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::enums::CurrencyType>()?;
     m.add_class::<crate::enums::InstrumentCloseType>()?;
     m.add_class::<crate::enums::LiquiditySide>()?;
+    m.add_class::<crate::enums::MarketStatisticType>()?;
     m.add_class::<crate::enums::MarketStatus>()?;
     m.add_class::<crate::enums::MarketStatusAction>()?;
     m.add_class::<crate::enums::OmsType>()?;
@@ -125,14 +133,18 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Instruments
     m.add_class::<crate::instruments::BettingInstrument>()?;
     m.add_class::<crate::instruments::BinaryOption>()?;
+    m.add_class::<crate::instruments::Cfd>()?;
+    m.add_class::<crate::instruments::Commodity>()?;
     m.add_class::<crate::instruments::CryptoFuture>()?;
     m.add_class::<crate::instruments::CryptoPerpetual>()?;
     m.add_class::<crate::instruments::CurrencyPair>()?;
     m.add_class::<crate::instruments::Equity>()?;
     m.add_class::<crate::instruments::FuturesContract>()?;
     m.add_class::<crate::instruments::FuturesSpread>()?;
+    m.add_class::<crate::instruments::IndexInstrument>()?;
     m.add_class::<crate::instruments::OptionsContract>()?;
     m.add_class::<crate::instruments::OptionsSpread>()?;
+    m.add_class::<crate::instruments::PerpetualOption>()?;
     m.add_class::<crate::instruments::SyntheticInstrument>()?;
     // Order book
     m.add_class::<crate::orderbook::book::OrderBook>()?;
@@ -168,6 +180,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Accounts
     m.add_class::<crate::accounts::CashAccount>()?;
     m.add_class::<crate::accounts::MarginAccount>()?;
+    m.add_class::<crate::accounts::BettingAccount>()?;
     m.add_function(wrap_pyfunction!(
         crate::python::account::transformer::cash_account_from_account_events,
         m
@@ -176,5 +189,9 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         crate::python::account::transformer::margin_account_from_account_events,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::account::transformer::betting_account_from_account_events,
+        m
+    )?)?;
     Ok(())
 }
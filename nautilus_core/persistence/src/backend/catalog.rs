@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::{fs::File, path::PathBuf};
 
 use datafusion::{arrow::record_batch::RecordBatch, error::Result};
 use heck::ToSnakeCase;
@@ -21,12 +21,14 @@ use itertools::Itertools;
 use log::info;
 use nautilus_core::UnixNanos;
 use nautilus_model::data::{
-    Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+    AuctionImbalance, Bar, Data, GetTsInit, IndexPriceUpdate, Liquidation, MarkPriceUpdate,
+    MarketStatistic, OpenInterestUpdate, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
 };
 use nautilus_serialization::{
     arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch},
-    parquet::write_batches_to_parquet,
+    parquet::{create_parquet_writer, write_batches_to_parquet},
 };
+use parquet::arrow::ArrowWriter;
 use serde::Serialize;
 
 use super::session::{self, build_query, DataBackendSession, QueryResult};
@@ -162,6 +164,101 @@ impl ParquetDataCatalog {
         path
     }
 
+    /// Writes a stream of data `chunks` to a single Parquet file, encoding and writing each chunk
+    /// as it arrives so that memory use is bounded by the chunk size rather than the total size.
+    ///
+    /// The file path is determined from the metadata of the first chunk, unless a `path` is
+    /// specified. Returns `None` if the stream contained no data. The file is written to a
+    /// temporary path and only renamed to its final path once complete.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any chunk is an error, if timestamps are not in
+    /// ascending order across the whole stream, if the stream contains data for more than one
+    /// instrument, or if writing to the file fails (in which case the temporary file is removed).
+    pub fn write_stream_to_parquet<T, I>(
+        &self,
+        chunks: I,
+        path: Option<PathBuf>,
+        compression: Option<parquet::basic::Compression>,
+        max_row_group_size: Option<usize>,
+    ) -> anyhow::Result<Option<PathBuf>>
+    where
+        T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+        I: IntoIterator<Item = anyhow::Result<Vec<T>>>,
+    {
+        let type_name = std::any::type_name::<T>().to_snake_case();
+        let mut writer: Option<(ArrowWriter<File>, PathBuf, PathBuf)> = None;
+        let mut last_ts_init = UnixNanos::default();
+        let mut first_instrument_id: Option<String> = None;
+        let mut count = 0;
+
+        let write_chunks = || -> anyhow::Result<()> {
+            for chunk in chunks {
+                let chunk = chunk?;
+                for item in &chunk {
+                    anyhow::ensure!(
+                        last_ts_init <= item.ts_init(),
+                        "{type_name} timestamps must be in ascending order"
+                    );
+                    last_ts_init = item.ts_init();
+
+                    // The file metadata (and default path) is taken from a single instrument
+                    let instrument_id = item.metadata().remove("instrument_id").unwrap_or_default();
+                    let first_instrument_id =
+                        first_instrument_id.get_or_insert_with(|| instrument_id.clone());
+                    anyhow::ensure!(
+                        *first_instrument_id == instrument_id,
+                        "{type_name} stream must contain a single instrument, found {instrument_id} after {first_instrument_id}"
+                    );
+                }
+                count += chunk.len();
+
+                for batch in self.data_to_record_batches(chunk) {
+                    if writer.is_none() {
+                        let schema = batch.schema();
+                        let instrument_id = schema.metadata.get("instrument_id");
+                        let path = path
+                            .clone()
+                            .unwrap_or_else(|| self.make_path(T::path_prefix(), instrument_id));
+                        // Write to a temporary file so a failed write never leaves a partial file
+                        let mut temp_path = path.clone().into_os_string();
+                        temp_path.push(".tmp");
+                        let temp_path = PathBuf::from(temp_path);
+                        let arrow_writer = create_parquet_writer(
+                            schema,
+                            &temp_path,
+                            compression,
+                            max_row_group_size,
+                        )
+                        .map_err(|e| anyhow::anyhow!("{e}"))?;
+                        writer = Some((arrow_writer, path, temp_path));
+                    }
+
+                    if let Some((arrow_writer, _, _)) = writer.as_mut() {
+                        arrow_writer.write(&batch)?;
+                    }
+                }
+            }
+            Ok(())
+        };
+        let result = write_chunks();
+
+        let Some((arrow_writer, path, temp_path)) = writer else {
+            return result.map(|()| None);
+        };
+        let result = result
+            .and_then(|()| arrow_writer.close().map_err(anyhow::Error::from))
+            .and_then(|_| std::fs::rename(&temp_path, &path).map_err(anyhow::Error::from));
+        if let Err(e) = result {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(e);
+        }
+
+        info!("Wrote {count} records of {type_name} data to {path:?}");
+        Ok(Some(path))
+    }
+
     /// Query data loaded in the catalog
     pub fn query_file<T>(
         &mut self,
@@ -218,6 +315,12 @@ impl ParquetDataCatalog {
         let mut quote: Vec<QuoteTick> = Vec::new();
         let mut trade: Vec<TradeTick> = Vec::new();
         let mut bar: Vec<Bar> = Vec::new();
+        let mut mark_price: Vec<MarkPriceUpdate> = Vec::new();
+        let mut index_price: Vec<IndexPriceUpdate> = Vec::new();
+        let mut open_interest: Vec<OpenInterestUpdate> = Vec::new();
+        let mut liquidation: Vec<Liquidation> = Vec::new();
+        let mut imbalance: Vec<AuctionImbalance> = Vec::new();
+        let mut statistics: Vec<MarketStatistic> = Vec::new();
 
         for d in data.iter().cloned() {
             match d {
@@ -236,15 +339,48 @@ impl ParquetDataCatalog {
                 Data::Bar(d) => {
                     bar.push(d);
                 }
+                Data::MarkPriceUpdate(d) => {
+                    mark_price.push(d);
+                }
+                Data::IndexPriceUpdate(d) => {
+                    index_price.push(d);
+                }
+                Data::OpenInterestUpdate(d) => {
+                    open_interest.push(d);
+                }
+                Data::Liquidation(d) => {
+                    liquidation.push(d);
+                }
+                Data::AuctionImbalance(d) => {
+                    imbalance.push(d);
+                }
+                Data::MarketStatistic(d) => {
+                    statistics.push(d);
+                }
                 Data::Deltas(_) => continue,
             }
         }
 
-        let _ = self.write_to_parquet(delta, None, None, None);
-        let _ = self.write_to_parquet(depth10, None, None, None);
-        let _ = self.write_to_parquet(quote, None, None, None);
-        let _ = self.write_to_parquet(trade, None, None, None);
-        let _ = self.write_to_parquet(bar, None, None, None);
+        self.write_non_empty(delta);
+        self.write_non_empty(depth10);
+        self.write_non_empty(quote);
+        self.write_non_empty(trade);
+        self.write_non_empty(bar);
+        self.write_non_empty(mark_price);
+        self.write_non_empty(index_price);
+        self.write_non_empty(open_interest);
+        self.write_non_empty(liquidation);
+        self.write_non_empty(imbalance);
+        self.write_non_empty(statistics);
+    }
+
+    fn write_non_empty<T>(&self, data: Vec<T>)
+    where
+        T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+    {
+        if !data.is_empty() {
+            let _ = self.write_to_parquet(data, None, None, None);
+        }
     }
 }
 
@@ -267,3 +403,9 @@ impl_catalog_path_prefix!(TradeTick, "trades");
 impl_catalog_path_prefix!(OrderBookDelta, "order_book_deltas");
 impl_catalog_path_prefix!(OrderBookDepth10, "order_book_depths");
 impl_catalog_path_prefix!(Bar, "bars");
+impl_catalog_path_prefix!(MarkPriceUpdate, "mark_prices");
+impl_catalog_path_prefix!(IndexPriceUpdate, "index_prices");
+impl_catalog_path_prefix!(OpenInterestUpdate, "open_interest");
+impl_catalog_path_prefix!(Liquidation, "liquidations");
+impl_catalog_path_prefix!(AuctionImbalance, "auction_imbalance");
+impl_catalog_path_prefix!(MarketStatistic, "market_statistics");
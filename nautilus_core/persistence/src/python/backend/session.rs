@@ -14,7 +14,9 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
-use nautilus_model::data::{Bar, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick};
+use nautilus_model::data::{
+    AuctionImbalance, Bar, MarketStatistic, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+};
 use pyo3::{prelude::*, types::PyCapsule};
 
 use crate::backend::session::{DataBackendSession, DataQueryResult};
@@ -29,6 +31,8 @@ pub enum NautilusDataType {
     QuoteTick = 3,
     TradeTick = 4,
     Bar = 5,
+    AuctionImbalance = 6,
+    MarketStatistic = 7,
 }
 
 #[pymethods]
@@ -79,6 +83,12 @@ impl DataBackendSession {
             NautilusDataType::Bar => slf
                 .add_file::<Bar>(table_name, file_path, sql_query)
                 .map_err(to_pyruntime_err),
+            NautilusDataType::AuctionImbalance => slf
+                .add_file::<AuctionImbalance>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::MarketStatistic => slf
+                .add_file::<MarketStatistic>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
         }
     }
 
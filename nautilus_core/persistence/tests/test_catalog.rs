@@ -16,9 +16,14 @@
 use std::path::PathBuf;
 
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{
-    is_monotonically_increasing_by_init, to_variant, Bar, Data, OrderBookDelta, QuoteTick,
-    TradeTick,
+use nautilus_model::{
+    data::{
+        is_monotonically_increasing_by_init, to_variant, AuctionImbalance, Bar, Data, Liquidation,
+        MarkPriceUpdate, MarketStatistic, OpenInterestUpdate, OrderBookDelta, QuoteTick, TradeTick,
+    },
+    enums::{AggressorSide, MarketStatisticType, OrderSide},
+    identifiers::{InstrumentId, TradeId},
+    types::{Price, Quantity},
 };
 use nautilus_persistence::{
     backend::{
@@ -492,3 +497,259 @@ fn test_catalog_export_functionality() {
         assert_eq!(original, final_quote, "Quotes don't match");
     }
 }
+
+#[rstest]
+fn test_catalog_write_data_enum_derivative_updates_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let mark_prices: Vec<MarkPriceUpdate> = (1..=3)
+        .map(|i| {
+            MarkPriceUpdate::new(
+                instrument_id,
+                Price::new(100_000.0 + i as f64, 1),
+                i.into(),
+                i.into(),
+            )
+        })
+        .collect();
+    let open_interest =
+        OpenInterestUpdate::new(instrument_id, Quantity::from("1234.5"), 2.into(), 2.into());
+
+    let mut data: Vec<Data> = mark_prices.iter().copied().map(Data::from).collect();
+    data.push(Data::from(open_interest));
+    catalog.write_data_enum(data);
+
+    let data_path = temp_dir.path().join("data");
+    let mark_prices_path = data_path
+        .join("mark_prices")
+        .join(instrument_id.to_string())
+        .join("data.parquet");
+    let result = catalog
+        .query_file::<MarkPriceUpdate>(mark_prices_path, None, None, None)
+        .unwrap();
+    let loaded: Vec<MarkPriceUpdate> = to_variant(result.collect());
+    assert_eq!(loaded, mark_prices);
+
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let open_interest_path = data_path
+        .join("open_interest")
+        .join(instrument_id.to_string())
+        .join("data.parquet");
+    let result = catalog
+        .query_file::<OpenInterestUpdate>(open_interest_path, None, None, None)
+        .unwrap();
+    let loaded: Vec<OpenInterestUpdate> = to_variant(result.collect());
+    assert_eq!(loaded, vec![open_interest]);
+}
+
+#[rstest]
+fn test_catalog_write_data_enum_liquidations_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let liquidations: Vec<Liquidation> = (1..=3)
+        .map(|i| {
+            Liquidation::new(
+                instrument_id,
+                if i % 2 == 0 {
+                    OrderSide::Buy
+                } else {
+                    OrderSide::Sell
+                },
+                Price::new(100_000.0 + i as f64, 1),
+                Quantity::new(i as f64, 3),
+                TradeId::new(i.to_string()),
+                i.into(),
+                i.into(),
+            )
+        })
+        .collect();
+
+    catalog.write_data_enum(liquidations.iter().copied().map(Data::from).collect());
+
+    let liquidations_path = temp_dir
+        .path()
+        .join("data")
+        .join("liquidations")
+        .join(instrument_id.to_string())
+        .join("data.parquet");
+    let result = catalog
+        .query_file::<Liquidation>(liquidations_path, None, None, None)
+        .unwrap();
+    let loaded: Vec<Liquidation> = to_variant(result.collect());
+    assert_eq!(loaded, liquidations);
+}
+
+#[rstest]
+fn test_catalog_write_data_enum_auction_imbalance_and_market_statistic_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let imbalances: Vec<AuctionImbalance> = (1..=2)
+        .map(|i| {
+            AuctionImbalance::new(
+                instrument_id,
+                Price::new(150.0 + i as f64, 2),
+                Price::new(150.5, 2),
+                Price::new(149.5, 2),
+                Quantity::new(1_000.0 * i as f64, 0),
+                Quantity::new(250.0, 0),
+                OrderSide::Sell,
+                i.into(),
+                i.into(),
+            )
+        })
+        .collect();
+    let statistics: Vec<MarketStatistic> = vec![
+        MarketStatistic::new(
+            instrument_id,
+            MarketStatisticType::OpeningPrice,
+            Some(Price::new(151.25, 2)),
+            None,
+            1.into(),
+            1.into(),
+        ),
+        MarketStatistic::new(
+            instrument_id,
+            MarketStatisticType::ClearedVolume,
+            None,
+            Some(Quantity::new(50_000.0, 0)),
+            2.into(),
+            2.into(),
+        ),
+    ];
+
+    let mut data: Vec<Data> = imbalances.iter().copied().map(Data::from).collect();
+    data.extend(statistics.iter().copied().map(Data::from));
+    catalog.write_data_enum(data);
+
+    let data_dir = temp_dir.path().join("data");
+    let imbalance_path = data_dir
+        .join("auction_imbalance")
+        .join(instrument_id.to_string())
+        .join("data.parquet");
+    let result = catalog
+        .query_file::<AuctionImbalance>(imbalance_path, None, None, None)
+        .unwrap();
+    let loaded: Vec<AuctionImbalance> = to_variant(result.collect());
+    assert_eq!(loaded, imbalances);
+
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let statistics_path = data_dir
+        .join("market_statistics")
+        .join(instrument_id.to_string())
+        .join("data.parquet");
+    let result = catalog
+        .query_file::<MarketStatistic>(statistics_path, None, None, None)
+        .unwrap();
+    let loaded: Vec<MarketStatistic> = to_variant(result.collect());
+    assert_eq!(loaded, statistics);
+    assert_eq!(loaded[1].price(), None);
+}
+
+#[rstest]
+fn test_catalog_write_stream_to_parquet_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(2));
+
+    let instrument_id = InstrumentId::from("XBTUSD.BITMEX");
+    let trades: Vec<TradeTick> = (1..=7)
+        .map(|i| {
+            TradeTick::new(
+                instrument_id,
+                Price::new(8_500.0 + i as f64, 1),
+                Quantity::new(i as f64, 0),
+                AggressorSide::Buyer,
+                TradeId::new(i.to_string()),
+                i.into(),
+                i.into(),
+            )
+        })
+        .collect();
+    let chunks = trades.chunks(3).map(|chunk| Ok(chunk.to_vec()));
+
+    let path = catalog
+        .write_stream_to_parquet(chunks, None, None, None)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        path,
+        temp_dir
+            .path()
+            .join("data")
+            .join("trades")
+            .join(instrument_id.to_string())
+            .join("data.parquet")
+    );
+    let result = catalog
+        .query_file::<TradeTick>(path, None, None, None)
+        .unwrap();
+    let loaded: Vec<TradeTick> = to_variant(result.collect());
+    assert_eq!(loaded, trades);
+}
+
+#[rstest]
+fn test_catalog_write_stream_to_parquet_empty_stream() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let result = catalog
+        .write_stream_to_parquet::<TradeTick, _>(Vec::new(), None, None, None)
+        .unwrap();
+
+    assert!(result.is_none());
+}
+
+#[rstest]
+fn test_catalog_write_stream_to_parquet_rejects_unordered_chunks() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let trade = TradeTick::default();
+    let later = TradeTick {
+        ts_init: 2.into(),
+        ..trade
+    };
+    let chunks = vec![Ok(vec![later]), Ok(vec![trade])];
+    let path = temp_dir.path().join("trades.parquet");
+
+    let result = catalog.write_stream_to_parquet(chunks, Some(path), None, None);
+
+    // The partially written file is removed
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+#[rstest]
+fn test_catalog_write_stream_to_parquet_rejects_mixed_instruments() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let trade = TradeTick::default();
+    let other = TradeTick {
+        instrument_id: InstrumentId::from("ETHUSDT.BINANCE"),
+        ts_init: 2.into(),
+        ..trade
+    };
+    let chunks = vec![Ok(vec![trade]), Ok(vec![other])];
+
+    let result = catalog.write_stream_to_parquet(chunks, None, None, None);
+
+    let err = result.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("must contain a single instrument, found ETHUSDT.BINANCE"));
+
+    // The partially written file is removed
+    let trades_dir = temp_dir
+        .path()
+        .join("data")
+        .join("trades")
+        .join(trade.instrument_id.to_string());
+    assert_eq!(std::fs::read_dir(trades_dir).unwrap().count(), 0);
+}
@@ -15,12 +15,17 @@
 
 //! Provides account management functionality.
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, ops::DerefMut, rc::Rc};
 
 use nautilus_common::{cache::Cache, clock::Clock};
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
-    accounts::{any::AccountAny, base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{
+        any::AccountAny,
+        base::{Account, BaseAccount},
+        error::AccountError,
+        margin::MarginAccount,
+    },
     enums::{AccountType, OrderSide, OrderSideSpecified, PriceType},
     events::{AccountState, OrderFilled},
     instruments::InstrumentAny,
@@ -101,6 +106,11 @@ impl AccountsManager {
                 .map(|(updated_cash_account, state)| {
                     (AccountAny::Cash(updated_cash_account), state)
                 }),
+            AccountAny::Betting(betting_account) => self
+                .update_balance_locked(&betting_account, instrument, orders_open, ts_event)
+                .map(|(updated_betting_account, state)| {
+                    (AccountAny::Betting(updated_betting_account), state)
+                }),
             AccountAny::Margin(margin_account) => self
                 .update_margin_init(&margin_account, instrument, orders_open, ts_event)
                 .map(|(updated_margin_account, state)| {
@@ -147,6 +157,18 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::Cfd(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
+                InstrumentAny::Commodity(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::CryptoFuture(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -183,6 +205,12 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::IndexInstrument(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::OptionsContract(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -195,6 +223,12 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::PerpetualOption(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
             };
 
             let mut margin_maint = margin_maint.as_decimal();
@@ -221,7 +255,10 @@ impl AccountsManager {
         }
 
         let margin_maint_money = Money::new(total_margin_maint.to_f64()?, currency);
-        account.update_maintenance_margin(instrument.id(), margin_maint_money);
+        if let Err(e) = account.update_maintenance_margin(instrument.id(), margin_maint_money) {
+            log::error!("{}", e);
+            return None;
+        }
 
         log::info!(
             "{} margin_maint={}",
@@ -236,22 +273,25 @@ impl AccountsManager {
         ))
     }
 
-    fn update_balance_locked(
+    fn update_balance_locked<A>(
         &self,
-        account: &CashAccount,
+        account: &A,
         instrument: InstrumentAny,
         orders_open: Vec<&OrderAny>,
         ts_event: UnixNanos,
-    ) -> Option<(CashAccount, AccountState)> {
+    ) -> Option<(A, AccountState)>
+    where
+        A: Account + Clone + DerefMut<Target = BaseAccount> + Into<AccountAny>,
+    {
         let mut account = account.clone();
         if orders_open.is_empty() {
             let balance = account.balances.remove(&instrument.quote_currency());
             if let Some(balance) = balance {
-                account.recalculate_balance(balance.currency);
+                account.base_recalculate_balance(balance.currency);
             }
             return Some((
                 account.clone(),
-                self.generate_account_state(AccountAny::Cash(account), ts_event),
+                self.generate_account_state(account.into(), ts_event),
             ));
         }
 
@@ -294,7 +334,7 @@ impl AccountsManager {
                 if base_xrate.is_zero() {
                     currency = base_curr;
                     base_xrate = self.calculate_xrate_to_base(
-                        AccountAny::Cash(account.clone()),
+                        account.clone().into(),
                         instrument.clone(),
                         order.order_side_specified(),
                     );
@@ -311,7 +351,7 @@ impl AccountsManager {
         if let Some(balance) = account.balances.get_mut(&instrument.quote_currency()) {
             balance.locked = locked_money;
             let currency = balance.currency;
-            account.recalculate_balance(currency);
+            account.base_recalculate_balance(currency);
         }
 
         log::info!(
@@ -322,7 +362,7 @@ impl AccountsManager {
 
         Some((
             account.clone(),
-            self.generate_account_state(AccountAny::Cash(account), ts_event),
+            self.generate_account_state(account.into(), ts_event),
         ))
     }
 
@@ -363,6 +403,12 @@ impl AccountsManager {
                 InstrumentAny::BinaryOption(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::Cfd(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
+                InstrumentAny::Commodity(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::CryptoFuture(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
@@ -381,12 +427,18 @@ impl AccountsManager {
                 InstrumentAny::FuturesSpread(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::IndexInstrument(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::OptionsContract(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
                 InstrumentAny::OptionsSpread(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::PerpetualOption(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
             };
 
             let mut margin_init = margin_init.as_decimal();
@@ -417,10 +469,11 @@ impl AccountsManager {
         }
 
         let money = Money::new(total_margin_init.to_f64().unwrap_or(0.0), currency);
-        let margin_init_money = {
-            account.update_initial_margin(instrument.id(), money);
-            money
-        };
+        if let Err(e) = account.update_initial_margin(instrument.id(), money) {
+            log::error!("{}", e);
+            return None;
+        }
+        let margin_init_money = money;
 
         log::info!(
             "{} margin_init={}",
@@ -525,17 +578,32 @@ impl AccountsManager {
 
         match account {
             AccountAny::Cash(mut cash) => {
-                cash.update_balances(balances);
+                if let Err(e) = cash.update_balances(balances) {
+                    log::error!("{}", e);
+                    return;
+                }
                 if let Some(comm) = commission {
                     cash.update_commissions(comm);
                 }
             }
             AccountAny::Margin(mut margin) => {
-                margin.update_balances(balances);
+                if let Err(e) = margin.update_balances(balances) {
+                    log::error!("{}", e);
+                    return;
+                }
                 if let Some(comm) = commission {
                     margin.update_commissions(comm);
                 }
             }
+            AccountAny::Betting(mut betting) => {
+                if let Err(e) = betting.update_balances(balances) {
+                    log::error!("{}", e);
+                    return;
+                }
+                if let Some(comm) = commission {
+                    betting.update_commissions(comm);
+                }
+            }
         }
     }
 
@@ -548,6 +616,7 @@ impl AccountsManager {
         let mut new_balances = Vec::new();
         let commission = fill.commission;
         let mut apply_commission = commission.is_some_and(|c| !c.is_zero());
+        let allow_borrowing = matches!(&account, AccountAny::Cash(cash) if cash.allow_borrowing);
 
         for pnl in pnls.iter_mut() {
             if apply_commission && pnl.currency == commission.unwrap().currency {
@@ -568,21 +637,21 @@ impl AccountsManager {
                 let total = Money::new(new_total, currency);
                 let free = Money::new(new_free, currency);
 
-                if new_total < 0.0 {
-                    log::error!(
-                        "AccountBalanceNegative: balance = {}, currency = {}",
-                        total.as_decimal(),
-                        currency
-                    );
+                if new_total < 0.0 && !allow_borrowing {
+                    let error = AccountError::BalanceNegative {
+                        balance: total.as_decimal(),
+                        currency,
+                    };
+                    log::error!("{}", error);
                     return;
                 }
-                if new_free < 0.0 {
-                    log::error!(
-                        "AccountMarginExceeded: balance = {}, margin = {}, currency = {}",
-                        total.as_decimal(),
-                        balance.locked.as_decimal(),
-                        currency
-                    );
+                if new_free < 0.0 && !allow_borrowing {
+                    let error = AccountError::MarginExceeded {
+                        balance: total.as_decimal(),
+                        margin: balance.locked.as_decimal(),
+                        currency,
+                    };
+                    log::error!("{}", error);
                     return;
                 }
 
@@ -639,17 +708,32 @@ impl AccountsManager {
 
         match account {
             AccountAny::Cash(mut cash) => {
-                cash.update_balances(new_balances);
+                if let Err(e) = cash.update_balances(new_balances) {
+                    log::error!("{}", e);
+                    return;
+                }
                 if let Some(commission) = commission {
                     cash.update_commissions(commission);
                 }
             }
             AccountAny::Margin(mut margin) => {
-                margin.update_balances(new_balances);
+                if let Err(e) = margin.update_balances(new_balances) {
+                    log::error!("{}", e);
+                    return;
+                }
                 if let Some(commission) = commission {
                     margin.update_commissions(commission);
                 }
             }
+            AccountAny::Betting(mut betting) => {
+                if let Err(e) = betting.update_balances(new_balances) {
+                    log::error!("{}", e);
+                    return;
+                }
+                if let Some(commission) = commission {
+                    betting.update_commissions(commission);
+                }
+            }
         }
     }
 
@@ -677,6 +761,17 @@ impl AccountsManager {
                 self.clock.borrow().timestamp_ns(),
                 margin_account.base_currency(),
             ),
+            AccountAny::Betting(betting_account) => AccountState::new(
+                betting_account.id,
+                AccountType::Betting,
+                betting_account.balances.clone().into_values().collect(),
+                vec![],
+                false,
+                UUID4::new(),
+                ts_event,
+                self.clock.borrow().timestamp_ns(),
+                betting_account.base_currency(),
+            ),
         }
     }
 
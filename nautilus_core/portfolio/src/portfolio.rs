@@ -16,7 +16,7 @@
 //! Provides a generic `Portfolio` for all environments.
 use std::{
     any::Any,
-    cell::RefCell,
+    cell::{Ref, RefCell, RefMut},
     collections::{HashMap, HashSet},
     rc::Rc,
     sync::Arc,
@@ -24,13 +24,37 @@ use std::{
 
 use nautilus_analysis::{
     analyzer::PortfolioAnalyzer,
+    attribution::PerformanceAttributionReport,
     statistics::{
-        expectancy::Expectancy, long_ratio::LongRatio, loser_max::MaxLoser, loser_min::MinLoser,
-        profit_factor::ProfitFactor, returns_avg::ReturnsAverage,
-        returns_avg_loss::ReturnsAverageLoss, returns_avg_win::ReturnsAverageWin,
-        returns_volatility::ReturnsVolatility, risk_return_ratio::RiskReturnRatio,
-        sharpe_ratio::SharpeRatio, sortino_ratio::SortinoRatio, win_rate::WinRate,
-        winner_avg::AvgWinner, winner_max::MaxWinner, winner_min::MinWinner,
+        alpha::Alpha,
+        benchmark_correlation::BenchmarkCorrelation,
+        beta::Beta,
+        cagr::Cagr,
+        calmar_ratio::CalmarRatio,
+        expectancy::Expectancy,
+        information_ratio::InformationRatio,
+        long_ratio::LongRatio,
+        loser_max::MaxLoser,
+        loser_min::MinLoser,
+        max_drawdown::MaxDrawdown,
+        max_drawdown_duration::MaxDrawdownDuration,
+        omega_ratio::OmegaRatio,
+        profit_factor::ProfitFactor,
+        returns_avg::ReturnsAverage,
+        returns_avg_loss::ReturnsAverageLoss,
+        returns_avg_win::ReturnsAverageWin,
+        returns_volatility::ReturnsVolatility,
+        risk_return_ratio::RiskReturnRatio,
+        sharpe_ratio::SharpeRatio,
+        sortino_ratio::SortinoRatio,
+        tail_ratio::TailRatio,
+        tracking_error::TrackingError,
+        ulcer_index::UlcerIndex,
+        value_at_risk::{ConditionalValueAtRisk, ValueAtRisk},
+        win_rate::WinRate,
+        winner_avg::AvgWinner,
+        winner_max::MaxWinner,
+        winner_min::MinWinner,
     },
 };
 use nautilus_common::{
@@ -73,7 +97,7 @@ impl MessageHandler for UpdateQuoteTickHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        (self.callback)(msg.downcast_ref::<&QuoteTick>().unwrap());
+        (self.callback)(msg.downcast_ref::<QuoteTick>().unwrap());
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
@@ -133,7 +157,7 @@ impl MessageHandler for UpdateAccountHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        (self.callback)(msg.downcast_ref::<&AccountState>().unwrap());
+        (self.callback)(msg.downcast_ref::<AccountState>().unwrap());
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
@@ -171,6 +195,20 @@ impl PortfolioState {
         analyzer.register_statistic(Arc::new(ProfitFactor {}));
         analyzer.register_statistic(Arc::new(RiskReturnRatio {}));
         analyzer.register_statistic(Arc::new(LongRatio::new(None)));
+        analyzer.register_statistic(Arc::new(MaxDrawdown {}));
+        analyzer.register_statistic(Arc::new(MaxDrawdownDuration {}));
+        analyzer.register_statistic(Arc::new(Cagr::new(None)));
+        analyzer.register_statistic(Arc::new(CalmarRatio::new(None)));
+        analyzer.register_statistic(Arc::new(OmegaRatio::new(None)));
+        analyzer.register_statistic(Arc::new(TailRatio {}));
+        analyzer.register_statistic(Arc::new(ValueAtRisk::new(None)));
+        analyzer.register_statistic(Arc::new(ConditionalValueAtRisk::new(None)));
+        analyzer.register_statistic(Arc::new(UlcerIndex {}));
+        analyzer.register_statistic(Arc::new(Alpha::new(None)));
+        analyzer.register_statistic(Arc::new(Beta {}));
+        analyzer.register_statistic(Arc::new(BenchmarkCorrelation {}));
+        analyzer.register_statistic(Arc::new(InformationRatio::new(None)));
+        analyzer.register_statistic(Arc::new(TrackingError::new(None)));
 
         Self {
             accounts: AccountsManager::new(clock, cache),
@@ -235,10 +273,12 @@ impl Portfolio {
     ) {
         let update_account_handler = {
             let cache = cache.clone();
+            let inner = inner.clone();
             ShareableMessageHandler(Rc::new(UpdateAccountHandler {
                 id: Ustr::from(&Uuid::new_v4().to_string()),
                 callback: Box::new(move |event: &AccountState| {
                     update_account(cache.clone(), event);
+                    inner.borrow_mut().analyzer.add_account_state(event);
                 }),
             }))
         };
@@ -270,13 +310,14 @@ impl Portfolio {
             ShareableMessageHandler(Rc::new(UpdateQuoteTickHandler {
                 id: Ustr::from(&Uuid::new_v4().to_string()),
                 callback: Box::new(move |quote: &QuoteTick| {
-                    update_quote_tick(
-                        cache.clone(),
-                        msgbus.clone(),
-                        clock.clone(),
-                        inner.clone(),
-                        quote,
-                    );
+                    // Route through the portfolio so the analyzer equity is sampled
+                    let mut portfolio = Portfolio {
+                        clock: clock.clone(),
+                        cache: cache.clone(),
+                        msgbus: msgbus.clone(),
+                        inner: inner.clone(),
+                    };
+                    portfolio.update_quote_tick(quote);
                 }),
             }))
         };
@@ -315,6 +356,34 @@ impl Portfolio {
         log::debug!("READY");
     }
 
+    /// Returns the portfolio analyzer.
+    #[must_use]
+    pub fn analyzer(&self) -> Ref<'_, PortfolioAnalyzer> {
+        Ref::map(self.inner.borrow(), |inner| &inner.analyzer)
+    }
+
+    /// Returns a mutable reference to the portfolio analyzer.
+    #[must_use]
+    pub fn analyzer_mut(&self) -> RefMut<'_, PortfolioAnalyzer> {
+        RefMut::map(self.inner.borrow_mut(), |inner| &mut inner.analyzer)
+    }
+
+    /// Returns the performance attribution by strategy and instrument for all positions
+    /// in the cache (including snapshots of prior NETTING position cycles).
+    #[must_use]
+    pub fn performance_attribution(&self) -> PerformanceAttributionReport {
+        let borrowed_cache = self.cache.borrow();
+        let mut positions = borrowed_cache.position_snapshots(None);
+        positions.extend(
+            borrowed_cache
+                .positions(None, None, None, None)
+                .into_iter()
+                .cloned(),
+        );
+
+        self.analyzer().calculate_attribution(&positions)
+    }
+
     // -- QUERIES ---------------------------------------------------------------------------------
 
     #[must_use]
@@ -352,6 +421,10 @@ impl Portfolio {
                     log::warn!("Initial margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Initial margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -372,6 +445,10 @@ impl Portfolio {
                     log::warn!("Maintenance margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Maintenance margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -821,7 +898,7 @@ impl Portfolio {
                 };
 
             let account = match account {
-                AccountAny::Cash(_) => continue,
+                AccountAny::Cash(_) | AccountAny::Betting(_) => continue,
                 AccountAny::Margin(margin_account) => margin_account,
             };
 
@@ -875,10 +952,12 @@ impl Portfolio {
             self.inner.clone(),
             quote,
         );
+        self.update_analyzer_equity(&quote.instrument_id.venue);
     }
 
     pub fn update_account(&mut self, event: &AccountState) {
         update_account(self.cache.clone(), event);
+        self.inner.borrow_mut().analyzer.add_account_state(event);
     }
 
     pub fn update_order(&mut self, event: &OrderEventAny) {
@@ -903,6 +982,31 @@ impl Portfolio {
 
     // -- INTERNAL --------------------------------------------------------------------------------
 
+    /// Marks-to-market the analyzer equity curve for the account at the given `venue`,
+    /// if an equity sample is due.
+    fn update_analyzer_equity(&mut self, venue: &Venue) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        if !self.inner.borrow().analyzer.is_equity_sample_due(ts_now) {
+            return;
+        }
+
+        let Some(account_id) = self
+            .cache
+            .borrow()
+            .account_for_venue(venue)
+            .map(|account| account.id())
+        else {
+            return;
+        };
+
+        let unrealized_pnls: Vec<Money> = self.unrealized_pnls(venue).into_values().collect();
+        self.inner.borrow_mut().analyzer.update_unrealized_pnls(
+            account_id,
+            ts_now,
+            &unrealized_pnls,
+        );
+    }
+
     fn update_net_position(&mut self, instrument_id: &InstrumentId, positions_open: Vec<Position>) {
         let mut net_position = Decimal::ZERO;
 
@@ -1224,7 +1328,7 @@ fn update_quote_tick(
         portfolio_clone.calculate_unrealized_pnl(&quote.instrument_id);
 
     if result_init.is_some()
-        && (matches!(account, AccountAny::Cash(_))
+        && (matches!(account, AccountAny::Cash(_) | AccountAny::Betting(_))
             || (result_maint.is_some() && result_unrealized_pnl.is_some()))
     {
         inner
@@ -1273,6 +1377,11 @@ fn update_order(
                 return;
             }
         }
+        AccountAny::Betting(betting_account) => {
+            if !betting_account.base.calculate_account_state {
+                return;
+            }
+        }
     }
 
     match event {
@@ -1457,7 +1566,10 @@ pub fn update_account(cache: Rc<RefCell<Cache>>, event: &AccountState) {
 
     if let Some(existing) = borrowed_cache.account(&event.account_id) {
         let mut account = existing.clone();
-        account.apply(event.clone());
+        if let Err(e) = account.apply(event.clone()) {
+            log::error!("Failed to apply account state: {}", e);
+            return;
+        }
 
         if let Err(e) = borrowed_cache.update_account(account.clone()) {
             log::error!("Failed to update account: {}", e);
@@ -1491,10 +1603,11 @@ mod tests {
     use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
     use nautilus_core::{UnixNanos, UUID4};
     use nautilus_model::{
+        accounts::AccountAny,
         data::QuoteTick,
         enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
         events::{
-            account::stubs::cash_account_state,
+            account::stubs::{betting_account_state, cash_account_state},
             order::stubs::{order_accepted, order_filled, order_submitted},
             AccountState, OrderAccepted, OrderEventAny, OrderFilled, OrderSubmitted,
             PositionChanged, PositionClosed, PositionEvent, PositionOpened,
@@ -1504,8 +1617,8 @@ mod tests {
             AccountId, ClientOrderId, PositionId, StrategyId, Symbol, TradeId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{audusd_sim, betting, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
+            BettingInstrument, CryptoPerpetual, CurrencyPair, InstrumentAny,
         },
         orders::{OrderAny, OrderTestBuilder},
         position::Position,
@@ -1813,6 +1926,145 @@ mod tests {
         assert_eq!(account.id().get_issuers_id(), "1513111");
     }
 
+    #[rstest]
+    fn test_performance_attribution_when_no_positions_returns_empty_report(portfolio: Portfolio) {
+        let report = portfolio.performance_attribution();
+
+        assert!(report.strategies.is_empty());
+        assert!(report.instruments.is_empty());
+    }
+
+    #[rstest]
+    fn test_performance_attribution_groups_cached_positions(
+        portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("P-1"));
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Hedging)
+            .unwrap();
+
+        let report = portfolio.performance_attribution();
+
+        let attribution = &report.strategies[&position.strategy_id];
+        assert_eq!(attribution.position_count, 1);
+        assert_eq!(attribution.fill_count, 1);
+        assert_eq!(attribution.realized_pnls["USD"], 0.0);
+        assert_eq!(
+            report.instruments[&instrument_audusd.id()].turnover,
+            attribution.turnover
+        );
+    }
+
+    #[rstest]
+    fn test_update_account_samples_analyzer_equity_curve(mut portfolio: Portfolio) {
+        let state = get_cash_account(None);
+
+        portfolio.update_account(&state);
+
+        let analyzer = portfolio.analyzer();
+        let equity_curve = analyzer.equity_curve(&Currency::BTC()).unwrap();
+        assert_eq!(equity_curve.len(), 1);
+        assert_eq!(equity_curve[&state.ts_event], 10.0);
+    }
+
+    #[rstest]
+    fn test_update_quote_tick_samples_analyzer_unrealized_pnl(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+        portfolio.analyzer_mut().set_equity_sample_interval(Some(0));
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Hedging)
+            .unwrap();
+
+        let last = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        portfolio.update_quote_tick(&last);
+
+        let analyzer = portfolio.analyzer();
+        let equity_curve = analyzer.equity_curve(&Currency::USD()).unwrap();
+        let (_, equity) = equity_curve.last_key_value().unwrap();
+        assert!((equity - (10.0 - 6445.89)).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_quote_published_on_msgbus_samples_analyzer_equity(
+        msgbus: MessageBus,
+        mut simple_cache: Cache,
+        instrument_audusd: InstrumentAny,
+    ) {
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let mut portfolio = Portfolio::new(
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(simple_cache)),
+            clock.clone(),
+        );
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+        portfolio.analyzer_mut().set_equity_sample_interval(Some(0));
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Hedging)
+            .unwrap();
+
+        let points_before = portfolio
+            .analyzer()
+            .equity_curve(&Currency::USD())
+            .map_or(0, |curve| curve.len());
+
+        clock.borrow_mut().advance_time(1_000.into(), true);
+        let last = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        let topic = portfolio
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_quotes_topic(last.instrument_id);
+        portfolio.msgbus.borrow().publish(&topic, &last);
+
+        let analyzer = portfolio.analyzer();
+        let equity_curve = analyzer.equity_curve(&Currency::USD()).unwrap();
+        assert_eq!(equity_curve.len(), points_before + 1);
+        let (_, equity) = equity_curve.last_key_value().unwrap();
+        assert!((equity - (10.0 - 6445.89)).abs() < 1e-9);
+    }
+
     #[rstest]
     fn test_balances_locked_when_no_account_for_venue_returns_none(
         portfolio: Portfolio,
@@ -2057,6 +2309,48 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_update_orders_open_betting_account_locks_liability(
+        portfolio: Portfolio,
+        betting_account_state: AccountState,
+        betting: BettingInstrument,
+    ) {
+        let instrument = InstrumentAny::Betting(betting);
+        let account = AccountAny::from(betting_account_state);
+
+        // Create lay order
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .price(Price::from("3.00"))
+            .build();
+        order
+            .apply(OrderEventAny::Submitted(submit_order(&order)))
+            .unwrap();
+        order
+            .apply(OrderEventAny::Accepted(accept_order(&order)))
+            .unwrap();
+
+        let (updated_account, account_state) = portfolio
+            .inner
+            .borrow()
+            .accounts
+            .update_orders(&account, instrument, vec![&order], UnixNanos::default())
+            .unwrap();
+
+        // Liability of the lay is the stake multiplied by the odds less one
+        assert_eq!(
+            updated_account.balance_locked(Some(Currency::GBP())),
+            Some(Money::from("20.00 GBP"))
+        );
+        assert_eq!(
+            updated_account.balance_free(Some(Currency::GBP())),
+            Some(Money::from("980.00 GBP"))
+        );
+        assert_eq!(account_state.account_type, AccountType::Betting);
+    }
+
     #[rstest]
     fn test_update_orders_open_margin_account(
         mut portfolio: Portfolio,
@@ -28,7 +28,7 @@ use nautilus_common::{
 use nautilus_core::UUID4;
 use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
 use nautilus_model::{
-    accounts::{Account, AccountAny},
+    accounts::AccountAny,
     enums::{InstrumentClass, OrderSide, OrderStatus, TradingState},
     events::{OrderDenied, OrderEventAny, OrderModifyRejected},
     identifiers::InstrumentId,
@@ -527,6 +527,14 @@ impl RiskEngine {
         ////////////////////////////////////////////////////////////////////////////////
         // VALIDATION CHECKS
         ////////////////////////////////////////////////////////////////////////////////
+        if !instrument.is_tradable() {
+            self.deny_order(
+                order,
+                &format!("Instrument {} is not tradable", instrument.id()),
+            );
+            return false; // Denied
+        }
+
         if !self.check_order_price(instrument.clone(), order.clone())
             || !self.check_order_quantity(instrument, order)
         {
@@ -604,11 +612,16 @@ impl RiskEngine {
             log::debug!("Cannot find account for venue {}", instrument.id().venue);
             return true; // TODO: Temporary early return until handling routing/multiple venues
         };
-        let cash_account = match account {
-            AccountAny::Cash(cash_account) => cash_account,
-            AccountAny::Margin(_) => return true, // TODO: Determine risk controls for margin
+        if let AccountAny::Margin(_) = account {
+            return true; // TODO: Determine risk controls for margin
+        }
+        let free = match &account {
+            // Borrowing covers any shortfall in the free balance up to the borrow limit
+            AccountAny::Cash(cash) if cash.allow_borrowing => {
+                cash.balance_available(instrument.quote_currency())
+            }
+            _ => account.balance_free(Some(instrument.quote_currency())),
         };
-        let free = cash_account.balance_free(Some(instrument.quote_currency()));
         if self.config.debug {
             log::debug!("Free cash: {:?}", free);
         }
@@ -714,14 +727,17 @@ impl RiskEngine {
                 }
             }
 
-            // Calculate OrderBalanceImpact (valid for CashAccount only)
+            // Calculate OrderBalanceImpact (valid for CashAccount and BettingAccount only)
             let notional = instrument.calculate_notional_value(order.quantity(), last_px, None);
-            let order_balance_impact = match order.order_side() {
-                OrderSide::Buy => Money::from_raw(-notional.raw, notional.currency),
-                OrderSide::Sell => Money::from_raw(notional.raw, notional.currency),
-                OrderSide::NoOrderSide => {
+            let order_balance_impact = match (&account, order.order_side()) {
+                (_, OrderSide::NoOrderSide) => {
                     panic!("invalid `OrderSide`, was {}", order.order_side());
                 }
+                (AccountAny::Betting(betting_account), side) => {
+                    betting_account.balance_impact(&instrument, order.quantity(), last_px, side)
+                }
+                (_, OrderSide::Buy) => Money::from_raw(-notional.raw, notional.currency),
+                (_, OrderSide::Sell) => Money::from_raw(notional.raw, notional.currency),
             };
 
             if self.config.debug {
@@ -767,7 +783,7 @@ impl RiskEngine {
                     }
                 }
             } else if order.is_sell() {
-                if cash_account.base_currency.is_some() {
+                if account.base_currency().is_some() {
                     match cum_notional_sell.as_mut() {
                         Some(cum_notional_buy_val) => {
                             cum_notional_buy_val.raw += order_balance_impact.raw;
@@ -804,15 +820,9 @@ impl RiskEngine {
 
                     if self.config.debug {
                         log::debug!("Cash value: {:?}", cash_value);
-                        log::debug!(
-                            "Total: {:?}",
-                            cash_account.balance_total(Some(base_currency))
-                        );
-                        log::debug!(
-                            "Locked: {:?}",
-                            cash_account.balance_locked(Some(base_currency))
-                        );
-                        log::debug!("Free: {:?}", cash_account.balance_free(Some(base_currency)));
+                        log::debug!("Total: {:?}", account.balance_total(Some(base_currency)));
+                        log::debug!("Locked: {:?}", account.balance_locked(Some(base_currency)));
+                        log::debug!("Free: {:?}", account.balance_free(Some(base_currency)));
                     }
 
                     match cum_notional_sell {
@@ -1090,14 +1100,15 @@ mod tests {
     };
     use nautilus_model::{
         accounts::{
-            stubs::{cash_account, margin_account},
+            stubs::{betting_account, cash_account, margin_account},
             AccountAny,
         },
         data::{stubs::quote_audusd, QuoteTick},
         enums::{AccountType, LiquiditySide, OrderSide, OrderType, TradingState},
         events::{
-            account::stubs::cash_account_state_million_usd, AccountState, OrderAccepted,
-            OrderDenied, OrderEventAny, OrderEventType, OrderFilled, OrderSubmitted,
+            account::stubs::{betting_account_state, cash_account_state_million_usd},
+            AccountState, OrderAccepted, OrderDenied, OrderEventAny, OrderEventType, OrderFilled,
+            OrderSubmitted,
         },
         identifiers::{
             stubs::{
@@ -1108,8 +1119,8 @@ mod tests {
             Symbol, TradeId, TraderId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{audusd_sim, betting, crypto_perpetual_ethusdt, index_spx, xbtusd_bitmex},
+            BettingInstrument, CryptoPerpetual, CurrencyPair, IndexInstrument, InstrumentAny,
         },
         orders::{OrderAny, OrderList, OrderTestBuilder},
         types::{fixed::FIXED_PRECISION, AccountBalance, Currency, Money, Price, Quantity},
@@ -2220,6 +2231,77 @@ mod tests {
             .contains(&format!("invalid (precision {FIXED_PRECISION} > 5)")));
     }
 
+    #[rstest]
+    fn test_submit_order_when_instrument_not_tradable_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        index_spx: IndexInstrument,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        let instrument = InstrumentAny::IndexInstrument(index_spx);
+        simple_cache.add_instrument(instrument.clone()).unwrap();
+
+        simple_cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd,
+            )))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("5000.00"))
+            .quantity(Quantity::from("1"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("Instrument SPX.CBOE is not tradable")
+        );
+    }
+
     #[rstest]
     fn test_submit_order_when_invalid_negative_price_and_not_option_then_denies(
         mut msgbus: MessageBus,
@@ -3095,6 +3177,233 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_submit_order_when_market_order_and_over_free_balance_with_borrowing_then_sends_to_execution_engine(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        quote_audusd: QuoteTick,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let mut account = cash_account(cash_account_state_million_usd);
+        account.set_allow_borrowing(true);
+        simple_cache.add_account(AccountAny::Cash(account)).unwrap();
+
+        simple_cache.add_quote(quote_audusd).unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("100000").unwrap())
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 0);
+
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(saved_execute_messages.len(), 1);
+    }
+
+    #[rstest]
+    fn test_submit_order_when_market_order_and_over_borrow_limit_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        quote_audusd: QuoteTick,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let mut account = cash_account(cash_account_state_million_usd);
+        account.set_allow_borrowing(true);
+        account.set_borrow_limit(Money::from("5000000 USD"));
+        simple_cache.add_account(AccountAny::Cash(account)).unwrap();
+
+        simple_cache.add_quote(quote_audusd).unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("100000").unwrap())
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("NOTIONAL_EXCEEDS_FREE_BALANCE: free=Money(6000000.00, USD), notional=Money(10100000.00, USD)")
+        );
+
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(saved_execute_messages.len(), 0);
+    }
+
+    #[rstest]
+    fn test_submit_order_when_lay_liability_over_free_balance_then_denies_with_betting_account(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        betting: BettingInstrument,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut betting_account_state: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        let instrument = InstrumentAny::Betting(betting);
+        simple_cache.add_instrument(instrument.clone()).unwrap();
+
+        betting_account_state.account_id = AccountId::from("BETFAIR-001");
+        simple_cache
+            .add_account(AccountAny::Betting(betting_account(betting_account_state)))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+
+        // Stake is within the free balance, but the liability of the lay is not
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("600.00"))
+            .price(Price::from("3.00"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("NOTIONAL_EXCEEDS_FREE_BALANCE: free=Money(1000.00, GBP), notional=Money(600.00, GBP)")
+        );
+    }
+
     #[rstest]
     fn test_submit_order_list_buys_when_over_free_balance_then_denies(
         mut msgbus: MessageBus,
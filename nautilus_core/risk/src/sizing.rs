@@ -14,6 +14,8 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Position sizing calculation functions.
+use nautilus_core::correctness::{check_equal_usize, FAILED};
+use nautilus_indicators::{indicator::Indicator, volatility::atr::AverageTrueRange};
 use nautilus_model::{
     instruments::InstrumentAny,
     types::{Money, Price, Quantity},
@@ -23,6 +25,9 @@ use rust_decimal::{
     Decimal,
 };
 
+const ERC_MAX_ITERATIONS: usize = 1_000;
+const ERC_TOLERANCE: f64 = 1e-12;
+
 #[must_use]
 #[allow(clippy::too_many_arguments)]
 pub fn calculate_fixed_risk_position_size(
@@ -48,9 +53,415 @@ pub fn calculate_fixed_risk_position_size(
         return instrument.make_qty(0.0);
     }
 
-    let mut position_size =
+    let position_size =
         ((risk_money / exchange_rate) / risk_points) / instrument.price_increment().as_decimal();
 
+    round_position_size(
+        &instrument,
+        position_size,
+        hard_limit,
+        unit_batch_size,
+        units,
+    )
+}
+
+/// Calculates a position size which risks the given fraction of `equity` over a stop
+/// distance of `volatility_multiple` times the given price `volatility` (e.g. an ATR value).
+///
+/// The `volatility` is expressed in price units of the instrument, and the `exchange_rate`
+/// converts from the instrument quote currency to the account currency.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_volatility_risk_position_size(
+    instrument: InstrumentAny,
+    volatility: Decimal,
+    volatility_multiple: Decimal,
+    equity: Money,
+    risk: Decimal,
+    commission_rate: Decimal,
+    exchange_rate: Decimal,
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+    units: usize,
+) -> Quantity {
+    if exchange_rate.is_zero() {
+        return instrument.make_qty(0.0);
+    }
+
+    let risk_per_unit = volatility * volatility_multiple * instrument.multiplier().as_decimal();
+    let risk_money = calculate_riskable_money(equity.as_decimal(), risk, commission_rate);
+
+    if risk_per_unit <= Decimal::ZERO {
+        return instrument.make_qty(0.0);
+    }
+
+    let position_size = (risk_money / exchange_rate) / risk_per_unit;
+
+    round_position_size(
+        &instrument,
+        position_size,
+        hard_limit,
+        unit_batch_size,
+        units,
+    )
+}
+
+/// Calculates a position size which risks the given fraction of `equity` over a stop
+/// distance of `atr_multiple` times the current value of the given `atr` indicator.
+///
+/// Returns a zero quantity until the `atr` indicator is initialized.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_atr_position_size(
+    instrument: InstrumentAny,
+    atr: &AverageTrueRange,
+    atr_multiple: Decimal,
+    equity: Money,
+    risk: Decimal,
+    commission_rate: Decimal,
+    exchange_rate: Decimal,
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+    units: usize,
+) -> Quantity {
+    if !atr.initialized() {
+        return instrument.make_qty(0.0);
+    }
+
+    let Some(volatility) = Decimal::from_f64(atr.value) else {
+        return instrument.make_qty(0.0);
+    };
+
+    calculate_volatility_risk_position_size(
+        instrument,
+        volatility,
+        atr_multiple,
+        equity,
+        risk,
+        commission_rate,
+        exchange_rate,
+        hard_limit,
+        unit_batch_size,
+        units,
+    )
+}
+
+/// Calculates a position size whose expected volatility matches `target_volatility`,
+/// scaling the notional exposure by the ratio of target to `realized_volatility`.
+///
+/// Both volatilities must be expressed over the same horizon (e.g. annualized), and the
+/// `exchange_rate` converts from the instrument quote currency to the account currency.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_volatility_target_position_size(
+    instrument: InstrumentAny,
+    price: Price,
+    equity: Money,
+    target_volatility: Decimal,
+    realized_volatility: Decimal,
+    exchange_rate: Decimal,
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+    units: usize,
+) -> Quantity {
+    if exchange_rate.is_zero() || realized_volatility <= Decimal::ZERO {
+        return instrument.make_qty(0.0);
+    }
+
+    let equity = equity.as_decimal().max(Decimal::ZERO);
+    let notional = equity * target_volatility / realized_volatility;
+
+    let position_size = calculate_units_for_notional(&instrument, price, notional, exchange_rate);
+
+    round_position_size(
+        &instrument,
+        position_size,
+        hard_limit,
+        unit_batch_size,
+        units,
+    )
+}
+
+/// Calculates the Kelly criterion fraction of equity from the given win rate and the
+/// average winning and losing trade results.
+///
+/// The `avg_loser` may be given as a negative or positive amount, only its magnitude is used.
+/// Returns zero when the statistics indicate no edge.
+#[must_use]
+pub fn calculate_kelly_fraction(
+    win_rate: Decimal,
+    avg_winner: Decimal,
+    avg_loser: Decimal,
+) -> Decimal {
+    if win_rate <= Decimal::ZERO || avg_winner <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+
+    let avg_loser = avg_loser.abs();
+    if avg_loser.is_zero() {
+        return win_rate.min(Decimal::ONE);
+    }
+
+    let payoff_ratio = avg_winner / avg_loser;
+    let kelly = win_rate - (Decimal::ONE - win_rate) / payoff_ratio;
+
+    kelly.max(Decimal::ZERO).min(Decimal::ONE)
+}
+
+/// Calculates a position size allocating a fraction of `equity` given by the Kelly criterion,
+/// scaled by `kelly_fraction` (e.g. 0.5 for half-Kelly).
+///
+/// The `exchange_rate` converts from the instrument quote currency to the account currency.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_fractional_kelly_position_size(
+    instrument: InstrumentAny,
+    price: Price,
+    equity: Money,
+    win_rate: Decimal,
+    avg_winner: Decimal,
+    avg_loser: Decimal,
+    kelly_fraction: Decimal,
+    exchange_rate: Decimal,
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+    units: usize,
+) -> Quantity {
+    if exchange_rate.is_zero() {
+        return instrument.make_qty(0.0);
+    }
+
+    let fraction = calculate_kelly_fraction(win_rate, avg_winner, avg_loser) * kelly_fraction;
+    let equity = equity.as_decimal().max(Decimal::ZERO);
+    let notional = equity * fraction.max(Decimal::ZERO);
+
+    let position_size = calculate_units_for_notional(&instrument, price, notional, exchange_rate);
+
+    round_position_size(
+        &instrument,
+        position_size,
+        hard_limit,
+        unit_batch_size,
+        units,
+    )
+}
+
+/// Calculates the equal-risk-contribution (risk parity) weights for a basket with the given
+/// `volatilities` and optional `correlations` matrix, with the weights summing to one.
+///
+/// When `correlations` is `None` the assets are treated as uncorrelated, which reduces to
+/// inverse-volatility weighting. Assets with a non-positive volatility (or a non-positive
+/// variance from the `correlations` diagonal) receive a zero weight.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `correlations` is not a square matrix matching the length of `volatilities`.
+#[must_use]
+pub fn calculate_equal_risk_weights(
+    volatilities: &[f64],
+    correlations: Option<&[Vec<f64>]>,
+) -> Vec<f64> {
+    if let Some(correlations) = correlations {
+        check_equal_usize(
+            correlations.len(),
+            volatilities.len(),
+            "correlations.len()",
+            "volatilities.len()",
+        )
+        .expect(FAILED);
+        for row in correlations {
+            check_equal_usize(
+                row.len(),
+                volatilities.len(),
+                "correlations row.len()",
+                "volatilities.len()",
+            )
+            .expect(FAILED);
+        }
+    }
+
+    let valid: Vec<usize> = (0..volatilities.len())
+        .filter(|&i| {
+            let variance = calculate_covariance(volatilities, correlations, i, i);
+            volatilities[i].is_finite() && volatilities[i] > 0.0 && variance > 0.0
+        })
+        .collect();
+
+    let mut weights = vec![0.0; volatilities.len()];
+    if valid.is_empty() {
+        return weights;
+    }
+
+    // Start from inverse-volatility weights, which is the exact solution when uncorrelated
+    for &i in &valid {
+        weights[i] = 1.0 / volatilities[i];
+    }
+
+    if correlations.is_some() {
+        // Cyclical coordinate descent on the risk budgeting problem
+        let budget = 1.0 / valid.len() as f64;
+        for _ in 0..ERC_MAX_ITERATIONS {
+            let mut max_change: f64 = 0.0;
+            for &i in &valid {
+                let variance = calculate_covariance(volatilities, correlations, i, i);
+                let cross: f64 = valid
+                    .iter()
+                    .filter(|&&j| j != i)
+                    .map(|&j| calculate_covariance(volatilities, correlations, i, j) * weights[j])
+                    .sum();
+                let weight = (-cross + cross.mul_add(cross, 4.0 * variance * budget).sqrt())
+                    / (2.0 * variance);
+                max_change = max_change.max((weight - weights[i]).abs());
+                weights[i] = weight;
+            }
+
+            if max_change < ERC_TOLERANCE {
+                break;
+            }
+        }
+    }
+
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        for weight in &mut weights {
+            *weight /= total;
+        }
+    }
+
+    weights
+}
+
+/// Calculates position sizes for a basket of instruments such that each contributes an equal
+/// share of risk, with the basket scaled to the given `target_volatility`.
+///
+/// The `volatilities` are the return volatilities of each instrument over the same horizon as
+/// `target_volatility`, and each `exchange_rates` value converts from the instruments quote
+/// currency to the account currency. See [`calculate_equal_risk_weights`] for the treatment
+/// of `correlations`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the lengths of `instruments`, `prices`, `volatilities` and `exchange_rates` differ.
+/// - If `correlations` is not a square matrix matching the length of `volatilities`.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_equal_risk_position_sizes(
+    instruments: &[InstrumentAny],
+    prices: &[Price],
+    volatilities: &[f64],
+    correlations: Option<&[Vec<f64>]>,
+    equity: Money,
+    target_volatility: Decimal,
+    exchange_rates: &[Decimal],
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+) -> Vec<Quantity> {
+    check_equal_usize(
+        prices.len(),
+        instruments.len(),
+        "prices.len()",
+        "instruments.len()",
+    )
+    .expect(FAILED);
+    check_equal_usize(
+        volatilities.len(),
+        instruments.len(),
+        "volatilities.len()",
+        "instruments.len()",
+    )
+    .expect(FAILED);
+    check_equal_usize(
+        exchange_rates.len(),
+        instruments.len(),
+        "exchange_rates.len()",
+        "instruments.len()",
+    )
+    .expect(FAILED);
+
+    let weights = calculate_equal_risk_weights(volatilities, correlations);
+
+    let mut portfolio_variance = 0.0;
+    for i in 0..weights.len() {
+        for j in 0..weights.len() {
+            portfolio_variance +=
+                weights[i] * weights[j] * calculate_covariance(volatilities, correlations, i, j);
+        }
+    }
+    let portfolio_volatility = portfolio_variance.sqrt();
+
+    let equity = equity.as_decimal().max(Decimal::ZERO);
+
+    instruments
+        .iter()
+        .enumerate()
+        .map(|(i, instrument)| {
+            if exchange_rates[i].is_zero()
+                || !portfolio_volatility.is_finite()
+                || portfolio_volatility <= 0.0
+                || weights[i] <= 0.0
+            {
+                return instrument.make_qty(0.0);
+            }
+
+            let Some(scale) = Decimal::from_f64(weights[i] / portfolio_volatility) else {
+                return instrument.make_qty(0.0);
+            };
+            let notional = equity * target_volatility * scale;
+            let position_size =
+                calculate_units_for_notional(instrument, prices[i], notional, exchange_rates[i]);
+
+            round_position_size(instrument, position_size, hard_limit, unit_batch_size, 1)
+        })
+        .collect()
+}
+
+// Helper functions
+fn calculate_units_for_notional(
+    instrument: &InstrumentAny,
+    price: Price,
+    notional: Decimal,
+    exchange_rate: Decimal,
+) -> Decimal {
+    let notional_quote = notional / exchange_rate;
+    let multiplier = instrument.multiplier().as_decimal();
+
+    // Inverse instruments are quoted in units of the quote currency
+    let unit_value = if instrument.is_inverse() {
+        multiplier
+    } else {
+        price.as_decimal() * multiplier
+    };
+
+    if unit_value <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+
+    notional_quote / unit_value
+}
+
+fn calculate_covariance(
+    volatilities: &[f64],
+    correlations: Option<&[Vec<f64>]>,
+    i: usize,
+    j: usize,
+) -> f64 {
+    let correlation = match correlations {
+        Some(correlations) => correlations[i][j],
+        None if i == j => 1.0,
+        None => 0.0,
+    };
+    correlation * volatilities[i] * volatilities[j]
+}
+
+fn round_position_size(
+    instrument: &InstrumentAny,
+    mut position_size: Decimal,
+    hard_limit: Option<Decimal>,
+    unit_batch_size: Decimal,
+    units: usize,
+) -> Quantity {
     if let Some(hard_limit) = hard_limit {
         position_size = position_size.min(hard_limit);
     }
@@ -78,7 +489,6 @@ pub fn calculate_fixed_risk_position_size(
     )
 }
 
-// Helper functions
 fn calculate_risk_ticks(entry: Price, stop_loss: Price, instrument: &InstrumentAny) -> Decimal {
     (entry - stop_loss).as_decimal().abs() / instrument.price_increment().as_decimal()
 }
@@ -96,6 +506,7 @@ fn calculate_riskable_money(equity: Decimal, risk: Decimal, commission_rate: Dec
 
 #[cfg(test)]
 mod tests {
+    use nautilus_indicators::volatility::atr::AverageTrueRange;
     use nautilus_model::{
         identifiers::Symbol, instruments::stubs::default_fx_ccy, types::Currency,
     };
@@ -328,4 +739,295 @@ mod tests {
 
         assert_eq!(result.as_f64(), 1000000.0);
     }
+
+    #[rstest]
+    fn test_calculate_volatility_risk_with_zero_volatility(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(100_000.0, instrument_gbpusd.quote_currency());
+
+        let result = calculate_volatility_risk_position_size(
+            instrument_gbpusd,
+            Decimal::ZERO,
+            Decimal::TWO,
+            equity,
+            Decimal::new(1, 2), // 1%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 0.0);
+    }
+
+    #[rstest]
+    fn test_calculate_volatility_risk_position_size(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(100_000.0, instrument_gbpusd.quote_currency());
+
+        let result = calculate_volatility_risk_position_size(
+            instrument_gbpusd,
+            Decimal::new(10, 4), // 0.0010
+            Decimal::TWO,
+            equity,
+            Decimal::new(1, 2), // 1%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 500_000.0);
+    }
+
+    #[rstest]
+    fn test_calculate_volatility_risk_position_size_capped_at_max_quantity(
+        instrument_gbpusd: InstrumentAny,
+    ) {
+        let equity = Money::new(10_000_000.0, instrument_gbpusd.quote_currency());
+
+        let result = calculate_volatility_risk_position_size(
+            instrument_gbpusd,
+            Decimal::new(10, 4), // 0.0010
+            Decimal::TWO,
+            equity,
+            Decimal::new(1, 2), // 1%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 1_000_000.0);
+    }
+
+    #[rstest]
+    fn test_calculate_atr_position_size_when_not_initialized(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(100_000.0, instrument_gbpusd.quote_currency());
+        let atr = AverageTrueRange::new(3, None, Some(false), None);
+
+        let result = calculate_atr_position_size(
+            instrument_gbpusd,
+            &atr,
+            Decimal::TWO,
+            equity,
+            Decimal::new(1, 2), // 1%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::ZERO,
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 0.0);
+    }
+
+    #[rstest]
+    fn test_calculate_atr_position_size(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(100_000.0, instrument_gbpusd.quote_currency());
+        let mut atr = AverageTrueRange::new(3, None, Some(false), None);
+        for _ in 0..3 {
+            atr.update_raw(1.0020, 1.0000, 1.0010);
+        }
+
+        let result = calculate_atr_position_size(
+            instrument_gbpusd,
+            &atr,
+            Decimal::ONE,
+            equity,
+            Decimal::new(1, 2), // 1%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::ZERO,
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 500_000.0);
+    }
+
+    #[rstest]
+    fn test_calculate_volatility_target_position_size(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(1_000_000.0, instrument_gbpusd.quote_currency());
+        let price = Price::new(1.25000, instrument_gbpusd.price_precision());
+
+        let result = calculate_volatility_target_position_size(
+            instrument_gbpusd,
+            price,
+            equity,
+            Decimal::new(10, 2), // 10%
+            Decimal::new(20, 2), // 20%
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 400_000.0);
+    }
+
+    #[rstest]
+    fn test_calculate_volatility_target_with_zero_realized_volatility(
+        instrument_gbpusd: InstrumentAny,
+    ) {
+        let equity = Money::new(1_000_000.0, instrument_gbpusd.quote_currency());
+        let price = Price::new(1.25000, instrument_gbpusd.price_precision());
+
+        let result = calculate_volatility_target_position_size(
+            instrument_gbpusd,
+            price,
+            equity,
+            Decimal::new(10, 2), // 10%
+            Decimal::ZERO,
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 0.0);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(6, 1), Decimal::from(100), Decimal::from(-50), Decimal::new(4, 1))]
+    #[case(
+        Decimal::new(5, 1),
+        Decimal::from(100),
+        Decimal::from(100),
+        Decimal::ZERO
+    )]
+    #[case(Decimal::new(3, 1), Decimal::from(100), Decimal::from(-100), Decimal::ZERO)]
+    #[case(
+        Decimal::new(8, 1),
+        Decimal::from(100),
+        Decimal::ZERO,
+        Decimal::new(8, 1)
+    )]
+    #[case(Decimal::ZERO, Decimal::from(100), Decimal::from(-100), Decimal::ZERO)]
+    fn test_calculate_kelly_fraction(
+        #[case] win_rate: Decimal,
+        #[case] avg_winner: Decimal,
+        #[case] avg_loser: Decimal,
+        #[case] expected: Decimal,
+    ) {
+        let result = calculate_kelly_fraction(win_rate, avg_winner, avg_loser);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_calculate_fractional_kelly_position_size(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(1_000_000.0, instrument_gbpusd.quote_currency());
+        let price = Price::new(1.00000, instrument_gbpusd.price_precision());
+
+        let result = calculate_fractional_kelly_position_size(
+            instrument_gbpusd,
+            price,
+            equity,
+            Decimal::new(6, 1), // 60% win rate
+            Decimal::from(100),
+            Decimal::from(-50),
+            Decimal::new(5, 1), // Half-Kelly
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 200_000.0);
+    }
+
+    #[rstest]
+    fn test_calculate_fractional_kelly_with_no_edge(instrument_gbpusd: InstrumentAny) {
+        let equity = Money::new(1_000_000.0, instrument_gbpusd.quote_currency());
+        let price = Price::new(1.00000, instrument_gbpusd.price_precision());
+
+        let result = calculate_fractional_kelly_position_size(
+            instrument_gbpusd,
+            price,
+            equity,
+            Decimal::new(3, 1), // 30% win rate
+            Decimal::from(100),
+            Decimal::from(-100),
+            Decimal::ONE,
+            EXCHANGE_RATE,
+            None,
+            Decimal::from(1000),
+            1,
+        );
+
+        assert_eq!(result.as_f64(), 0.0);
+    }
+
+    #[rstest]
+    fn test_calculate_equal_risk_weights_uncorrelated() {
+        let weights = calculate_equal_risk_weights(&[0.1, 0.2], None);
+
+        assert!((weights[0] - 2.0 / 3.0).abs() < 1e-12);
+        assert!((weights[1] - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_calculate_equal_risk_weights_with_non_positive_volatility() {
+        let weights = calculate_equal_risk_weights(&[0.1, 0.0, 0.1], None);
+
+        assert_eq!(weights, vec![0.5, 0.0, 0.5]);
+    }
+
+    #[rstest]
+    fn test_calculate_equal_risk_weights_with_zero_variance_in_correlations() {
+        let correlations = vec![vec![1.0, 0.0], vec![0.0, 0.0]];
+        let weights = calculate_equal_risk_weights(&[0.1, 0.2], Some(&correlations));
+        assert_eq!(weights, vec![1.0, 0.0]);
+    }
+
+    #[rstest]
+    fn test_calculate_equal_risk_weights_correlated_have_equal_risk_contributions() {
+        let volatilities = [0.1, 0.2, 0.3];
+        let correlations = vec![
+            vec![1.0, 0.5, 0.2],
+            vec![0.5, 1.0, -0.3],
+            vec![0.2, -0.3, 1.0],
+        ];
+
+        let weights = calculate_equal_risk_weights(&volatilities, Some(&correlations));
+
+        let contributions: Vec<f64> = (0..3)
+            .map(|i| {
+                let marginal: f64 = (0..3)
+                    .map(|j| correlations[i][j] * volatilities[i] * volatilities[j] * weights[j])
+                    .sum();
+                weights[i] * marginal
+            })
+            .collect();
+
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!((contributions[0] - contributions[1]).abs() < 1e-10);
+        assert!((contributions[1] - contributions[2]).abs() < 1e-10);
+    }
+
+    #[rstest]
+    fn test_calculate_equal_risk_position_sizes(instrument_gbpusd: InstrumentAny) {
+        let instrument_audusd = InstrumentAny::CurrencyPair(default_fx_ccy(
+            Symbol::from_str_unchecked("AUD/USD"),
+            None,
+        ));
+        let equity = Money::new(100_000.0, Currency::USD());
+        let correlations = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+
+        let result = calculate_equal_risk_position_sizes(
+            &[instrument_gbpusd, instrument_audusd],
+            &[Price::from("1.00000"), Price::from("0.50000")],
+            &[0.1, 0.1],
+            Some(&correlations),
+            equity,
+            Decimal::new(1, 1), // 10%
+            &[EXCHANGE_RATE, EXCHANGE_RATE],
+            None,
+            Decimal::ZERO,
+        );
+
+        assert_eq!(result[0].as_f64(), 50_000.0);
+        assert_eq!(result[1].as_f64(), 100_000.0);
+    }
 }
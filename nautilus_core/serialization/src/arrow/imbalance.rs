@@ -0,0 +1,313 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{FixedSizeBinaryArray, FixedSizeBinaryBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::AuctionImbalance,
+    enums::{FromU8, OrderSide},
+    identifiers::InstrumentId,
+    types::{fixed::PRECISION_BYTES, Price, Quantity},
+};
+
+use super::{
+    extract_column, get_raw_price, get_raw_quantity, DecodeDataFromRecordBatch, EncodingError,
+    KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for AuctionImbalance {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new(
+                "ref_price",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new(
+                "near_price",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new(
+                "far_price",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new(
+                "paired_qty",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new(
+                "imbalance_qty",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new("side", DataType::UInt8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for AuctionImbalance {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut ref_price_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut near_price_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut far_price_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut paired_qty_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut imbalance_qty_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut side_builder = UInt8Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for imbalance in data {
+            ref_price_builder
+                .append_value(imbalance.ref_price.raw.to_le_bytes())
+                .unwrap();
+            near_price_builder
+                .append_value(imbalance.near_price.raw.to_le_bytes())
+                .unwrap();
+            far_price_builder
+                .append_value(imbalance.far_price.raw.to_le_bytes())
+                .unwrap();
+            paired_qty_builder
+                .append_value(imbalance.paired_qty.raw.to_le_bytes())
+                .unwrap();
+            imbalance_qty_builder
+                .append_value(imbalance.imbalance_qty.raw.to_le_bytes())
+                .unwrap();
+            side_builder.append_value(imbalance.side as u8);
+            ts_event_builder.append_value(imbalance.ts_event.as_u64());
+            ts_init_builder.append_value(imbalance.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(ref_price_builder.finish()),
+                Arc::new(near_price_builder.finish()),
+                Arc::new(far_price_builder.finish()),
+                Arc::new(paired_qty_builder.finish()),
+                Arc::new(imbalance_qty_builder.finish()),
+                Arc::new(side_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.ref_price.precision,
+            self.paired_qty.precision,
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for AuctionImbalance {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let ref_price_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "ref_price",
+            0,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let near_price_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "near_price",
+            1,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let far_price_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "far_price",
+            2,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let paired_qty_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "paired_qty",
+            3,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let imbalance_qty_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "imbalance_qty",
+            4,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let side_values = extract_column::<UInt8Array>(cols, "side", 5, DataType::UInt8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 6, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 7, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let side_value = side_values.value(i);
+                let side = OrderSide::from_u8(side_value).ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(OrderSide),
+                        format!("Invalid enum value, was {side_value}"),
+                    )
+                })?;
+
+                Ok(Self {
+                    instrument_id,
+                    ref_price: Price::from_raw(
+                        get_raw_price(ref_price_values.value(i)),
+                        price_precision,
+                    ),
+                    near_price: Price::from_raw(
+                        get_raw_price(near_price_values.value(i)),
+                        price_precision,
+                    ),
+                    far_price: Price::from_raw(
+                        get_raw_price(far_price_values.value(i)),
+                        price_precision,
+                    ),
+                    paired_qty: Quantity::from_raw(
+                        get_raw_quantity(paired_qty_values.value(i)),
+                        size_precision,
+                    ),
+                    imbalance_qty: Quantity::from_raw(
+                        get_raw_quantity(imbalance_qty_values.value(i)),
+                        size_precision,
+                    ),
+                    side,
+                    ts_event: ts_event_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for AuctionImbalance {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let imbalances: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(imbalances.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_auction_imbalance;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = AuctionImbalance::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let precision_bytes = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("ref_price".to_string(), precision_bytes.clone());
+        expected_map.insert("near_price".to_string(), precision_bytes.clone());
+        expected_map.insert("far_price".to_string(), precision_bytes.clone());
+        expected_map.insert("paired_qty".to_string(), precision_bytes.clone());
+        expected_map.insert("imbalance_qty".to_string(), precision_bytes);
+        expected_map.insert("side".to_string(), "UInt8".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_auction_imbalance: AuctionImbalance) {
+        let mut second = stub_auction_imbalance;
+        second.side = OrderSide::NoOrderSide;
+        second.ts_init = 4.into();
+        let data = vec![stub_auction_imbalance, second];
+        let metadata = AuctionImbalance::chunk_metadata(&data);
+
+        let record_batch = AuctionImbalance::encode_batch(&metadata, &data).unwrap();
+        assert_eq!(record_batch.num_columns(), 8);
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = AuctionImbalance::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_invalid_side_returns_error(stub_auction_imbalance: AuctionImbalance) {
+        let data = vec![stub_auction_imbalance];
+        let metadata = AuctionImbalance::chunk_metadata(&data);
+        let record_batch = AuctionImbalance::encode_batch(&metadata, &data).unwrap();
+
+        let mut columns = record_batch.columns().to_vec();
+        columns[5] = Arc::new(UInt8Array::from(vec![u8::MAX]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+
+        assert!(AuctionImbalance::decode_batch(&metadata, record_batch).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{
+        FixedSizeBinaryArray, FixedSizeBinaryBuilder, StringArray, StringBuilder, StringViewArray,
+        UInt64Array, UInt8Array,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::Liquidation,
+    enums::{FromU8, OrderSide},
+    identifiers::{InstrumentId, TradeId},
+    types::{fixed::PRECISION_BYTES, Price, Quantity},
+};
+
+use super::{
+    extract_column, get_raw_price, get_raw_quantity, DecodeDataFromRecordBatch, EncodingError,
+    KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for Liquidation {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("side", DataType::UInt8, false),
+            Field::new("price", DataType::FixedSizeBinary(PRECISION_BYTES), false),
+            Field::new(
+                "quantity",
+                DataType::FixedSizeBinary(PRECISION_BYTES),
+                false,
+            ),
+            Field::new("trade_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for Liquidation {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut side_builder = UInt8Array::builder(data.len());
+        let mut price_builder = FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut quantity_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut trade_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for liquidation in data {
+            side_builder.append_value(liquidation.side as u8);
+            price_builder
+                .append_value(liquidation.price.raw.to_le_bytes())
+                .unwrap();
+            quantity_builder
+                .append_value(liquidation.quantity.raw.to_le_bytes())
+                .unwrap();
+            trade_id_builder.append_value(liquidation.trade_id.to_string());
+            ts_event_builder.append_value(liquidation.ts_event.as_u64());
+            ts_init_builder.append_value(liquidation.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(side_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(quantity_builder.finish()),
+                Arc::new(trade_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.price.precision,
+            self.quantity.precision,
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for Liquidation {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let side_values = extract_column::<UInt8Array>(cols, "side", 0, DataType::UInt8)?;
+        let price_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "price",
+            1,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let quantity_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "quantity",
+            2,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
+
+        // Datafusion reads trade_ids as StringView
+        let trade_id_values: Vec<TradeId> = if record_batch
+            .schema()
+            .field_with_name("trade_id")?
+            .data_type()
+            == &DataType::Utf8View
+        {
+            extract_column::<StringViewArray>(cols, "trade_id", 3, DataType::Utf8View)?
+                .iter()
+                .map(|id| TradeId::from(id.unwrap()))
+                .collect()
+        } else {
+            extract_column::<StringArray>(cols, "trade_id", 3, DataType::Utf8)?
+                .iter()
+                .map(|id| TradeId::from(id.unwrap()))
+                .collect()
+        };
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let side_value = side_values.value(i);
+                let side = OrderSide::from_u8(side_value).ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(OrderSide),
+                        format!("Invalid enum value, was {side_value}"),
+                    )
+                })?;
+                let price = Price::from_raw(get_raw_price(price_values.value(i)), price_precision);
+                let quantity =
+                    Quantity::from_raw(get_raw_quantity(quantity_values.value(i)), size_precision);
+
+                Ok(Self {
+                    instrument_id,
+                    side,
+                    price,
+                    quantity,
+                    trade_id: trade_id_values[i],
+                    ts_event: ts_event_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for Liquidation {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let liquidations: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(liquidations.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = Liquidation::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let precision_bytes = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("side".to_string(), "UInt8".to_string());
+        expected_map.insert("price".to_string(), precision_bytes.clone());
+        expected_map.insert("quantity".to_string(), precision_bytes);
+        expected_map.insert("trade_id".to_string(), "Utf8".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = Liquidation::get_metadata(&instrument_id, 2, 3);
+        let data = vec![
+            Liquidation::new(
+                instrument_id,
+                OrderSide::Sell,
+                Price::from("100000.50"),
+                Quantity::from("1.500"),
+                TradeId::new("1"),
+                1.into(),
+                3.into(),
+            ),
+            Liquidation::new(
+                instrument_id,
+                OrderSide::Buy,
+                Price::from("100010.00"),
+                Quantity::from("0.250"),
+                TradeId::new("2"),
+                2.into(),
+                4.into(),
+            ),
+        ];
+
+        let record_batch = Liquidation::encode_batch(&metadata, &data).unwrap();
+        assert_eq!(record_batch.num_columns(), 6);
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = Liquidation::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_invalid_side_returns_error() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = Liquidation::get_metadata(&instrument_id, 2, 3);
+        let data = vec![Liquidation::new(
+            instrument_id,
+            OrderSide::Sell,
+            Price::from("100000.50"),
+            Quantity::from("1.500"),
+            TradeId::new("1"),
+            1.into(),
+            3.into(),
+        )];
+        let record_batch = Liquidation::encode_batch(&metadata, &data).unwrap();
+
+        let mut columns = record_batch.columns().to_vec();
+        columns[0] = Arc::new(UInt8Array::from(vec![u8::MAX]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+
+        assert!(Liquidation::decode_batch(&metadata, record_batch).is_err());
+    }
+}
@@ -18,7 +18,12 @@
 pub mod bar;
 pub mod delta;
 pub mod depth;
+pub mod imbalance;
+pub mod liquidation;
+pub mod open_interest;
+pub mod prices;
 pub mod quote;
+pub mod statistics;
 pub mod trade;
 
 use std::{
@@ -35,8 +40,17 @@ use arrow::{
 };
 use nautilus_model::{
     data::{
-        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
-        trade::TradeTick, Data,
+        bar::Bar,
+        delta::OrderBookDelta,
+        depth::OrderBookDepth10,
+        imbalance::AuctionImbalance,
+        liquidation::Liquidation,
+        open_interest::OpenInterestUpdate,
+        prices::{IndexPriceUpdate, MarkPriceUpdate},
+        quote::QuoteTick,
+        statistics::MarketStatistic,
+        trade::TradeTick,
+        Data,
     },
     types::{price::PriceRaw, quantity::QuantityRaw},
 };
@@ -238,3 +252,69 @@ pub fn bars_to_arrow_record_batch_bytes(data: Vec<Bar>) -> Result<RecordBatch, E
     let metadata = first.metadata();
     Bar::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
 }
+
+pub fn mark_price_updates_to_arrow_record_batch_bytes(
+    data: Vec<MarkPriceUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = MarkPriceUpdate::chunk_metadata(&data);
+    MarkPriceUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn index_price_updates_to_arrow_record_batch_bytes(
+    data: Vec<IndexPriceUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = IndexPriceUpdate::chunk_metadata(&data);
+    IndexPriceUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn open_interest_updates_to_arrow_record_batch_bytes(
+    data: Vec<OpenInterestUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = OpenInterestUpdate::chunk_metadata(&data);
+    OpenInterestUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn liquidations_to_arrow_record_batch_bytes(
+    data: Vec<Liquidation>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = Liquidation::chunk_metadata(&data);
+    Liquidation::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn auction_imbalances_to_arrow_record_batch_bytes(
+    data: Vec<AuctionImbalance>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = AuctionImbalance::chunk_metadata(&data);
+    AuctionImbalance::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn market_statistics_to_arrow_record_batch_bytes(
+    data: Vec<MarketStatistic>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = MarketStatistic::chunk_metadata(&data);
+    MarketStatistic::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
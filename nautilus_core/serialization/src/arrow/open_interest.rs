@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{FixedSizeBinaryArray, FixedSizeBinaryBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::OpenInterestUpdate,
+    identifiers::InstrumentId,
+    types::{fixed::PRECISION_BYTES, Quantity},
+};
+
+use super::{
+    extract_column, get_raw_quantity, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for OpenInterestUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", DataType::FixedSizeBinary(PRECISION_BYTES), false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, size_precision))
+}
+
+impl EncodeToRecordBatch for OpenInterestUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            value_builder
+                .append_value(update.value.raw.to_le_bytes())
+                .unwrap();
+            ts_event_builder.append_value(update.ts_event.as_u64());
+            ts_init_builder.append_value(update.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(&self.instrument_id, self.value.precision)
+    }
+}
+
+impl DecodeFromRecordBatch for OpenInterestUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let value_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "value",
+            0,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        Ok((0..record_batch.num_rows())
+            .map(|i| {
+                Self::new(
+                    instrument_id,
+                    Quantity::from_raw(get_raw_quantity(value_values.value(i)), size_precision),
+                    ts_event_values.value(i).into(),
+                    ts_init_values.value(i).into(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for OpenInterestUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OpenInterestUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let precision_bytes = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("value".to_string(), precision_bytes);
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = OpenInterestUpdate::get_metadata(&instrument_id, 3);
+        let data = vec![
+            OpenInterestUpdate::new(
+                instrument_id,
+                Quantity::from("1000.500"),
+                1.into(),
+                3.into(),
+            ),
+            OpenInterestUpdate::new(
+                instrument_id,
+                Quantity::from("1001.250"),
+                2.into(),
+                4.into(),
+            ),
+        ];
+
+        let record_batch = OpenInterestUpdate::encode_batch(&metadata, &data).unwrap();
+        assert_eq!(record_batch.num_columns(), 3);
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded = OpenInterestUpdate::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+}
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{FixedSizeBinaryArray, FixedSizeBinaryBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{IndexPriceUpdate, MarkPriceUpdate},
+    identifiers::InstrumentId,
+    types::{fixed::PRECISION_BYTES, Price},
+};
+
+use super::{
+    extract_column, get_raw_price, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    KEY_PRICE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+/// Decoded `(value, ts_event, ts_init)` rows shared by the price update types.
+type PriceUpdateRows = Vec<(Price, UnixNanos, UnixNanos)>;
+
+fn price_update_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+    let fields = vec![
+        Field::new("value", DataType::FixedSizeBinary(PRECISION_BYTES), false),
+        Field::new("ts_event", DataType::UInt64, false),
+        Field::new("ts_init", DataType::UInt64, false),
+    ];
+
+    match metadata {
+        Some(metadata) => Schema::new_with_metadata(fields, metadata),
+        None => Schema::new(fields),
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision))
+}
+
+fn encode_price_updates(
+    schema: Schema,
+    rows: impl ExactSizeIterator<Item = (Price, UnixNanos, UnixNanos)>,
+) -> Result<RecordBatch, ArrowError> {
+    let mut value_builder = FixedSizeBinaryBuilder::with_capacity(rows.len(), PRECISION_BYTES);
+    let mut ts_event_builder = UInt64Array::builder(rows.len());
+    let mut ts_init_builder = UInt64Array::builder(rows.len());
+
+    for (value, ts_event, ts_init) in rows {
+        value_builder.append_value(value.raw.to_le_bytes()).unwrap();
+        ts_event_builder.append_value(ts_event.as_u64());
+        ts_init_builder.append_value(ts_init.as_u64());
+    }
+
+    RecordBatch::try_new(
+        schema.into(),
+        vec![
+            Arc::new(value_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+        ],
+    )
+}
+
+fn decode_price_updates(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+) -> Result<(InstrumentId, PriceUpdateRows), EncodingError> {
+    let (instrument_id, price_precision) = parse_metadata(metadata)?;
+    let cols = record_batch.columns();
+
+    let value_values = extract_column::<FixedSizeBinaryArray>(
+        cols,
+        "value",
+        0,
+        DataType::FixedSizeBinary(PRECISION_BYTES),
+    )?;
+    let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+    let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+    let rows = (0..record_batch.num_rows())
+        .map(|i| {
+            (
+                Price::from_raw(get_raw_price(value_values.value(i)), price_precision),
+                ts_event_values.value(i).into(),
+                ts_init_values.value(i).into(),
+            )
+        })
+        .collect();
+
+    Ok((instrument_id, rows))
+}
+
+impl ArrowSchemaProvider for MarkPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        price_update_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for MarkPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        encode_price_updates(
+            Self::get_schema(Some(metadata.clone())),
+            data.iter().map(|u| (u.value, u.ts_event, u.ts_init)),
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(&self.instrument_id, self.value.precision)
+    }
+}
+
+impl DecodeFromRecordBatch for MarkPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, rows) = decode_price_updates(metadata, &record_batch)?;
+        Ok(rows
+            .into_iter()
+            .map(|(value, ts_event, ts_init)| Self::new(instrument_id, value, ts_event, ts_init))
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for MarkPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+impl ArrowSchemaProvider for IndexPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        price_update_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for IndexPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        encode_price_updates(
+            Self::get_schema(Some(metadata.clone())),
+            data.iter().map(|u| (u.value, u.ts_event, u.ts_init)),
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(&self.instrument_id, self.value.precision)
+    }
+}
+
+impl DecodeFromRecordBatch for IndexPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, rows) = decode_price_updates(metadata, &record_batch)?;
+        Ok(rows
+            .into_iter()
+            .map(|(value, ts_event, ts_init)| Self::new(instrument_id, value, ts_event, ts_init))
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for IndexPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::types::{fixed::FIXED_SCALAR, price::PriceRaw};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = MarkPriceUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let precision_bytes = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("value".to_string(), precision_bytes);
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+        assert_eq!(IndexPriceUpdate::get_schema_map(), schema_map);
+    }
+
+    #[rstest]
+    fn test_encode_mark_price_updates() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+
+        let update1 =
+            MarkPriceUpdate::new(instrument_id, Price::from("100.10"), 1.into(), 3.into());
+        let update2 =
+            MarkPriceUpdate::new(instrument_id, Price::from("100.50"), 2.into(), 4.into());
+
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &[update1, update2]).unwrap();
+        let columns = record_batch.columns();
+
+        let value_values = columns[0]
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let ts_event_values = columns[1].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = columns[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(value_values.len(), 2);
+        assert_eq!(
+            get_raw_price(value_values.value(0)),
+            (100.10 * FIXED_SCALAR) as PriceRaw
+        );
+        assert_eq!(
+            get_raw_price(value_values.value(1)),
+            (100.50 * FIXED_SCALAR) as PriceRaw
+        );
+        assert_eq!(ts_event_values.value(0), 1);
+        assert_eq!(ts_event_values.value(1), 2);
+        assert_eq!(ts_init_values.value(0), 3);
+        assert_eq!(ts_init_values.value(1), 4);
+    }
+
+    #[rstest]
+    fn test_index_price_updates_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = IndexPriceUpdate::get_metadata(&instrument_id, 2);
+        let data = vec![
+            IndexPriceUpdate::new(instrument_id, Price::from("100.10"), 1.into(), 3.into()),
+            IndexPriceUpdate::new(instrument_id, Price::from("100.50"), 2.into(), 4.into()),
+        ];
+
+        let record_batch = IndexPriceUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded = IndexPriceUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_with_missing_precision_returns_error() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let mut metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+        let data = vec![MarkPriceUpdate::new(
+            instrument_id,
+            Price::from("100.10"),
+            1.into(),
+            3.into(),
+        )];
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &data).unwrap();
+        metadata.remove(KEY_PRICE_PRECISION);
+
+        let result = MarkPriceUpdate::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_PRICE_PRECISION))
+        ));
+    }
+}
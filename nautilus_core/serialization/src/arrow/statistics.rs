@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{Array, FixedSizeBinaryArray, FixedSizeBinaryBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::MarketStatistic,
+    enums::{FromU8, MarketStatisticType},
+    identifiers::InstrumentId,
+    types::{fixed::PRECISION_BYTES, Price, Quantity},
+};
+
+use super::{
+    extract_column, get_raw_price, get_raw_quantity, DecodeDataFromRecordBatch, EncodingError,
+    KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for MarketStatistic {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("stat_type", DataType::UInt8, false),
+            Field::new("price", DataType::FixedSizeBinary(PRECISION_BYTES), true),
+            Field::new("quantity", DataType::FixedSizeBinary(PRECISION_BYTES), true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for MarketStatistic {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut stat_type_builder = UInt8Array::builder(data.len());
+        let mut price_builder = FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut quantity_builder =
+            FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for statistic in data {
+            stat_type_builder.append_value(statistic.stat_type as u8);
+            match statistic.price() {
+                Some(price) => price_builder.append_value(price.raw.to_le_bytes())?,
+                None => price_builder.append_null(),
+            }
+            match statistic.quantity() {
+                Some(quantity) => quantity_builder.append_value(quantity.raw.to_le_bytes())?,
+                None => quantity_builder.append_null(),
+            }
+            ts_event_builder.append_value(statistic.ts_event.as_u64());
+            ts_init_builder.append_value(statistic.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(stat_type_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(quantity_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.price.precision,
+            self.quantity.precision,
+        )
+    }
+
+    /// Statistics only populate a price or a quantity depending on the `stat_type`,
+    /// so precisions are taken from the first defined value in the chunk.
+    fn chunk_metadata(chunk: &[Self]) -> HashMap<String, String> {
+        let first = chunk
+            .first()
+            .expect("Chunk must have atleast one element to encode");
+        let price_precision = chunk
+            .iter()
+            .find_map(Self::price)
+            .map_or(0, |price| price.precision);
+        let size_precision = chunk
+            .iter()
+            .find_map(Self::quantity)
+            .map_or(0, |quantity| quantity.precision);
+        Self::get_metadata(&first.instrument_id, price_precision, size_precision)
+    }
+}
+
+impl DecodeFromRecordBatch for MarketStatistic {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let stat_type_values = extract_column::<UInt8Array>(cols, "stat_type", 0, DataType::UInt8)?;
+        let price_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "price",
+            1,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let quantity_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "quantity",
+            2,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 3, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 4, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let stat_type_value = stat_type_values.value(i);
+                let stat_type = MarketStatisticType::from_u8(stat_type_value).ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(MarketStatisticType),
+                        format!("Invalid enum value, was {stat_type_value}"),
+                    )
+                })?;
+                let price = (!price_values.is_null(i)).then(|| {
+                    Price::from_raw(get_raw_price(price_values.value(i)), price_precision)
+                });
+                let quantity = (!quantity_values.is_null(i)).then(|| {
+                    Quantity::from_raw(get_raw_quantity(quantity_values.value(i)), size_precision)
+                });
+
+                Ok(Self::new(
+                    instrument_id,
+                    stat_type,
+                    price,
+                    quantity,
+                    ts_event_values.value(i).into(),
+                    ts_init_values.value(i).into(),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for MarketStatistic {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let statistics: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(statistics.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_market_statistic;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = MarketStatistic::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let precision_bytes = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("stat_type".to_string(), "UInt8".to_string());
+        expected_map.insert("price".to_string(), precision_bytes.clone());
+        expected_map.insert("quantity".to_string(), precision_bytes);
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_market_statistic: MarketStatistic) {
+        let open_interest = MarketStatistic::new(
+            stub_market_statistic.instrument_id,
+            MarketStatisticType::OpenInterest,
+            None,
+            Some(Quantity::from(12_345)),
+            3.into(),
+            4.into(),
+        );
+        let data = vec![stub_market_statistic, open_interest];
+        let metadata = MarketStatistic::chunk_metadata(&data);
+        assert_eq!(metadata[KEY_PRICE_PRECISION], "2");
+        assert_eq!(metadata[KEY_SIZE_PRECISION], "0");
+
+        let record_batch = MarketStatistic::encode_batch(&metadata, &data).unwrap();
+        assert_eq!(record_batch.num_columns(), 5);
+        assert_eq!(record_batch.num_rows(), 2);
+        assert_eq!(record_batch.column(1).null_count(), 1);
+        assert_eq!(record_batch.column(2).null_count(), 1);
+
+        let decoded = MarketStatistic::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_invalid_stat_type_returns_error(stub_market_statistic: MarketStatistic) {
+        let data = vec![stub_market_statistic];
+        let metadata = MarketStatistic::chunk_metadata(&data);
+        let record_batch = MarketStatistic::encode_batch(&metadata, &data).unwrap();
+
+        let mut columns = record_batch.columns().to_vec();
+        columns[0] = Arc::new(UInt8Array::from(vec![0]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+
+        assert!(MarketStatistic::decode_batch(&metadata, record_batch).is_err());
+    }
+}
@@ -15,7 +15,7 @@
 
 use std::{error::Error, fs::File, path::Path};
 
-use arrow::record_batch::RecordBatch;
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
 use parquet::{arrow::ArrowWriter, file::properties::WriterProperties};
 
 /// Writes a `RecordBatch` to a Parquet file at the specified `filepath`, with optional compression.
@@ -34,6 +34,30 @@ pub fn write_batches_to_parquet(
     compression: Option<parquet::basic::Compression>,
     max_row_group_size: Option<usize>,
 ) -> Result<(), Box<dyn Error>> {
+    let mut writer = create_parquet_writer(
+        batches[0].schema(),
+        filepath,
+        compression,
+        max_row_group_size,
+    )?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.close()?;
+
+    Ok(())
+}
+
+/// Creates an `ArrowWriter` for the given `schema` at the specified `filepath`, with optional
+/// compression and maximum row group size.
+///
+/// Batches can then be written incrementally, the writer must be closed to finalize the file.
+pub fn create_parquet_writer(
+    schema: SchemaRef,
+    filepath: &Path,
+    compression: Option<parquet::basic::Compression>,
+    max_row_group_size: Option<usize>,
+) -> Result<ArrowWriter<File>, Box<dyn Error>> {
     // Ensure the parent directory exists
     if let Some(parent) = filepath.parent() {
         std::fs::create_dir_all(parent)?;
@@ -48,11 +72,5 @@ pub fn write_batches_to_parquet(
         .set_max_row_group_size(max_row_group_size.unwrap_or(5000))
         .build();
 
-    let mut writer = ArrowWriter::try_new(file, batches[0].schema(), Some(writer_props))?;
-    for batch in batches {
-        writer.write(batch)?;
-    }
-    writer.close()?;
-
-    Ok(())
+    Ok(ArrowWriter::try_new(file, schema, Some(writer_props))?)
 }
@@ -18,7 +18,10 @@ use std::io::Cursor;
 use arrow::{ipc::writer::StreamWriter, record_batch::RecordBatch};
 use nautilus_core::python::to_pyvalue_err;
 use nautilus_model::{
-    data::{Bar, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    data::{
+        AuctionImbalance, Bar, IndexPriceUpdate, Liquidation, MarkPriceUpdate, MarketStatistic,
+        OpenInterestUpdate, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+    },
     python::data::{
         pyobjects_to_bars, pyobjects_to_order_book_deltas, pyobjects_to_quote_ticks,
         pyobjects_to_trade_ticks,
@@ -31,9 +34,13 @@ use pyo3::{
 };
 
 use crate::arrow::{
-    bars_to_arrow_record_batch_bytes, order_book_deltas_to_arrow_record_batch_bytes,
-    order_book_depth10_to_arrow_record_batch_bytes, quote_ticks_to_arrow_record_batch_bytes,
-    trade_ticks_to_arrow_record_batch_bytes, ArrowSchemaProvider,
+    auction_imbalances_to_arrow_record_batch_bytes, bars_to_arrow_record_batch_bytes,
+    index_price_updates_to_arrow_record_batch_bytes, liquidations_to_arrow_record_batch_bytes,
+    mark_price_updates_to_arrow_record_batch_bytes, market_statistics_to_arrow_record_batch_bytes,
+    open_interest_updates_to_arrow_record_batch_bytes,
+    order_book_deltas_to_arrow_record_batch_bytes, order_book_depth10_to_arrow_record_batch_bytes,
+    quote_ticks_to_arrow_record_batch_bytes, trade_ticks_to_arrow_record_batch_bytes,
+    ArrowSchemaProvider,
 };
 
 /// Transforms the given record `batches` into Python `bytes`.
@@ -68,6 +75,12 @@ pub fn get_arrow_schema_map(py: Python<'_>, cls: &Bound<'_, PyType>) -> PyResult
         stringify!(QuoteTick) => QuoteTick::get_schema_map(),
         stringify!(TradeTick) => TradeTick::get_schema_map(),
         stringify!(Bar) => Bar::get_schema_map(),
+        stringify!(MarkPriceUpdate) => MarkPriceUpdate::get_schema_map(),
+        stringify!(IndexPriceUpdate) => IndexPriceUpdate::get_schema_map(),
+        stringify!(OpenInterestUpdate) => OpenInterestUpdate::get_schema_map(),
+        stringify!(Liquidation) => Liquidation::get_schema_map(),
+        stringify!(AuctionImbalance) => AuctionImbalance::get_schema_map(),
+        stringify!(MarketStatistic) => MarketStatistic::get_schema_map(),
         _ => {
             return Err(PyTypeError::new_err(format!(
                 "Arrow schema for `{cls_str}` is not currently implemented in Rust."
@@ -171,3 +184,69 @@ pub fn py_bars_to_arrow_record_batch_bytes(py: Python, data: Vec<Bar>) -> PyResu
         Err(e) => Err(to_pyvalue_err(e)),
     }
 }
+
+#[pyfunction(name = "mark_price_updates_to_arrow_record_batch_bytes")]
+pub fn py_mark_price_updates_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<MarkPriceUpdate>,
+) -> PyResult<Py<PyBytes>> {
+    match mark_price_updates_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
+
+#[pyfunction(name = "index_price_updates_to_arrow_record_batch_bytes")]
+pub fn py_index_price_updates_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<IndexPriceUpdate>,
+) -> PyResult<Py<PyBytes>> {
+    match index_price_updates_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
+
+#[pyfunction(name = "open_interest_updates_to_arrow_record_batch_bytes")]
+pub fn py_open_interest_updates_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<OpenInterestUpdate>,
+) -> PyResult<Py<PyBytes>> {
+    match open_interest_updates_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
+
+#[pyfunction(name = "liquidations_to_arrow_record_batch_bytes")]
+pub fn py_liquidations_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<Liquidation>,
+) -> PyResult<Py<PyBytes>> {
+    match liquidations_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
+
+#[pyfunction(name = "auction_imbalances_to_arrow_record_batch_bytes")]
+pub fn py_auction_imbalances_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<AuctionImbalance>,
+) -> PyResult<Py<PyBytes>> {
+    match auction_imbalances_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
+
+#[pyfunction(name = "market_statistics_to_arrow_record_batch_bytes")]
+pub fn py_market_statistics_to_arrow_record_batch_bytes(
+    py: Python,
+    data: Vec<MarketStatistic>,
+) -> PyResult<Py<PyBytes>> {
+    match market_statistics_to_arrow_record_batch_bytes(data) {
+        Ok(batch) => arrow_record_batch_to_pybytes(py, batch),
+        Err(e) => Err(to_pyvalue_err(e)),
+    }
+}
@@ -50,5 +50,29 @@ pub fn serialization(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         crate::python::arrow::py_bars_to_arrow_record_batch_bytes,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_mark_price_updates_to_arrow_record_batch_bytes,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_index_price_updates_to_arrow_record_batch_bytes,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_open_interest_updates_to_arrow_record_batch_bytes,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_liquidations_to_arrow_record_batch_bytes,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_auction_imbalances_to_arrow_record_batch_bytes,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::arrow::py_market_statistics_to_arrow_record_batch_bytes,
+        m
+    )?)?;
     Ok(())
 }
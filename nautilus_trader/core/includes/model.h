@@ -364,6 +364,64 @@ typedef enum LiquiditySide {
     TAKER = 2,
 } LiquiditySide;
 
+/**
+ * The type of a market statistic published by a venue.
+ */
+typedef enum MarketStatisticType {
+    /**
+     * The price of the first trade of the session.
+     */
+    OPENING_PRICE = 1,
+    /**
+     * The indicative price at which the opening auction would currently match.
+     */
+    INDICATIVE_OPENING_PRICE = 2,
+    /**
+     * The settlement price determined by the venue.
+     */
+    SETTLEMENT_PRICE = 3,
+    /**
+     * The lowest trade price of the session.
+     */
+    SESSION_LOW_PRICE = 4,
+    /**
+     * The highest trade price of the session.
+     */
+    SESSION_HIGH_PRICE = 5,
+    /**
+     * The total volume traded (or cleared) for the session.
+     */
+    CLEARED_VOLUME = 6,
+    /**
+     * The lowest offer price of the session.
+     */
+    LOWEST_OFFER = 7,
+    /**
+     * The highest bid price of the session.
+     */
+    HIGHEST_BID = 8,
+    /**
+     * The total number of outstanding contracts.
+     */
+    OPEN_INTEREST = 9,
+    /**
+     * The reference price fixed by the venue.
+     */
+    FIXING_PRICE = 10,
+    /**
+     * The last trade price of the session.
+     */
+    CLOSE_PRICE = 11,
+    /**
+     * The change in price from the previous session.
+     */
+    NET_CHANGE = 12,
+    /**
+     * The volume-weighted average price of the session.
+     */
+    VWAP = 13,
+} MarketStatisticType;
+
 /**
  * The status of an individual market on a trading venue.
  */
@@ -1281,6 +1339,193 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents a mark price update for a derivative instrument.
+ *
+ * The mark price is the reference price a venue uses to value open positions, and is typically
+ * the basis for unrealized PnL, margin requirements and liquidations.
+ */
+typedef struct MarkPriceUpdate_t {
+    /**
+     * The instrument ID for the mark price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The mark price.
+     */
+    struct Price_t value;
+    /**
+     * UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} MarkPriceUpdate_t;
+
+/**
+ * Represents an index price update for a derivative instrument.
+ *
+ * The index price is the (usually multi-venue) spot reference price of the underlying.
+ */
+typedef struct IndexPriceUpdate_t {
+    /**
+     * The instrument ID for the index price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The index price.
+     */
+    struct Price_t value;
+    /**
+     * UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} IndexPriceUpdate_t;
+
+/**
+ * Represents an open interest update for a derivative instrument.
+ */
+typedef struct OpenInterestUpdate_t {
+    /**
+     * The instrument ID for the open interest.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The total number of outstanding contracts.
+     */
+    struct Quantity_t value;
+    /**
+     * UNIX timestamp (nanoseconds) when the open interest event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} OpenInterestUpdate_t;
+
+/**
+ * Represents a liquidation event in a market.
+ *
+ * The `side` is the side of the liquidation order, so a `BUY` liquidation closed a short
+ * position and a `SELL` liquidation closed a long position.
+ */
+typedef struct Liquidation_t {
+    /**
+     * The liquidation instrument ID.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The liquidation order side.
+     */
+    enum OrderSide side;
+    /**
+     * The liquidation price.
+     */
+    struct Price_t price;
+    /**
+     * The liquidated quantity.
+     */
+    struct Quantity_t quantity;
+    /**
+     * The liquidation trade match ID (assigned by the venue).
+     */
+    struct TradeId_t trade_id;
+    /**
+     * UNIX timestamp (nanoseconds) when the liquidation event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} Liquidation_t;
+
+/**
+ * Represents an order imbalance published by a venue ahead of an opening or closing auction.
+ *
+ * The near and far prices follow the common exchange imbalance feed conventions, where the
+ * near price includes continuous book orders and the far price includes auction orders only.
+ */
+typedef struct AuctionImbalance_t {
+    /**
+     * The instrument ID for the imbalance.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The reference price at which the paired and imbalance quantities are calculated.
+     */
+    struct Price_t ref_price;
+    /**
+     * The hypothetical auction clearing price for both auction and continuous book orders.
+     */
+    struct Price_t near_price;
+    /**
+     * The hypothetical auction clearing price for auction orders only.
+     */
+    struct Price_t far_price;
+    /**
+     * The quantity which is eligible to be matched at `ref_price`.
+     */
+    struct Quantity_t paired_qty;
+    /**
+     * The quantity which is not paired at `ref_price`.
+     */
+    struct Quantity_t imbalance_qty;
+    /**
+     * The side of the `imbalance_qty` (can be `NO_ORDER_SIDE`).
+     */
+    enum OrderSide side;
+    /**
+     * UNIX timestamp (nanoseconds) when the imbalance event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} AuctionImbalance_t;
+
+/**
+ * Represents a market statistic published by a venue, such as a settlement price or the
+ * cleared volume for a session.
+ *
+ * Depending on the `stat_type` either the price or the quantity is populated, with the other
+ * value left undefined.
+ */
+typedef struct MarketStatistic_t {
+    /**
+     * The instrument ID for the statistic.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The type of the statistic.
+     */
+    enum MarketStatisticType stat_type;
+    /**
+     * The statistic price (undefined if not populated for the `stat_type`).
+     */
+    struct Price_t price;
+    /**
+     * The statistic value for non-price statistics (undefined if not populated for the `stat_type`).
+     */
+    struct Quantity_t quantity;
+    /**
+     * UNIX timestamp (nanoseconds) when the statistic event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} MarketStatistic_t;
+
 /**
  * A built-in Nautilus data type.
  *
@@ -1294,6 +1539,12 @@ typedef enum Data_t_Tag {
     QUOTE,
     TRADE,
     BAR,
+    MARK_PRICE_UPDATE,
+    INDEX_PRICE_UPDATE,
+    OPEN_INTEREST_UPDATE,
+    LIQUIDATION,
+    AUCTION_IMBALANCE,
+    MARKET_STATISTIC,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1317,6 +1568,24 @@ typedef struct Data_t {
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct MarkPriceUpdate_t mark_price_update;
+        };
+        struct {
+            struct IndexPriceUpdate_t index_price_update;
+        };
+        struct {
+            struct OpenInterestUpdate_t open_interest_update;
+        };
+        struct {
+            struct Liquidation_t liquidation;
+        };
+        struct {
+            struct AuctionImbalance_t auction_imbalance;
+        };
+        struct {
+            struct MarketStatistic_t market_statistic;
+        };
     };
 } Data_t;
 
@@ -2148,6 +2417,17 @@ const char *liquidity_side_to_cstr(enum LiquiditySide value);
  */
 enum LiquiditySide liquidity_side_from_cstr(const char *ptr);
 
+const char *market_statistic_type_to_cstr(enum MarketStatisticType value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum MarketStatisticType market_statistic_type_from_cstr(const char *ptr);
+
 const char *market_status_to_cstr(enum MarketStatus value);
 
 /**
@@ -296,6 +296,7 @@ class CashAccount:
         self,
         event: AccountState,
         calculate_account_state: bool,
+        allow_borrowing: bool = False,
     ) -> None: ...
     def to_dict(self) -> dict[str, str]: ...
     @staticmethod
@@ -309,6 +310,61 @@ class CashAccount:
     def events(self) -> list[AccountState]: ...
     @property
     def event_count(self) -> int: ...
+    @property
+    def allow_borrowing(self) -> bool: ...
+    def set_borrow_rate(self, currency: Currency, rate: float) -> None: ...
+    def get_borrow_rate(self, currency: Currency) -> float: ...
+    def borrowed(self, currency: Currency) -> Money: ...
+    def interest(self, currency: Currency) -> Money: ...
+    def accrue_interest(self, ts: int) -> list[Money]: ...
+    def balance_total(self, currency: Currency | None) -> Money | None: ...
+    def balances_total(self) -> dict[Currency, Money]: ...
+    def balance_free(self, currency: Currency | None) -> Money | None: ...
+    def balances_free(self) -> dict[Currency, Money]: ...
+    def balance_locked(self, currency: Currency | None) -> Money | None: ...
+    def balances_locked(self) -> dict[Currency, Money]: ...
+    def apply(self, event: AccountState) -> None: ...
+    def calculate_balance_locked(
+        self,
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: bool | None = None,
+    ) -> Money: ...
+    def calculate_commission(
+        self,
+        instrument: Instrument,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: bool | None = None,
+    ) -> Money: ...
+    def calculate_pnls(
+        self,
+        instrument: Instrument,
+        fill: OrderFilled,
+        position: Position | None = None,
+    ) -> list[Money]: ...
+
+class BettingAccount:
+    def __init__(
+        self,
+        event: AccountState,
+        calculate_account_state: bool,
+    ) -> None: ...
+    def to_dict(self) -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, str]) -> BettingAccount: ...
+    @property
+    def id(self) -> AccountId: ...
+    @property
+    def base_currency(self) -> Currency | None: ...
+    @property
+    def last_event(self) -> AccountState | None: ...
+    def events(self) -> list[AccountState]: ...
+    @property
+    def event_count(self) -> int: ...
     def balance_total(self, currency: Currency | None) -> Money | None: ...
     def balances_total(self) -> dict[Currency, Money]: ...
     def balance_free(self, currency: Currency | None) -> Money | None: ...
@@ -338,10 +394,18 @@ class CashAccount:
         fill: OrderFilled,
         position: Position | None = None,
     ) -> list[Money]: ...
+    def balance_impact(
+        self,
+        instrument: Instrument,
+        quantity: Quantity,
+        price: Price,
+        order_side: OrderSide,
+    ) -> Money: ...
 
 Account: TypeAlias = Union[
     CashAccount,
-    MarginAccount
+    MarginAccount,
+    BettingAccount
 ]
 
 # Accounting transformers
@@ -356,6 +420,11 @@ def margin_account_from_account_events(
     calculate_account_state: bool,
 ) -> MarginAccount: ...
 
+def betting_account_from_account_events(
+    events: list[dict],
+    calculate_account_state: bool,
+) -> BettingAccount: ...
+
 # Data types
 
 def drop_cvec_pycapsule(capsule: object) -> None: ...
@@ -678,6 +747,252 @@ class TradeTick:
     def as_json(self) -> bytes: ...
     def as_msgpack(self) -> bytes: ...
 
+class MarkPriceUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Price: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, price_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> MarkPriceUpdate: ...
+    @staticmethod
+    def from_json(data: bytes) -> MarkPriceUpdate: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> MarkPriceUpdate: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
+class IndexPriceUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Price: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, price_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> IndexPriceUpdate: ...
+    @staticmethod
+    def from_json(data: bytes) -> IndexPriceUpdate: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> IndexPriceUpdate: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
+class OpenInterestUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Quantity,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, size_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> OpenInterestUpdate: ...
+    @staticmethod
+    def from_json(data: bytes) -> OpenInterestUpdate: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> OpenInterestUpdate: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
+class Liquidation:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        trade_id: TradeId,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def side(self) -> OrderSide: ...
+    @property
+    def price(self) -> Price: ...
+    @property
+    def quantity(self) -> Quantity: ...
+    @property
+    def trade_id(self) -> TradeId: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+    ) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> Liquidation: ...
+    @staticmethod
+    def from_json(data: bytes) -> Liquidation: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> Liquidation: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
+class AuctionImbalance:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        ref_price: Price,
+        near_price: Price,
+        far_price: Price,
+        paired_qty: Quantity,
+        imbalance_qty: Quantity,
+        side: OrderSide,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def ref_price(self) -> Price: ...
+    @property
+    def near_price(self) -> Price: ...
+    @property
+    def far_price(self) -> Price: ...
+    @property
+    def paired_qty(self) -> Quantity: ...
+    @property
+    def imbalance_qty(self) -> Quantity: ...
+    @property
+    def side(self) -> OrderSide: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+    ) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> AuctionImbalance: ...
+    @staticmethod
+    def from_json(data: bytes) -> AuctionImbalance: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> AuctionImbalance: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
+class MarketStatistic:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        stat_type: MarketStatisticType,
+        price: Price | None,
+        quantity: Quantity | None,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def stat_type(self) -> MarketStatisticType: ...
+    @property
+    def price(self) -> Price | None: ...
+    @property
+    def quantity(self) -> Quantity | None: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+    @staticmethod
+    def fully_qualified_name() -> str: ...
+    @staticmethod
+    def get_metadata(
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+    ) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> MarketStatistic: ...
+    @staticmethod
+    def from_json(data: bytes) -> MarketStatistic: ...
+    @staticmethod
+    def from_msgpack(data: bytes) -> MarketStatistic: ...
+
+    def as_dict(self) -> dict[str, Any]: ...
+    def as_json(self) -> bytes: ...
+    def as_msgpack(self) -> bytes: ...
+
 class InstrumentStatus:
     def __init__(
         self,
@@ -804,8 +1119,23 @@ class LiquiditySide(Enum):
     TAKER = "TAKER"
     NO_LIQUIDITY_SIDE = "NO_LIQUIDITY_SIDE"
 
-class MarketStatus(Enum):
-    OPEN = "OPEN"
+class MarketStatisticType(Enum):
+    OPENING_PRICE = "OPENING_PRICE"
+    INDICATIVE_OPENING_PRICE = "INDICATIVE_OPENING_PRICE"
+    SETTLEMENT_PRICE = "SETTLEMENT_PRICE"
+    SESSION_LOW_PRICE = "SESSION_LOW_PRICE"
+    SESSION_HIGH_PRICE = "SESSION_HIGH_PRICE"
+    CLEARED_VOLUME = "CLEARED_VOLUME"
+    LOWEST_OFFER = "LOWEST_OFFER"
+    HIGHEST_BID = "HIGHEST_BID"
+    OPEN_INTEREST = "OPEN_INTEREST"
+    FIXING_PRICE = "FIXING_PRICE"
+    CLOSE_PRICE = "CLOSE_PRICE"
+    NET_CHANGE = "NET_CHANGE"
+    VWAP = "VWAP"
+
+class MarketStatus(Enum):
+    OPEN = "OPEN"
     CLOSED = "CLOSED"
     PAUSED = "PAUSED"
     SUSPENDED = "SUSPENDED"
@@ -1809,6 +2139,148 @@ class BinaryOption:
     def from_dict(cls, values: dict[str, str]) -> BinaryOption: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class Cfd:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        quote_currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        base_currency: Currency | None = None,
+        lot_size: Quantity | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def asset_class(self) -> AssetClass: ...
+    @property
+    def base_currency(self) -> Currency | None: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def lot_size(self) -> Quantity | None: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> Cfd: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
+class Commodity:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        quote_currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        lot_size: Quantity | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def lot_size(self) -> Quantity | None: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> Commodity: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class CryptoFuture:
     def __init__(
         self,
@@ -2266,6 +2738,41 @@ class FuturesSpread:
     def from_dict(cls, values: dict[str, str]) -> FuturesSpread: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class IndexInstrument:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> IndexInstrument: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class OptionsContract:
     def __init__(
         self,
@@ -2443,11 +2950,47 @@ class OptionsSpread:
     def from_dict(cls, values: dict[str, str]) -> OptionsContract: ...
     def to_dict(self) -> dict[str, Any]: ...
 
-class SyntheticInstrument:
+class PerpetualOption:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        underlying: str,
+        option_kind: OptionKind,
+        strike_price: Price,
+        currency: Currency,
+        price_precision: int,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Quantity,
+        ts_event: int,
+        ts_init: int,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+        exchange: str | None = None,
+        # info: dict[str, Any] | None = None,  # Add for pyo3
+    ) -> None: ...
     @property
     def id(self) -> InstrumentId: ...
     @property
-    def base_currency(self) -> Currency: ...
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def asset_class(self) -> AssetClass: ...
+    @property
+    def exchange(self) -> str: ...
+    @property
+    def underlying(self) -> str: ...
+    @property
+    def option_kind(self) -> OptionKind: ...
+    @property
+    def base_currency(self) -> Currency | None: ...
     @property
     def quote_currency(self) -> Currency: ...
     @property
@@ -2459,19 +3002,70 @@ class SyntheticInstrument:
     @property
     def size_increment(self) -> Quantity: ...
     @property
+    def multiplier(self) -> Quantity: ...
+    @property
+    def lot_size(self) -> Quantity: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
     def ts_event(self) -> int: ...
     @property
     def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> PerpetualOption: ...
     def to_dict(self) -> dict[str, Any]: ...
 
-Instrument: TypeAlias = Union[
-    CryptoFuture,
-    CryptoPerpetual,
-    CurrencyPair,
-    Equity,
-    FuturesContract,
-    OptionsContract,
-    SyntheticInstrument,
+class SyntheticInstrument:
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def base_currency(self) -> Currency: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
+Instrument: TypeAlias = Union[
+    Cfd,
+    Commodity,
+    CryptoFuture,
+    CryptoPerpetual,
+    CurrencyPair,
+    Equity,
+    FuturesContract,
+    IndexInstrument,
+    OptionsContract,
+    PerpetualOption,
+    SyntheticInstrument,
 ]
 
 # Events
@@ -3280,6 +3874,8 @@ class NautilusDataType(Enum):
     QuoteTick = 3
     TradeTick = 4
     Bar = 5
+    AuctionImbalance = 6
+    MarketStatistic = 7
 
 class DataBackendSession:
     def __init__(self, chunk_size: int = 10_000) -> None: ...
@@ -3372,6 +3968,12 @@ def order_book_depth10_to_arrow_record_batch_bytes(data: list[OrderBookDepth10])
 def quote_ticks_to_arrow_record_batch_bytes(data: list[QuoteTick]) -> bytes: ...
 def trade_ticks_to_arrow_record_batch_bytes(data: list[TradeTick]) -> bytes: ...
 def bars_to_arrow_record_batch_bytes(data: list[Bar]) -> bytes: ...
+def mark_price_updates_to_arrow_record_batch_bytes(data: list[MarkPriceUpdate]) -> bytes: ...
+def index_price_updates_to_arrow_record_batch_bytes(data: list[IndexPriceUpdate]) -> bytes: ...
+def open_interest_updates_to_arrow_record_batch_bytes(data: list[OpenInterestUpdate]) -> bytes: ...
+def liquidations_to_arrow_record_batch_bytes(data: list[Liquidation]) -> bytes: ...
+def auction_imbalances_to_arrow_record_batch_bytes(data: list[AuctionImbalance]) -> bytes: ...
+def market_statistics_to_arrow_record_batch_bytes(data: list[MarketStatistic]) -> bytes: ...
 
 ###################################################################################################
 # Indicators
@@ -4025,55 +4627,423 @@ class MovingAverageConvergenceDivergence:
     @property
     def name(self) -> str: ...
     @property
-    def count(self) -> int: ...
+    def count(self) -> int: ...
+    @property
+    def fast_period(self) -> int: ...
+    @property
+    def slow_period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class OnBalanceVolume:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, close: float, volume: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class AverageDirectionalIndex:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def pos_di(self) -> float: ...
+    @property
+    def neg_di(self) -> float: ...
+    @property
+    def dx(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class ParabolicStopAndReverse:
+    def __init__(
+        self,
+        af_start: float | None = None,
+        af_step: float | None = None,
+        af_max: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def af_start(self) -> float: ...
+    @property
+    def af_step(self) -> float: ...
+    @property
+    def af_max(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def is_long(self) -> bool: ...
+    @property
+    def af(self) -> float: ...
+    @property
+    def extreme_point(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class IchimokuCloud:
+    def __init__(
+        self,
+        tenkan_period: int | None = None,
+        kijun_period: int | None = None,
+        senkou_period: int | None = None,
+        displacement: int | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def tenkan_period(self) -> int: ...
+    @property
+    def kijun_period(self) -> int: ...
+    @property
+    def senkou_period(self) -> int: ...
+    @property
+    def displacement(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def tenkan_sen(self) -> float: ...
+    @property
+    def kijun_sen(self) -> float: ...
+    @property
+    def leading_span_a(self) -> float: ...
+    @property
+    def leading_span_b(self) -> float: ...
+    @property
+    def senkou_span_a(self) -> float: ...
+    @property
+    def senkou_span_b(self) -> float: ...
+    @property
+    def chikou_span(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class AverageTrueRange:
+    def __init__(
+        self,
+        period: int,
+        ma_type: MovingAverageType = ...,
+        use_previous: bool = True,
+        value_floor: float = 0.0,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class CommodityChannelIndex:
+    def __init__(
+        self,
+        period: int,
+        scalar: float,
+        ma_type: MovingAverageType = ...,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def scalar(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class DonchianChannel:
+    def __init__(
+        self,
+        period: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def upper(self) -> float: ...
+    @property
+    def middle(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class KeltnerChannel:
+    def __init__(
+        self,
+        period: int,
+        k_multiplier: float,
+        ma_type: MovingAverageType = ...,
+        ma_type_atr: MovingAverageType = ...,
+        use_previous: bool = True,
+        atr_floor: float = 0.0,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def k_multiplier(self) -> float: ...
+    @property
+    def use_previous(self) -> bool: ...
+    @property
+    def atr_floor(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def upper(self) -> float: ...
+    @property
+    def middle(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class FuzzyCandle:
+    def __init__(
+        self,
+        direction: CandleDirection,
+        size: CandleSize,
+        body_size: CandleBodySize,
+        upper_wick_size: CandleWickSize,
+        lower_wick_size: CandleWickSize,
+    ) -> None: ...
+    @property
+    def direction(self) -> CandleDirection: ...
+    @property
+    def size(self) -> CandleBodySize: ...
+    @property
+    def body_size(self) -> CandleBodySize: ...
+    @property
+    def upper_wick_size(self) -> CandleWickSize: ...
+    @property
+    def lower_wick_size(self) -> CandleWickSize: ...
+
+class FuzzyCandlesticks:
+    def __init__(
+        self,
+        period: int,
+        threshold1: float,
+        threshold2: float,
+        threshold3: float,
+        threshold4: float,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def threshold1(self) -> float: ...
+    @property
+    def threshold2(self) -> float: ...
+    @property
+    def threshold3(self) -> float: ...
+    @property
+    def threshold4(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> FuzzyCandle: ...
+    @property
+    def vector(self) -> list[int]: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+# Fuzzy Enums
+class CandleBodySize(Enum):
+    NONE = 0
+    SMALL = 1
+    MEDIUM = 2
+    LARGE = 3
+    TREND = 4
+
+class CandleDirection(Enum):
+    BULL = 1
+    NONE = 0
+    BEAR = -1
+
+class CandleSize(Enum):
+    NONE = 0
+    VERY_SMALL = 1
+    SMALL = 2
+    MEDIUM = 3
+    LARGE = 4
+    VERY_LARGE = 5
+    EXTREMELY_LARGE = 6
+
+class CandleWickSize(Enum):
+    NONE = 0
+    SMALL = 1
+    MEDIUM = 2
+    LARGE = 3
+
+class SpreadAnalyzer:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        capacity: int,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def capacity(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def current(self) -> float: ...
+    @property
+    def average(self) -> float: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def reset(self) -> None: ...
+
+class KeltnerPosition:
+    def __init__(
+        self,
+        period: int,
+        k_multiplier: float,
+        ma_type: MovingAverageType = ...,
+        ma_type_atr: MovingAverageType = ...,
+        use_previous: bool = True,
+        atr_floor: float = 0.0,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
     @property
-    def fast_period(self) -> int: ...
+    def k_multiplier(self) -> float: ...
     @property
-    def slow_period(self) -> int: ...
+    def use_previous(self) -> bool: ...
+    @property
+    def atr_floor(self) -> float: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
     def value(self) -> float: ...
-    def update_raw(self, close: float) -> None: ...
-    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
-    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class OnBalanceVolume:
+class SuperTrend:
     def __init__(
         self,
         period: int,
+        multiplier: float,
+        ma_type: MovingAverageType = ...,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
+    def multiplier(self) -> float: ...
+    @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
+    def upper(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    @property
     def value(self) -> float: ...
-    def update_raw(self, open: float, close: float, volume: float) -> None: ...
+    @property
+    def is_uptrend(self) -> bool: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class AverageTrueRange:
+class ParkinsonVolatility:
     def __init__(
         self,
         period: int,
-        ma_type: MovingAverageType = ...,
-        use_previous: bool = True,
-        value_floor: float = 0.0,
+        bars_per_year: float | None = None,
+        days_per_year: float | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
+    def days_per_year(self) -> float: ...
+    @property
+    def bars_per_year(self) -> float | None: ...
+    @property
     def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
@@ -4081,219 +5051,198 @@ class AverageTrueRange:
     def has_inputs(self) -> bool: ...
     @property
     def value(self) -> float: ...
-    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class CommodityChannelIndex:
+class GarmanKlassVolatility:
     def __init__(
         self,
         period: int,
-        scalar: float,
-        ma_type: MovingAverageType = ...,
+        bars_per_year: float | None = None,
+        days_per_year: float | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
-    def scalar(self) -> float: ...
+    def days_per_year(self) -> float: ...
+    @property
+    def bars_per_year(self) -> float | None: ...
+    @property
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
     def value(self) -> float: ...
-    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class DonchianChannel:
+class RogersSatchellVolatility:
     def __init__(
         self,
         period: int,
+        bars_per_year: float | None = None,
+        days_per_year: float | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
-    def upper(self) -> float: ...
+    def days_per_year(self) -> float: ...
     @property
-    def middle(self) -> float: ...
+    def bars_per_year(self) -> float | None: ...
     @property
-    def lower(self) -> float: ...
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
-    def update_raw(self, high: float, low: float) -> None: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class KeltnerChannel:
+class YangZhangVolatility:
     def __init__(
         self,
         period: int,
-        k_multiplier: float,
-        ma_type: MovingAverageType = ...,
-        ma_type_atr: MovingAverageType = ...,
-        use_previous: bool = True,
-        atr_floor: float = 0.0,
+        bars_per_year: float | None = None,
+        days_per_year: float | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
-    def k_multiplier(self) -> float: ...
+    def days_per_year(self) -> float: ...
     @property
-    def use_previous(self) -> bool: ...
+    def bars_per_year(self) -> float | None: ...
     @property
-    def atr_floor(self) -> float: ...
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
-    def upper(self) -> float: ...
-    @property
-    def middle(self) -> float: ...
-    @property
-    def lower(self) -> float: ...
-    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def value(self) -> float: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class FuzzyCandle:
+class RollingZScore:
     def __init__(
         self,
-        direction: CandleDirection,
-        size: CandleSize,
-        body_size: CandleBodySize,
-        upper_wick_size: CandleWickSize,
-        lower_wick_size: CandleWickSize,
+        period: int,
+        price_type: PriceType | None = None,
     ) -> None: ...
     @property
-    def direction(self) -> CandleDirection: ...
+    def name(self) -> str: ...
     @property
-    def size(self) -> CandleBodySize: ...
+    def period(self) -> int: ...
     @property
-    def body_size(self) -> CandleBodySize: ...
+    def count(self) -> int: ...
     @property
-    def upper_wick_size(self) -> CandleWickSize: ...
+    def initialized(self) -> bool: ...
     @property
-    def lower_wick_size(self) -> CandleWickSize: ...
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def mean(self) -> float: ...
+    @property
+    def std(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
 
-class FuzzyCandlesticks:
+class RollingPercentile:
     def __init__(
         self,
         period: int,
-        threshold1: float,
-        threshold2: float,
-        threshold3: float,
-        threshold4: float,
+        percentile: float,
+        relative_accuracy: float | None = None,
+        price_type: PriceType | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
-    def threshold1(self) -> float: ...
-    @property
-    def threshold2(self) -> float: ...
-    @property
-    def threshold3(self) -> float: ...
+    def percentile(self) -> float: ...
     @property
-    def threshold4(self) -> float: ...
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
-    def value(self) -> FuzzyCandle: ...
-    @property
-    def vector(self) -> list[int]: ...
-    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-# Fuzzy Enums
-class CandleBodySize(Enum):
-    NONE = 0
-    SMALL = 1
-    MEDIUM = 2
-    LARGE = 3
-    TREND = 4
-
-class CandleDirection(Enum):
-    BULL = 1
-    NONE = 0
-    BEAR = -1
-
-class CandleSize(Enum):
-    NONE = 0
-    VERY_SMALL = 1
-    SMALL = 2
-    MEDIUM = 3
-    LARGE = 4
-    VERY_LARGE = 5
-    EXTREMELY_LARGE = 6
-
-class CandleWickSize(Enum):
-    NONE = 0
-    SMALL = 1
-    MEDIUM = 2
-    LARGE = 3
-
-class SpreadAnalyzer:
+class RollingCorrelation:
     def __init__(
         self,
-        instrument_id: InstrumentId,
-        capacity: int,
+        period: int,
+        instrument_id_x: InstrumentId,
+        instrument_id_y: InstrumentId,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
-    def capacity(self) -> int: ...
+    def period(self) -> int: ...
+    @property
+    def instrument_id_x(self) -> InstrumentId: ...
+    @property
+    def instrument_id_y(self) -> InstrumentId: ...
+    @property
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
-    def current(self) -> float: ...
-    @property
-    def average(self) -> float: ...
-    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def value(self) -> float: ...
+    def update_raw(self, x: float, y: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
-class KeltnerPosition:
+class RollingBeta:
     def __init__(
         self,
         period: int,
-        k_multiplier: float,
-        ma_type: MovingAverageType = ...,
-        ma_type_atr: MovingAverageType = ...,
-        use_previous: bool = True,
-        atr_floor: float = 0.0,
+        instrument_id: InstrumentId,
+        benchmark_id: InstrumentId,
     ) -> None: ...
     @property
     def name(self) -> str: ...
     @property
     def period(self) -> int: ...
     @property
-    def k_multiplier(self) -> float: ...
+    def instrument_id(self) -> InstrumentId: ...
     @property
-    def use_previous(self) -> bool: ...
+    def benchmark_id(self) -> InstrumentId: ...
     @property
-    def atr_floor(self) -> float: ...
+    def count(self) -> int: ...
     @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
     def value(self) -> float: ...
-    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def update_raw(self, instrument_value: float, benchmark_value: float) -> None: ...
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
@@ -4341,6 +5290,93 @@ class BookImbalanceRatio:
     def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
     def reset(self) -> None: ...
 
+class BookDepthImbalance:
+    def __init__(self, depth: int = 10) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def bid_size(self) -> float: ...
+    @property
+    def ask_size(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update_raw(self, bid_size: float, ask_size: float) -> None: ...
+    def reset(self) -> None: ...
+
+class MicroPrice:
+    def __init__(self, depth: int = 1) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def update_raw(
+        self,
+        bids: list[tuple[float, float]],
+        asks: list[tuple[float, float]],
+    ) -> None: ...
+    def reset(self) -> None: ...
+
+class CumulativeVolumeDelta:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def buy_volume(self) -> float: ...
+    @property
+    def sell_volume(self) -> float: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def update_raw(self, size: float, aggressor_side: AggressorSide) -> None: ...
+    def reset(self) -> None: ...
+
+class VolumeSynchronizedProbabilityOfInformedTrading:
+    def __init__(self, bucket_volume: float, window: int = 50) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def bucket_volume(self) -> float: ...
+    @property
+    def window(self) -> int: ...
+    @property
+    def bucket_count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def update_raw(self, size: float, aggressor_side: AggressorSide) -> None: ...
+    def reset(self) -> None: ...
+
 ###################################################################################################
 # Adapters
 ###################################################################################################
@@ -4560,6 +5596,9 @@ def load_tardis_depth10_from_snapshot5(filepath: str, price_precision: int, size
 def load_tardis_depth10_from_snapshot25(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> list[OrderBookDepth10]: ...  # noqa
 def load_tardis_quotes(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> list[QuoteTick]: ...  # noqa
 def load_tardis_trades(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> list[TradeTick]: ...  # noqa
+def load_tardis_liquidations(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> list[Liquidation]: ...  # noqa
+def load_tardis_derivative_tickers(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> list[MarkPriceUpdate | IndexPriceUpdate | OpenInterestUpdate]: ...  # noqa
+def load_tardis_options_chain(filepath: str, price_precision: int, size_precision: int, limit: int | None = None) -> tuple[list[OptionsContract], list[QuoteTick], list[OptionGreeks]]: ...  # noqa
 def load_tardis_deltas_as_pycapsule(filepath: str, price_precision: int, size_precision: int, instrument_id: InstrumentId | None, limit: int | None = None) -> object: ...  # noqa
 def load_tardis_depth10_from_snapshot5_as_pycapsule(filepath: str, price_precision: int, size_precision: int,  instrument_id: InstrumentId | None, limit: int | None = None) -> object: ...  # noqa
 def load_tardis_depth10_from_snapshot25_as_pycapsule(filepath: str, price_precision: int, size_precision: int,  instrument_id: InstrumentId | None, limit: int | None = None) -> object: ...  # noqa
@@ -4636,6 +5675,47 @@ class ImplyVolAndGreeksResult:
     vega: float
     theta: float
 
+class OptionGreeks:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        delta: float,
+        gamma: float,
+        vega: float,
+        theta: float,
+        rho: float,
+        mark_iv: float,
+        bid_iv: float | None,
+        ask_iv: float | None,
+        underlying_price: float,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def delta(self) -> float: ...
+    @property
+    def gamma(self) -> float: ...
+    @property
+    def vega(self) -> float: ...
+    @property
+    def theta(self) -> float: ...
+    @property
+    def rho(self) -> float: ...
+    @property
+    def mark_iv(self) -> float: ...
+    @property
+    def bid_iv(self) -> float | None: ...
+    @property
+    def ask_iv(self) -> float | None: ...
+    @property
+    def underlying_price(self) -> float: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
 
 def black_scholes_greeks(
     s: float,
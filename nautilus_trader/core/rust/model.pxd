@@ -198,6 +198,35 @@ cdef extern from "../includes/model.h":
         # The order aggressively took liqudity from the market to complete the trade.
         TAKER # = 2,
 
+    # The type of a market statistic published by a venue.
+    cpdef enum MarketStatisticType:
+        # The price of the first trade of the session.
+        OPENING_PRICE # = 1,
+        # The indicative price at which the opening auction would currently match.
+        INDICATIVE_OPENING_PRICE # = 2,
+        # The settlement price determined by the venue.
+        SETTLEMENT_PRICE # = 3,
+        # The lowest trade price of the session.
+        SESSION_LOW_PRICE # = 4,
+        # The highest trade price of the session.
+        SESSION_HIGH_PRICE # = 5,
+        # The total volume traded (or cleared) for the session.
+        CLEARED_VOLUME # = 6,
+        # The lowest offer price of the session.
+        LOWEST_OFFER # = 7,
+        # The highest bid price of the session.
+        HIGHEST_BID # = 8,
+        # The total number of outstanding contracts.
+        OPEN_INTEREST # = 9,
+        # The reference price fixed by the venue.
+        FIXING_PRICE # = 10,
+        # The last trade price of the session.
+        CLOSE_PRICE # = 11,
+        # The change in price from the previous session.
+        NET_CHANGE # = 12,
+        # The volume-weighted average price of the session.
+        VWAP # = 13,
+
     # The status of an individual market on a trading venue.
     cpdef enum MarketStatus:
         # The instrument is trading.
@@ -711,6 +740,107 @@ cdef extern from "../includes/model.h":
         # UNIX timestamp (nanoseconds) when the struct was initialized.
         uint64_t ts_init;
 
+    # Represents a mark price update for a derivative instrument.
+    #
+    # The mark price is the reference price a venue uses to value open positions, and is typically
+    # the basis for unrealized PnL, margin requirements and liquidations.
+    cdef struct MarkPriceUpdate_t:
+        # The instrument ID for the mark price.
+        InstrumentId_t instrument_id;
+        # The mark price.
+        Price_t value;
+        # UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents an index price update for a derivative instrument.
+    #
+    # The index price is the (usually multi-venue) spot reference price of the underlying.
+    cdef struct IndexPriceUpdate_t:
+        # The instrument ID for the index price.
+        InstrumentId_t instrument_id;
+        # The index price.
+        Price_t value;
+        # UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents an open interest update for a derivative instrument.
+    cdef struct OpenInterestUpdate_t:
+        # The instrument ID for the open interest.
+        InstrumentId_t instrument_id;
+        # The total number of outstanding contracts.
+        Quantity_t value;
+        # UNIX timestamp (nanoseconds) when the open interest event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents a liquidation event in a market.
+    #
+    # The `side` is the side of the liquidation order, so a `BUY` liquidation closed a short
+    # position and a `SELL` liquidation closed a long position.
+    cdef struct Liquidation_t:
+        # The liquidation instrument ID.
+        InstrumentId_t instrument_id;
+        # The liquidation order side.
+        OrderSide side;
+        # The liquidation price.
+        Price_t price;
+        # The liquidated quantity.
+        Quantity_t quantity;
+        # The liquidation trade match ID (assigned by the venue).
+        TradeId_t trade_id;
+        # UNIX timestamp (nanoseconds) when the liquidation event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents an order imbalance published by a venue ahead of an opening or closing auction.
+    #
+    # The near and far prices follow the common exchange imbalance feed conventions, where the
+    # near price includes continuous book orders and the far price includes auction orders only.
+    cdef struct AuctionImbalance_t:
+        # The instrument ID for the imbalance.
+        InstrumentId_t instrument_id;
+        # The reference price at which the paired and imbalance quantities are calculated.
+        Price_t ref_price;
+        # The hypothetical auction clearing price for both auction and continuous book orders.
+        Price_t near_price;
+        # The hypothetical auction clearing price for auction orders only.
+        Price_t far_price;
+        # The quantity which is eligible to be matched at `ref_price`.
+        Quantity_t paired_qty;
+        # The quantity which is not paired at `ref_price`.
+        Quantity_t imbalance_qty;
+        # The side of the `imbalance_qty` (can be `NO_ORDER_SIDE`).
+        OrderSide side;
+        # UNIX timestamp (nanoseconds) when the imbalance event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents a market statistic published by a venue, such as a settlement price or the
+    # cleared volume for a session.
+    #
+    # Depending on the `stat_type` either the price or the quantity is populated, with the other
+    # value left undefined.
+    cdef struct MarketStatistic_t:
+        # The instrument ID for the statistic.
+        InstrumentId_t instrument_id;
+        # The type of the statistic.
+        MarketStatisticType stat_type;
+        # The statistic price (undefined if not populated for the `stat_type`).
+        Price_t price;
+        # The statistic value for non-price statistics (undefined if not populated for the `stat_type`).
+        Quantity_t quantity;
+        # UNIX timestamp (nanoseconds) when the statistic event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
     # A built-in Nautilus data type.
     #
     # Not recommended for storing large amounts of data, as the largest variant is significantly
@@ -722,6 +852,12 @@ cdef extern from "../includes/model.h":
         QUOTE,
         TRADE,
         BAR,
+        MARK_PRICE_UPDATE,
+        INDEX_PRICE_UPDATE,
+        OPEN_INTEREST_UPDATE,
+        LIQUIDATION,
+        AUCTION_IMBALANCE,
+        MARKET_STATISTIC,
 
     cdef struct Data_t:
         Data_t_Tag tag;
@@ -731,6 +867,12 @@ cdef extern from "../includes/model.h":
         QuoteTick_t quote;
         TradeTick_t trade;
         Bar_t bar;
+        MarkPriceUpdate_t mark_price_update;
+        IndexPriceUpdate_t index_price_update;
+        OpenInterestUpdate_t open_interest_update;
+        Liquidation_t liquidation;
+        AuctionImbalance_t auction_imbalance;
+        MarketStatistic_t market_statistic;
 
     # Represents a valid trader ID.
     cdef struct TraderId_t:
@@ -1311,6 +1453,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     LiquiditySide liquidity_side_from_cstr(const char *ptr);
 
+    const char *market_statistic_type_to_cstr(MarketStatisticType value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    MarketStatisticType market_statistic_type_from_cstr(const char *ptr);
+
     const char *market_status_to_cstr(MarketStatus value);
 
     # Returns an enum from a Python string.
@@ -51,7 +51,7 @@ from nautilus_trader.model.objects cimport Quantity
 
 
 cpdef list capsule_to_list(capsule)
-cpdef Data capsule_to_data(capsule)
+cpdef object capsule_to_data(capsule)
 
 cdef inline void capsule_destructor(object capsule):
     cdef CVec *cvec = <CVec *>PyCapsule_GetPointer(capsule, NULL)
@@ -37,6 +37,7 @@ from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport DEPTH10_LEN
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.core.rust.model cimport AuctionImbalance_t
 from nautilus_trader.core.rust.model cimport Bar_t
 from nautilus_trader.core.rust.model cimport BarSpecification_t
 from nautilus_trader.core.rust.model cimport BarType_t
@@ -44,8 +45,13 @@ from nautilus_trader.core.rust.model cimport BookAction
 from nautilus_trader.core.rust.model cimport BookOrder_t
 from nautilus_trader.core.rust.model cimport Data_t
 from nautilus_trader.core.rust.model cimport Data_t_Tag
+from nautilus_trader.core.rust.model cimport IndexPriceUpdate_t
 from nautilus_trader.core.rust.model cimport InstrumentCloseType
+from nautilus_trader.core.rust.model cimport Liquidation_t
+from nautilus_trader.core.rust.model cimport MarkPriceUpdate_t
+from nautilus_trader.core.rust.model cimport MarketStatistic_t
 from nautilus_trader.core.rust.model cimport MarketStatusAction
+from nautilus_trader.core.rust.model cimport OpenInterestUpdate_t
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport PriceRaw
@@ -90,6 +96,9 @@ from nautilus_trader.core.rust.model cimport book_order_hash
 from nautilus_trader.core.rust.model cimport book_order_new
 from nautilus_trader.core.rust.model cimport book_order_signed_size
 from nautilus_trader.core.rust.model cimport instrument_id_from_cstr
+from nautilus_trader.core.rust.model cimport instrument_id_to_cstr
+from nautilus_trader.core.rust.model cimport market_statistic_type_to_cstr
+from nautilus_trader.core.rust.model cimport trade_id_to_cstr
 from nautilus_trader.core.rust.model cimport orderbook_delta_eq
 from nautilus_trader.core.rust.model cimport orderbook_delta_hash
 from nautilus_trader.core.rust.model cimport orderbook_delta_new
@@ -193,6 +202,71 @@ cdef inline Bar bar_from_mem_c(Bar_t mem):
     return bar
 
 
+cdef inline object mark_price_from_mem_c(MarkPriceUpdate_t mem):
+    return nautilus_pyo3.MarkPriceUpdate(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.Price.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object index_price_from_mem_c(IndexPriceUpdate_t mem):
+    return nautilus_pyo3.IndexPriceUpdate(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.Price.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object open_interest_from_mem_c(OpenInterestUpdate_t mem):
+    return nautilus_pyo3.OpenInterestUpdate(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.Quantity.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object liquidation_from_mem_c(Liquidation_t mem):
+    return nautilus_pyo3.Liquidation(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.OrderSide.from_str(order_side_to_str(mem.side)),
+        nautilus_pyo3.Price.from_raw(mem.price.raw, mem.price.precision),
+        nautilus_pyo3.Quantity.from_raw(mem.quantity.raw, mem.quantity.precision),
+        nautilus_pyo3.TradeId(cstr_to_pystr(trade_id_to_cstr(&mem.trade_id), False)),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object auction_imbalance_from_mem_c(AuctionImbalance_t mem):
+    return nautilus_pyo3.AuctionImbalance(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.Price.from_raw(mem.ref_price.raw, mem.ref_price.precision),
+        nautilus_pyo3.Price.from_raw(mem.near_price.raw, mem.near_price.precision),
+        nautilus_pyo3.Price.from_raw(mem.far_price.raw, mem.far_price.precision),
+        nautilus_pyo3.Quantity.from_raw(mem.paired_qty.raw, mem.paired_qty.precision),
+        nautilus_pyo3.Quantity.from_raw(mem.imbalance_qty.raw, mem.imbalance_qty.precision),
+        nautilus_pyo3.OrderSide.from_str(order_side_to_str(mem.side)),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object market_statistic_from_mem_c(MarketStatistic_t mem):
+    # An unpopulated price or quantity keeps its undefined sentinel raw value
+    return nautilus_pyo3.MarketStatistic(
+        nautilus_pyo3.InstrumentId.from_str(cstr_to_pystr(instrument_id_to_cstr(&mem.instrument_id))),
+        nautilus_pyo3.MarketStatisticType.from_str(cstr_to_pystr(market_statistic_type_to_cstr(mem.stat_type))),
+        nautilus_pyo3.Price.from_raw(mem.price.raw, mem.price.precision),
+        nautilus_pyo3.Quantity.from_raw(mem.quantity.raw, mem.quantity.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
 # SAFETY: Do NOT deallocate the capsule here
 cpdef list capsule_to_list(capsule):
     cdef CVec* data = <CVec*>PyCapsule_GetPointer(capsule, NULL)
@@ -213,12 +287,24 @@ cpdef list capsule_to_list(capsule):
             objects.append(trade_from_mem_c(ptr[i].trade))
         elif ptr[i].tag == Data_t_Tag.BAR:
             objects.append(bar_from_mem_c(ptr[i].bar))
+        elif ptr[i].tag == Data_t_Tag.MARK_PRICE_UPDATE:
+            objects.append(mark_price_from_mem_c(ptr[i].mark_price_update))
+        elif ptr[i].tag == Data_t_Tag.INDEX_PRICE_UPDATE:
+            objects.append(index_price_from_mem_c(ptr[i].index_price_update))
+        elif ptr[i].tag == Data_t_Tag.OPEN_INTEREST_UPDATE:
+            objects.append(open_interest_from_mem_c(ptr[i].open_interest_update))
+        elif ptr[i].tag == Data_t_Tag.LIQUIDATION:
+            objects.append(liquidation_from_mem_c(ptr[i].liquidation))
+        elif ptr[i].tag == Data_t_Tag.AUCTION_IMBALANCE:
+            objects.append(auction_imbalance_from_mem_c(ptr[i].auction_imbalance))
+        elif ptr[i].tag == Data_t_Tag.MARKET_STATISTIC:
+            objects.append(market_statistic_from_mem_c(ptr[i].market_statistic))
 
     return objects
 
 
 # SAFETY: Do NOT deallocate the capsule here
-cpdef Data capsule_to_data(capsule):
+cpdef object capsule_to_data(capsule):
     cdef Data_t* ptr = <Data_t*>PyCapsule_GetPointer(capsule, NULL)
 
     if ptr.tag == Data_t_Tag.DELTA:
@@ -233,6 +319,18 @@ cpdef Data capsule_to_data(capsule):
         return trade_from_mem_c(ptr.trade)
     elif ptr.tag == Data_t_Tag.BAR:
         return bar_from_mem_c(ptr.bar)
+    elif ptr.tag == Data_t_Tag.MARK_PRICE_UPDATE:
+        return mark_price_from_mem_c(ptr.mark_price_update)
+    elif ptr.tag == Data_t_Tag.INDEX_PRICE_UPDATE:
+        return index_price_from_mem_c(ptr.index_price_update)
+    elif ptr.tag == Data_t_Tag.OPEN_INTEREST_UPDATE:
+        return open_interest_from_mem_c(ptr.open_interest_update)
+    elif ptr.tag == Data_t_Tag.LIQUIDATION:
+        return liquidation_from_mem_c(ptr.liquidation)
+    elif ptr.tag == Data_t_Tag.AUCTION_IMBALANCE:
+        return auction_imbalance_from_mem_c(ptr.auction_imbalance)
+    elif ptr.tag == Data_t_Tag.MARKET_STATISTIC:
+        return market_statistic_from_mem_c(ptr.market_statistic)
     else:
         raise RuntimeError("Invalid data element to convert from `PyCapsule`")
 
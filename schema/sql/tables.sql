@@ -77,6 +77,7 @@ CREATE TABLE IF NOT EXISTS "instrument" (
     min_price TEXT,
     margin_init TEXT NOT NULL,
     margin_maint TEXT NOT NULL,
+    strategy_type TEXT,
     maker_fee TEXT NULL,
     taker_fee TEXT NULL,
     ts_event TEXT NOT NULL,
@@ -85,6 +86,17 @@ CREATE TABLE IF NOT EXISTS "instrument" (
     updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
 );
 
+CREATE TABLE IF NOT EXISTS "synthetic" (
+    id TEXT PRIMARY KEY NOT NULL,
+    price_precision INTEGER NOT NULL,
+    components TEXT[] NOT NULL,
+    formula TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
 CREATE TABLE IF NOT EXISTS "order" (
     id TEXT PRIMARY KEY NOT NULL,
     trader_id TEXT REFERENCES trader(id) ON DELETE CASCADE,
@@ -175,6 +187,7 @@ CREATE TABLE IF NOT EXISTS "order_event" (
     account_id TEXT,
     position_id TEXT,
     commission TEXT,
+    reason TEXT,
     tags TEXT[],
     ts_event TEXT NOT NULL,
     ts_init TEXT NOT NULL,
@@ -213,6 +226,20 @@ CREATE TABLE IF NOT EXISTS "position"(
     updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
 );
 
+CREATE TABLE IF NOT EXISTS "index_order_position" (
+    client_order_id TEXT PRIMARY KEY NOT NULL,
+    position_id TEXT NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS "index_venue_order_id" (
+    client_order_id TEXT PRIMARY KEY NOT NULL,
+    venue_order_id TEXT NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
 CREATE TABLE IF NOT EXISTS "account_event"(
     id TEXT PRIMARY KEY NOT NULL,
     kind TEXT NOT NULL,
@@ -291,3 +318,27 @@ CREATE TABLE IF NOT EXISTS "custom" (
     created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
 );
+
+CREATE TABLE IF NOT EXISTS "actor_state" (
+    actor_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value BYTEA NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (actor_id, key)
+);
+
+CREATE TABLE IF NOT EXISTS "strategy_state" (
+    strategy_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value BYTEA NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (strategy_id, key)
+);
+
+CREATE TABLE IF NOT EXISTS "heartbeat" (
+    id TEXT PRIMARY KEY NOT NULL,
+    ts TEXT NOT NULL,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);